@@ -0,0 +1,60 @@
+-- Add migration script here
+
+create table if not exists customer_order (
+    id uuid,
+    created_at timestamptz not null,
+    updated_at timestamptz not null,
+
+    constraint pk_customer_order primary key (id)
+);
+
+-- products and extras are snapshotted, so there are no foreign keys to them,
+-- otherwise deleting a product would rewrite the order history
+create table if not exists order_item (
+    id uuid,
+    order_id uuid not null,
+    position smallint not null,
+    product_id uuid not null,
+    catalog_id uuid not null,
+    name varchar(64) not null,
+    price decimal(20, 2) not null,
+    quantity smallint not null,
+
+    constraint pk_order_item primary key (id),
+    constraint ak_order_item_position unique (order_id, position),
+    constraint fk_order_item_order_id
+        foreign key (order_id) references customer_order (id) on delete cascade
+);
+
+create table if not exists order_item_extra (
+    order_item_id uuid,
+    extra_id uuid,
+    name varchar(128) not null,
+    price decimal(20, 2) not null,
+
+    constraint pk_order_item_extra primary key (order_item_id, extra_id),
+    constraint fk_order_item_extra_order_item_id
+        foreign key (order_item_id) references order_item (id) on delete cascade
+);
+
+create or replace view order_item_with_extras as
+select
+    item.*,
+    coalesce(
+        (select jsonb_agg(extra.*)
+        from order_item_extra as extra
+        where extra.order_item_id = item.id),
+        '[]'::jsonb
+    ) as extras
+from order_item as item;
+
+create or replace view order_with_items as
+select
+    customer_order.*,
+    coalesce(
+        (select jsonb_agg(item.* order by item.position)
+        from order_item_with_extras as item
+        where item.order_id = customer_order.id),
+        '[]'::jsonb
+    ) as items
+from customer_order;
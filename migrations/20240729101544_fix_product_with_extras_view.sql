@@ -0,0 +1,14 @@
+-- Add migration script here
+
+-- extras were not joined by their id, so every product reported all extras
+create or replace view product_with_extras as
+select 
+    product.*,
+    coalesce(
+        (select jsonb_agg(extra.*)
+        from extra
+        inner join product_extras as pe on pe.extra_id = extra.id
+        where pe.product_id = product.id),
+        '[]'::jsonb
+    ) as extras
+from product;
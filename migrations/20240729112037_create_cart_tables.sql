@@ -0,0 +1,63 @@
+-- Add migration script here
+
+create table if not exists cart (
+    id uuid,
+    created_at timestamptz not null,
+    updated_at timestamptz not null,
+
+    constraint pk_cart primary key (id)
+);
+
+create table if not exists cart_item (
+    id uuid,
+    cart_id uuid not null,
+    product_id uuid not null,
+    quantity smallint not null,
+
+    constraint pk_cart_item primary key (id),
+    constraint fk_cart_item_cart_id
+        foreign key (cart_id) references cart (id) on delete cascade,
+    constraint fk_cart_item_product_id
+        foreign key (product_id) references product (id) on delete cascade
+);
+
+-- referencing product_extras guarantees a selected extra is bound to the
+-- product, and unbinding it from the product removes it from every cart
+create table if not exists cart_item_extra (
+    cart_item_id uuid,
+    product_id uuid not null,
+    extra_id uuid,
+
+    constraint pk_cart_item_extra primary key (cart_item_id, extra_id),
+    constraint fk_cart_item_extra_cart_item_id
+        foreign key (cart_item_id) references cart_item (id) on delete cascade,
+    constraint fk_cart_item_extra_product_extras
+        foreign key (product_id, extra_id)
+        references product_extras (product_id, extra_id) on delete cascade
+);
+
+create or replace view cart_item_with_product as
+select
+    item.id,
+    item.cart_id,
+    item.quantity,
+    to_jsonb(product.*) as product,
+    coalesce(
+        (select jsonb_agg(cie.extra_id)
+        from cart_item_extra as cie
+        where cie.cart_item_id = item.id),
+        '[]'::jsonb
+    ) as extras_ids
+from cart_item as item
+inner join product_with_extras as product on product.id = item.product_id;
+
+create or replace view cart_with_items as
+select
+    cart.*,
+    coalesce(
+        (select jsonb_agg(item.* order by item.id)
+        from cart_item_with_product as item
+        where item.cart_id = cart.id),
+        '[]'::jsonb
+    ) as items
+from cart;
@@ -0,0 +1,47 @@
+-- Add migration script here
+
+create table if not exists ticket (
+    id uuid,
+    product_id uuid not null,
+    status varchar(16) not null,
+    received_at timestamptz not null,
+    preparing_at timestamptz,
+    ready_at timestamptz,
+    handed_over_at timestamptz,
+    cancelled_at timestamptz,
+    created_at timestamptz not null,
+    updated_at timestamptz not null,
+
+    constraint pk_ticket primary key (id),
+    constraint fk_ticket_product_id
+        foreign key (product_id) references product (id) on delete cascade
+);
+
+create index if not exists idx_ticket_status on ticket (status);
+
+create table if not exists ticket_extra (
+    ticket_id uuid,
+    product_id uuid not null,
+    extra_id uuid,
+
+    constraint pk_ticket_extra primary key (ticket_id, extra_id),
+    constraint fk_ticket_extra_ticket_id
+        foreign key (ticket_id) references ticket (id) on delete cascade,
+    constraint fk_ticket_extra_product_extras
+        foreign key (product_id, extra_id)
+        references product_extras (product_id, extra_id) on delete cascade
+);
+
+create or replace view ticket_with_product as
+select
+    ticket.*,
+    (select to_jsonb(product.*)
+    from product_with_extras as product
+    where product.id = ticket.product_id) as product,
+    coalesce(
+        (select jsonb_agg(te.extra_id)
+        from ticket_extra as te
+        where te.ticket_id = ticket.id),
+        '[]'::jsonb
+    ) as extras_ids
+from ticket;
@@ -0,0 +1,13 @@
+-- Add migration script here
+
+create table if not exists exchange_rate (
+    from_currency char(3),
+    to_currency char(3),
+    rate decimal(20, 10) not null,
+    created_at timestamptz not null,
+    updated_at timestamptz not null,
+
+    constraint pk_exchange_rate primary key (from_currency, to_currency),
+    constraint ck_exchange_rate_rate check (rate > 0),
+    constraint ck_exchange_rate_currencies check (from_currency <> to_currency)
+);
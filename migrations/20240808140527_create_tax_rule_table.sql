@@ -0,0 +1,21 @@
+-- Add migration script here
+
+-- a rule targets either every product of a kind or every product of a
+-- catalog, and at most one rule exists for each target
+create table if not exists tax_rule (
+    id uuid,
+    name varchar(64) not null,
+    kind varchar(32),
+    catalog_id uuid,
+    rate decimal(7, 4) not null,
+    mode varchar(16) not null,
+    created_at timestamptz not null,
+    updated_at timestamptz not null,
+
+    constraint pk_tax_rule primary key (id),
+    constraint ak_tax_rule_kind unique (kind),
+    constraint ak_tax_rule_catalog_id unique (catalog_id),
+    constraint fk_tax_rule_catalog_id
+        foreign key (catalog_id) references catalog (id) on delete cascade,
+    constraint ck_tax_rule_target check ((kind is null) <> (catalog_id is null))
+);
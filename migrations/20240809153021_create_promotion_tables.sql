@@ -0,0 +1,65 @@
+-- Add migration script here
+
+-- fixed discounts carry the currency of their value, while percentage
+-- discounts apply to prices in any currency
+create table if not exists promotion (
+    id uuid,
+    name varchar(64) not null,
+    discount_kind varchar(16) not null,
+    discount_value decimal(19, 4) not null,
+    currency char(3),
+    priority smallint not null,
+    starts_at timestamptz,
+    ends_at timestamptz,
+    created_at timestamptz not null,
+    updated_at timestamptz not null,
+
+    constraint pk_promotion primary key (id),
+    constraint ck_promotion_discount check (
+        (discount_kind = 'percentage' and currency is null)
+        or (discount_kind = 'fixed' and currency is not null)
+    ),
+    constraint ck_promotion_validity check (
+        starts_at is null or ends_at is null or starts_at < ends_at
+    )
+);
+
+-- each row targets exactly one of a catalog, a kind, a product or an extra
+create table if not exists promotion_target (
+    promotion_id uuid not null,
+    catalog_id uuid,
+    kind varchar(32),
+    product_id uuid,
+    extra_id uuid,
+
+    constraint fk_promotion_target_promotion_id
+        foreign key (promotion_id) references promotion (id) on delete cascade,
+    constraint fk_promotion_target_catalog_id
+        foreign key (catalog_id) references catalog (id) on delete cascade,
+    constraint fk_promotion_target_product_id
+        foreign key (product_id) references product (id) on delete cascade,
+    constraint fk_promotion_target_extra_id
+        foreign key (extra_id) references extra (id) on delete cascade,
+    constraint ck_promotion_target check (
+        num_nonnulls(catalog_id, kind, product_id, extra_id) = 1
+    )
+);
+
+create index if not exists ix_promotion_target_promotion_id
+    on promotion_target (promotion_id);
+
+create or replace view promotion_with_targets as
+select
+    promotion.*,
+    coalesce(
+        (select jsonb_agg(jsonb_build_object(
+            'catalog_id', pt.catalog_id,
+            'kind', pt.kind,
+            'product_id', pt.product_id,
+            'extra_id', pt.extra_id
+        ))
+        from promotion_target as pt
+        where pt.promotion_id = promotion.id),
+        '[]'::jsonb
+    ) as targets
+from promotion;
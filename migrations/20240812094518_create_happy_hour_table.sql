@@ -0,0 +1,25 @@
+-- Add migration script here
+
+-- weekdays is a bit set starting from monday, and the window times are local
+-- to the fixed utc offset, stored in seconds
+create table if not exists happy_hour (
+    id uuid,
+    catalog_id uuid not null,
+    name varchar(64) not null,
+    kind varchar(32),
+    percentage decimal(5, 2) not null,
+    weekdays smallint not null,
+    starts_at time not null,
+    ends_at time not null,
+    utc_offset integer not null,
+    created_at timestamptz not null,
+    updated_at timestamptz not null,
+
+    constraint pk_happy_hour primary key (id),
+    constraint fk_happy_hour_catalog_id
+        foreign key (catalog_id) references catalog (id) on delete cascade,
+    constraint ck_happy_hour_weekdays check (weekdays between 1 and 127),
+    constraint ck_happy_hour_window check (starts_at <> ends_at)
+);
+
+create index if not exists ix_happy_hour_catalog_id on happy_hour (catalog_id);
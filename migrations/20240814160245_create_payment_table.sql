@@ -0,0 +1,18 @@
+-- Add migration script here
+
+-- every checkout attempt is recorded, even when the provider declines it, so
+-- provider records can be reconciled through the reference
+create table if not exists payment (
+    id uuid,
+    amount decimal(19, 4) not null,
+    currency char(3) not null,
+    provider varchar(32) not null,
+    status varchar(16) not null,
+    reference varchar(128),
+    failure varchar(256),
+    created_at timestamptz not null,
+    updated_at timestamptz not null,
+
+    constraint pk_payment primary key (id),
+    constraint ak_payment_provider_reference unique (provider, reference)
+);
@@ -0,0 +1,105 @@
+-- Add migration script here
+
+create table if not exists sale (
+    id uuid,
+    created_at timestamptz not null,
+    updated_at timestamptz not null,
+
+    constraint pk_sale primary key (id)
+);
+
+-- products and extras are snapshotted, so there are no foreign keys to them,
+-- otherwise deleting a product would rewrite the sales ledger
+create table if not exists sale_line (
+    id uuid,
+    sale_id uuid not null,
+    position smallint not null,
+    product_id uuid not null,
+    catalog_id uuid not null,
+    name varchar(64) not null,
+    price decimal(20, 3) not null,
+    currency char(3) not null,
+    quantity smallint not null,
+
+    constraint pk_sale_line primary key (id),
+    constraint ak_sale_line_position unique (sale_id, position),
+    constraint fk_sale_line_sale_id
+        foreign key (sale_id) references sale (id) on delete cascade
+);
+
+create table if not exists sale_line_extra (
+    sale_line_id uuid,
+    extra_id uuid,
+    name varchar(128) not null,
+    price decimal(20, 3) not null,
+    currency char(3) not null,
+
+    constraint pk_sale_line_extra primary key (sale_line_id, extra_id),
+    constraint fk_sale_line_extra_sale_line_id
+        foreign key (sale_line_id) references sale_line (id) on delete cascade
+);
+
+create table if not exists sale_refund (
+    id uuid,
+    sale_id uuid not null,
+    reason varchar(256) not null,
+    amount decimal(20, 3) not null,
+    currency char(3) not null,
+    created_at timestamptz not null,
+
+    constraint pk_sale_refund primary key (id),
+    constraint fk_sale_refund_sale_id
+        foreign key (sale_id) references sale (id) on delete cascade
+);
+
+create table if not exists sale_refund_line (
+    refund_id uuid,
+    sale_line_id uuid,
+    quantity smallint not null,
+
+    constraint pk_sale_refund_line primary key (refund_id, sale_line_id),
+    constraint fk_sale_refund_line_refund_id
+        foreign key (refund_id) references sale_refund (id) on delete cascade,
+    constraint fk_sale_refund_line_sale_line_id
+        foreign key (sale_line_id) references sale_line (id) on delete cascade,
+    constraint ck_sale_refund_line_quantity check (quantity > 0)
+);
+
+create or replace view sale_line_with_extras as
+select
+    line.*,
+    coalesce(
+        (select jsonb_agg(extra.*)
+        from sale_line_extra as extra
+        where extra.sale_line_id = line.id),
+        '[]'::jsonb
+    ) as extras
+from sale_line as line;
+
+create or replace view sale_refund_with_lines as
+select
+    refund.*,
+    coalesce(
+        (select jsonb_agg(refund_line.*)
+        from sale_refund_line as refund_line
+        where refund_line.refund_id = refund.id),
+        '[]'::jsonb
+    ) as lines
+from sale_refund as refund;
+
+create or replace view sale_with_lines as
+select
+    sale.*,
+    coalesce(
+        (select jsonb_agg(line.* order by line.position)
+        from sale_line_with_extras as line
+        where line.sale_id = sale.id),
+        '[]'::jsonb
+    ) as lines,
+    coalesce(
+        (select jsonb_agg(refund.* order by refund.created_at)
+        from sale_refund_with_lines as refund
+        where refund.sale_id = sale.id),
+        '[]'::jsonb
+    ) as refunds
+from sale;
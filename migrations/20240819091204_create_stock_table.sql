@@ -0,0 +1,14 @@
+-- Add migration script here
+
+-- products without a stock row are not tracked, being always available
+create table if not exists stock (
+    product_id uuid,
+    level integer not null,
+    created_at timestamptz not null,
+    updated_at timestamptz not null,
+
+    constraint pk_stock primary key (product_id),
+    constraint fk_stock_product_id
+        foreign key (product_id) references product (id) on delete cascade,
+    constraint ck_stock_level check (level >= 0)
+);
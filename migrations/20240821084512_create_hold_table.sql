@@ -0,0 +1,19 @@
+-- Add migration script here
+
+-- units reserved from a product stock level until `expires_at`, so the
+-- available quantity of a product is its stock level minus unexpired holds
+create table if not exists hold (
+    id uuid,
+    product_id uuid not null,
+    quantity integer not null,
+    expires_at timestamptz not null,
+    created_at timestamptz not null,
+    updated_at timestamptz not null,
+
+    constraint pk_hold primary key (id),
+    constraint fk_hold_product_id
+        foreign key (product_id) references stock (product_id) on delete cascade,
+    constraint ck_hold_quantity check (quantity > 0)
+);
+
+create index if not exists idx_hold_product_id_expires_at on hold (product_id, expires_at);
\ No newline at end of file
@@ -0,0 +1,43 @@
+-- Add migration script here
+
+-- level is measured in the ingredient unit, such as grams of beef
+create table if not exists ingredient (
+    id uuid,
+    name varchar(64) not null,
+    unit varchar(16) not null,
+    level integer not null,
+    created_at timestamptz not null,
+    updated_at timestamptz not null,
+
+    constraint pk_ingredient primary key (id),
+    constraint ak_ingredient_name unique (name),
+    constraint ck_ingredient_level check (level >= 0)
+);
+
+-- amount of each ingredient used to prepare a single unit of product
+create table if not exists product_ingredient (
+    product_id uuid,
+    ingredient_id uuid,
+    amount integer not null,
+
+    constraint pk_product_ingredient primary key (product_id, ingredient_id),
+    constraint fk_product_ingredient_product_id
+        foreign key (product_id) references product (id) on delete cascade,
+    constraint fk_product_ingredient_ingredient_id
+        foreign key (ingredient_id) references ingredient (id) on delete cascade,
+    constraint ck_product_ingredient_amount check (amount > 0)
+);
+
+-- amount of each ingredient used to prepare a single unit of extra
+create table if not exists extra_ingredient (
+    extra_id uuid,
+    ingredient_id uuid,
+    amount integer not null,
+
+    constraint pk_extra_ingredient primary key (extra_id, ingredient_id),
+    constraint fk_extra_ingredient_extra_id
+        foreign key (extra_id) references extra (id) on delete cascade,
+    constraint fk_extra_ingredient_ingredient_id
+        foreign key (ingredient_id) references ingredient (id) on delete cascade,
+    constraint ck_extra_ingredient_amount check (amount > 0)
+);
\ No newline at end of file
@@ -0,0 +1,79 @@
+-- Add migration script here
+
+-- views expand `*` when created, so every view depending on the product table
+-- has to be recreated to expose the new availability columns
+drop view if exists
+    catalog_with_products,
+    cart_with_items,
+    cart_item_with_product,
+    ticket_with_product,
+    product_with_extras;
+
+-- schedule holds weekly windows as weekday bit sets with times in seconds
+-- from midnight, plus optional season dates, all local to its utc offset
+alter table product
+    add column available boolean not null default true,
+    add column schedule jsonb;
+
+create or replace view product_with_extras as
+select 
+    product.*,
+    coalesce(
+        (select jsonb_agg(extra.*)
+        from extra
+        inner join product_extras as pe on pe.extra_id = extra.id
+        where pe.product_id = product.id),
+        '[]'::jsonb
+    ) as extras
+from product;
+
+create or replace view catalog_with_products as
+select
+    catalog.*,
+    coalesce(
+        (select jsonb_agg(product.*)
+        from product_with_extras as product
+        where product.catalog_id = catalog.id),
+        '[]'::jsonb
+    ) as products
+from catalog;
+
+create or replace view cart_item_with_product as
+select
+    item.id,
+    item.cart_id,
+    item.quantity,
+    to_jsonb(product.*) as product,
+    coalesce(
+        (select jsonb_agg(cie.extra_id)
+        from cart_item_extra as cie
+        where cie.cart_item_id = item.id),
+        '[]'::jsonb
+    ) as extras_ids
+from cart_item as item
+inner join product_with_extras as product on product.id = item.product_id;
+
+create or replace view cart_with_items as
+select
+    cart.*,
+    coalesce(
+        (select jsonb_agg(item.* order by item.id)
+        from cart_item_with_product as item
+        where item.cart_id = cart.id),
+        '[]'::jsonb
+    ) as items
+from cart;
+
+create or replace view ticket_with_product as
+select
+    ticket.*,
+    (select to_jsonb(product.*)
+    from product_with_extras as product
+    where product.id = ticket.product_id) as product,
+    coalesce(
+        (select jsonb_agg(te.extra_id)
+        from ticket_extra as te
+        where te.ticket_id = ticket.id),
+        '[]'::jsonb
+    ) as extras_ids
+from ticket;
\ No newline at end of file
@@ -0,0 +1,20 @@
+-- Add migration script here
+
+-- hours holds weekly windows in the same format as product schedules, plus
+-- closure dates, all local to its utc offset
+alter table catalog add column hours jsonb;
+
+-- views expand `*` when created, so the view has to be recreated to expose
+-- the new hours column
+drop view if exists catalog_with_products;
+
+create view catalog_with_products as
+select
+    catalog.*,
+    coalesce(
+        (select jsonb_agg(product.*)
+        from product_with_extras as product
+        where product.catalog_id = catalog.id),
+        '[]'::jsonb
+    ) as products
+from catalog;
\ No newline at end of file
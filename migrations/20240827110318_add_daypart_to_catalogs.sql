@@ -0,0 +1,52 @@
+-- Add migration script here
+
+-- daypart holds weekly windows in the same format as product schedules, all
+-- local to its utc offset, and catalogs without one are always active
+alter table catalog add column daypart jsonb;
+
+-- mirrors `catalog::Daypart::contains`, where a window ending before it
+-- starts spans midnight and weekday bits start from monday
+create function catalog_is_active(daypart jsonb, active_at timestamptz)
+returns boolean
+language sql
+immutable
+as $$
+    select daypart is null or exists (
+        select 1
+        from jsonb_array_elements(daypart -> 'windows') as daypart_window(value),
+        lateral (
+            select (active_at at time zone 'UTC')
+                + make_interval(secs => (daypart ->> 'utc_offset')::int) as local_at
+        ) as localized,
+        lateral (
+            select
+                extract(isodow from localized.local_at)::int - 1 as day,
+                floor(extract(epoch from localized.local_at::time))::int as seconds,
+                (daypart_window.value ->> 'weekdays')::int as weekdays,
+                (daypart_window.value ->> 'starts_at')::int as starts_at,
+                (daypart_window.value ->> 'ends_at')::int as ends_at
+        ) as w
+        where
+            (w.weekdays & (1 << w.day) <> 0
+                and w.starts_at <= w.seconds
+                and (w.ends_at < w.starts_at or w.seconds < w.ends_at))
+            or (w.ends_at < w.starts_at
+                and w.weekdays & (1 << ((w.day + 6) % 7)) <> 0
+                and w.seconds < w.ends_at)
+    )
+$$;
+
+-- views expand `*` when created, so the view has to be recreated to expose
+-- the new daypart column
+drop view if exists catalog_with_products;
+
+create view catalog_with_products as
+select
+    catalog.*,
+    coalesce(
+        (select jsonb_agg(product.*)
+        from product_with_extras as product
+        where product.catalog_id = catalog.id),
+        '[]'::jsonb
+    ) as products
+from catalog;
\ No newline at end of file
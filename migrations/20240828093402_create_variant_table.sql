@@ -0,0 +1,97 @@
+-- Add migration script here
+
+create table if not exists variant (
+    id uuid,
+    product_id uuid not null,
+    name varchar(32) not null,
+    pricing varchar(8) not null,
+    price decimal(20, 3) not null,
+    currency char(3) not null,
+    created_at timestamptz not null,
+    updated_at timestamptz not null,
+
+    constraint pk_variant primary key (id),
+    constraint fk_variant_product_id
+        foreign key (product_id) references product (id) on delete cascade,
+    constraint ak_variant_product_id_name unique (product_id, name),
+    constraint ck_variant_pricing check (pricing in ('fixed', 'delta')),
+    constraint ck_variant_price check (price >= 0)
+);
+
+-- views expand `*` when created, so every view depending on product_with_extras
+-- has to be recreated to expose the new variants column
+drop view if exists
+    catalog_with_products,
+    cart_with_items,
+    cart_item_with_product,
+    ticket_with_product,
+    product_with_extras;
+
+create or replace view product_with_extras as
+select 
+    product.*,
+    coalesce(
+        (select jsonb_agg(extra.*)
+        from extra
+        inner join product_extras as pe on pe.extra_id = extra.id
+        where pe.product_id = product.id),
+        '[]'::jsonb
+    ) as extras,
+    coalesce(
+        (select jsonb_agg(variant.* order by variant.created_at)
+        from variant
+        where variant.product_id = product.id),
+        '[]'::jsonb
+    ) as variants
+from product;
+
+create or replace view catalog_with_products as
+select
+    catalog.*,
+    coalesce(
+        (select jsonb_agg(product.*)
+        from product_with_extras as product
+        where product.catalog_id = catalog.id),
+        '[]'::jsonb
+    ) as products
+from catalog;
+
+create or replace view cart_item_with_product as
+select
+    item.id,
+    item.cart_id,
+    item.quantity,
+    to_jsonb(product.*) as product,
+    coalesce(
+        (select jsonb_agg(cie.extra_id)
+        from cart_item_extra as cie
+        where cie.cart_item_id = item.id),
+        '[]'::jsonb
+    ) as extras_ids
+from cart_item as item
+inner join product_with_extras as product on product.id = item.product_id;
+
+create or replace view cart_with_items as
+select
+    cart.*,
+    coalesce(
+        (select jsonb_agg(item.* order by item.id)
+        from cart_item_with_product as item
+        where item.cart_id = cart.id),
+        '[]'::jsonb
+    ) as items
+from cart;
+
+create or replace view ticket_with_product as
+select
+    ticket.*,
+    (select to_jsonb(product.*)
+    from product_with_extras as product
+    where product.id = ticket.product_id) as product,
+    coalesce(
+        (select jsonb_agg(te.extra_id)
+        from ticket_extra as te
+        where te.ticket_id = ticket.id),
+        '[]'::jsonb
+    ) as extras_ids
+from ticket;
\ No newline at end of file
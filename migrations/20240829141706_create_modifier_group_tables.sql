@@ -0,0 +1,131 @@
+-- Add migration script here
+
+create table if not exists modifier_group (
+    id uuid,
+    product_id uuid not null,
+    name varchar(32) not null,
+    min_selections smallint not null,
+    max_selections smallint not null,
+    created_at timestamptz not null,
+    updated_at timestamptz not null,
+
+    constraint pk_modifier_group primary key (id),
+    constraint fk_modifier_group_product_id
+        foreign key (product_id) references product (id) on delete cascade,
+    constraint ak_modifier_group_id_product_id unique (id, product_id),
+    constraint ak_modifier_group_product_id_name unique (product_id, name),
+    constraint ck_modifier_group_selections
+        check (min_selections >= 0 and max_selections >= 1 and min_selections <= max_selections)
+);
+
+-- referencing product_extras guarantees a group only offers extras bound to
+-- its product, while the unique key keeps an extra in a single group
+create table if not exists modifier_group_extra (
+    group_id uuid,
+    product_id uuid not null,
+    extra_id uuid,
+    position smallint not null,
+
+    constraint pk_modifier_group_extra primary key (group_id, extra_id),
+    constraint fk_modifier_group_extra_group
+        foreign key (group_id, product_id)
+        references modifier_group (id, product_id) on delete cascade,
+    constraint fk_modifier_group_extra_product_extras
+        foreign key (product_id, extra_id)
+        references product_extras (product_id, extra_id) on delete cascade,
+    constraint ak_modifier_group_extra_product_id_extra_id unique (product_id, extra_id)
+);
+
+-- views expand `*` when created, so every view depending on product_with_extras
+-- has to be recreated to expose the new modifiers column
+drop view if exists
+    catalog_with_products,
+    cart_with_items,
+    cart_item_with_product,
+    ticket_with_product,
+    product_with_extras;
+
+create or replace view product_with_extras as
+select 
+    product.*,
+    coalesce(
+        (select jsonb_agg(extra.*)
+        from extra
+        inner join product_extras as pe on pe.extra_id = extra.id
+        where pe.product_id = product.id),
+        '[]'::jsonb
+    ) as extras,
+    coalesce(
+        (select jsonb_agg(variant.* order by variant.created_at)
+        from variant
+        where variant.product_id = product.id),
+        '[]'::jsonb
+    ) as variants,
+    coalesce(
+        (select jsonb_agg(
+            to_jsonb(mg.*) || jsonb_build_object(
+                'extras_ids',
+                coalesce(
+                    (select jsonb_agg(mge.extra_id order by mge.position)
+                    from modifier_group_extra as mge
+                    where mge.group_id = mg.id),
+                    '[]'::jsonb
+                )
+            )
+            order by mg.created_at)
+        from modifier_group as mg
+        where mg.product_id = product.id),
+        '[]'::jsonb
+    ) as modifiers
+from product;
+
+create or replace view catalog_with_products as
+select
+    catalog.*,
+    coalesce(
+        (select jsonb_agg(product.*)
+        from product_with_extras as product
+        where product.catalog_id = catalog.id),
+        '[]'::jsonb
+    ) as products
+from catalog;
+
+create or replace view cart_item_with_product as
+select
+    item.id,
+    item.cart_id,
+    item.quantity,
+    to_jsonb(product.*) as product,
+    coalesce(
+        (select jsonb_agg(cie.extra_id)
+        from cart_item_extra as cie
+        where cie.cart_item_id = item.id),
+        '[]'::jsonb
+    ) as extras_ids
+from cart_item as item
+inner join product_with_extras as product on product.id = item.product_id;
+
+create or replace view cart_with_items as
+select
+    cart.*,
+    coalesce(
+        (select jsonb_agg(item.* order by item.id)
+        from cart_item_with_product as item
+        where item.cart_id = cart.id),
+        '[]'::jsonb
+    ) as items
+from cart;
+
+create or replace view ticket_with_product as
+select
+    ticket.*,
+    (select to_jsonb(product.*)
+    from product_with_extras as product
+    where product.id = ticket.product_id) as product,
+    coalesce(
+        (select jsonb_agg(te.extra_id)
+        from ticket_extra as te
+        where te.ticket_id = ticket.id),
+        '[]'::jsonb
+    ) as extras_ids
+from ticket;
\ No newline at end of file
@@ -0,0 +1,48 @@
+-- Add migration script here
+
+alter table product_extras
+    add column if not exists override_price decimal(20, 3),
+    add column if not exists override_currency char(3),
+    add constraint ck_product_extras_override
+        check ((override_price is null) = (override_currency is null)),
+    add constraint ck_product_extras_override_price check (override_price >= 0);
+
+-- columns of the view are kept, so views depending on it need no recreation
+create or replace view product_with_extras as
+select 
+    product.*,
+    coalesce(
+        (select jsonb_agg(
+            to_jsonb(extra.*) || jsonb_build_object(
+                'max_quantity', pe.max_quantity,
+                'override_price', pe.override_price,
+                'override_currency', pe.override_currency
+            ))
+        from extra
+        inner join product_extras as pe on pe.extra_id = extra.id
+        where pe.product_id = product.id),
+        '[]'::jsonb
+    ) as extras,
+    coalesce(
+        (select jsonb_agg(variant.* order by variant.created_at)
+        from variant
+        where variant.product_id = product.id),
+        '[]'::jsonb
+    ) as variants,
+    coalesce(
+        (select jsonb_agg(
+            to_jsonb(mg.*) || jsonb_build_object(
+                'extras_ids',
+                coalesce(
+                    (select jsonb_agg(mge.extra_id order by mge.position)
+                    from modifier_group_extra as mge
+                    where mge.group_id = mg.id),
+                    '[]'::jsonb
+                )
+            )
+            order by mg.created_at)
+        from modifier_group as mg
+        where mg.product_id = product.id),
+        '[]'::jsonb
+    ) as modifiers
+from product;
\ No newline at end of file
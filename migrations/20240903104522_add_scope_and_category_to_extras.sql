@@ -0,0 +1,11 @@
+-- Add migration script here
+
+alter table extra
+    add column if not exists catalog_id uuid,
+    add column if not exists category varchar(16),
+    add constraint fk_extra_catalog_id
+        foreign key (catalog_id) references catalog (id) on delete cascade,
+    add constraint ck_extra_category
+        check (category in ('sauce', 'side', 'topping')),
+    drop constraint if exists ak_extra_name,
+    add constraint ak_extra_scope_name unique nulls not distinct (catalog_id, name);
\ No newline at end of file
@@ -0,0 +1,79 @@
+-- Add migration script here
+
+-- schedules used to store a fixed utc offset, which drifts by an hour across
+-- daylight saving time, so they now store an iana time zone name instead.
+-- existing offsets map to the fixed `Etc/GMT` zones, whose signs are inverted
+create function pg_temp.offset_time_zone(utc_offset integer)
+returns varchar
+language plpgsql
+immutable
+as $$
+begin
+    if utc_offset % 3600 <> 0 or utc_offset not between -43200 and 50400 then
+        raise exception 'utc offset % has no matching time zone', utc_offset;
+    end if;
+
+    return case
+        when utc_offset = 0 then 'UTC'
+        when utc_offset < 0 then 'Etc/GMT+' || (-utc_offset / 3600)
+        else 'Etc/GMT-' || (utc_offset / 3600)
+    end;
+end
+$$;
+
+alter table happy_hour add column time_zone varchar(64);
+
+update happy_hour set time_zone = pg_temp.offset_time_zone(utc_offset);
+
+alter table happy_hour
+    alter column time_zone set not null,
+    drop column utc_offset;
+
+update product
+set schedule = (schedule - 'utc_offset') || jsonb_build_object(
+    'time_zone', pg_temp.offset_time_zone((schedule ->> 'utc_offset')::int)
+)
+where schedule is not null;
+
+update catalog
+set hours = (hours - 'utc_offset') || jsonb_build_object(
+    'time_zone', pg_temp.offset_time_zone((hours ->> 'utc_offset')::int)
+)
+where hours is not null;
+
+update catalog
+set daypart = (daypart - 'utc_offset') || jsonb_build_object(
+    'time_zone', pg_temp.offset_time_zone((daypart ->> 'utc_offset')::int)
+)
+where daypart is not null;
+
+-- time zone rules may change with the server's tz database, so the function
+-- is only stable from now on
+create or replace function catalog_is_active(daypart jsonb, active_at timestamptz)
+returns boolean
+language sql
+stable
+as $$
+    select daypart is null or exists (
+        select 1
+        from jsonb_array_elements(daypart -> 'windows') as daypart_window(value),
+        lateral (
+            select active_at at time zone (daypart ->> 'time_zone') as local_at
+        ) as localized,
+        lateral (
+            select
+                extract(isodow from localized.local_at)::int - 1 as day,
+                floor(extract(epoch from localized.local_at::time))::int as seconds,
+                (daypart_window.value ->> 'weekdays')::int as weekdays,
+                (daypart_window.value ->> 'starts_at')::int as starts_at,
+                (daypart_window.value ->> 'ends_at')::int as ends_at
+        ) as w
+        where
+            (w.weekdays & (1 << w.day) <> 0
+                and w.starts_at <= w.seconds
+                and (w.ends_at < w.starts_at or w.seconds < w.ends_at))
+            or (w.ends_at < w.starts_at
+                and w.weekdays & (1 << ((w.day + 6) % 7)) <> 0
+                and w.seconds < w.ends_at)
+    )
+$$;
\ No newline at end of file
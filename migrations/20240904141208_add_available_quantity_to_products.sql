@@ -0,0 +1,13 @@
+-- Add migration script here
+
+-- units of a product neither consumed nor held, recomputed under the stock row
+-- lock whenever its level or holds change, and null while stock is untracked
+alter table product add column if not exists available_quantity integer;
+
+update product
+set available_quantity = greatest(
+    stock.level - (select coalesce(sum(hold.quantity), 0) from hold where hold.product_id = stock.product_id),
+    0
+)
+from stock
+where stock.product_id = product.id;
\ No newline at end of file
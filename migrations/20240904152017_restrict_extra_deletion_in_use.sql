@@ -0,0 +1,8 @@
+-- Add migration script here
+
+-- deleting an extra offered by products used to silently unbind it from all of
+-- them, so now it must be unbound explicitly in the same transaction instead
+alter table product_extras
+    drop constraint if exists fk_product_extras_extra_id,
+    add constraint fk_product_extras_extra_id
+        foreign key (extra_id) references extra (id) on delete restrict;
\ No newline at end of file
@@ -0,0 +1,5 @@
+-- Add migration script here
+
+-- available quantity of products was kept in sync but never read, since it is
+-- always counted from stock level and held units instead
+alter table product drop column if exists available_quantity;
\ No newline at end of file
@@ -1,9 +1,33 @@
+pub mod cart;
 pub mod catalog;
+pub mod exchange;
 pub mod extra;
+pub mod happy_hour;
+pub mod hold;
+pub mod ingredient;
+pub mod order;
+pub mod payment;
 pub mod product;
+pub mod promotion;
+pub mod sale;
+pub mod stock;
+pub mod tax;
+pub mod ticket;
 
-use serde::Serialize;
-use time::OffsetDateTime;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use time::format_description::BorrowedFormatItem;
+use time::macros::format_description;
+use time::{Date, OffsetDateTime, Time};
+
+use domain::core::money::{Money, ParseMoneyError};
+use domain::core::schedule;
+
+/// Format of schedule window times, such as `17:30`
+pub(crate) const TIME_FORMAT: &[BorrowedFormatItem<'_>] = format_description!("[hour]:[minute]");
+/// Format of calendar dates, such as `2024-12-25`
+pub(crate) const DATE_FORMAT: &[BorrowedFormatItem<'_>] =
+    format_description!("[year]-[month]-[day]");
 
 #[derive(Clone, Debug, Serialize)]
 pub(crate) struct ApiError {
@@ -22,3 +46,96 @@ impl ApiError {
         }
     }
 }
+
+/// Money as exchanged through the API, e.g. `{ "amount": "12.50", "currency": "BRL" }`
+#[derive(Clone, Debug, Serialize)]
+pub(crate) struct MoneyView {
+    amount: String,
+    currency: String,
+}
+
+impl MoneyView {
+    pub(crate) fn new(money: Money) -> Self {
+        Self {
+            amount: money.format_amount(),
+            currency: money.currency().as_str().to_owned(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct MoneyBody {
+    amount: String,
+    currency: String,
+}
+
+impl MoneyBody {
+    pub(crate) fn parse(&self) -> Result<Money, ParseMoneyError> {
+        Money::parse(&self.amount, &self.currency)
+    }
+}
+
+/// Try parsing an optional price `amount` in `currency`, as used by list
+/// filters
+pub(crate) fn parse_price(
+    amount: Option<&str>,
+    currency: &str,
+) -> Result<Option<Money>, ParseMoneyError> {
+    amount
+        .map(|amount| Money::parse(amount, currency))
+        .transpose()
+}
+
+/// Try parsing a [`schedule::Window`] from weekday names, `HH:MM` times
+/// and an IANA time zone name
+pub(crate) fn parse_window(
+    weekdays: &[String],
+    starts_at: &str,
+    ends_at: &str,
+    time_zone: &str,
+) -> Result<schedule::Window, ParseWindowError> {
+    let zone = schedule::Zone::parse_str(time_zone)?;
+    parse_window_in(weekdays, starts_at, ends_at, zone)
+}
+
+/// Try parsing a [`schedule::Window`] in a known `zone` from weekday names
+/// and `HH:MM` times
+pub(crate) fn parse_window_in(
+    weekdays: &[String],
+    starts_at: &str,
+    ends_at: &str,
+    zone: schedule::Zone,
+) -> Result<schedule::Window, ParseWindowError> {
+    let days = weekdays
+        .iter()
+        .map(|day| schedule::Weekdays::parse_day(day))
+        .collect::<Result<Vec<_>, _>>()?;
+    let weekdays = schedule::Weekdays::new(&days)?;
+    let starts_at = Time::parse(starts_at, TIME_FORMAT).map_err(ParseWindowError::Time)?;
+    let ends_at = Time::parse(ends_at, TIME_FORMAT).map_err(ParseWindowError::Time)?;
+
+    Ok(schedule::Window::new(weekdays, starts_at, ends_at, zone)?)
+}
+
+/// Try parsing a `YYYY-MM-DD` calendar date
+pub(crate) fn parse_date(value: &str) -> Result<Date, ParseDateError> {
+    Date::parse(value, DATE_FORMAT).map_err(ParseDateError)
+}
+
+#[derive(Clone, Debug, Error, PartialEq)]
+pub(crate) enum ParseWindowError {
+    #[error("Schedule times must be formatted as `HH:MM`: {0}")]
+    Time(time::error::Parse),
+    #[error(transparent)]
+    Weekday(#[from] schedule::ParseWeekdayError),
+    #[error(transparent)]
+    Weekdays(#[from] schedule::WeekdaysError),
+    #[error(transparent)]
+    Window(#[from] schedule::WindowError),
+    #[error(transparent)]
+    Zone(#[from] schedule::ParseZoneError),
+}
+
+#[derive(Clone, Debug, Error, PartialEq)]
+#[error("Dates must be formatted as `YYYY-MM-DD`: {0}")]
+pub(crate) struct ParseDateError(time::error::Parse);
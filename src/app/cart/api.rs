@@ -0,0 +1,280 @@
+use axum::extract::{Json, Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::Deserialize;
+
+use domain::cart;
+use domain::catalog;
+use domain::core::money::Currency;
+use domain::core::quantity::Quantity;
+use domain::product;
+
+use super::service::{
+    AddItemInput, CartService, CreateInput, DeleteInput, FindInput, RemoveItemInput,
+    UpdateItemInput,
+};
+use super::view::CartView;
+use crate::app::product::service::ExtrasIds;
+use crate::app::ApiError;
+use crate::infra::{PgCarts, PgProducts};
+use crate::Context;
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CreateBody {
+    pub currency: String,
+}
+
+pub async fn create(State(ctx): State<Context>, body: Option<Json<CreateBody>>) -> Response {
+    let currency = match body {
+        Some(Json(body)) => match Currency::parse_str(&body.currency) {
+            Ok(currency) => currency,
+            Err(err) => return create_validation_error_response(&err).into_response(),
+        },
+        None => Currency::BRL,
+    };
+    let input = CreateInput { currency };
+
+    let pg_carts = PgCarts::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool);
+    let mut service = CartService::new(pg_carts, pg_products);
+
+    let created_cart = match service.create(input).await {
+        Ok(cart) => cart,
+        Err(err) => {
+            eprintln!("Create cart error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    create_view_response(&created_cart)
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct DeletePath {
+    pub id: String,
+}
+
+pub async fn delete(State(ctx): State<Context>, Path(path): Path<DeletePath>) -> Response {
+    let id = match cart::Id::parse_str(&path.id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = DeleteInput { id };
+
+    let pg_carts = PgCarts::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool);
+    let mut service = CartService::new(pg_carts, pg_products);
+
+    let deleted_cart = match service.delete(input).await {
+        Ok(cart) => cart,
+        Err(err) => {
+            eprintln!("Delete cart error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    create_view_response(&deleted_cart)
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct FindPath {
+    pub id: String,
+}
+
+pub async fn find(State(ctx): State<Context>, Path(path): Path<FindPath>) -> Response {
+    let id = match cart::Id::parse_str(&path.id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = FindInput { id };
+
+    let pg_carts = PgCarts::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool);
+    let service = CartService::new(pg_carts, pg_products);
+
+    let found_cart = match service.find(input).await {
+        Ok(cart) => cart,
+        Err(err) => {
+            eprintln!("Find cart error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    create_view_response(&found_cart)
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AddItemPath {
+    pub id: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AddItemBody {
+    pub catalog_id: String,
+    pub product_id: String,
+    pub extras_ids: Vec<String>,
+    pub quantity: u16,
+}
+
+pub async fn add_item(
+    State(ctx): State<Context>,
+    Path(path): Path<AddItemPath>,
+    Json(body): Json<AddItemBody>,
+) -> Response {
+    let id = match cart::Id::parse_str(&path.id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let catalog_id = match catalog::Id::parse_str(&body.catalog_id) {
+        Ok(catalog_id) => catalog_id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let product_id = match product::Id::parse_str(&body.product_id) {
+        Ok(product_id) => product_id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let extras_ids = match ExtrasIds::parse(&body.extras_ids) {
+        Ok(extras_ids) => extras_ids,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let quantity = match Quantity::new(body.quantity) {
+        Ok(quantity) => quantity,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = AddItemInput {
+        id,
+        catalog_id,
+        product_id,
+        extras_ids,
+        quantity,
+    };
+
+    let pg_carts = PgCarts::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool);
+    let mut service = CartService::new(pg_carts, pg_products);
+
+    let updated_cart = match service.add_item(input).await {
+        Ok(cart) => cart,
+        Err(err) => {
+            eprintln!("Add cart item error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    create_view_response(&updated_cart)
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UpdateItemPath {
+    pub id: String,
+    pub item_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UpdateItemBody {
+    pub quantity: u16,
+}
+
+pub async fn update_item(
+    State(ctx): State<Context>,
+    Path(path): Path<UpdateItemPath>,
+    Json(body): Json<UpdateItemBody>,
+) -> Response {
+    let id = match cart::Id::parse_str(&path.id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let item_id = match cart::ItemId::parse_str(&path.item_id) {
+        Ok(item_id) => item_id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let quantity = match Quantity::new(body.quantity) {
+        Ok(quantity) => quantity,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = UpdateItemInput {
+        id,
+        item_id,
+        quantity,
+    };
+
+    let pg_carts = PgCarts::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool);
+    let mut service = CartService::new(pg_carts, pg_products);
+
+    let updated_cart = match service.update_item(input).await {
+        Ok(cart) => cart,
+        Err(err) => {
+            eprintln!("Update cart item error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    create_view_response(&updated_cart)
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RemoveItemPath {
+    pub id: String,
+    pub item_id: String,
+}
+
+pub async fn remove_item(State(ctx): State<Context>, Path(path): Path<RemoveItemPath>) -> Response {
+    let id = match cart::Id::parse_str(&path.id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let item_id = match cart::ItemId::parse_str(&path.item_id) {
+        Ok(item_id) => item_id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = RemoveItemInput { id, item_id };
+
+    let pg_carts = PgCarts::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool);
+    let mut service = CartService::new(pg_carts, pg_products);
+
+    let updated_cart = match service.remove_item(input).await {
+        Ok(cart) => cart,
+        Err(err) => {
+            eprintln!("Remove cart item error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    create_view_response(&updated_cart)
+}
+
+fn create_view_response(cart: &cart::Cart) -> Response {
+    match CartView::new(cart) {
+        Ok(view) => Json(view).into_response(),
+        Err(err) => {
+            eprintln!("Cart view error: {err:?}");
+            create_error_response(err).into_response()
+        }
+    }
+}
+
+fn create_error_response(err: cart::Error) -> impl IntoResponse {
+    use cart::Error;
+
+    match err {
+        Error::Conflict(kind) => (
+            StatusCode::CONFLICT,
+            Json(ApiError::new("Conflict", kind.to_string())),
+        ),
+        Error::Internal(_) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new("Internal", "Internal server error")),
+        ),
+        Error::NotFound(kind) => (
+            StatusCode::NOT_FOUND,
+            Json(ApiError::new("NotFound", kind.to_string())),
+        ),
+    }
+}
+
+fn create_validation_error_response(err: &dyn std::error::Error) -> impl IntoResponse {
+    let body = ApiError::new("Validation", err.to_string());
+    (StatusCode::BAD_REQUEST, Json(body))
+}
@@ -0,0 +1,100 @@
+mod dto;
+
+pub use dto::{
+    AddItemInput, CreateInput, DeleteInput, FindInput, RemoveItemInput, UpdateItemInput,
+};
+
+use domain::cart;
+use domain::catalog;
+use domain::product;
+
+#[derive(Clone, Debug)]
+pub struct CartService<T, U> {
+    carts: T,
+    products: U,
+}
+
+impl<T: cart::Repository, U: product::Repository> CartService<T, U> {
+    pub fn new(carts: T, products: U) -> Self {
+        Self { carts, products }
+    }
+}
+
+impl<T: cart::Repository, U: product::Repository> CartService<T, U> {
+    pub async fn create(&mut self, input: CreateInput) -> Result<cart::Cart, cart::Error> {
+        let cart = cart::Cart::new(input.currency);
+        self.carts.create(&cart).await?;
+
+        Ok(cart)
+    }
+
+    pub async fn delete(&mut self, input: DeleteInput) -> Result<cart::Cart, cart::Error> {
+        self.carts.delete(input.id).await
+    }
+
+    pub async fn find(&self, input: FindInput) -> Result<cart::Cart, cart::Error> {
+        self.carts.find(input.id).await
+    }
+
+    pub async fn add_item(&mut self, input: AddItemInput) -> Result<cart::Cart, cart::Error> {
+        let mut cart = self.carts.find(input.id).await?;
+        let product = self
+            .find_product(input.product_id, input.catalog_id)
+            .await?;
+
+        cart.add_item(product, input.extras_ids.as_slice(), input.quantity)?;
+        cart.metadata.update();
+        self.carts.update(&cart).await?;
+
+        // reload so every item is priced with the current product data
+        self.carts.find(input.id).await
+    }
+
+    pub async fn update_item(&mut self, input: UpdateItemInput) -> Result<cart::Cart, cart::Error> {
+        let mut cart = self.carts.find(input.id).await?;
+        let item = cart
+            .items()
+            .iter()
+            .find(|item| item.id() == input.item_id)
+            .ok_or_else(|| cart::Error::item_not_found(input.item_id, input.id))?;
+
+        // item must still be valid as if it was added now
+        let product = item.product();
+        let product = self
+            .find_product(product.id(), product.catalog_id())
+            .await?;
+        cart.refresh_item(input.item_id, product)?;
+
+        cart.set_item_quantity(input.item_id, input.quantity)?;
+        cart.metadata.update();
+        self.carts.update(&cart).await?;
+
+        Ok(cart)
+    }
+
+    pub async fn remove_item(&mut self, input: RemoveItemInput) -> Result<cart::Cart, cart::Error> {
+        let mut cart = self.carts.find(input.id).await?;
+        cart.remove_item(input.item_id)?;
+        cart.metadata.update();
+        self.carts.update(&cart).await?;
+
+        Ok(cart)
+    }
+
+    /// Find product with `id`, mapping its absence to a cart error
+    async fn find_product(
+        &self,
+        id: product::Id,
+        catalog_id: catalog::Id,
+    ) -> Result<product::Product, cart::Error> {
+        self.products
+            .find(id, catalog_id)
+            .await
+            .map_err(|err| match err {
+                product::Error::NotFound(kind) => {
+                    cart::Error::NotFound(cart::NotFoundKind::Product(kind))
+                }
+                err => cart::Error::any(err),
+            })
+    }
+}
@@ -0,0 +1,44 @@
+use domain::cart;
+use domain::catalog;
+use domain::core::money::Currency;
+use domain::core::quantity::Quantity;
+use domain::product;
+
+use crate::app::product::service::ExtrasIds;
+
+#[derive(Clone, Copy, Debug)]
+pub struct CreateInput {
+    pub currency: Currency,
+}
+
+#[derive(Clone, Debug)]
+pub struct DeleteInput {
+    pub id: cart::Id,
+}
+
+#[derive(Clone, Debug)]
+pub struct FindInput {
+    pub id: cart::Id,
+}
+
+#[derive(Clone, Debug)]
+pub struct AddItemInput {
+    pub id: cart::Id,
+    pub catalog_id: catalog::Id,
+    pub product_id: product::Id,
+    pub extras_ids: ExtrasIds,
+    pub quantity: Quantity,
+}
+
+#[derive(Clone, Debug)]
+pub struct UpdateItemInput {
+    pub id: cart::Id,
+    pub item_id: cart::ItemId,
+    pub quantity: Quantity,
+}
+
+#[derive(Clone, Debug)]
+pub struct RemoveItemInput {
+    pub id: cart::Id,
+    pub item_id: cart::ItemId,
+}
@@ -0,0 +1,67 @@
+use serde::Serialize;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use domain::cart;
+
+use crate::app::product::view::ExtraView;
+use crate::app::MoneyView;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct CartView<'a> {
+    pub id: Uuid,
+    pub currency: String,
+    pub items: Vec<ItemView<'a>>,
+    pub total: MoneyView,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime,
+}
+
+impl<'a> CartView<'a> {
+    pub fn new(cart: &'a cart::Cart) -> Result<Self, cart::Error> {
+        Ok(Self {
+            id: cart.id().uuid(),
+            currency: cart.currency().to_string(),
+            items: cart
+                .items()
+                .iter()
+                .map(ItemView::new)
+                .collect::<Result<_, _>>()?,
+            total: MoneyView::new(cart.total()?),
+            created_at: cart.metadata.created_at(),
+            updated_at: cart.metadata.updated_at(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ItemView<'a> {
+    pub id: Uuid,
+    pub product_id: Uuid,
+    pub catalog_id: Uuid,
+    pub name: &'a str,
+    pub price: MoneyView,
+    pub extras: Vec<ExtraView<'a>>,
+    pub quantity: u16,
+    pub unit_price: MoneyView,
+    pub total: MoneyView,
+}
+
+impl<'a> ItemView<'a> {
+    pub fn new(item: &'a cart::Item) -> Result<Self, cart::Error> {
+        let product = item.product();
+        Ok(Self {
+            id: item.id().uuid(),
+            product_id: product.id().uuid(),
+            catalog_id: product.catalog_id().uuid(),
+            name: product.name.as_str(),
+            price: MoneyView::new(product.price),
+            extras: item.extras().map(ExtraView::new).collect(),
+            quantity: item.quantity().value(),
+            unit_price: MoneyView::new(item.unit_price()?),
+            total: MoneyView::new(item.total()?),
+        })
+    }
+}
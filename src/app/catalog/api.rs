@@ -4,19 +4,49 @@ use axum::extract::{Json, Path, Query, State};
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
 use serde::Deserialize;
+use thiserror::Error;
+use time::OffsetDateTime;
 
 use domain::catalog;
+use domain::core::money::{Currency, ParseCurrencyError};
+use domain::core::schedule;
+use domain::exchange;
+use domain::happy_hour;
+use domain::ingredient;
+use domain::product;
+use domain::promotion;
+use domain::stock;
+use domain::tax;
 
 use super::service::{CatalogService, CreateInput, DeleteInput, FindInput, ListInput, UpdateInput};
 use super::view::{CatalogProductsView, PaginationView};
-use crate::app::ApiError;
-use crate::infra::PgCatalogs;
+use crate::app::exchange::api::create_error_response as create_exchange_error_response;
+use crate::app::exchange::service::{ExchangeService, FindInput as FindRateInput};
+use crate::app::exchange::view::ExchangeRateView;
+use crate::app::happy_hour::api::create_error_response as create_happy_hour_error_response;
+use crate::app::happy_hour::service::{AllInput as AllHappyHoursInput, HappyHourService};
+use crate::app::ingredient::api::create_error_response as create_ingredient_error_response;
+use crate::app::ingredient::service::{ExhaustedInput, IngredientService};
+use crate::app::product::api::ScheduleWindowBody;
+use crate::app::promotion::api::create_error_response as create_promotion_error_response;
+use crate::app::promotion::service::{ActiveInput, PromotionService};
+use crate::app::stock::api::create_error_response as create_stock_error_response;
+use crate::app::stock::service::{AllInput as AllStocksInput, StockService};
+use crate::app::tax::api::create_error_response as create_tax_error_response;
+use crate::app::tax::service::TaxService;
+use crate::app::{parse_date, parse_window_in, ApiError, ParseDateError, ParseWindowError};
+use crate::infra::{
+    PgCatalogs, PgExchangeRates, PgExtras, PgHappyHours, PgIngredients, PgProducts, PgPromotions,
+    PgStocks, PgTaxRules,
+};
 use crate::Context;
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct CreateBody {
     pub name: String,
     pub description: Option<String>,
+    pub hours: Option<OpeningHoursBody>,
+    pub daypart: Option<DaypartBody>,
 }
 
 pub async fn create(State(ctx): State<Context>, Json(body): Json<CreateBody>) -> Response {
@@ -28,7 +58,20 @@ pub async fn create(State(ctx): State<Context>, Json(body): Json<CreateBody>) ->
         Ok(description) => description,
         Err(err) => return create_validation_error_response(&err).into_response(),
     };
-    let input = CreateInput { name, description };
+    let hours = match body.hours.as_ref().map(OpeningHoursBody::parse).transpose() {
+        Ok(hours) => hours,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let daypart = match body.daypart.as_ref().map(DaypartBody::parse).transpose() {
+        Ok(daypart) => daypart,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = CreateInput {
+        name,
+        description,
+        hours,
+        daypart,
+    };
 
     let mut service = CatalogService::new(PgCatalogs::new(ctx.pool));
     let created_product_catalog = match service.create(input).await {
@@ -71,15 +114,37 @@ pub struct FindPath {
     pub id: String,
 }
 
-pub async fn find(State(ctx): State<Context>, Path(path): Path<FindPath>) -> Response {
+#[derive(Clone, Debug, Deserialize)]
+pub struct FindQuery {
+    pub currency: Option<String>,
+    /// RFC 3339 timestamp to preview prices and availability at, defaulting
+    /// to now
+    pub at: Option<String>,
+    /// Whether to leave out products not available, as public menus do
+    pub only_available: Option<bool>,
+}
+
+pub async fn find(
+    State(ctx): State<Context>,
+    Path(path): Path<FindPath>,
+    Query(query): Query<FindQuery>,
+) -> Response {
     let id = match catalog::Id::parse_str(&path.id) {
         Ok(id) => id,
         Err(err) => return create_validation_error_response(&err).into_response(),
     };
+    let exchange_pair = match parse_exchange_pair(ctx.base_currency, query.currency.as_deref()) {
+        Ok(exchange_pair) => exchange_pair,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let at = match query.at.as_deref().map(parse_at).transpose() {
+        Ok(at) => at.unwrap_or_else(OffsetDateTime::now_utc),
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
     let input = FindInput { id };
 
-    let service = CatalogService::new(PgCatalogs::new(ctx.pool));
-    let found_product_catalog = match service.find(input).await {
+    let service = CatalogService::new(PgCatalogs::new(ctx.pool.clone()));
+    let mut found_product_catalog = match service.find(input).await {
         Ok(product_catalog) => product_catalog,
         Err(err) => {
             eprintln!("Find product catalog error: {err:?}");
@@ -87,37 +152,132 @@ pub async fn find(State(ctx): State<Context>, Path(path): Path<FindPath>) -> Res
         }
     };
 
-    Json(CatalogProductsView::new(&found_product_catalog)).into_response()
+    let exchange_rate = match find_exchange_rate(&ctx, exchange_pair).await {
+        Ok(exchange_rate) => exchange_rate,
+        Err(err) => return create_exchange_error_response(err).into_response(),
+    };
+    if let Some(exchange_rate) = &exchange_rate {
+        if let Err(err) = found_product_catalog.exchange(exchange_rate) {
+            eprintln!("Exchange product catalog error: {err:?}");
+            return create_exchange_error_response(err).into_response();
+        }
+    }
+
+    let tax_rules = match find_tax_rules(&ctx).await {
+        Ok(tax_rules) => tax_rules,
+        Err(err) => return create_tax_error_response(err).into_response(),
+    };
+
+    let promotions = match find_promotions(&ctx, exchange_rate.as_ref(), at).await {
+        Ok(promotions) => promotions,
+        Err(err) => return err,
+    };
+
+    let happy_hours = match find_happy_hours(&ctx, id).await {
+        Ok(happy_hours) => happy_hours,
+        Err(err) => return create_happy_hour_error_response(err).into_response(),
+    };
+
+    let products_ids = found_product_catalog
+        .products
+        .iter()
+        .map(product::Product::id)
+        .collect::<Vec<_>>();
+    let stocks = match find_stocks(&ctx, products_ids.clone()).await {
+        Ok(stocks) => stocks,
+        Err(err) => return create_stock_error_response(err).into_response(),
+    };
+    let exhausted = match find_exhausted(&ctx, products_ids).await {
+        Ok(exhausted) => exhausted,
+        Err(err) => return create_ingredient_error_response(err).into_response(),
+    };
+
+    let mut view = match CatalogProductsView::with_taxes(&found_product_catalog, &tax_rules) {
+        Ok(view) => view,
+        Err(err) => {
+            eprintln!("Product catalog taxes error: {err:?}");
+            return create_tax_error_response(err).into_response();
+        }
+    };
+    if let Err(err) = view.apply_promotions(&found_product_catalog, &promotions, at) {
+        eprintln!("Product catalog promotions error: {err:?}");
+        return create_promotion_error_response(err).into_response();
+    }
+    if let Err(err) = view.apply_happy_hours(&found_product_catalog, &happy_hours, at) {
+        eprintln!("Product catalog happy hours error: {err:?}");
+        return create_happy_hour_error_response(err).into_response();
+    }
+    view.apply_stocks(&found_product_catalog, &stocks);
+    view.apply_ingredients(&found_product_catalog, &exhausted);
+    view.apply_schedule(&found_product_catalog, at);
+    view.apply_hours(&found_product_catalog, at);
+    if query.only_available.unwrap_or(false) {
+        view.retain_available();
+    }
+    view.exchange_rate = exchange_rate.as_ref().map(ExchangeRateView::new);
+    Json(view).into_response()
 }
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct ListQuery {
     pub page: Option<u32>,
     pub limit: Option<u8>,
+    pub currency: Option<String>,
+    /// Whether to leave out products not available, as public menus do
+    pub only_available: Option<bool>,
 }
 
 pub async fn list(State(ctx): State<Context>, Query(query): Query<ListQuery>) -> Response {
-    let page = match query.page {
-        Some(0) | None => NonZeroU32::new(1).unwrap(),
-        Some(page) => NonZeroU32::new(page).expect("Page is not zero"),
+    let (page, limit) = parse_pagination(query.page, query.limit);
+    let exchange_pair = match parse_exchange_pair(ctx.base_currency, query.currency.as_deref()) {
+        Ok(exchange_pair) => exchange_pair,
+        Err(err) => return create_validation_error_response(&err).into_response(),
     };
-    let limit = match query.limit {
-        Some(0) | None => NonZeroU8::new(10).unwrap(),
-        Some(limit) => NonZeroU8::new(limit).expect("Limit is not zero"),
+    let input = ListInput {
+        page,
+        limit,
+        active_at: None,
     };
-    let input = ListInput { page, limit };
+    let only_available = query.only_available.unwrap_or(false);
 
-    let service = CatalogService::new(PgCatalogs::new(ctx.pool));
-    let pagination = match service.list(input).await {
-        Ok(pagination) => pagination,
-        Err(err) => {
-            eprintln!("List product catalogs error: {err:?}");
-            return create_error_response(err).into_response();
-        }
+    create_pagination_response(
+        &ctx,
+        input,
+        exchange_pair,
+        OffsetDateTime::now_utc(),
+        only_available,
+    )
+    .await
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ActiveQuery {
+    pub page: Option<u32>,
+    pub limit: Option<u8>,
+    pub currency: Option<String>,
+    /// RFC 3339 timestamp catalogs must be active at, defaulting to now
+    pub at: Option<String>,
+}
+
+/// List catalogs whose menu is served at the requested time, such as the
+/// breakfast menu in the morning
+pub async fn active(State(ctx): State<Context>, Query(query): Query<ActiveQuery>) -> Response {
+    let (page, limit) = parse_pagination(query.page, query.limit);
+    let exchange_pair = match parse_exchange_pair(ctx.base_currency, query.currency.as_deref()) {
+        Ok(exchange_pair) => exchange_pair,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let at = match query.at.as_deref().map(parse_at).transpose() {
+        Ok(at) => at.unwrap_or_else(OffsetDateTime::now_utc),
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = ListInput {
+        page,
+        limit,
+        active_at: Some(at),
     };
 
-    let view = PaginationView::new(&pagination);
-    Json(view).into_response()
+    create_pagination_response(&ctx, input, exchange_pair, at, false).await
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -129,6 +289,8 @@ pub struct UpdatePath {
 pub struct UpdateBody {
     pub name: String,
     pub description: Option<String>,
+    pub hours: Option<OpeningHoursBody>,
+    pub daypart: Option<DaypartBody>,
 }
 
 pub async fn update(
@@ -148,10 +310,20 @@ pub async fn update(
         Ok(description) => description,
         Err(err) => return create_validation_error_response(&err).into_response(),
     };
+    let hours = match body.hours.as_ref().map(OpeningHoursBody::parse).transpose() {
+        Ok(hours) => hours,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let daypart = match body.daypart.as_ref().map(DaypartBody::parse).transpose() {
+        Ok(daypart) => daypart,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
     let input = UpdateInput {
         id,
         name,
         description,
+        hours,
+        daypart,
     };
 
     let mut service = CatalogService::new(PgCatalogs::new(ctx.pool));
@@ -166,6 +338,291 @@ pub async fn update(
     Json(CatalogProductsView::new(&updated_product_catalog)).into_response()
 }
 
+/// Weekly windows a catalog menu is served in, e.g. `{ "windows": [{
+/// "weekdays": ["monday"], "starts_at": "06:00", "ends_at": "10:30" }],
+/// "time_zone": "America/Sao_Paulo" }`
+#[derive(Clone, Debug, Deserialize)]
+pub struct DaypartBody {
+    pub windows: Vec<ScheduleWindowBody>,
+    pub time_zone: String,
+}
+
+impl DaypartBody {
+    /// Try parsing body into [`catalog::Daypart`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if any window or the daypart itself is not valid
+    pub fn parse(&self) -> Result<catalog::Daypart, ParseDaypartError> {
+        let zone = schedule::Zone::parse_str(&self.time_zone)?;
+        let windows = self
+            .windows
+            .iter()
+            .map(|window| {
+                parse_window_in(&window.weekdays, &window.starts_at, &window.ends_at, zone)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(catalog::Daypart::new(windows, zone)?)
+    }
+}
+
+#[derive(Clone, Debug, Error, PartialEq)]
+pub enum ParseDaypartError {
+    #[error(transparent)]
+    Daypart(#[from] catalog::DaypartError),
+    #[error(transparent)]
+    Window(#[from] ParseWindowError),
+    #[error(transparent)]
+    Zone(#[from] schedule::ParseZoneError),
+}
+
+/// Weekly opening hours of a store, e.g. `{ "windows": [{ "weekdays": ["monday"],
+/// "starts_at": "11:00", "ends_at": "23:00" }], "closures": ["2024-12-25"],
+/// "time_zone": "America/Sao_Paulo" }`
+#[derive(Clone, Debug, Deserialize)]
+pub struct OpeningHoursBody {
+    pub windows: Vec<ScheduleWindowBody>,
+    #[serde(default)]
+    pub closures: Vec<String>,
+    pub time_zone: String,
+}
+
+impl OpeningHoursBody {
+    /// Try parsing body into [`catalog::OpeningHours`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if any window, closure or the opening hours
+    /// themselves are not valid
+    pub fn parse(&self) -> Result<catalog::OpeningHours, ParseOpeningHoursError> {
+        let zone = schedule::Zone::parse_str(&self.time_zone)?;
+        let windows = self
+            .windows
+            .iter()
+            .map(|window| {
+                parse_window_in(&window.weekdays, &window.starts_at, &window.ends_at, zone)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let closures = self
+            .closures
+            .iter()
+            .map(|closure| parse_date(closure))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(catalog::OpeningHours::new(windows, closures, zone)?)
+    }
+}
+
+#[derive(Clone, Debug, Error, PartialEq)]
+pub enum ParseOpeningHoursError {
+    #[error(transparent)]
+    Date(#[from] ParseDateError),
+    #[error(transparent)]
+    Hours(#[from] catalog::OpeningHoursError),
+    #[error(transparent)]
+    Window(#[from] ParseWindowError),
+    #[error(transparent)]
+    Zone(#[from] schedule::ParseZoneError),
+}
+
+/// Page of product catalogs as seen `at`, with prices converted according to
+/// `exchange_pair`
+async fn create_pagination_response(
+    ctx: &Context,
+    input: ListInput,
+    exchange_pair: Option<exchange::Pair>,
+    at: OffsetDateTime,
+    only_available: bool,
+) -> Response {
+    let service = CatalogService::new(PgCatalogs::new(ctx.pool.clone()));
+    let mut pagination = match service.list(input).await {
+        Ok(pagination) => pagination,
+        Err(err) => {
+            eprintln!("List product catalogs error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    let exchange_rate = match find_exchange_rate(ctx, exchange_pair).await {
+        Ok(exchange_rate) => exchange_rate,
+        Err(err) => return create_exchange_error_response(err).into_response(),
+    };
+    if let Some(exchange_rate) = &exchange_rate {
+        for product_catalog in &mut pagination.items {
+            if let Err(err) = product_catalog.exchange(exchange_rate) {
+                eprintln!("Exchange product catalogs error: {err:?}");
+                return create_exchange_error_response(err).into_response();
+            }
+        }
+    }
+
+    let tax_rules = match find_tax_rules(ctx).await {
+        Ok(tax_rules) => tax_rules,
+        Err(err) => return create_tax_error_response(err).into_response(),
+    };
+
+    let promotions = match find_promotions(ctx, exchange_rate.as_ref(), at).await {
+        Ok(promotions) => promotions,
+        Err(err) => return err,
+    };
+
+    let products_ids = pagination
+        .items
+        .iter()
+        .flat_map(|product_catalog| product_catalog.products.iter().map(product::Product::id))
+        .collect::<Vec<_>>();
+    let stocks = match find_stocks(ctx, products_ids.clone()).await {
+        Ok(stocks) => stocks,
+        Err(err) => return create_stock_error_response(err).into_response(),
+    };
+    let exhausted = match find_exhausted(ctx, products_ids).await {
+        Ok(exhausted) => exhausted,
+        Err(err) => return create_ingredient_error_response(err).into_response(),
+    };
+
+    let mut view = match PaginationView::with_taxes(&pagination, &tax_rules) {
+        Ok(view) => view,
+        Err(err) => {
+            eprintln!("Product catalogs taxes error: {err:?}");
+            return create_tax_error_response(err).into_response();
+        }
+    };
+    if let Err(err) = view.apply_promotions(&pagination, &promotions, at) {
+        eprintln!("Product catalogs promotions error: {err:?}");
+        return create_promotion_error_response(err).into_response();
+    }
+    view.apply_stocks(&pagination, &stocks);
+    view.apply_ingredients(&pagination, &exhausted);
+    view.apply_schedule(&pagination, at);
+    view.apply_hours(&pagination, at);
+    if only_available {
+        view.retain_available();
+    }
+    view.exchange_rate = exchange_rate.as_ref().map(ExchangeRateView::new);
+    Json(view).into_response()
+}
+
+/// Page and limit of a listing, defaulting to the first page of 10 items
+fn parse_pagination(page: Option<u32>, limit: Option<u8>) -> (NonZeroU32, NonZeroU8) {
+    let page = match page {
+        Some(0) | None => NonZeroU32::new(1).unwrap(),
+        Some(page) => NonZeroU32::new(page).expect("Page is not zero"),
+    };
+    let limit = match limit {
+        Some(0) | None => NonZeroU8::new(10).unwrap(),
+        Some(limit) => NonZeroU8::new(limit).expect("Limit is not zero"),
+    };
+
+    (page, limit)
+}
+
+/// Parse the currency catalog prices were requested in, returning [`None`]
+/// when no conversion from `base` currency is needed
+fn parse_exchange_pair(
+    base: Currency,
+    currency: Option<&str>,
+) -> Result<Option<exchange::Pair>, ParseCurrencyError> {
+    let Some(currency) = currency.map(Currency::parse_str).transpose()? else {
+        return Ok(None);
+    };
+
+    Ok(exchange::Pair::new(base, currency).ok())
+}
+
+async fn find_exchange_rate(
+    ctx: &Context,
+    pair: Option<exchange::Pair>,
+) -> Result<Option<exchange::ExchangeRate>, exchange::Error> {
+    let Some(pair) = pair else {
+        return Ok(None);
+    };
+
+    let service = ExchangeService::new(PgExchangeRates::new(ctx.pool.clone()));
+    service
+        .find(FindRateInput { pair })
+        .await
+        .map(Some)
+        .inspect_err(|err| eprintln!("Find exchange rate error: {err:?}"))
+}
+
+async fn find_tax_rules(ctx: &Context) -> Result<tax::Rules, tax::Error> {
+    let service = TaxService::new(PgTaxRules::new(ctx.pool.clone()));
+    service
+        .all()
+        .await
+        .inspect_err(|err| eprintln!("All tax rules error: {err:?}"))
+}
+
+fn parse_at(value: &str) -> Result<OffsetDateTime, time::error::Parse> {
+    use time::format_description::well_known::Rfc3339;
+    OffsetDateTime::parse(value, &Rfc3339)
+}
+
+async fn find_happy_hours(
+    ctx: &Context,
+    catalog_id: catalog::Id,
+) -> Result<happy_hour::HappyHours, happy_hour::Error> {
+    let service = HappyHourService::new(PgHappyHours::new(ctx.pool.clone()));
+    service
+        .all(AllHappyHoursInput { catalog_id })
+        .await
+        .inspect_err(|err| eprintln!("All happy hours error: {err:?}"))
+}
+
+async fn find_stocks(
+    ctx: &Context,
+    products_ids: Vec<product::Id>,
+) -> Result<stock::Stocks, stock::Error> {
+    let pg_stocks = PgStocks::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool.clone());
+    let service = StockService::new(pg_stocks, pg_products);
+    service
+        .all(AllStocksInput { products_ids })
+        .await
+        .inspect_err(|err| eprintln!("All stocks error: {err:?}"))
+}
+
+async fn find_exhausted(
+    ctx: &Context,
+    products_ids: Vec<product::Id>,
+) -> Result<ingredient::Exhausted, ingredient::Error> {
+    let pg_ingredients = PgIngredients::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool.clone());
+    let pg_extras = PgExtras::new(ctx.pool.clone());
+    let service = IngredientService::new(pg_ingredients, pg_products, pg_extras);
+    service
+        .exhausted(ExhaustedInput { products_ids })
+        .await
+        .inspect_err(|err| eprintln!("Exhausted products error: {err:?}"))
+}
+
+/// Promotions active `at`, with fixed discounts converted by `exchange_rate`
+/// so they apply to exchanged prices
+async fn find_promotions(
+    ctx: &Context,
+    exchange_rate: Option<&exchange::ExchangeRate>,
+    at: OffsetDateTime,
+) -> Result<promotion::Promotions, Response> {
+    let service = PromotionService::new(PgPromotions::new(ctx.pool.clone()));
+    let mut promotions = match service.active(ActiveInput { at }).await {
+        Ok(promotions) => promotions,
+        Err(err) => {
+            eprintln!("Active promotions error: {err:?}");
+            return Err(create_promotion_error_response(err).into_response());
+        }
+    };
+
+    if let Some(exchange_rate) = exchange_rate {
+        if let Err(err) = promotions.exchange(exchange_rate) {
+            eprintln!("Exchange promotions error: {err:?}");
+            return Err(create_exchange_error_response(err).into_response());
+        }
+    }
+
+    Ok(promotions)
+}
+
 fn create_error_response(err: catalog::Error) -> impl IntoResponse {
     use catalog::Error;
 
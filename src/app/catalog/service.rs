@@ -20,7 +20,9 @@ impl<T: catalog::Repository> CatalogService<T> {
         &mut self,
         input: CreateInput,
     ) -> Result<catalog::ProductCatalog, catalog::Error> {
-        let catalog = catalog::Catalog::new(input.name, input.description);
+        let mut catalog = catalog::Catalog::new(input.name, input.description);
+        catalog.hours = input.hours;
+        catalog.daypart = input.daypart;
         self.catalogs.create(&catalog).await?;
 
         let products = catalog::Products::default();
@@ -42,6 +44,7 @@ impl<T: catalog::Repository> CatalogService<T> {
         let query = catalog::ListQuery {
             page: input.page,
             limit: input.limit,
+            active_at: input.active_at,
         };
 
         self.catalogs.list(query).await
@@ -54,6 +57,8 @@ impl<T: catalog::Repository> CatalogService<T> {
         let mut product_catalog = self.catalogs.find(input.id).await?;
         product_catalog.catalog.name = input.name;
         product_catalog.catalog.description = input.description;
+        product_catalog.catalog.hours = input.hours;
+        product_catalog.catalog.daypart = input.daypart;
         product_catalog.catalog.metadata.update();
 
         self.catalogs.update(&product_catalog.catalog).await?;
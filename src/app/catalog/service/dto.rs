@@ -1,11 +1,15 @@
 use std::num::{NonZeroU32, NonZeroU8};
 
+use time::OffsetDateTime;
+
 use domain::catalog;
 
 #[derive(Clone, Debug)]
 pub struct CreateInput {
     pub name: catalog::Name,
     pub description: Option<catalog::Description>,
+    pub hours: Option<catalog::OpeningHours>,
+    pub daypart: Option<catalog::Daypart>,
 }
 
 #[derive(Clone, Debug)]
@@ -22,6 +26,7 @@ pub struct FindInput {
 pub struct ListInput {
     pub page: NonZeroU32,
     pub limit: NonZeroU8,
+    pub active_at: Option<OffsetDateTime>,
 }
 
 #[derive(Clone, Debug)]
@@ -29,4 +34,6 @@ pub struct UpdateInput {
     pub id: catalog::Id,
     pub name: catalog::Name,
     pub description: Option<catalog::Description>,
+    pub hours: Option<catalog::OpeningHours>,
+    pub daypart: Option<catalog::Daypart>,
 }
@@ -3,21 +3,45 @@ use time::OffsetDateTime;
 use uuid::Uuid;
 
 use domain::catalog;
+use domain::happy_hour;
+use domain::ingredient;
+use domain::promotion;
+use domain::stock;
+use domain::tax;
 
-use crate::app::product::view::ProductView;
+use crate::app::exchange::view::ExchangeRateView;
+use crate::app::product::view::{ProductView, ScheduleWindowView};
+use crate::app::DATE_FORMAT;
 
 #[derive(Clone, Debug, Serialize)]
 pub struct CatalogProductsView<'a> {
     pub id: Uuid,
     pub name: &'a str,
     pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hours: Option<OpeningHoursView>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub daypart: Option<DaypartView>,
+    /// Whether catalog menu is served at the requested time, defaulting to
+    /// now
+    pub is_active: bool,
+    /// Whether store is open at the requested time, defaulting to now
+    pub is_open_now: bool,
+    /// First time store opens after the requested time, when it has opening
+    /// hours
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub next_opening: Option<OffsetDateTime>,
     pub products: Vec<ProductView<'a>>,
+    /// Rate prices were converted with, when requested in another currency
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exchange_rate: Option<ExchangeRateView>,
     pub created_at: String,
     pub updated_at: String,
 }
 
 impl<'a> CatalogProductsView<'a> {
     pub fn new(value: &'a catalog::ProductCatalog) -> Self {
+        let now = OffsetDateTime::now_utc();
         Self {
             id: value.catalog.id().uuid(),
             name: value.catalog.name.as_str(),
@@ -26,24 +50,163 @@ impl<'a> CatalogProductsView<'a> {
                 .description
                 .as_ref()
                 .map(catalog::Description::as_str),
+            hours: value.catalog.hours.as_ref().map(OpeningHoursView::new),
+            daypart: value.catalog.daypart.as_ref().map(DaypartView::new),
+            is_active: value.catalog.is_active_at(now),
+            is_open_now: value.catalog.is_open_at(now),
+            next_opening: value.catalog.next_opening(now),
             products: value.products.iter().map(ProductView::new).collect(),
+            exchange_rate: None,
             created_at: Self::to_rfc3339(value.catalog.metadata.created_at()),
             updated_at: Self::to_rfc3339(value.catalog.metadata.updated_at()),
         }
     }
 
+    /// Same as [`CatalogProductsView::new`], also reporting taxes of every
+    /// product according to `rules`
+    pub fn with_taxes(
+        value: &'a catalog::ProductCatalog,
+        rules: &tax::Rules,
+    ) -> Result<Self, tax::Error> {
+        let mut view = Self::new(value);
+        view.products = value
+            .products
+            .iter()
+            .map(|product| ProductView::with_taxes(product, rules))
+            .collect::<Result<_, _>>()?;
+
+        Ok(view)
+    }
+
+    /// Report promoted prices of every product according to the `promotions`
+    /// active `at`
+    pub fn apply_promotions(
+        &mut self,
+        value: &catalog::ProductCatalog,
+        promotions: &promotion::Promotions,
+        at: OffsetDateTime,
+    ) -> Result<(), promotion::Error> {
+        for (product_view, product) in self.products.iter_mut().zip(value.products.iter()) {
+            product_view.apply_promotions(product, promotions, at)?;
+        }
+
+        Ok(())
+    }
+
+    /// Report price of every product in effect `at` according to
+    /// `happy_hours`
+    pub fn apply_happy_hours(
+        &mut self,
+        value: &catalog::ProductCatalog,
+        happy_hours: &happy_hour::HappyHours,
+        at: OffsetDateTime,
+    ) -> Result<(), happy_hour::Error> {
+        for (product_view, product) in self.products.iter_mut().zip(value.products.iter()) {
+            product_view.apply_happy_hours(product, happy_hours, at)?;
+        }
+
+        Ok(())
+    }
+
+    /// Report units on hand of every product according to `stocks`
+    pub fn apply_stocks(&mut self, value: &catalog::ProductCatalog, stocks: &stock::Stocks) {
+        for (product_view, product) in self.products.iter_mut().zip(value.products.iter()) {
+            product_view.apply_stocks(product, stocks);
+        }
+    }
+
+    /// Report every product among `exhausted` ones as unavailable
+    pub fn apply_ingredients(
+        &mut self,
+        value: &catalog::ProductCatalog,
+        exhausted: &ingredient::Exhausted,
+    ) {
+        for (product_view, product) in self.products.iter_mut().zip(value.products.iter()) {
+            product_view.apply_ingredients(product, exhausted);
+        }
+    }
+
+    /// Report every product not offered `at` as unavailable, according to
+    /// its schedule
+    pub fn apply_schedule(&mut self, value: &catalog::ProductCatalog, at: OffsetDateTime) {
+        for (product_view, product) in self.products.iter_mut().zip(value.products.iter()) {
+            product_view.apply_schedule(product, at);
+        }
+    }
+
+    /// Report whether store is open `at` and when it opens next, as well as
+    /// whether catalog menu is served then
+    pub fn apply_hours(&mut self, value: &catalog::ProductCatalog, at: OffsetDateTime) {
+        self.is_active = value.catalog.is_active_at(at);
+        self.is_open_now = value.catalog.is_open_at(at);
+        self.next_opening = value.catalog.next_opening(at);
+    }
+
+    /// Leave out unavailable products, which must happen after every other
+    /// report since products are no longer paired with the catalog ones
+    pub fn retain_available(&mut self) {
+        self.products.retain(|product_view| product_view.available);
+    }
+
     fn to_rfc3339(date: OffsetDateTime) -> String {
         use time::format_description::well_known::Rfc3339;
         date.format(&Rfc3339).unwrap_or_default()
     }
 }
 
+#[derive(Clone, Debug, Serialize)]
+pub struct DaypartView {
+    pub windows: Vec<ScheduleWindowView>,
+    pub time_zone: &'static str,
+}
+
+impl DaypartView {
+    pub fn new(daypart: &catalog::Daypart) -> Self {
+        Self {
+            windows: daypart
+                .windows()
+                .iter()
+                .map(ScheduleWindowView::new)
+                .collect(),
+            time_zone: daypart.zone().as_str(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct OpeningHoursView {
+    pub windows: Vec<ScheduleWindowView>,
+    pub closures: Vec<String>,
+    pub time_zone: &'static str,
+}
+
+impl OpeningHoursView {
+    pub fn new(hours: &catalog::OpeningHours) -> Self {
+        Self {
+            windows: hours
+                .windows()
+                .iter()
+                .map(ScheduleWindowView::new)
+                .collect(),
+            closures: hours
+                .closures()
+                .iter()
+                .map(|closure| closure.format(DATE_FORMAT).unwrap_or_default())
+                .collect(),
+            time_zone: hours.zone().as_str(),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize)]
 pub struct PaginationView<'a> {
     pub count: u64,
     pub page: u32,
     pub limit: u8,
     pub items: Vec<CatalogProductsView<'a>>,
+    /// Rate prices were converted with, when requested in another currency
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exchange_rate: Option<ExchangeRateView>,
 }
 
 impl<'a> PaginationView<'a> {
@@ -57,6 +220,80 @@ impl<'a> PaginationView<'a> {
                 .iter()
                 .map(CatalogProductsView::new)
                 .collect(),
+            exchange_rate: None,
+        }
+    }
+
+    /// Same as [`PaginationView::new`], also reporting taxes of every
+    /// product according to `rules`
+    pub fn with_taxes(
+        pagination: &'a catalog::Pagination,
+        rules: &tax::Rules,
+    ) -> Result<Self, tax::Error> {
+        let mut view = Self::new(pagination);
+        view.items = pagination
+            .items
+            .iter()
+            .map(|product_catalog| CatalogProductsView::with_taxes(product_catalog, rules))
+            .collect::<Result<_, _>>()?;
+
+        Ok(view)
+    }
+
+    /// Report promoted prices of every product according to the `promotions`
+    /// active `at`
+    pub fn apply_promotions(
+        &mut self,
+        pagination: &catalog::Pagination,
+        promotions: &promotion::Promotions,
+        at: OffsetDateTime,
+    ) -> Result<(), promotion::Error> {
+        for (item, product_catalog) in self.items.iter_mut().zip(pagination.items.iter()) {
+            item.apply_promotions(product_catalog, promotions, at)?;
+        }
+
+        Ok(())
+    }
+
+    /// Report units on hand of every product according to `stocks`
+    pub fn apply_stocks(&mut self, pagination: &catalog::Pagination, stocks: &stock::Stocks) {
+        for (item, product_catalog) in self.items.iter_mut().zip(pagination.items.iter()) {
+            item.apply_stocks(product_catalog, stocks);
+        }
+    }
+
+    /// Report every product among `exhausted` ones as unavailable
+    pub fn apply_ingredients(
+        &mut self,
+        pagination: &catalog::Pagination,
+        exhausted: &ingredient::Exhausted,
+    ) {
+        for (item, product_catalog) in self.items.iter_mut().zip(pagination.items.iter()) {
+            item.apply_ingredients(product_catalog, exhausted);
+        }
+    }
+
+    /// Report every product not offered `at` as unavailable, according to
+    /// its schedule
+    pub fn apply_schedule(&mut self, pagination: &catalog::Pagination, at: OffsetDateTime) {
+        for (item, product_catalog) in self.items.iter_mut().zip(pagination.items.iter()) {
+            item.apply_schedule(product_catalog, at);
+        }
+    }
+
+    /// Report whether every store is open `at` and when it opens next, as
+    /// well as whether every catalog menu is served then
+    pub fn apply_hours(&mut self, pagination: &catalog::Pagination, at: OffsetDateTime) {
+        for (item, product_catalog) in self.items.iter_mut().zip(pagination.items.iter()) {
+            item.apply_hours(product_catalog, at);
+        }
+    }
+
+    /// Leave out unavailable products of every catalog, which must happen
+    /// after every other report
+    pub fn retain_available(&mut self) {
+        for item in &mut self.items {
+            item.retain_available();
         }
     }
 }
@@ -0,0 +1,103 @@
+use axum::extract::{Json, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::Deserialize;
+
+use domain::core::money::Currency;
+use domain::exchange;
+
+use super::service::{ExchangeService, RateInput, UploadInput};
+use super::view::ExchangeRateView;
+use crate::app::ApiError;
+use crate::infra::PgExchangeRates;
+use crate::Context;
+
+pub async fn list(State(ctx): State<Context>) -> Response {
+    let service = ExchangeService::new(PgExchangeRates::new(ctx.pool));
+    let rates = match service.list().await {
+        Ok(rates) => rates,
+        Err(err) => {
+            eprintln!("List exchange rates error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    let views = rates.iter().map(ExchangeRateView::new).collect::<Vec<_>>();
+    Json(views).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UploadBody {
+    pub rates: Vec<RateBody>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RateBody {
+    pub from: String,
+    pub to: String,
+    pub rate: String,
+}
+
+pub async fn upload(State(ctx): State<Context>, Json(body): Json<UploadBody>) -> Response {
+    let mut rates = Vec::with_capacity(body.rates.len());
+    for rate_body in body.rates {
+        let from = match Currency::parse_str(&rate_body.from) {
+            Ok(from) => from,
+            Err(err) => return create_validation_error_response(&err).into_response(),
+        };
+        let to = match Currency::parse_str(&rate_body.to) {
+            Ok(to) => to,
+            Err(err) => return create_validation_error_response(&err).into_response(),
+        };
+        let pair = match exchange::Pair::new(from, to) {
+            Ok(pair) => pair,
+            Err(err) => return create_validation_error_response(&err).into_response(),
+        };
+        let rate = match exchange::Rate::parse_str(&rate_body.rate) {
+            Ok(rate) => rate,
+            Err(err) => return create_validation_error_response(&err).into_response(),
+        };
+
+        rates.push(RateInput { pair, rate });
+    }
+    let input = UploadInput { rates };
+
+    let mut service = ExchangeService::new(PgExchangeRates::new(ctx.pool));
+    let uploaded_rates = match service.upload(input).await {
+        Ok(rates) => rates,
+        Err(err) => {
+            eprintln!("Upload exchange rates error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    let views = uploaded_rates
+        .iter()
+        .map(ExchangeRateView::new)
+        .collect::<Vec<_>>();
+    Json(views).into_response()
+}
+
+pub fn create_error_response(err: exchange::Error) -> impl IntoResponse {
+    use exchange::Error;
+
+    match err {
+        Error::Conflict(kind) => (
+            StatusCode::CONFLICT,
+            Json(ApiError::new("Conflict", kind.to_string())),
+        ),
+        Error::Internal(_) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new("Internal", "Internal server error")),
+        ),
+        Error::NotFound(_) => (
+            StatusCode::NOT_FOUND,
+            Json(ApiError::new("NotFound", err.to_string())),
+        ),
+    }
+}
+
+fn create_validation_error_response(err: &dyn std::error::Error) -> impl IntoResponse {
+    let body = ApiError::new("Validation", err.to_string());
+    (StatusCode::BAD_REQUEST, Json(body))
+}
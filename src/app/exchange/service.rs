@@ -0,0 +1,58 @@
+mod dto;
+
+pub use dto::{FindInput, RateInput, UploadInput};
+
+use domain::exchange;
+
+#[derive(Clone, Debug)]
+pub struct ExchangeService<T> {
+    rates: T,
+}
+
+impl<T: exchange::Repository> ExchangeService<T> {
+    pub fn new(rates: T) -> Self {
+        Self { rates }
+    }
+}
+
+impl<T: exchange::Repository> ExchangeService<T> {
+    pub async fn find(&self, input: FindInput) -> Result<exchange::ExchangeRate, exchange::Error> {
+        self.rates.find(input.pair).await
+    }
+
+    pub async fn list(&self) -> Result<Vec<exchange::ExchangeRate>, exchange::Error> {
+        self.rates.list().await
+    }
+
+    /// Register uploaded rates, replacing current rates of the same pairs.
+    /// When a pair is uploaded more than once the last rate wins
+    pub async fn upload(
+        &mut self,
+        input: UploadInput,
+    ) -> Result<Vec<exchange::ExchangeRate>, exchange::Error> {
+        let mut rates: Vec<exchange::ExchangeRate> = Vec::with_capacity(input.rates.len());
+        for rate_input in input.rates {
+            if let Some(rate) = rates.iter_mut().find(|rate| rate.pair() == rate_input.pair) {
+                rate.set_rate(rate_input.rate);
+                continue;
+            }
+
+            let rate = match self.rates.find(rate_input.pair).await {
+                Ok(mut rate) => {
+                    rate.set_rate(rate_input.rate);
+                    rate
+                }
+                Err(exchange::Error::NotFound(pair)) => {
+                    exchange::ExchangeRate::new(pair, rate_input.rate)
+                }
+                Err(err) => return Err(err),
+            };
+
+            rates.push(rate);
+        }
+
+        self.rates.save_many(&rates).await?;
+
+        Ok(rates)
+    }
+}
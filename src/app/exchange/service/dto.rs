@@ -0,0 +1,17 @@
+use domain::exchange;
+
+#[derive(Clone, Copy, Debug)]
+pub struct FindInput {
+    pub pair: exchange::Pair,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct RateInput {
+    pub pair: exchange::Pair,
+    pub rate: exchange::Rate,
+}
+
+#[derive(Clone, Debug)]
+pub struct UploadInput {
+    pub rates: Vec<RateInput>,
+}
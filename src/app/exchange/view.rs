@@ -0,0 +1,27 @@
+use serde::Serialize;
+use time::OffsetDateTime;
+
+use domain::exchange;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ExchangeRateView {
+    pub from: String,
+    pub to: String,
+    pub rate: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime,
+}
+
+impl ExchangeRateView {
+    pub fn new(rate: &exchange::ExchangeRate) -> Self {
+        Self {
+            from: rate.pair().from().to_string(),
+            to: rate.pair().to().to_string(),
+            rate: rate.rate.to_string(),
+            created_at: rate.metadata.created_at(),
+            updated_at: rate.metadata.updated_at(),
+        }
+    }
+}
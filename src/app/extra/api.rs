@@ -1,34 +1,29 @@
-use axum::extract::{Json, Path, State};
+use std::num::{NonZeroU32, NonZeroU8};
+
+use axum::extract::{Json, Path, Query, State};
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
 use serde::Deserialize;
 
+use domain::catalog;
+use domain::core::sort::Sort;
 use domain::extra;
 
-use super::service::{CreateInput, DeleteInput, ExtraService, UpdateInput};
-use super::view::ExtraView;
-use crate::app::ApiError;
+use super::service::{
+    CreateInput, DeleteInput, ExtraService, FindInput, ListInput, UpdateInput, UsagesInput,
+};
+use super::view::{ExtraView, PaginationView, UsageView};
+use crate::app::{parse_price, ApiError, MoneyBody};
 use crate::infra::PgExtras;
 use crate::Context;
 
-pub async fn all(State(ctx): State<Context>) -> Response {
-    let service = ExtraService::new(PgExtras::new(ctx.pool));
-    let extras = match service.all().await {
-        Ok(extras) => extras,
-        Err(err) => {
-            eprintln!("All products extras error: {err:?}");
-            return create_error_response(err).into_response();
-        }
-    };
-
-    let views = extras.iter().map(ExtraView::new).collect::<Vec<_>>();
-    Json(views).into_response()
-}
-
 #[derive(Clone, Debug, Deserialize)]
 pub struct CreateBody {
     pub name: String,
-    pub price: u64,
+    pub price: MoneyBody,
+    /// Catalog the extra is restricted to, being global when absent
+    pub catalog_id: Option<String>,
+    pub category: Option<String>,
 }
 
 pub async fn create(State(ctx): State<Context>, Json(body): Json<CreateBody>) -> Response {
@@ -36,9 +31,24 @@ pub async fn create(State(ctx): State<Context>, Json(body): Json<CreateBody>) ->
         Ok(name) => name,
         Err(err) => return create_validation_error_response(&err).into_response(),
     };
+    let price = match body.price.parse() {
+        Ok(price) => price,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let catalog_id = match body.catalog_id.as_deref().map(catalog::Id::parse_str) {
+        Some(Ok(catalog_id)) => Some(catalog_id),
+        Some(Err(err)) => return create_validation_error_response(&err).into_response(),
+        None => None,
+    };
+    let category = match parse_category(body.category.as_deref()) {
+        Ok(category) => category,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
     let input = CreateInput {
         name,
-        price: extra::Price::from_cents(body.price),
+        price,
+        catalog_id,
+        category,
     };
 
     let mut service = ExtraService::new(PgExtras::new(ctx.pool));
@@ -58,12 +68,25 @@ pub struct DeletePath {
     pub id: String,
 }
 
-pub async fn delete(State(ctx): State<Context>, Path(path): Path<DeletePath>) -> Response {
+#[derive(Clone, Debug, Deserialize)]
+pub struct DeleteQuery {
+    /// Delete extra even if products offer it
+    pub force: Option<bool>,
+}
+
+pub async fn delete(
+    State(ctx): State<Context>,
+    Path(path): Path<DeletePath>,
+    Query(query): Query<DeleteQuery>,
+) -> Response {
     let id = match extra::Id::parse_str(&path.id) {
         Ok(id) => id,
         Err(err) => return create_validation_error_response(&err).into_response(),
     };
-    let input = DeleteInput { id };
+    let input = DeleteInput {
+        id,
+        force: query.force.unwrap_or(false),
+    };
 
     let mut service = ExtraService::new(PgExtras::new(ctx.pool));
     let deleted_product_extra = match service.delete(input).await {
@@ -77,6 +100,130 @@ pub async fn delete(State(ctx): State<Context>, Path(path): Path<DeletePath>) ->
     Json(ExtraView::new(&deleted_product_extra)).into_response()
 }
 
+#[derive(Clone, Debug, Deserialize)]
+pub struct FindPath {
+    pub id: String,
+}
+
+pub async fn find(State(ctx): State<Context>, Path(path): Path<FindPath>) -> Response {
+    let id = match extra::Id::parse_str(&path.id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = FindInput { id };
+
+    let service = ExtraService::new(PgExtras::new(ctx.pool));
+    let found_extra = match service.find(input).await {
+        Ok(extra) => extra,
+        Err(err) => {
+            eprintln!("Find product extra error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(ExtraView::new(&found_extra)).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListQuery {
+    pub page: Option<u32>,
+    pub limit: Option<u8>,
+    /// Only extras available to the catalog, either scoped to it or global
+    pub catalog_id: Option<String>,
+    pub category: Option<String>,
+    /// Prefix of extras names, ignoring case
+    pub name: Option<String>,
+    pub min_price: Option<String>,
+    pub max_price: Option<String>,
+    /// Currency of the price range, defaulting to the base currency
+    pub currency: Option<String>,
+    /// Either `name`, `price` or `created_at`, which is the default
+    pub sort: Option<String>,
+}
+
+pub async fn list(State(ctx): State<Context>, Query(query): Query<ListQuery>) -> Response {
+    let page = match query.page {
+        Some(0) | None => NonZeroU32::new(1).unwrap(),
+        Some(page) => NonZeroU32::new(page).expect("Page is not zero"),
+    };
+    let limit = match query.limit {
+        Some(0) | None => NonZeroU8::new(10).unwrap(),
+        Some(limit) => NonZeroU8::new(limit).expect("Limit is not zero"),
+    };
+    let catalog_id = match query.catalog_id.as_deref().map(catalog::Id::parse_str) {
+        Some(Ok(catalog_id)) => Some(catalog_id),
+        Some(Err(err)) => return create_validation_error_response(&err).into_response(),
+        None => None,
+    };
+    let category = match parse_category(query.category.as_deref()) {
+        Ok(category) => category,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let currency = query
+        .currency
+        .unwrap_or_else(|| ctx.base_currency.to_string());
+    let min_price = match parse_price(query.min_price.as_deref(), &currency) {
+        Ok(min_price) => min_price,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let max_price = match parse_price(query.max_price.as_deref(), &currency) {
+        Ok(max_price) => max_price,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let sort = match query.sort.as_deref().map(Sort::parse_str) {
+        Some(Ok(sort)) => sort,
+        Some(Err(err)) => return create_validation_error_response(&err).into_response(),
+        None => Sort::default(),
+    };
+    let input = ListInput {
+        page,
+        limit,
+        catalog_id,
+        category,
+        name: query.name,
+        min_price,
+        max_price,
+        sort,
+    };
+
+    let service = ExtraService::new(PgExtras::new(ctx.pool));
+    let pagination = match service.list(input).await {
+        Ok(pagination) => pagination,
+        Err(err) => {
+            eprintln!("List product extras error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(PaginationView::new(&pagination)).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ProductsPath {
+    pub id: String,
+}
+
+/// List products offering extra, which would lose it if it were deleted
+pub async fn products(State(ctx): State<Context>, Path(path): Path<ProductsPath>) -> Response {
+    let id = match extra::Id::parse_str(&path.id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = UsagesInput { id };
+
+    let service = ExtraService::new(PgExtras::new(ctx.pool));
+    let usages = match service.usages(input).await {
+        Ok(usages) => usages,
+        Err(err) => {
+            eprintln!("Product extra usages error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    let views = usages.iter().map(UsageView::new).collect::<Vec<_>>();
+    Json(views).into_response()
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct UpdatePath {
     pub id: String,
@@ -85,7 +232,8 @@ pub struct UpdatePath {
 #[derive(Clone, Debug, Deserialize)]
 pub struct UpdateBody {
     pub name: String,
-    pub price: u64,
+    pub price: MoneyBody,
+    pub category: Option<String>,
 }
 
 pub async fn update(
@@ -101,10 +249,19 @@ pub async fn update(
         Ok(name) => name,
         Err(err) => return create_validation_error_response(&err).into_response(),
     };
+    let price = match body.price.parse() {
+        Ok(price) => price,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let category = match parse_category(body.category.as_deref()) {
+        Ok(category) => category,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
     let input = UpdateInput {
         id,
         name,
-        price: extra::Price::from_cents(body.price),
+        price,
+        category,
     };
 
     let mut service = ExtraService::new(PgExtras::new(ctx.pool));
@@ -131,13 +288,17 @@ fn create_error_response(err: extra::Error) -> impl IntoResponse {
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(ApiError::new("Internal", "Internal server error")),
         ),
-        Error::NotFound(_) => (
+        Error::NotFound(_) | Error::CatalogNotFound(_) => (
             StatusCode::NOT_FOUND,
             Json(ApiError::new("NotFound", err.to_string())),
         ),
     }
 }
 
+fn parse_category(category: Option<&str>) -> Result<Option<extra::Category>, extra::CategoryError> {
+    category.map(extra::Category::parse_str).transpose()
+}
+
 fn create_validation_error_response(err: &dyn std::error::Error) -> impl IntoResponse {
     let msg = err.to_string();
     let body = ApiError::new("Validation", msg);
@@ -1,6 +1,6 @@
 mod dto;
 
-pub use dto::{CreateInput, DeleteInput, FindInput, UpdateInput};
+pub use dto::{CreateInput, DeleteInput, FindInput, ListInput, UpdateInput, UsagesInput};
 
 use domain::extra;
 
@@ -16,29 +16,51 @@ impl<T: extra::Repository> ExtraService<T> {
 }
 
 impl<T: extra::Repository> ExtraService<T> {
-    pub async fn all(&self) -> Result<Vec<extra::Extra>, extra::Error> {
-        self.extras.all().await
-    }
-
     pub async fn create(&mut self, input: CreateInput) -> Result<extra::Extra, extra::Error> {
-        let extra = extra::Extra::new(input.name, input.price);
+        let mut extra = extra::Extra::new(input.name, input.price);
+        extra.catalog_id = input.catalog_id;
+        extra.category = input.category;
         self.extras.create(&extra).await?;
 
         Ok(extra)
     }
 
+    /// Delete extra, refusing to when products offer it unless `force` is set
     pub async fn delete(&mut self, input: DeleteInput) -> Result<extra::Extra, extra::Error> {
-        self.extras.delete(input.id).await
+        self.extras.delete(input.id, input.force).await
     }
 
     pub async fn find(&self, input: FindInput) -> Result<extra::Extra, extra::Error> {
         self.extras.find(input.id).await
     }
 
+    pub async fn list(&self, input: ListInput) -> Result<extra::Pagination, extra::Error> {
+        let query = extra::ListQuery {
+            page: input.page,
+            limit: input.limit,
+            catalog_id: input.catalog_id,
+            category: input.category,
+            name: input.name,
+            min_price: input.min_price,
+            max_price: input.max_price,
+            sort: input.sort,
+        };
+        self.extras.list(query).await
+    }
+
+    /// List products offering extra
+    pub async fn usages(&self, input: UsagesInput) -> Result<Vec<extra::Usage>, extra::Error> {
+        self.extras.find(input.id).await?;
+        self.extras.usages(input.id).await
+    }
+
+    /// Update extra, which keeps the catalog it is scoped to, since products
+    /// may already offer it
     pub async fn update(&mut self, input: UpdateInput) -> Result<extra::Extra, extra::Error> {
         let mut extra = self.extras.find(input.id).await?;
         extra.name = input.name;
         extra.price = input.price;
+        extra.category = input.category;
         extra.metadata.update();
 
         self.extras.update(&extra).await?;
@@ -1,14 +1,23 @@
+use std::num::{NonZeroU32, NonZeroU8};
+
+use domain::catalog;
+use domain::core::money::Money;
+use domain::core::sort::Sort;
 use domain::extra;
 
 #[derive(Clone, Debug)]
 pub struct CreateInput {
     pub name: extra::Name,
-    pub price: extra::Price,
+    pub price: Money,
+    pub catalog_id: Option<catalog::Id>,
+    pub category: Option<extra::Category>,
 }
 
 #[derive(Clone, Debug)]
 pub struct DeleteInput {
     pub id: extra::Id,
+    /// Delete extra even if products offer it, unbinding it from them
+    pub force: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -16,8 +25,26 @@ pub struct FindInput {
     pub id: extra::Id,
 }
 
+#[derive(Clone, Debug)]
+pub struct UsagesInput {
+    pub id: extra::Id,
+}
+
+#[derive(Clone, Debug)]
+pub struct ListInput {
+    pub page: NonZeroU32,
+    pub limit: NonZeroU8,
+    pub catalog_id: Option<catalog::Id>,
+    pub category: Option<extra::Category>,
+    pub name: Option<String>,
+    pub min_price: Option<Money>,
+    pub max_price: Option<Money>,
+    pub sort: Sort,
+}
+
 pub struct UpdateInput {
     pub id: extra::Id,
     pub name: extra::Name,
-    pub price: extra::Price,
+    pub price: Money,
+    pub category: Option<extra::Category>,
 }
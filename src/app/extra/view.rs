@@ -2,13 +2,17 @@ use serde::Serialize;
 use time::OffsetDateTime;
 use uuid::Uuid;
 
-use domain::extra::Extra;
+use domain::extra::{self, Extra, Usage};
+
+use crate::app::MoneyView;
 
 #[derive(Clone, Debug, Serialize)]
 pub struct ExtraView<'a> {
     pub id: Uuid,
     pub name: &'a str,
-    pub price: u64,
+    pub price: MoneyView,
+    pub catalog_id: Option<Uuid>,
+    pub category: Option<&'a str>,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -18,7 +22,9 @@ impl<'a> ExtraView<'a> {
         Self {
             id: extra.id().uuid(),
             name: extra.name.as_str(),
-            price: extra.price.to_cents(),
+            price: MoneyView::new(extra.price),
+            catalog_id: extra.catalog_id.map(|id| id.uuid()),
+            category: extra.category.as_ref().map(extra::Category::as_str),
             created_at: Self::to_rfc3339(extra.metadata.created_at()),
             updated_at: Self::to_rfc3339(extra.metadata.updated_at()),
         }
@@ -29,3 +35,39 @@ impl<'a> ExtraView<'a> {
         date.format(&Rfc3339).unwrap_or_default()
     }
 }
+
+#[derive(Clone, Debug, Serialize)]
+pub struct PaginationView<'a> {
+    pub count: u64,
+    pub page: u32,
+    pub limit: u8,
+    pub items: Vec<ExtraView<'a>>,
+}
+
+impl<'a> PaginationView<'a> {
+    pub fn new(pagination: &'a extra::Pagination) -> Self {
+        Self {
+            count: pagination.count,
+            page: pagination.page.into(),
+            limit: pagination.limit.into(),
+            items: pagination.items.iter().map(ExtraView::new).collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct UsageView<'a> {
+    pub product_id: Uuid,
+    pub catalog_id: Uuid,
+    pub product_name: &'a str,
+}
+
+impl<'a> UsageView<'a> {
+    pub fn new(usage: &'a Usage) -> Self {
+        Self {
+            product_id: usage.product_id.uuid(),
+            catalog_id: usage.catalog_id.uuid(),
+            product_name: usage.product_name.as_str(),
+        }
+    }
+}
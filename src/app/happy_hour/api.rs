@@ -0,0 +1,276 @@
+use axum::extract::{Json, Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::Deserialize;
+
+use domain::catalog;
+use domain::happy_hour;
+use domain::product;
+use domain::promotion;
+
+use super::service::{
+    AllInput, CreateInput, DeleteInput, FindInput, HappyHourService, UpdateInput,
+};
+use super::view::HappyHourView;
+use crate::app::{parse_window, ApiError};
+use crate::infra::PgHappyHours;
+use crate::Context;
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AllPath {
+    pub catalog_id: String,
+}
+
+pub async fn all(State(ctx): State<Context>, Path(path): Path<AllPath>) -> Response {
+    let catalog_id = match catalog::Id::parse_str(&path.catalog_id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = AllInput { catalog_id };
+
+    let service = HappyHourService::new(PgHappyHours::new(ctx.pool));
+    let happy_hours = match service.all(input).await {
+        Ok(happy_hours) => happy_hours,
+        Err(err) => {
+            eprintln!("All happy hours error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    let views = happy_hours
+        .as_slice()
+        .iter()
+        .map(HappyHourView::new)
+        .collect::<Vec<_>>();
+    Json(views).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CreatePath {
+    pub catalog_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CreateBody {
+    pub name: String,
+    pub kind: Option<String>,
+    pub percentage: String,
+    pub weekdays: Vec<String>,
+    pub starts_at: String,
+    pub ends_at: String,
+    pub time_zone: String,
+}
+
+pub async fn create(
+    State(ctx): State<Context>,
+    Path(path): Path<CreatePath>,
+    Json(body): Json<CreateBody>,
+) -> Response {
+    let catalog_id = match catalog::Id::parse_str(&path.catalog_id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let name = match happy_hour::Name::new(body.name) {
+        Ok(name) => name,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let kind = match body
+        .kind
+        .as_deref()
+        .map(product::Kind::parse_str)
+        .transpose()
+    {
+        Ok(kind) => kind,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let percentage = match promotion::Percentage::parse_str(&body.percentage) {
+        Ok(percentage) => percentage,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let window = match parse_window(
+        &body.weekdays,
+        &body.starts_at,
+        &body.ends_at,
+        &body.time_zone,
+    ) {
+        Ok(window) => window,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = CreateInput {
+        catalog_id,
+        name,
+        kind,
+        percentage,
+        window,
+    };
+
+    let mut service = HappyHourService::new(PgHappyHours::new(ctx.pool));
+    let created_happy_hour = match service.create(input).await {
+        Ok(happy_hour) => happy_hour,
+        Err(err) => {
+            eprintln!("Create happy hour error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(HappyHourView::new(&created_happy_hour)).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct DeletePath {
+    pub id: String,
+    pub catalog_id: String,
+}
+
+pub async fn delete(State(ctx): State<Context>, Path(path): Path<DeletePath>) -> Response {
+    let id = match happy_hour::Id::parse_str(&path.id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let catalog_id = match catalog::Id::parse_str(&path.catalog_id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = DeleteInput { id, catalog_id };
+
+    let mut service = HappyHourService::new(PgHappyHours::new(ctx.pool));
+    let deleted_happy_hour = match service.delete(input).await {
+        Ok(happy_hour) => happy_hour,
+        Err(err) => {
+            eprintln!("Delete happy hour error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(HappyHourView::new(&deleted_happy_hour)).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct FindPath {
+    pub id: String,
+    pub catalog_id: String,
+}
+
+pub async fn find(State(ctx): State<Context>, Path(path): Path<FindPath>) -> Response {
+    let id = match happy_hour::Id::parse_str(&path.id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let catalog_id = match catalog::Id::parse_str(&path.catalog_id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = FindInput { id, catalog_id };
+
+    let service = HappyHourService::new(PgHappyHours::new(ctx.pool));
+    let found_happy_hour = match service.find(input).await {
+        Ok(happy_hour) => happy_hour,
+        Err(err) => {
+            eprintln!("Find happy hour error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(HappyHourView::new(&found_happy_hour)).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UpdatePath {
+    pub id: String,
+    pub catalog_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UpdateBody {
+    pub name: String,
+    pub kind: Option<String>,
+    pub percentage: String,
+    pub weekdays: Vec<String>,
+    pub starts_at: String,
+    pub ends_at: String,
+    pub time_zone: String,
+}
+
+pub async fn update(
+    State(ctx): State<Context>,
+    Path(path): Path<UpdatePath>,
+    Json(body): Json<UpdateBody>,
+) -> Response {
+    let id = match happy_hour::Id::parse_str(&path.id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let catalog_id = match catalog::Id::parse_str(&path.catalog_id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let name = match happy_hour::Name::new(body.name) {
+        Ok(name) => name,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let kind = match body
+        .kind
+        .as_deref()
+        .map(product::Kind::parse_str)
+        .transpose()
+    {
+        Ok(kind) => kind,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let percentage = match promotion::Percentage::parse_str(&body.percentage) {
+        Ok(percentage) => percentage,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let window = match parse_window(
+        &body.weekdays,
+        &body.starts_at,
+        &body.ends_at,
+        &body.time_zone,
+    ) {
+        Ok(window) => window,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = UpdateInput {
+        id,
+        catalog_id,
+        name,
+        kind,
+        percentage,
+        window,
+    };
+
+    let mut service = HappyHourService::new(PgHappyHours::new(ctx.pool));
+    let updated_happy_hour = match service.update(input).await {
+        Ok(happy_hour) => happy_hour,
+        Err(err) => {
+            eprintln!("Update happy hour error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(HappyHourView::new(&updated_happy_hour)).into_response()
+}
+
+pub fn create_error_response(err: happy_hour::Error) -> impl IntoResponse {
+    use happy_hour::Error;
+
+    match err {
+        Error::Conflict(kind) => (
+            StatusCode::CONFLICT,
+            Json(ApiError::new("Conflict", kind.to_string())),
+        ),
+        Error::Internal(_) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new("Internal", "Internal server error")),
+        ),
+        Error::NotFound(kind) => (
+            StatusCode::NOT_FOUND,
+            Json(ApiError::new("NotFound", kind.to_string())),
+        ),
+    }
+}
+
+fn create_validation_error_response(err: &dyn std::error::Error) -> impl IntoResponse {
+    let body = ApiError::new("Validation", err.to_string());
+    (StatusCode::BAD_REQUEST, Json(body))
+}
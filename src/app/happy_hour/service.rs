@@ -0,0 +1,65 @@
+mod dto;
+
+pub use dto::{AllInput, CreateInput, DeleteInput, FindInput, UpdateInput};
+
+use domain::happy_hour;
+
+#[derive(Clone, Debug)]
+pub struct HappyHourService<T> {
+    happy_hours: T,
+}
+
+impl<T: happy_hour::Repository> HappyHourService<T> {
+    pub fn new(happy_hours: T) -> Self {
+        Self { happy_hours }
+    }
+}
+
+impl<T: happy_hour::Repository> HappyHourService<T> {
+    pub async fn all(&self, input: AllInput) -> Result<happy_hour::HappyHours, happy_hour::Error> {
+        self.happy_hours.all(input.catalog_id).await
+    }
+
+    pub async fn create(
+        &mut self,
+        input: CreateInput,
+    ) -> Result<happy_hour::HappyHour, happy_hour::Error> {
+        let happy_hour = happy_hour::HappyHour::new(
+            input.catalog_id,
+            input.name,
+            input.kind,
+            input.percentage,
+            input.window,
+        );
+        self.happy_hours.create(&happy_hour).await?;
+
+        Ok(happy_hour)
+    }
+
+    pub async fn delete(
+        &mut self,
+        input: DeleteInput,
+    ) -> Result<happy_hour::HappyHour, happy_hour::Error> {
+        self.happy_hours.delete(input.id, input.catalog_id).await
+    }
+
+    pub async fn find(&self, input: FindInput) -> Result<happy_hour::HappyHour, happy_hour::Error> {
+        self.happy_hours.find(input.id, input.catalog_id).await
+    }
+
+    pub async fn update(
+        &mut self,
+        input: UpdateInput,
+    ) -> Result<happy_hour::HappyHour, happy_hour::Error> {
+        let mut happy_hour = self.happy_hours.find(input.id, input.catalog_id).await?;
+        happy_hour.name = input.name;
+        happy_hour.kind = input.kind;
+        happy_hour.percentage = input.percentage;
+        happy_hour.window = input.window;
+        happy_hour.metadata.update();
+
+        self.happy_hours.update(&happy_hour).await?;
+
+        Ok(happy_hour)
+    }
+}
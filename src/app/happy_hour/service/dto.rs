@@ -0,0 +1,40 @@
+use domain::catalog;
+use domain::happy_hour;
+use domain::product;
+use domain::promotion;
+
+#[derive(Clone, Debug)]
+pub struct AllInput {
+    pub catalog_id: catalog::Id,
+}
+
+#[derive(Clone, Debug)]
+pub struct CreateInput {
+    pub catalog_id: catalog::Id,
+    pub name: happy_hour::Name,
+    pub kind: Option<product::Kind>,
+    pub percentage: promotion::Percentage,
+    pub window: happy_hour::Window,
+}
+
+#[derive(Clone, Debug)]
+pub struct DeleteInput {
+    pub id: happy_hour::Id,
+    pub catalog_id: catalog::Id,
+}
+
+#[derive(Clone, Debug)]
+pub struct FindInput {
+    pub id: happy_hour::Id,
+    pub catalog_id: catalog::Id,
+}
+
+#[derive(Clone, Debug)]
+pub struct UpdateInput {
+    pub id: happy_hour::Id,
+    pub catalog_id: catalog::Id,
+    pub name: happy_hour::Name,
+    pub kind: Option<product::Kind>,
+    pub percentage: promotion::Percentage,
+    pub window: happy_hour::Window,
+}
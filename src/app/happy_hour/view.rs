@@ -0,0 +1,90 @@
+use serde::Serialize;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use domain::core::money::Money;
+use domain::happy_hour;
+use domain::product;
+
+use crate::app::{MoneyView, TIME_FORMAT};
+
+#[derive(Clone, Debug, Serialize)]
+pub struct HappyHourView<'a> {
+    pub id: Uuid,
+    pub catalog_id: Uuid,
+    pub name: &'a str,
+    pub kind: Option<&'a str>,
+    pub percentage: String,
+    pub weekdays: Vec<&'static str>,
+    pub starts_at: String,
+    pub ends_at: String,
+    pub time_zone: &'static str,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime,
+}
+
+impl<'a> HappyHourView<'a> {
+    pub fn new(happy_hour: &'a happy_hour::HappyHour) -> Self {
+        let window = happy_hour.window;
+        Self {
+            id: happy_hour.id().uuid(),
+            catalog_id: happy_hour.catalog_id().uuid(),
+            name: happy_hour.name.as_str(),
+            kind: happy_hour.kind.as_ref().map(product::Kind::as_str),
+            percentage: happy_hour.percentage.to_string(),
+            weekdays: window
+                .weekdays()
+                .iter()
+                .map(happy_hour::Weekdays::day_as_str)
+                .collect(),
+            starts_at: window.starts_at().format(TIME_FORMAT).unwrap_or_default(),
+            ends_at: window.ends_at().format(TIME_FORMAT).unwrap_or_default(),
+            time_zone: window.zone().as_str(),
+            created_at: happy_hour.metadata.created_at(),
+            updated_at: happy_hour.metadata.updated_at(),
+        }
+    }
+}
+
+/// Price in effect at the requested time, which is the list price unless a
+/// happy hour applies
+#[derive(Clone, Debug, Serialize)]
+pub struct EffectivePriceView {
+    pub price: MoneyView,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub happy_hour: Option<AppliedHappyHourView>,
+}
+
+impl EffectivePriceView {
+    pub fn new(list_price: Money, applied: Option<&happy_hour::Applied>) -> Self {
+        match applied {
+            Some(applied) => Self {
+                price: MoneyView::new(applied.price),
+                happy_hour: Some(AppliedHappyHourView::new(applied)),
+            },
+            None => Self {
+                price: MoneyView::new(list_price),
+                happy_hour: None,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct AppliedHappyHourView {
+    pub id: Uuid,
+    pub name: String,
+    pub discount: MoneyView,
+}
+
+impl AppliedHappyHourView {
+    pub fn new(applied: &happy_hour::Applied) -> Self {
+        Self {
+            id: applied.happy_hour_id.uuid(),
+            name: applied.name.to_string(),
+            discount: MoneyView::new(applied.discount),
+        }
+    }
+}
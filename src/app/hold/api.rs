@@ -0,0 +1,149 @@
+use axum::extract::{Json, Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::Deserialize;
+
+use domain::catalog;
+use domain::hold;
+use domain::product;
+use domain::stock;
+
+use super::service::{CreateInput, DeleteInput, FindInput, HoldService};
+use super::view::HoldView;
+use crate::app::ApiError;
+use crate::infra::{PgHolds, PgProducts};
+use crate::Context;
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CreatePath {
+    pub id: String,
+    pub catalog_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CreateBody {
+    pub quantity: u32,
+    pub window_seconds: Option<u32>,
+}
+
+pub async fn create(
+    State(ctx): State<Context>,
+    Path(path): Path<CreatePath>,
+    Json(body): Json<CreateBody>,
+) -> Response {
+    let product_id = match product::Id::parse_str(&path.id) {
+        Ok(product_id) => product_id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let catalog_id = match catalog::Id::parse_str(&path.catalog_id) {
+        Ok(catalog_id) => catalog_id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let quantity = match stock::Quantity::new(body.quantity) {
+        Ok(quantity) => quantity,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let window = match body.window_seconds.map(hold::Window::from_seconds) {
+        Some(Ok(window)) => window,
+        Some(Err(err)) => return create_validation_error_response(&err).into_response(),
+        None => hold::Window::default(),
+    };
+    let input = CreateInput {
+        product_id,
+        catalog_id,
+        quantity,
+        window,
+    };
+
+    let pg_holds = PgHolds::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool);
+    let mut service = HoldService::new(pg_holds, pg_products);
+
+    let created_hold = match service.create(input).await {
+        Ok(hold) => hold,
+        Err(err) => {
+            eprintln!("Create hold error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(HoldView::new(&created_hold)).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct DeletePath {
+    pub id: String,
+}
+
+pub async fn delete(State(ctx): State<Context>, Path(path): Path<DeletePath>) -> Response {
+    let id = match hold::Id::parse_str(&path.id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = DeleteInput { id };
+
+    let pg_holds = PgHolds::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool);
+    let mut service = HoldService::new(pg_holds, pg_products);
+
+    let deleted_hold = match service.delete(input).await {
+        Ok(hold) => hold,
+        Err(err) => {
+            eprintln!("Delete hold error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(HoldView::new(&deleted_hold)).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct FindPath {
+    pub id: String,
+}
+
+pub async fn find(State(ctx): State<Context>, Path(path): Path<FindPath>) -> Response {
+    let id = match hold::Id::parse_str(&path.id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = FindInput { id };
+
+    let pg_holds = PgHolds::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool);
+    let service = HoldService::new(pg_holds, pg_products);
+
+    let found_hold = match service.find(input).await {
+        Ok(hold) => hold,
+        Err(err) => {
+            eprintln!("Find hold error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(HoldView::new(&found_hold)).into_response()
+}
+
+pub fn create_error_response(err: hold::Error) -> impl IntoResponse {
+    use hold::Error;
+
+    match err {
+        Error::Conflict(kind) => (
+            StatusCode::CONFLICT,
+            Json(ApiError::new("Conflict", kind.to_string())),
+        ),
+        Error::Internal(_) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new("Internal", "Internal server error")),
+        ),
+        Error::NotFound(kind) => (
+            StatusCode::NOT_FOUND,
+            Json(ApiError::new("NotFound", kind.to_string())),
+        ),
+    }
+}
+
+fn create_validation_error_response(err: &dyn std::error::Error) -> impl IntoResponse {
+    let body = ApiError::new("Validation", err.to_string());
+    (StatusCode::BAD_REQUEST, Json(body))
+}
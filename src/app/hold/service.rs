@@ -0,0 +1,45 @@
+mod dto;
+
+pub use dto::{CreateInput, DeleteInput, FindInput};
+
+use domain::hold;
+use domain::product;
+
+#[derive(Clone, Debug)]
+pub struct HoldService<T, U> {
+    holds: T,
+    products: U,
+}
+
+impl<T: hold::Repository, U: product::Repository> HoldService<T, U> {
+    pub fn new(holds: T, products: U) -> Self {
+        Self { holds, products }
+    }
+}
+
+impl<T: hold::Repository, U: product::Repository> HoldService<T, U> {
+    pub async fn create(&mut self, input: CreateInput) -> Result<hold::Hold, hold::Error> {
+        self.products
+            .find(input.product_id, input.catalog_id)
+            .await
+            .map_err(|err| match err {
+                product::Error::NotFound(kind) => {
+                    hold::Error::NotFound(hold::NotFoundKind::Product(kind))
+                }
+                err => hold::Error::any(err),
+            })?;
+
+        let hold = hold::Hold::new(input.product_id, input.quantity, input.window);
+        self.holds.create(&hold).await?;
+
+        Ok(hold)
+    }
+
+    pub async fn delete(&mut self, input: DeleteInput) -> Result<hold::Hold, hold::Error> {
+        self.holds.delete(input.id).await
+    }
+
+    pub async fn find(&self, input: FindInput) -> Result<hold::Hold, hold::Error> {
+        self.holds.find(input.id).await
+    }
+}
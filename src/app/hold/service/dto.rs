@@ -0,0 +1,22 @@
+use domain::catalog;
+use domain::hold;
+use domain::product;
+use domain::stock;
+
+#[derive(Clone, Debug)]
+pub struct CreateInput {
+    pub product_id: product::Id,
+    pub catalog_id: catalog::Id,
+    pub quantity: stock::Quantity,
+    pub window: hold::Window,
+}
+
+#[derive(Clone, Debug)]
+pub struct DeleteInput {
+    pub id: hold::Id,
+}
+
+#[derive(Clone, Debug)]
+pub struct FindInput {
+    pub id: hold::Id,
+}
@@ -0,0 +1,31 @@
+use serde::Serialize;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use domain::hold;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct HoldView {
+    pub id: Uuid,
+    pub product_id: Uuid,
+    pub quantity: u32,
+    #[serde(with = "time::serde::rfc3339")]
+    pub expires_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime,
+}
+
+impl HoldView {
+    pub fn new(hold: &hold::Hold) -> Self {
+        Self {
+            id: hold.id().uuid(),
+            product_id: hold.product_id().uuid(),
+            quantity: hold.quantity().value(),
+            expires_at: hold.expires_at(),
+            created_at: hold.metadata.created_at(),
+            updated_at: hold.metadata.updated_at(),
+        }
+    }
+}
@@ -0,0 +1,462 @@
+use axum::extract::{Json, Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::Deserialize;
+use thiserror::Error;
+
+use domain::catalog;
+use domain::core::quantity::Quantity;
+use domain::extra;
+use domain::ingredient;
+use domain::product;
+
+use super::service::{
+    ConsumeInput, CreateInput, DeleteInput, ExtraRecipeInput, FindInput, IngredientService,
+    ProductRecipeInput, SetExtraRecipeInput, SetProductRecipeInput, UpdateInput,
+};
+use super::view::{IngredientView, RecipeView};
+use crate::app::product::service::ExtrasIds;
+use crate::app::ApiError;
+use crate::infra::{PgExtras, PgIngredients, PgProducts};
+use crate::Context;
+
+pub async fn all(State(ctx): State<Context>) -> Response {
+    let pg_ingredients = PgIngredients::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool.clone());
+    let pg_extras = PgExtras::new(ctx.pool);
+    let service = IngredientService::new(pg_ingredients, pg_products, pg_extras);
+
+    let ingredients = match service.all().await {
+        Ok(ingredients) => ingredients,
+        Err(err) => {
+            eprintln!("All ingredients error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    let views = ingredients
+        .iter()
+        .map(IngredientView::new)
+        .collect::<Vec<_>>();
+
+    Json(views).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CreateBody {
+    pub name: String,
+    pub unit: String,
+    pub level: Option<u32>,
+}
+
+pub async fn create(State(ctx): State<Context>, Json(body): Json<CreateBody>) -> Response {
+    let name = match ingredient::Name::new(body.name) {
+        Ok(name) => name,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let unit = match ingredient::Unit::parse_str(&body.unit) {
+        Ok(unit) => unit,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let level = match ingredient::Level::new(body.level.unwrap_or_default()) {
+        Ok(level) => level,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = CreateInput { name, unit, level };
+
+    let pg_ingredients = PgIngredients::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool.clone());
+    let pg_extras = PgExtras::new(ctx.pool);
+    let mut service = IngredientService::new(pg_ingredients, pg_products, pg_extras);
+
+    let created_ingredient = match service.create(input).await {
+        Ok(ingredient) => ingredient,
+        Err(err) => {
+            eprintln!("Create ingredient error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(IngredientView::new(&created_ingredient)).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct DeletePath {
+    pub id: String,
+}
+
+pub async fn delete(State(ctx): State<Context>, Path(path): Path<DeletePath>) -> Response {
+    let id = match ingredient::Id::parse_str(&path.id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = DeleteInput { id };
+
+    let pg_ingredients = PgIngredients::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool.clone());
+    let pg_extras = PgExtras::new(ctx.pool);
+    let mut service = IngredientService::new(pg_ingredients, pg_products, pg_extras);
+
+    let deleted_ingredient = match service.delete(input).await {
+        Ok(ingredient) => ingredient,
+        Err(err) => {
+            eprintln!("Delete ingredient error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(IngredientView::new(&deleted_ingredient)).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct FindPath {
+    pub id: String,
+}
+
+pub async fn find(State(ctx): State<Context>, Path(path): Path<FindPath>) -> Response {
+    let id = match ingredient::Id::parse_str(&path.id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = FindInput { id };
+
+    let pg_ingredients = PgIngredients::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool.clone());
+    let pg_extras = PgExtras::new(ctx.pool);
+    let service = IngredientService::new(pg_ingredients, pg_products, pg_extras);
+
+    let found_ingredient = match service.find(input).await {
+        Ok(ingredient) => ingredient,
+        Err(err) => {
+            eprintln!("Find ingredient error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(IngredientView::new(&found_ingredient)).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UpdatePath {
+    pub id: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UpdateBody {
+    pub name: String,
+    pub unit: String,
+    pub level: u32,
+}
+
+pub async fn update(
+    State(ctx): State<Context>,
+    Path(path): Path<UpdatePath>,
+    Json(body): Json<UpdateBody>,
+) -> Response {
+    let id = match ingredient::Id::parse_str(&path.id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let name = match ingredient::Name::new(body.name) {
+        Ok(name) => name,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let unit = match ingredient::Unit::parse_str(&body.unit) {
+        Ok(unit) => unit,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let level = match ingredient::Level::new(body.level) {
+        Ok(level) => level,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = UpdateInput {
+        id,
+        name,
+        unit,
+        level,
+    };
+
+    let pg_ingredients = PgIngredients::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool.clone());
+    let pg_extras = PgExtras::new(ctx.pool);
+    let mut service = IngredientService::new(pg_ingredients, pg_products, pg_extras);
+
+    let updated_ingredient = match service.update(input).await {
+        Ok(ingredient) => ingredient,
+        Err(err) => {
+            eprintln!("Update ingredient error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(IngredientView::new(&updated_ingredient)).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ProductRecipePath {
+    pub id: String,
+    pub catalog_id: String,
+}
+
+pub async fn product_recipe(
+    State(ctx): State<Context>,
+    Path(path): Path<ProductRecipePath>,
+) -> Response {
+    let product_id = match product::Id::parse_str(&path.id) {
+        Ok(product_id) => product_id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let catalog_id = match catalog::Id::parse_str(&path.catalog_id) {
+        Ok(catalog_id) => catalog_id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = ProductRecipeInput {
+        product_id,
+        catalog_id,
+    };
+
+    let pg_ingredients = PgIngredients::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool.clone());
+    let pg_extras = PgExtras::new(ctx.pool);
+    let service = IngredientService::new(pg_ingredients, pg_products, pg_extras);
+
+    let recipe = match service.product_recipe(input).await {
+        Ok(recipe) => recipe,
+        Err(err) => {
+            eprintln!("Product recipe error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(RecipeView::new(&recipe)).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RecipeBody {
+    pub ingredients: Vec<PortionBody>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct PortionBody {
+    pub ingredient_id: String,
+    pub amount: u32,
+}
+
+impl RecipeBody {
+    /// Try parsing body into [`ingredient::Recipe`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if any portion or the recipe itself is not valid
+    pub fn parse(&self) -> Result<ingredient::Recipe, ParseRecipeError> {
+        let portions = self
+            .ingredients
+            .iter()
+            .map(|portion| {
+                Ok(ingredient::Portion {
+                    ingredient_id: ingredient::Id::parse_str(&portion.ingredient_id)?,
+                    amount: ingredient::Amount::new(portion.amount)?,
+                })
+            })
+            .collect::<Result<Vec<_>, ParseRecipeError>>()?;
+
+        Ok(ingredient::Recipe::new(portions)?)
+    }
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum ParseRecipeError {
+    #[error(transparent)]
+    Amount(#[from] ingredient::AmountError),
+    #[error(transparent)]
+    Id(#[from] ingredient::ParseIdError),
+    #[error(transparent)]
+    Recipe(#[from] ingredient::RecipeError),
+}
+
+pub async fn set_product_recipe(
+    State(ctx): State<Context>,
+    Path(path): Path<ProductRecipePath>,
+    Json(body): Json<RecipeBody>,
+) -> Response {
+    let product_id = match product::Id::parse_str(&path.id) {
+        Ok(product_id) => product_id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let catalog_id = match catalog::Id::parse_str(&path.catalog_id) {
+        Ok(catalog_id) => catalog_id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let recipe = match body.parse() {
+        Ok(recipe) => recipe,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = SetProductRecipeInput {
+        product_id,
+        catalog_id,
+        recipe,
+    };
+
+    let pg_ingredients = PgIngredients::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool.clone());
+    let pg_extras = PgExtras::new(ctx.pool);
+    let mut service = IngredientService::new(pg_ingredients, pg_products, pg_extras);
+
+    let recipe = match service.set_product_recipe(input).await {
+        Ok(recipe) => recipe,
+        Err(err) => {
+            eprintln!("Set product recipe error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(RecipeView::new(&recipe)).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ExtraRecipePath {
+    pub id: String,
+}
+
+pub async fn extra_recipe(
+    State(ctx): State<Context>,
+    Path(path): Path<ExtraRecipePath>,
+) -> Response {
+    let extra_id = match extra::Id::parse_str(&path.id) {
+        Ok(extra_id) => extra_id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = ExtraRecipeInput { extra_id };
+
+    let pg_ingredients = PgIngredients::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool.clone());
+    let pg_extras = PgExtras::new(ctx.pool);
+    let service = IngredientService::new(pg_ingredients, pg_products, pg_extras);
+
+    let recipe = match service.extra_recipe(input).await {
+        Ok(recipe) => recipe,
+        Err(err) => {
+            eprintln!("Extra recipe error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(RecipeView::new(&recipe)).into_response()
+}
+
+pub async fn set_extra_recipe(
+    State(ctx): State<Context>,
+    Path(path): Path<ExtraRecipePath>,
+    Json(body): Json<RecipeBody>,
+) -> Response {
+    let extra_id = match extra::Id::parse_str(&path.id) {
+        Ok(extra_id) => extra_id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let recipe = match body.parse() {
+        Ok(recipe) => recipe,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = SetExtraRecipeInput { extra_id, recipe };
+
+    let pg_ingredients = PgIngredients::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool.clone());
+    let pg_extras = PgExtras::new(ctx.pool);
+    let mut service = IngredientService::new(pg_ingredients, pg_products, pg_extras);
+
+    let recipe = match service.set_extra_recipe(input).await {
+        Ok(recipe) => recipe,
+        Err(err) => {
+            eprintln!("Set extra recipe error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(RecipeView::new(&recipe)).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ConsumePath {
+    pub id: String,
+    pub catalog_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ConsumeBody {
+    pub quantity: Option<u16>,
+    #[serde(default)]
+    pub extras: Vec<String>,
+}
+
+pub async fn consume(
+    State(ctx): State<Context>,
+    Path(path): Path<ConsumePath>,
+    Json(body): Json<ConsumeBody>,
+) -> Response {
+    let product_id = match product::Id::parse_str(&path.id) {
+        Ok(product_id) => product_id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let catalog_id = match catalog::Id::parse_str(&path.catalog_id) {
+        Ok(catalog_id) => catalog_id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let quantity = match Quantity::new(body.quantity.unwrap_or(1)) {
+        Ok(quantity) => quantity,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let extras_ids = match ExtrasIds::parse(&body.extras) {
+        Ok(extras_ids) => extras_ids,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = ConsumeInput {
+        product_id,
+        catalog_id,
+        quantity,
+        extras_ids,
+    };
+
+    let pg_ingredients = PgIngredients::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool.clone());
+    let pg_extras = PgExtras::new(ctx.pool);
+    let mut service = IngredientService::new(pg_ingredients, pg_products, pg_extras);
+
+    let depleted_ingredients = match service.consume(input).await {
+        Ok(ingredients) => ingredients,
+        Err(err) => {
+            eprintln!("Consume ingredients error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    let views = depleted_ingredients
+        .iter()
+        .map(IngredientView::new)
+        .collect::<Vec<_>>();
+
+    Json(views).into_response()
+}
+
+pub fn create_error_response(err: ingredient::Error) -> impl IntoResponse {
+    use ingredient::Error;
+
+    match err {
+        Error::Conflict(kind) => (
+            StatusCode::CONFLICT,
+            Json(ApiError::new("Conflict", kind.to_string())),
+        ),
+        Error::Internal(_) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new("Internal", "Internal server error")),
+        ),
+        Error::NotFound(kind) => (
+            StatusCode::NOT_FOUND,
+            Json(ApiError::new("NotFound", kind.to_string())),
+        ),
+    }
+}
+
+fn create_validation_error_response(err: &dyn std::error::Error) -> impl IntoResponse {
+    let body = ApiError::new("Validation", err.to_string());
+    (StatusCode::BAD_REQUEST, Json(body))
+}
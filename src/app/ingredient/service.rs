@@ -0,0 +1,199 @@
+mod dto;
+
+pub use dto::{
+    ConsumeInput, CreateInput, DeleteInput, ExhaustedInput, ExtraRecipeInput, FindInput,
+    ProductRecipeInput, SetExtraRecipeInput, SetProductRecipeInput, UpdateInput,
+};
+
+use domain::catalog;
+use domain::extra;
+use domain::ingredient;
+use domain::product;
+
+#[derive(Clone, Debug)]
+pub struct IngredientService<T, U, V> {
+    ingredients: T,
+    products: U,
+    extras: V,
+}
+
+impl<T: ingredient::Repository, U: product::Repository, V: extra::Repository>
+    IngredientService<T, U, V>
+{
+    pub fn new(ingredients: T, products: U, extras: V) -> Self {
+        Self {
+            ingredients,
+            products,
+            extras,
+        }
+    }
+}
+
+impl<T: ingredient::Repository, U: product::Repository, V: extra::Repository>
+    IngredientService<T, U, V>
+{
+    pub async fn all(&self) -> Result<Vec<ingredient::Ingredient>, ingredient::Error> {
+        self.ingredients.all().await
+    }
+
+    pub async fn create(
+        &mut self,
+        input: CreateInput,
+    ) -> Result<ingredient::Ingredient, ingredient::Error> {
+        let ingredient = ingredient::Ingredient::new(input.name, input.unit, input.level);
+        self.ingredients.create(&ingredient).await?;
+
+        Ok(ingredient)
+    }
+
+    pub async fn delete(
+        &mut self,
+        input: DeleteInput,
+    ) -> Result<ingredient::Ingredient, ingredient::Error> {
+        self.ingredients.delete(input.id).await
+    }
+
+    pub async fn find(
+        &self,
+        input: FindInput,
+    ) -> Result<ingredient::Ingredient, ingredient::Error> {
+        self.ingredients.find(input.id).await
+    }
+
+    pub async fn update(
+        &mut self,
+        input: UpdateInput,
+    ) -> Result<ingredient::Ingredient, ingredient::Error> {
+        let mut ingredient = self.ingredients.find(input.id).await?;
+        ingredient.name = input.name;
+        ingredient.unit = input.unit;
+        ingredient.set_level(input.level);
+        ingredient.metadata.update();
+
+        self.ingredients.update(&ingredient).await?;
+
+        Ok(ingredient)
+    }
+
+    pub async fn product_recipe(
+        &self,
+        input: ProductRecipeInput,
+    ) -> Result<ingredient::Recipe, ingredient::Error> {
+        self.find_product(input.product_id, input.catalog_id)
+            .await?;
+
+        self.ingredients.product_recipe(input.product_id).await
+    }
+
+    pub async fn set_product_recipe(
+        &mut self,
+        input: SetProductRecipeInput,
+    ) -> Result<ingredient::Recipe, ingredient::Error> {
+        self.find_product(input.product_id, input.catalog_id)
+            .await?;
+        self.ingredients
+            .find_many(&input.recipe.ingredients_ids())
+            .await?;
+
+        self.ingredients
+            .set_product_recipe(input.product_id, &input.recipe)
+            .await?;
+
+        Ok(input.recipe)
+    }
+
+    pub async fn extra_recipe(
+        &self,
+        input: ExtraRecipeInput,
+    ) -> Result<ingredient::Recipe, ingredient::Error> {
+        self.ensure_extra(input.extra_id).await?;
+
+        self.ingredients.extra_recipe(input.extra_id).await
+    }
+
+    pub async fn set_extra_recipe(
+        &mut self,
+        input: SetExtraRecipeInput,
+    ) -> Result<ingredient::Recipe, ingredient::Error> {
+        self.ensure_extra(input.extra_id).await?;
+        self.ingredients
+            .find_many(&input.recipe.ingredients_ids())
+            .await?;
+
+        self.ingredients
+            .set_extra_recipe(input.extra_id, &input.recipe)
+            .await?;
+
+        Ok(input.recipe)
+    }
+
+    /// Deplete ingredients used to prepare `input.quantity` units of product
+    /// with the selected extras, returning the depleted ingredients
+    pub async fn consume(
+        &mut self,
+        input: ConsumeInput,
+    ) -> Result<Vec<ingredient::Ingredient>, ingredient::Error> {
+        let product = self
+            .find_product(input.product_id, input.catalog_id)
+            .await?;
+
+        let times = input.quantity.value();
+        let mut consumption = ingredient::Consumption::new();
+        let recipe = self.ingredients.product_recipe(product.id()).await?;
+        consumption.add(&recipe, times);
+
+        for extra_id in input.extras_ids.take() {
+            if !product.extras.iter().any(|extra| extra.id() == extra_id) {
+                let kind = product::NotFoundKind::ExtraId(extra_id);
+                return Err(ingredient::Error::NotFound(
+                    ingredient::NotFoundKind::Product(kind),
+                ));
+            }
+
+            let recipe = self.ingredients.extra_recipe(extra_id).await?;
+            consumption.add(&recipe, times);
+        }
+
+        if consumption.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.ingredients.consume(&consumption).await
+    }
+
+    pub async fn exhausted(
+        &self,
+        input: ExhaustedInput,
+    ) -> Result<ingredient::Exhausted, ingredient::Error> {
+        self.ingredients.exhausted(&input.products_ids).await
+    }
+
+    async fn find_product(
+        &self,
+        product_id: product::Id,
+        catalog_id: catalog::Id,
+    ) -> Result<product::Product, ingredient::Error> {
+        self.products
+            .find(product_id, catalog_id)
+            .await
+            .map_err(|err| match err {
+                product::Error::NotFound(kind) => {
+                    ingredient::Error::NotFound(ingredient::NotFoundKind::Product(kind))
+                }
+                err => ingredient::Error::any(err),
+            })
+    }
+
+    async fn ensure_extra(&self, extra_id: extra::Id) -> Result<(), ingredient::Error> {
+        self.extras
+            .find(extra_id)
+            .await
+            .map(|_| ())
+            .map_err(|err| match err {
+                extra::Error::NotFound(id) => {
+                    ingredient::Error::NotFound(ingredient::NotFoundKind::Extra(id))
+                }
+                err => ingredient::Error::any(err),
+            })
+    }
+}
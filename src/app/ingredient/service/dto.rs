@@ -0,0 +1,70 @@
+use domain::catalog;
+use domain::core::quantity::Quantity;
+use domain::extra;
+use domain::ingredient;
+use domain::product;
+
+use crate::app::product::service::ExtrasIds;
+
+#[derive(Clone, Debug)]
+pub struct CreateInput {
+    pub name: ingredient::Name,
+    pub unit: ingredient::Unit,
+    pub level: ingredient::Level,
+}
+
+#[derive(Clone, Debug)]
+pub struct DeleteInput {
+    pub id: ingredient::Id,
+}
+
+#[derive(Clone, Debug)]
+pub struct FindInput {
+    pub id: ingredient::Id,
+}
+
+#[derive(Clone, Debug)]
+pub struct UpdateInput {
+    pub id: ingredient::Id,
+    pub name: ingredient::Name,
+    pub unit: ingredient::Unit,
+    pub level: ingredient::Level,
+}
+
+#[derive(Clone, Debug)]
+pub struct ProductRecipeInput {
+    pub product_id: product::Id,
+    pub catalog_id: catalog::Id,
+}
+
+#[derive(Clone, Debug)]
+pub struct SetProductRecipeInput {
+    pub product_id: product::Id,
+    pub catalog_id: catalog::Id,
+    pub recipe: ingredient::Recipe,
+}
+
+#[derive(Clone, Debug)]
+pub struct ExtraRecipeInput {
+    pub extra_id: extra::Id,
+}
+
+#[derive(Clone, Debug)]
+pub struct SetExtraRecipeInput {
+    pub extra_id: extra::Id,
+    pub recipe: ingredient::Recipe,
+}
+
+#[derive(Clone, Debug)]
+pub struct ConsumeInput {
+    pub product_id: product::Id,
+    pub catalog_id: catalog::Id,
+    pub quantity: Quantity,
+    /// Extras added to every unit of product, which must be bound to it
+    pub extras_ids: ExtrasIds,
+}
+
+#[derive(Clone, Debug)]
+pub struct ExhaustedInput {
+    pub products_ids: Vec<product::Id>,
+}
@@ -0,0 +1,60 @@
+use serde::Serialize;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use domain::ingredient;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct IngredientView<'a> {
+    pub id: Uuid,
+    pub name: &'a str,
+    pub unit: &'a str,
+    pub level: u32,
+    pub exhausted: bool,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime,
+}
+
+impl<'a> IngredientView<'a> {
+    pub fn new(ingredient: &'a ingredient::Ingredient) -> Self {
+        Self {
+            id: ingredient.id().uuid(),
+            name: ingredient.name.as_str(),
+            unit: ingredient.unit.as_str(),
+            level: ingredient.level().value(),
+            exhausted: ingredient.is_exhausted(),
+            created_at: ingredient.metadata.created_at(),
+            updated_at: ingredient.metadata.updated_at(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct RecipeView {
+    pub ingredients: Vec<PortionView>,
+}
+
+impl RecipeView {
+    pub fn new(recipe: &ingredient::Recipe) -> Self {
+        Self {
+            ingredients: recipe.iter().map(PortionView::new).collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct PortionView {
+    pub ingredient_id: Uuid,
+    pub amount: u32,
+}
+
+impl PortionView {
+    pub fn new(portion: &ingredient::Portion) -> Self {
+        Self {
+            ingredient_id: portion.ingredient_id.uuid(),
+            amount: portion.amount.value(),
+        }
+    }
+}
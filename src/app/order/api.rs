@@ -0,0 +1,208 @@
+use std::num::{NonZeroU32, NonZeroU8};
+
+use axum::extract::{Json, Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::Deserialize;
+
+use domain::catalog;
+use domain::core::quantity::Quantity;
+use domain::order;
+use domain::product;
+
+use super::service::{CreateInput, DeleteInput, FindInput, ItemInput, ListInput, OrderService};
+use super::view::{OrderView, PaginationView};
+use crate::app::product::service::ExtrasIds;
+use crate::app::ApiError;
+use crate::infra::{PgOrders, PgProducts};
+use crate::Context;
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CreateBody {
+    pub items: Vec<CreateItemBody>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CreateItemBody {
+    pub catalog_id: String,
+    pub product_id: String,
+    pub extras_ids: Vec<String>,
+    pub quantity: u16,
+}
+
+pub async fn create(State(ctx): State<Context>, Json(body): Json<CreateBody>) -> Response {
+    if body.items.is_empty() {
+        return create_validation_error_response(&order::ItemsError::Empty).into_response();
+    }
+    if body.items.len() > order::Items::MAX_LEN {
+        return create_validation_error_response(&order::ItemsError::Length).into_response();
+    }
+
+    let mut items = Vec::with_capacity(body.items.len());
+    for item in &body.items {
+        let catalog_id = match catalog::Id::parse_str(&item.catalog_id) {
+            Ok(catalog_id) => catalog_id,
+            Err(err) => return create_validation_error_response(&err).into_response(),
+        };
+        let product_id = match product::Id::parse_str(&item.product_id) {
+            Ok(product_id) => product_id,
+            Err(err) => return create_validation_error_response(&err).into_response(),
+        };
+        let extras_ids = match ExtrasIds::parse(&item.extras_ids) {
+            Ok(extras_ids) => extras_ids,
+            Err(err) => return create_validation_error_response(&err).into_response(),
+        };
+        let quantity = match Quantity::new(item.quantity) {
+            Ok(quantity) => quantity,
+            Err(err) => return create_validation_error_response(&err).into_response(),
+        };
+
+        items.push(ItemInput {
+            catalog_id,
+            product_id,
+            extras_ids,
+            quantity,
+        });
+    }
+    let input = CreateInput { items };
+
+    let pg_orders = PgOrders::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool);
+    let mut service = OrderService::new(pg_orders, pg_products);
+
+    let created_order = match service.create(input).await {
+        Ok(order) => order,
+        Err(err) => {
+            eprintln!("Create order error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    create_view_response(&created_order)
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct DeletePath {
+    pub id: String,
+}
+
+pub async fn delete(State(ctx): State<Context>, Path(path): Path<DeletePath>) -> Response {
+    let id = match order::Id::parse_str(&path.id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = DeleteInput { id };
+
+    let pg_orders = PgOrders::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool);
+    let mut service = OrderService::new(pg_orders, pg_products);
+
+    let deleted_order = match service.delete(input).await {
+        Ok(order) => order,
+        Err(err) => {
+            eprintln!("Delete order error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    create_view_response(&deleted_order)
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct FindPath {
+    pub id: String,
+}
+
+pub async fn find(State(ctx): State<Context>, Path(path): Path<FindPath>) -> Response {
+    let id = match order::Id::parse_str(&path.id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = FindInput { id };
+
+    let pg_orders = PgOrders::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool);
+    let service = OrderService::new(pg_orders, pg_products);
+
+    let found_order = match service.find(input).await {
+        Ok(order) => order,
+        Err(err) => {
+            eprintln!("Find order error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    create_view_response(&found_order)
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListQuery {
+    pub page: Option<u32>,
+    pub limit: Option<u8>,
+}
+
+pub async fn list(State(ctx): State<Context>, Query(query): Query<ListQuery>) -> Response {
+    let page = match query.page {
+        Some(0) | None => NonZeroU32::new(1).unwrap(),
+        Some(page) => NonZeroU32::new(page).expect("Page is not zero"),
+    };
+    let limit = match query.limit {
+        Some(0) | None => NonZeroU8::new(10).unwrap(),
+        Some(limit) => NonZeroU8::new(limit).expect("Limit is not zero"),
+    };
+    let input = ListInput { page, limit };
+
+    let pg_orders = PgOrders::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool);
+    let service = OrderService::new(pg_orders, pg_products);
+
+    let pagination = match service.list(input).await {
+        Ok(pagination) => pagination,
+        Err(err) => {
+            eprintln!("List orders error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    match PaginationView::new(&pagination) {
+        Ok(view) => Json(view).into_response(),
+        Err(err) => {
+            eprintln!("Orders pagination view error: {err:?}");
+            create_error_response(err).into_response()
+        }
+    }
+}
+
+fn create_view_response(order: &order::Order) -> Response {
+    match OrderView::new(order) {
+        Ok(view) => Json(view).into_response(),
+        Err(err) => {
+            eprintln!("Order view error: {err:?}");
+            create_error_response(err).into_response()
+        }
+    }
+}
+
+fn create_error_response(err: order::Error) -> impl IntoResponse {
+    use order::Error;
+
+    match err {
+        Error::Conflict(kind) => (
+            StatusCode::CONFLICT,
+            Json(ApiError::new("Conflict", kind.to_string())),
+        ),
+        Error::Internal(_) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new("Internal", "Internal server error")),
+        ),
+        Error::NotFound(kind) => (
+            StatusCode::NOT_FOUND,
+            Json(ApiError::new("NotFound", kind.to_string())),
+        ),
+    }
+}
+
+fn create_validation_error_response(err: &dyn std::error::Error) -> impl IntoResponse {
+    let body = ApiError::new("Validation", err.to_string());
+    (StatusCode::BAD_REQUEST, Json(body))
+}
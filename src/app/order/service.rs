@@ -0,0 +1,69 @@
+mod dto;
+
+pub use dto::{CreateInput, DeleteInput, FindInput, ItemInput, ListInput};
+
+use domain::order;
+use domain::product;
+
+#[derive(Clone, Debug)]
+pub struct OrderService<T, U> {
+    orders: T,
+    products: U,
+}
+
+impl<T: order::Repository, U: product::Repository> OrderService<T, U> {
+    pub fn new(orders: T, products: U) -> Self {
+        Self { orders, products }
+    }
+}
+
+impl<T: order::Repository, U: product::Repository> OrderService<T, U> {
+    pub async fn create(&mut self, input: CreateInput) -> Result<order::Order, order::Error> {
+        let mut items = Vec::with_capacity(input.items.len());
+        for item_input in input.items {
+            let product = self
+                .products
+                .find(item_input.product_id, item_input.catalog_id)
+                .await
+                .map_err(|err| match err {
+                    product::Error::NotFound(kind) => {
+                        order::Error::NotFound(order::NotFoundKind::Product(kind))
+                    }
+                    err => order::Error::any(err),
+                })?;
+
+            let item = order::Item::new(
+                &product,
+                item_input.extras_ids.as_slice(),
+                item_input.quantity,
+            )?;
+
+            items.push(item);
+        }
+
+        let items = order::Items::new(items).map_err(order::Error::any)?;
+        let order = order::Order::new(items);
+        // orders must be payable in a single currency
+        order.total()?;
+        self.orders.create(&order).await?;
+
+        Ok(order)
+    }
+
+    pub async fn delete(&mut self, input: DeleteInput) -> Result<order::Order, order::Error> {
+        self.orders.delete(input.id).await
+    }
+
+    pub async fn find(&self, input: FindInput) -> Result<order::Order, order::Error> {
+        self.orders.find(input.id).await
+    }
+
+    pub async fn list(&self, input: ListInput) -> Result<order::Pagination, order::Error> {
+        let query = order::ListQuery {
+            page: input.page,
+            limit: input.limit,
+        };
+
+        self.orders.list(query).await
+    }
+}
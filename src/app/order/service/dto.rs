@@ -0,0 +1,37 @@
+use std::num::{NonZeroU32, NonZeroU8};
+
+use domain::catalog;
+use domain::core::quantity::Quantity;
+use domain::order;
+use domain::product;
+
+use crate::app::product::service::ExtrasIds;
+
+#[derive(Clone, Debug)]
+pub struct CreateInput {
+    pub items: Vec<ItemInput>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ItemInput {
+    pub catalog_id: catalog::Id,
+    pub product_id: product::Id,
+    pub extras_ids: ExtrasIds,
+    pub quantity: Quantity,
+}
+
+#[derive(Clone, Debug)]
+pub struct DeleteInput {
+    pub id: order::Id,
+}
+
+#[derive(Clone, Debug)]
+pub struct FindInput {
+    pub id: order::Id,
+}
+
+#[derive(Clone, Debug)]
+pub struct ListInput {
+    pub page: NonZeroU32,
+    pub limit: NonZeroU8,
+}
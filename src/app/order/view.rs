@@ -0,0 +1,101 @@
+use serde::Serialize;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use domain::order;
+
+use crate::app::MoneyView;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct OrderView<'a> {
+    pub id: Uuid,
+    pub items: Vec<ItemView<'a>>,
+    pub total: MoneyView,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime,
+}
+
+impl<'a> OrderView<'a> {
+    pub fn new(order: &'a order::Order) -> Result<Self, order::Error> {
+        Ok(Self {
+            id: order.id().uuid(),
+            items: order
+                .items
+                .iter()
+                .map(ItemView::new)
+                .collect::<Result<_, _>>()?,
+            total: MoneyView::new(order.total()?),
+            created_at: order.metadata.created_at(),
+            updated_at: order.metadata.updated_at(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ItemView<'a> {
+    pub id: Uuid,
+    pub product_id: Uuid,
+    pub catalog_id: Uuid,
+    pub name: &'a str,
+    pub price: MoneyView,
+    pub quantity: u16,
+    pub extras: Vec<ItemExtraView<'a>>,
+    pub total: MoneyView,
+}
+
+impl<'a> ItemView<'a> {
+    pub fn new(item: &'a order::Item) -> Result<Self, order::Error> {
+        Ok(Self {
+            id: item.id().uuid(),
+            product_id: item.product_id().uuid(),
+            catalog_id: item.catalog_id().uuid(),
+            name: item.name().as_str(),
+            price: MoneyView::new(item.price()),
+            quantity: item.quantity().value(),
+            extras: item.extras().iter().map(ItemExtraView::new).collect(),
+            total: MoneyView::new(item.total()?),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ItemExtraView<'a> {
+    pub id: Uuid,
+    pub name: &'a str,
+    pub price: MoneyView,
+}
+
+impl<'a> ItemExtraView<'a> {
+    pub fn new(extra: &'a order::ItemExtra) -> Self {
+        Self {
+            id: extra.id.uuid(),
+            name: extra.name.as_str(),
+            price: MoneyView::new(extra.price),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct PaginationView<'a> {
+    pub count: u64,
+    pub page: u32,
+    pub limit: u8,
+    pub items: Vec<OrderView<'a>>,
+}
+
+impl<'a> PaginationView<'a> {
+    pub fn new(pagination: &'a order::Pagination) -> Result<Self, order::Error> {
+        Ok(Self {
+            count: pagination.count,
+            page: pagination.page.into(),
+            limit: pagination.limit.into(),
+            items: pagination
+                .items
+                .iter()
+                .map(OrderView::new)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
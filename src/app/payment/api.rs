@@ -0,0 +1,209 @@
+use axum::extract::{Json, Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::Deserialize;
+
+use domain::catalog;
+use domain::core::quantity::Quantity;
+use domain::payment;
+use domain::product;
+
+use super::service::{
+    CheckoutInput, CheckoutItemInput, CheckoutItems, FindInput, PaymentService, RefundInput,
+    VoidInput,
+};
+use super::view::{CheckoutView, PaymentView};
+use crate::app::product::service::ExtrasIds;
+use crate::app::ApiError;
+use crate::infra::{AnyPaymentProvider, PgPayments, PgProducts};
+use crate::Context;
+
+type Service = PaymentService<PgPayments, PgProducts, AnyPaymentProvider>;
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CheckoutBody {
+    pub items: Vec<CheckoutItemBody>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CheckoutItemBody {
+    pub catalog_id: String,
+    pub product_id: String,
+    pub extras_ids: Vec<String>,
+    pub quantity: u16,
+}
+
+pub async fn checkout(State(ctx): State<Context>, Json(body): Json<CheckoutBody>) -> Response {
+    let mut items = Vec::with_capacity(body.items.len());
+    for item in &body.items {
+        let catalog_id = match catalog::Id::parse_str(&item.catalog_id) {
+            Ok(catalog_id) => catalog_id,
+            Err(err) => return create_validation_error_response(&err).into_response(),
+        };
+        let product_id = match product::Id::parse_str(&item.product_id) {
+            Ok(product_id) => product_id,
+            Err(err) => return create_validation_error_response(&err).into_response(),
+        };
+        let extras_ids = match ExtrasIds::parse(&item.extras_ids) {
+            Ok(extras_ids) => extras_ids,
+            Err(err) => return create_validation_error_response(&err).into_response(),
+        };
+        let quantity = match Quantity::new(item.quantity) {
+            Ok(quantity) => quantity,
+            Err(err) => return create_validation_error_response(&err).into_response(),
+        };
+
+        items.push(CheckoutItemInput {
+            catalog_id,
+            product_id,
+            extras_ids,
+            quantity,
+        });
+    }
+    let items = match CheckoutItems::new(items) {
+        Ok(items) => items,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = CheckoutInput { items };
+
+    let Some(mut service) = create_service(ctx) else {
+        return create_unavailable_response().into_response();
+    };
+    let checkout = match service.checkout(input).await {
+        Ok(checkout) => checkout,
+        Err(err) => {
+            eprintln!("Checkout error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    let status = match checkout.payment.status() {
+        payment::Status::Failed => StatusCode::PAYMENT_REQUIRED,
+        _ => StatusCode::CREATED,
+    };
+
+    (status, Json(CheckoutView::new(&checkout))).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct FindPath {
+    pub id: String,
+}
+
+pub async fn find(State(ctx): State<Context>, Path(path): Path<FindPath>) -> Response {
+    let id = match payment::Id::parse_str(&path.id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = FindInput { id };
+
+    let Some(service) = create_service(ctx) else {
+        return create_unavailable_response().into_response();
+    };
+    let found_payment = match service.find(input).await {
+        Ok(payment) => payment,
+        Err(err) => {
+            eprintln!("Find payment error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(PaymentView::new(&found_payment)).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RefundPath {
+    pub id: String,
+}
+
+pub async fn refund(State(ctx): State<Context>, Path(path): Path<RefundPath>) -> Response {
+    let id = match payment::Id::parse_str(&path.id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = RefundInput { id };
+
+    let Some(mut service) = create_service(ctx) else {
+        return create_unavailable_response().into_response();
+    };
+    let refunded_payment = match service.refund(input).await {
+        Ok(payment) => payment,
+        Err(err) => {
+            eprintln!("Refund payment error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(PaymentView::new(&refunded_payment)).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct VoidPath {
+    pub id: String,
+}
+
+pub async fn void(State(ctx): State<Context>, Path(path): Path<VoidPath>) -> Response {
+    let id = match payment::Id::parse_str(&path.id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = VoidInput { id };
+
+    let Some(mut service) = create_service(ctx) else {
+        return create_unavailable_response().into_response();
+    };
+    let voided_payment = match service.void(input).await {
+        Ok(payment) => payment,
+        Err(err) => {
+            eprintln!("Void payment error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(PaymentView::new(&voided_payment)).into_response()
+}
+
+/// Service charging through the configured provider, if there is one
+fn create_service(ctx: Context) -> Option<Service> {
+    let provider = ctx.payment_provider?;
+    let pg_payments = PgPayments::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool);
+    Some(PaymentService::new(pg_payments, pg_products, provider))
+}
+
+pub fn create_error_response(err: payment::Error) -> impl IntoResponse {
+    use payment::{Error, ProviderError};
+
+    match err {
+        Error::Conflict(kind) => (
+            StatusCode::CONFLICT,
+            Json(ApiError::new("Conflict", kind.to_string())),
+        ),
+        Error::Internal(_) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new("Internal", "Internal server error")),
+        ),
+        Error::NotFound(kind) => (
+            StatusCode::NOT_FOUND,
+            Json(ApiError::new("NotFound", kind.to_string())),
+        ),
+        Error::Provider(err @ ProviderError::Declined(_)) => (
+            StatusCode::PAYMENT_REQUIRED,
+            Json(ApiError::new("Declined", err.to_string())),
+        ),
+        Error::Provider(err @ ProviderError::Unavailable(_)) => (
+            StatusCode::BAD_GATEWAY,
+            Json(ApiError::new("Unavailable", err.to_string())),
+        ),
+    }
+}
+
+fn create_unavailable_response() -> impl IntoResponse {
+    let body = ApiError::new("Unavailable", "No payment provider is configured");
+    (StatusCode::SERVICE_UNAVAILABLE, Json(body))
+}
+
+fn create_validation_error_response(err: &dyn std::error::Error) -> impl IntoResponse {
+    let body = ApiError::new("Validation", err.to_string());
+    (StatusCode::BAD_REQUEST, Json(body))
+}
@@ -0,0 +1,200 @@
+mod dto;
+
+pub use dto::{
+    Checkout, CheckoutInput, CheckoutItemInput, CheckoutItems, CheckoutItemsError, FindInput,
+    RefundInput, VoidInput,
+};
+
+use domain::core::money::Money;
+use domain::payment::{self, PaymentProvider};
+use domain::product;
+
+#[derive(Clone, Debug)]
+pub struct PaymentService<T, U, P> {
+    payments: T,
+    products: U,
+    provider: P,
+}
+
+impl<T, U, P> PaymentService<T, U, P>
+where
+    T: payment::Repository,
+    U: product::Repository,
+    P: PaymentProvider,
+{
+    pub fn new(payments: T, products: U, provider: P) -> Self {
+        Self {
+            payments,
+            products,
+            provider,
+        }
+    }
+}
+
+impl<T, U, P> PaymentService<T, U, P>
+where
+    T: payment::Repository,
+    U: product::Repository,
+    P: PaymentProvider,
+{
+    /// Price `input` items from current prices and charge their total through
+    /// provider. Declined charges are not an [`Err`], they are recorded as a
+    /// failed payment instead
+    pub async fn checkout(&mut self, input: CheckoutInput) -> Result<Checkout, payment::Error> {
+        let mut quotes = Vec::with_capacity(input.items.as_slice().len());
+        for item in input.items.as_slice() {
+            let product = self
+                .products
+                .find(item.product_id, item.catalog_id)
+                .await
+                .map_err(map_product_error)?;
+
+            // every extra id selects a single unit of it, as in orders
+            let selections = item
+                .extras_ids
+                .as_slice()
+                .iter()
+                .map(|id| product::ExtraSelection::single(*id))
+                .collect::<Vec<_>>();
+
+            let quote = product
+                .quote(item.quantity, &selections)
+                .map_err(map_product_error)?;
+
+            quotes.push(quote);
+        }
+
+        let Some(currency) = quotes.first().map(|quote| quote.total().currency()) else {
+            return Err(payment::Error::any(CheckoutItemsError::Empty));
+        };
+        let totals = quotes.iter().map(product::Quote::total);
+        let total = Money::checked_sum(currency, totals).map_err(payment::Error::money)?;
+
+        let mut payment = payment::Payment::new(total, self.provider.name());
+        self.payments.create(&payment).await?;
+
+        match self.provider.authorize(payment.id(), total).await {
+            Ok(reference) => {
+                payment.authorize(reference.clone())?;
+                self.payments
+                    .update(&payment, payment::Status::Pending)
+                    .await?;
+
+                if let Err(err) = self.provider.capture(&reference, total).await {
+                    // the authorization would otherwise keep the amount reserved
+                    // until provider expires it
+                    let reason = match self.provider.void(&reference).await {
+                        Ok(()) => err.to_string(),
+                        Err(void_err) => format!("{err}, and voiding failed: {void_err}"),
+                    };
+                    payment.fail(reason)?;
+                } else {
+                    payment.capture()?;
+                }
+
+                self.payments
+                    .update(&payment, payment::Status::Authorized)
+                    .await?;
+            }
+            Err(err) => {
+                payment.fail(err.to_string())?;
+                self.payments
+                    .update(&payment, payment::Status::Pending)
+                    .await?;
+            }
+        }
+
+        Ok(Checkout { quotes, payment })
+    }
+
+    pub async fn find(&self, input: FindInput) -> Result<payment::Payment, payment::Error> {
+        self.payments.find(input.id).await
+    }
+
+    /// Refund a captured payment, recording it as refunding before reaching
+    /// provider. Payments left refunding because provider was unavailable
+    /// can be refunded again
+    pub async fn refund(&mut self, input: RefundInput) -> Result<payment::Payment, payment::Error> {
+        let mut payment = self.payments.find(input.id).await?;
+        if payment.status() != payment::Status::Refunding {
+            payment.start_refund()?;
+            self.payments
+                .update(&payment, payment::Status::Captured)
+                .await?;
+        }
+
+        let reference = provider_reference(&payment)?;
+        let result = self.provider.refund(&reference, payment.amount()).await;
+        match result {
+            Ok(()) => payment.refund()?,
+            Err(payment::ProviderError::Declined(_)) => payment.revert_refund()?,
+            // provider may have refunded it anyway, so it stays refunding
+            Err(err @ payment::ProviderError::Unavailable(_)) => {
+                return Err(payment::Error::Provider(err))
+            }
+        }
+
+        self.payments
+            .update(&payment, payment::Status::Refunding)
+            .await?;
+
+        result.map_err(payment::Error::Provider)?;
+
+        Ok(payment)
+    }
+
+    /// Void an authorized payment, recording it as voiding before reaching
+    /// provider. Payments left voiding because provider was unavailable can
+    /// be voided again
+    pub async fn void(&mut self, input: VoidInput) -> Result<payment::Payment, payment::Error> {
+        let mut payment = self.payments.find(input.id).await?;
+        if payment.status() != payment::Status::Voiding {
+            payment.start_void()?;
+            self.payments
+                .update(&payment, payment::Status::Authorized)
+                .await?;
+        }
+
+        let reference = provider_reference(&payment)?;
+        let result = self.provider.void(&reference).await;
+        match result {
+            Ok(()) => payment.void()?,
+            Err(payment::ProviderError::Declined(_)) => payment.revert_void()?,
+            // provider may have voided it anyway, so it stays voiding
+            Err(err @ payment::ProviderError::Unavailable(_)) => {
+                return Err(payment::Error::Provider(err))
+            }
+        }
+
+        self.payments
+            .update(&payment, payment::Status::Voiding)
+            .await?;
+
+        result.map_err(payment::Error::Provider)?;
+
+        Ok(payment)
+    }
+}
+
+fn provider_reference(payment: &payment::Payment) -> Result<payment::Reference, payment::Error> {
+    // authorized payments always keep the reference given by provider
+    payment
+        .reference()
+        .cloned()
+        .ok_or_else(|| payment::Error::any("Payment without provider reference"))
+}
+
+fn map_product_error(err: product::Error) -> payment::Error {
+    match err {
+        product::Error::NotFound(kind) => {
+            payment::Error::NotFound(payment::NotFoundKind::Product(kind))
+        }
+        product::Error::Conflict(product::ConflictKind::Currency { expected, found }) => {
+            payment::Error::Conflict(payment::ConflictKind::Currency { expected, found })
+        }
+        product::Error::Conflict(product::ConflictKind::Selection(err)) => {
+            payment::Error::selection(err)
+        }
+        err => payment::Error::any(err),
+    }
+}
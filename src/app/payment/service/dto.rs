@@ -0,0 +1,74 @@
+use thiserror::Error;
+
+use domain::catalog;
+use domain::core::quantity::Quantity;
+use domain::payment;
+use domain::product;
+
+use crate::app::product::service::ExtrasIds;
+
+#[derive(Clone, Debug)]
+pub struct CheckoutInput {
+    pub items: CheckoutItems,
+}
+
+#[derive(Clone, Debug)]
+pub struct CheckoutItemInput {
+    pub catalog_id: catalog::Id,
+    pub product_id: product::Id,
+    pub extras_ids: ExtrasIds,
+    pub quantity: Quantity,
+}
+
+/// Products being paid at once, never empty
+#[derive(Clone, Debug)]
+pub struct CheckoutItems(Vec<CheckoutItemInput>);
+
+impl CheckoutItems {
+    pub const MAX_LEN: usize = 64;
+
+    pub fn new(items: Vec<CheckoutItemInput>) -> Result<Self, CheckoutItemsError> {
+        if items.is_empty() {
+            return Err(CheckoutItemsError::Empty);
+        }
+        if items.len() > Self::MAX_LEN {
+            return Err(CheckoutItemsError::Length);
+        }
+
+        Ok(Self(items))
+    }
+
+    pub fn as_slice(&self) -> &[CheckoutItemInput] {
+        &self.0
+    }
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum CheckoutItemsError {
+    #[error("Checkout must have at least one item")]
+    Empty,
+    #[error("Checkout cannot have more than {len} items", len = CheckoutItems::MAX_LEN)]
+    Length,
+}
+
+/// Outcome of a checkout, where `payment` may have failed
+#[derive(Clone, Debug)]
+pub struct Checkout {
+    pub quotes: Vec<product::Quote>,
+    pub payment: payment::Payment,
+}
+
+#[derive(Clone, Debug)]
+pub struct FindInput {
+    pub id: payment::Id,
+}
+
+#[derive(Clone, Debug)]
+pub struct RefundInput {
+    pub id: payment::Id,
+}
+
+#[derive(Clone, Debug)]
+pub struct VoidInput {
+    pub id: payment::Id,
+}
@@ -0,0 +1,55 @@
+use serde::Serialize;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use domain::payment;
+
+use super::service::Checkout;
+use crate::app::product::view::QuoteView;
+use crate::app::MoneyView;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct PaymentView<'a> {
+    pub id: Uuid,
+    pub amount: MoneyView,
+    pub provider: &'a str,
+    pub status: &'static str,
+    pub reference: Option<&'a str>,
+    pub failure: Option<&'a str>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime,
+}
+
+impl<'a> PaymentView<'a> {
+    pub fn new(payment: &'a payment::Payment) -> Self {
+        Self {
+            id: payment.id().uuid(),
+            amount: MoneyView::new(payment.amount()),
+            provider: payment.provider(),
+            status: payment.status().as_str(),
+            reference: payment.reference().map(payment::Reference::as_str),
+            failure: payment.failure(),
+            created_at: payment.metadata.created_at(),
+            updated_at: payment.metadata.updated_at(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct CheckoutView<'a> {
+    pub items: Vec<QuoteView<'a>>,
+    pub total: MoneyView,
+    pub payment: PaymentView<'a>,
+}
+
+impl<'a> CheckoutView<'a> {
+    pub fn new(checkout: &'a Checkout) -> Self {
+        Self {
+            items: checkout.quotes.iter().map(QuoteView::new).collect(),
+            total: MoneyView::new(checkout.payment.amount()),
+            payment: PaymentView::new(&checkout.payment),
+        }
+    }
+}
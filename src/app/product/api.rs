@@ -1,15 +1,39 @@
-use axum::extract::{Json, Path, State};
+use std::num::{NonZeroU32, NonZeroU8};
+
+use axum::extract::{Json, Path, Query, State};
 use axum::http::StatusCode;
-use axum::response::IntoResponse;
+use axum::response::{IntoResponse, Response};
 use serde::Deserialize;
+use thiserror::Error;
+use time::OffsetDateTime;
 
 use domain::catalog;
+use domain::core::money::Money;
+use domain::core::quantity::Quantity;
+use domain::core::schedule;
+use domain::core::sort::Sort;
+use domain::extra;
+use domain::modifier;
 use domain::product;
+use domain::variant;
 
-use super::service::{CreateInput, DeleteInput, ExtrasIds, FindInput, ProductService, UpdateInput};
-use super::view::ProductView;
-use crate::app::ApiError;
-use crate::infra::{PgExtras, PgProducts};
+use super::service::{
+    CreateInput, CreateModifierInput, CreateVariantInput, DeleteInput, DeleteModifierInput,
+    DeleteVariantInput, ExtrasIds, FindInput, FindModifierInput, FindVariantInput, ListInput,
+    ProductService, QuoteInput, ScheduleUpdate, UpdateInput, UpdateModifierInput,
+    UpdateVariantInput, ValidateSelectionInput,
+};
+use super::view::{ModifierView, PaginationView, ProductView, QuoteView, VariantView};
+use crate::app::ingredient::api::create_error_response as create_ingredient_error_response;
+use crate::app::ingredient::service::{ExhaustedInput, IngredientService};
+use crate::app::stock::api::create_error_response as create_stock_error_response;
+use crate::app::stock::service::{AllInput as AllStocksInput, StockService};
+use crate::app::tax::api::create_error_response as create_tax_error_response;
+use crate::app::tax::service::TaxService;
+use crate::app::{
+    parse_date, parse_price, parse_window_in, ApiError, MoneyBody, ParseDateError, ParseWindowError,
+};
+use crate::infra::{PgExtras, PgIngredients, PgProducts, PgStocks, PgTaxRules};
 use crate::Context;
 
 #[derive(Clone, Debug, Deserialize)]
@@ -20,9 +44,15 @@ pub struct CreatePath {
 #[derive(Clone, Debug, Deserialize)]
 pub struct CreateBody {
     pub name: String,
-    pub price: u64,
+    pub price: MoneyBody,
     pub kind: String,
     pub extras_ids: Vec<String>,
+    #[serde(default)]
+    pub max_quantities: Vec<MaxQuantityBody>,
+    #[serde(default)]
+    pub price_overrides: Vec<PriceOverrideBody>,
+    pub available: Option<bool>,
+    pub schedule: Option<ScheduleBody>,
 }
 
 pub async fn create(
@@ -38,6 +68,10 @@ pub async fn create(
         Ok(name) => name,
         Err(err) => return create_validation_error_response(&err).into_response(),
     };
+    let price = match body.price.parse() {
+        Ok(price) => price,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
     let kind = match product::Kind::parse_str(&body.kind) {
         Ok(name) => name,
         Err(err) => return create_validation_error_response(&err).into_response(),
@@ -46,16 +80,34 @@ pub async fn create(
         Ok(extras_ids) => extras_ids,
         Err(err) => return create_validation_error_response(&err).into_response(),
     };
+    let max_quantities = match parse_max_quantities(&body.max_quantities) {
+        Ok(max_quantities) => max_quantities,
+        Err(err) => return create_validation_error_response(err.as_ref()).into_response(),
+    };
+    let price_overrides = match parse_price_overrides(&body.price_overrides) {
+        Ok(price_overrides) => price_overrides,
+        Err(err) => return create_validation_error_response(err.as_ref()).into_response(),
+    };
+    let schedule = match body.schedule.as_ref().map(ScheduleBody::parse).transpose() {
+        Ok(schedule) => schedule,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
     let input = CreateInput {
         catalog_id,
         name,
-        price: product::Price::from_cents(body.price),
+        price,
         kind,
         extras_ids,
+        max_quantities,
+        price_overrides,
+        available: body.available.unwrap_or(true),
+        schedule,
     };
 
-    let mut service =
-        ProductService::new(PgProducts::new(ctx.pool.clone()), PgExtras::new(ctx.pool));
+    let mut service = ProductService::new(
+        PgProducts::new(ctx.pool.clone()),
+        PgExtras::new(ctx.pool.clone()),
+    );
 
     let created_product = match service.create(input).await {
         Ok(product) => product,
@@ -65,7 +117,24 @@ pub async fn create(
         }
     };
 
-    Json(ProductView::new(&created_product)).into_response()
+    create_written_view_response(&ctx, &created_product).await
+}
+
+/// How many of an extra can be selected for a single unit of product, e.g.
+/// `{ "extra_id": "0190ec13-15cc-7f53-bc0f-d60f0beea824", "max_quantity": 2 }`
+#[derive(Clone, Debug, Deserialize)]
+pub struct MaxQuantityBody {
+    pub extra_id: String,
+    pub max_quantity: u16,
+}
+
+/// Price of an extra when bound to product, instead of its own price, e.g.
+/// `{ "extra_id": "0190ec13-15cc-7f53-bc0f-d60f0beea824", "price": { "amount":
+/// 5.0, "currency": "BRL" } }`
+#[derive(Clone, Debug, Deserialize)]
+pub struct PriceOverrideBody {
+    pub extra_id: String,
+    pub price: MoneyBody,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -111,14 +180,14 @@ pub async fn find(State(ctx): State<Context>, Path(path): Path<FindPath>) -> imp
         Ok(id) => id,
         Err(err) => return create_validation_error_response(&err).into_response(),
     };
-    let catalog_id = match catalog::Id::parse_str(&path.id) {
+    let catalog_id = match catalog::Id::parse_str(&path.catalog_id) {
         Ok(catalog_id) => catalog_id,
         Err(err) => return create_validation_error_response(&err).into_response(),
     };
     let input = FindInput { id, catalog_id };
 
     let pg_products = PgProducts::new(ctx.pool.clone());
-    let pg_extras = PgExtras::new(ctx.pool);
+    let pg_extras = PgExtras::new(ctx.pool.clone());
 
     let service = ProductService::new(pg_products, pg_extras);
     let found_product = match service.find(input).await {
@@ -129,7 +198,113 @@ pub async fn find(State(ctx): State<Context>, Path(path): Path<FindPath>) -> imp
         }
     };
 
-    Json(ProductView::new(&found_product)).into_response()
+    create_taxed_view_response(&ctx, &found_product).await
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListQuery {
+    pub page: Option<u32>,
+    pub limit: Option<u8>,
+    pub kind: Option<String>,
+    /// Part of products names, ignoring case
+    pub name: Option<String>,
+    pub min_price: Option<String>,
+    pub max_price: Option<String>,
+    /// Currency of the price range, defaulting to the base currency
+    pub currency: Option<String>,
+    /// Only products offering the extra
+    pub extra_id: Option<String>,
+    /// Only products enabled or disabled, regardless of their schedule,
+    /// ingredients or stock
+    pub enabled: Option<bool>,
+    /// Either `name`, `price` or `created_at`, which is the default
+    pub sort: Option<String>,
+}
+
+/// List products across every catalog
+pub async fn list(State(ctx): State<Context>, Query(query): Query<ListQuery>) -> Response {
+    create_list_response(&ctx, None, query).await
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListPath {
+    pub catalog_id: String,
+}
+
+/// List products of a single catalog, without loading the whole catalog,
+/// responding not found when there is no such catalog
+pub async fn list_in_catalog(
+    State(ctx): State<Context>,
+    Path(path): Path<ListPath>,
+    Query(query): Query<ListQuery>,
+) -> Response {
+    let catalog_id = match catalog::Id::parse_str(&path.catalog_id) {
+        Ok(catalog_id) => catalog_id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+
+    create_list_response(&ctx, Some(catalog_id), query).await
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct QuotePath {
+    pub id: String,
+    pub catalog_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct QuoteBody {
+    pub quantity: u16,
+    pub extras: Vec<QuoteExtraBody>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct QuoteExtraBody {
+    pub id: String,
+    pub quantity: u16,
+}
+
+pub async fn quote(
+    State(ctx): State<Context>,
+    Path(path): Path<QuotePath>,
+    Json(body): Json<QuoteBody>,
+) -> impl IntoResponse {
+    let id = match product::Id::parse_str(&path.id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let catalog_id = match catalog::Id::parse_str(&path.catalog_id) {
+        Ok(catalog_id) => catalog_id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let quantity = match Quantity::new(body.quantity) {
+        Ok(quantity) => quantity,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let extras = match parse_selections(&body.extras) {
+        Ok(extras) => extras,
+        Err(err) => return create_validation_error_response(err.as_ref()).into_response(),
+    };
+    let input = QuoteInput {
+        id,
+        catalog_id,
+        quantity,
+        extras,
+    };
+
+    let pg_products = PgProducts::new(ctx.pool.clone());
+    let pg_extras = PgExtras::new(ctx.pool);
+    let service = ProductService::new(pg_products, pg_extras);
+
+    let quote = match service.quote(input).await {
+        Ok(quote) => quote,
+        Err(err) => {
+            eprintln!("Quote product error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(QuoteView::new(&quote)).into_response()
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -141,9 +316,19 @@ pub struct UpdatePath {
 #[derive(Clone, Debug, Deserialize)]
 pub struct UpdateBody {
     pub name: String,
-    pub price: u64,
+    pub price: MoneyBody,
     pub kind: String,
     pub extras_ids: Vec<String>,
+    /// Maximum quantities replacing the current ones, if any
+    pub max_quantities: Option<Vec<MaxQuantityBody>>,
+    /// Prices of extras replacing the current ones, if any
+    pub price_overrides: Option<Vec<PriceOverrideBody>>,
+    /// Whether product is enabled, keeping the current flag if absent
+    pub available: Option<bool>,
+    /// Schedule replacing the current one, which is kept if absent
+    pub schedule: Option<ScheduleBody>,
+    /// Whether to remove the current schedule when no new one is provided
+    pub remove_schedule: Option<bool>,
 }
 
 pub async fn update(
@@ -163,6 +348,10 @@ pub async fn update(
         Ok(name) => name,
         Err(err) => return create_validation_error_response(&err).into_response(),
     };
+    let price = match body.price.parse() {
+        Ok(price) => price,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
     let kind = match product::Kind::parse_str(&body.kind) {
         Ok(kind) => kind,
         Err(err) => return create_validation_error_response(&err).into_response(),
@@ -171,17 +360,37 @@ pub async fn update(
         Ok(extras_ids) => extras_ids,
         Err(err) => return create_validation_error_response(&err).into_response(),
     };
+    let max_quantities = body.max_quantities.as_deref().map(parse_max_quantities);
+    let max_quantities = match max_quantities.transpose() {
+        Ok(max_quantities) => max_quantities,
+        Err(err) => return create_validation_error_response(err.as_ref()).into_response(),
+    };
+    let price_overrides = body.price_overrides.as_deref().map(parse_price_overrides);
+    let price_overrides = match price_overrides.transpose() {
+        Ok(price_overrides) => price_overrides,
+        Err(err) => return create_validation_error_response(err.as_ref()).into_response(),
+    };
+    let schedule = match body.schedule.as_ref().map(ScheduleBody::parse).transpose() {
+        Ok(Some(schedule)) => ScheduleUpdate::Replace(schedule),
+        Ok(None) if body.remove_schedule.unwrap_or(false) => ScheduleUpdate::Remove,
+        Ok(None) => ScheduleUpdate::Keep,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
     let input = UpdateInput {
         id,
         catalog_id,
         name,
-        price: product::Price::from_cents(body.price),
+        price,
         kind,
         extras_ids,
+        max_quantities,
+        price_overrides,
+        available: body.available,
+        schedule,
     };
 
     let pg_products = PgProducts::new(ctx.pool.clone());
-    let pg_extras = PgExtras::new(ctx.pool);
+    let pg_extras = PgExtras::new(ctx.pool.clone());
     let mut service = ProductService::new(pg_products, pg_extras);
 
     let updated_product = match service.update(input).await {
@@ -192,7 +401,762 @@ pub async fn update(
         }
     };
 
-    Json(ProductView::new(&updated_product)).into_response()
+    create_written_view_response(&ctx, &updated_product).await
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct VariantsPath {
+    pub id: String,
+    pub catalog_id: String,
+}
+
+pub async fn variants(
+    State(ctx): State<Context>,
+    Path(path): Path<VariantsPath>,
+) -> impl IntoResponse {
+    let id = match product::Id::parse_str(&path.id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let catalog_id = match catalog::Id::parse_str(&path.catalog_id) {
+        Ok(catalog_id) => catalog_id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = FindInput { id, catalog_id };
+
+    let pg_products = PgProducts::new(ctx.pool.clone());
+    let pg_extras = PgExtras::new(ctx.pool);
+    let service = ProductService::new(pg_products, pg_extras);
+
+    let product = match service.find(input).await {
+        Ok(product) => product,
+        Err(err) => {
+            eprintln!("Find product variants error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    let views = product
+        .variants
+        .iter()
+        .map(|variant| VariantView::new(variant, product.price))
+        .collect::<Vec<_>>();
+    Json(views).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct VariantPath {
+    pub id: String,
+    pub catalog_id: String,
+    pub variant_id: String,
+}
+
+/// Variant of a product, e.g. `{ "name": "Large", "pricing": "delta",
+/// "price": { "amount": "4.50", "currency": "BRL" } }`, where `pricing` is
+/// either `fixed` (default) or `delta`
+#[derive(Clone, Debug, Deserialize)]
+pub struct VariantBody {
+    pub name: String,
+    pub pricing: Option<String>,
+    pub price: MoneyBody,
+}
+
+impl VariantBody {
+    /// Try parsing body into variant name, pricing and price
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if any of the fields is not valid
+    pub fn parse(
+        self,
+    ) -> Result<(variant::Name, variant::Pricing, Money), Box<dyn std::error::Error>> {
+        let name = variant::Name::new(self.name)?;
+        let pricing = match self.pricing.as_deref() {
+            Some(pricing) => variant::Pricing::parse_str(pricing)?,
+            None => variant::Pricing::Fixed,
+        };
+        let price = self.price.parse()?;
+
+        Ok((name, pricing, price))
+    }
+}
+
+pub async fn create_variant(
+    State(ctx): State<Context>,
+    Path(path): Path<VariantsPath>,
+    Json(body): Json<VariantBody>,
+) -> impl IntoResponse {
+    let product_id = match product::Id::parse_str(&path.id) {
+        Ok(product_id) => product_id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let catalog_id = match catalog::Id::parse_str(&path.catalog_id) {
+        Ok(catalog_id) => catalog_id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let (name, pricing, price) = match body.parse() {
+        Ok(fields) => fields,
+        Err(err) => return create_validation_error_response(err.as_ref()).into_response(),
+    };
+    let input = CreateVariantInput {
+        product_id,
+        catalog_id,
+        name,
+        pricing,
+        price,
+    };
+
+    let pg_products = PgProducts::new(ctx.pool.clone());
+    let pg_extras = PgExtras::new(ctx.pool);
+    let mut service = ProductService::new(pg_products, pg_extras);
+
+    let (product, variant) = match service.create_variant(input).await {
+        Ok(created) => created,
+        Err(err) => {
+            eprintln!("Create product variant error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(VariantView::new(&variant, product.price)).into_response()
+}
+
+pub async fn delete_variant(
+    State(ctx): State<Context>,
+    Path(path): Path<VariantPath>,
+) -> impl IntoResponse {
+    let (id, product_id, catalog_id) = match parse_variant_path(&path) {
+        Ok(ids) => ids,
+        Err(err) => return create_validation_error_response(err.as_ref()).into_response(),
+    };
+    let input = DeleteVariantInput {
+        id,
+        product_id,
+        catalog_id,
+    };
+
+    let pg_products = PgProducts::new(ctx.pool.clone());
+    let pg_extras = PgExtras::new(ctx.pool);
+    let mut service = ProductService::new(pg_products, pg_extras);
+
+    let (product, variant) = match service.delete_variant(input).await {
+        Ok(deleted) => deleted,
+        Err(err) => {
+            eprintln!("Delete product variant error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(VariantView::new(&variant, product.price)).into_response()
+}
+
+pub async fn find_variant(
+    State(ctx): State<Context>,
+    Path(path): Path<VariantPath>,
+) -> impl IntoResponse {
+    let (id, product_id, catalog_id) = match parse_variant_path(&path) {
+        Ok(ids) => ids,
+        Err(err) => return create_validation_error_response(err.as_ref()).into_response(),
+    };
+    let input = FindVariantInput {
+        id,
+        product_id,
+        catalog_id,
+    };
+
+    let pg_products = PgProducts::new(ctx.pool.clone());
+    let pg_extras = PgExtras::new(ctx.pool);
+    let service = ProductService::new(pg_products, pg_extras);
+
+    let (product, variant) = match service.find_variant(input).await {
+        Ok(found) => found,
+        Err(err) => {
+            eprintln!("Find product variant error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(VariantView::new(&variant, product.price)).into_response()
+}
+
+pub async fn update_variant(
+    State(ctx): State<Context>,
+    Path(path): Path<VariantPath>,
+    Json(body): Json<VariantBody>,
+) -> impl IntoResponse {
+    let (id, product_id, catalog_id) = match parse_variant_path(&path) {
+        Ok(ids) => ids,
+        Err(err) => return create_validation_error_response(err.as_ref()).into_response(),
+    };
+    let (name, pricing, price) = match body.parse() {
+        Ok(fields) => fields,
+        Err(err) => return create_validation_error_response(err.as_ref()).into_response(),
+    };
+    let input = UpdateVariantInput {
+        id,
+        product_id,
+        catalog_id,
+        name,
+        pricing,
+        price,
+    };
+
+    let pg_products = PgProducts::new(ctx.pool.clone());
+    let pg_extras = PgExtras::new(ctx.pool);
+    let mut service = ProductService::new(pg_products, pg_extras);
+
+    let (product, variant) = match service.update_variant(input).await {
+        Ok(updated) => updated,
+        Err(err) => {
+            eprintln!("Update product variant error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(VariantView::new(&variant, product.price)).into_response()
+}
+
+fn parse_variant_path(
+    path: &VariantPath,
+) -> Result<(variant::Id, product::Id, catalog::Id), Box<dyn std::error::Error>> {
+    let id = variant::Id::parse_str(&path.variant_id)?;
+    let product_id = product::Id::parse_str(&path.id)?;
+    let catalog_id = catalog::Id::parse_str(&path.catalog_id)?;
+
+    Ok((id, product_id, catalog_id))
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ModifiersPath {
+    pub id: String,
+    pub catalog_id: String,
+}
+
+pub async fn modifiers(
+    State(ctx): State<Context>,
+    Path(path): Path<ModifiersPath>,
+) -> impl IntoResponse {
+    let id = match product::Id::parse_str(&path.id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let catalog_id = match catalog::Id::parse_str(&path.catalog_id) {
+        Ok(catalog_id) => catalog_id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = FindInput { id, catalog_id };
+
+    let pg_products = PgProducts::new(ctx.pool.clone());
+    let pg_extras = PgExtras::new(ctx.pool);
+    let service = ProductService::new(pg_products, pg_extras);
+
+    let product = match service.find(input).await {
+        Ok(product) => product,
+        Err(err) => {
+            eprintln!("Find product modifiers error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    let views = product
+        .modifiers
+        .iter()
+        .map(ModifierView::new)
+        .collect::<Vec<_>>();
+    Json(views).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ModifierPath {
+    pub id: String,
+    pub catalog_id: String,
+    pub modifier_id: String,
+}
+
+/// Modifier group of a product, e.g. `{ "name": "Sauces", "min": 1, "max": 1,
+/// "extras_ids": ["0190ec14-0af8-71d1-9554-f1e5249ae3a2"] }`
+#[derive(Clone, Debug, Deserialize)]
+pub struct ModifierBody {
+    pub name: String,
+    pub min: u8,
+    pub max: u8,
+    pub extras_ids: Vec<String>,
+}
+
+impl ModifierBody {
+    /// Try parsing body into modifier group name, limits and options
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if any of the fields is not valid
+    pub fn parse(
+        self,
+    ) -> Result<(modifier::Name, modifier::Limits, modifier::Options), Box<dyn std::error::Error>>
+    {
+        let name = modifier::Name::new(self.name)?;
+        let limits = modifier::Limits::new(self.min, self.max)?;
+        let extras_ids = self
+            .extras_ids
+            .iter()
+            .map(|extra_id| extra::Id::parse_str(extra_id))
+            .collect::<Result<Vec<_>, _>>()?;
+        let options = modifier::Options::new(extras_ids)?;
+
+        Ok((name, limits, options))
+    }
+}
+
+pub async fn create_modifier(
+    State(ctx): State<Context>,
+    Path(path): Path<ModifiersPath>,
+    Json(body): Json<ModifierBody>,
+) -> impl IntoResponse {
+    let product_id = match product::Id::parse_str(&path.id) {
+        Ok(product_id) => product_id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let catalog_id = match catalog::Id::parse_str(&path.catalog_id) {
+        Ok(catalog_id) => catalog_id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let (name, limits, options) = match body.parse() {
+        Ok(fields) => fields,
+        Err(err) => return create_validation_error_response(err.as_ref()).into_response(),
+    };
+    let input = CreateModifierInput {
+        product_id,
+        catalog_id,
+        name,
+        limits,
+        options,
+    };
+
+    let pg_products = PgProducts::new(ctx.pool.clone());
+    let pg_extras = PgExtras::new(ctx.pool);
+    let mut service = ProductService::new(pg_products, pg_extras);
+
+    let group = match service.create_modifier(input).await {
+        Ok(group) => group,
+        Err(err) => {
+            eprintln!("Create product modifier error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(ModifierView::new(&group)).into_response()
+}
+
+pub async fn delete_modifier(
+    State(ctx): State<Context>,
+    Path(path): Path<ModifierPath>,
+) -> impl IntoResponse {
+    let (id, product_id, catalog_id) = match parse_modifier_path(&path) {
+        Ok(ids) => ids,
+        Err(err) => return create_validation_error_response(err.as_ref()).into_response(),
+    };
+    let input = DeleteModifierInput {
+        id,
+        product_id,
+        catalog_id,
+    };
+
+    let pg_products = PgProducts::new(ctx.pool.clone());
+    let pg_extras = PgExtras::new(ctx.pool);
+    let mut service = ProductService::new(pg_products, pg_extras);
+
+    let group = match service.delete_modifier(input).await {
+        Ok(group) => group,
+        Err(err) => {
+            eprintln!("Delete product modifier error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(ModifierView::new(&group)).into_response()
+}
+
+pub async fn find_modifier(
+    State(ctx): State<Context>,
+    Path(path): Path<ModifierPath>,
+) -> impl IntoResponse {
+    let (id, product_id, catalog_id) = match parse_modifier_path(&path) {
+        Ok(ids) => ids,
+        Err(err) => return create_validation_error_response(err.as_ref()).into_response(),
+    };
+    let input = FindModifierInput {
+        id,
+        product_id,
+        catalog_id,
+    };
+
+    let pg_products = PgProducts::new(ctx.pool.clone());
+    let pg_extras = PgExtras::new(ctx.pool);
+    let service = ProductService::new(pg_products, pg_extras);
+
+    let group = match service.find_modifier(input).await {
+        Ok(group) => group,
+        Err(err) => {
+            eprintln!("Find product modifier error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(ModifierView::new(&group)).into_response()
+}
+
+pub async fn update_modifier(
+    State(ctx): State<Context>,
+    Path(path): Path<ModifierPath>,
+    Json(body): Json<ModifierBody>,
+) -> impl IntoResponse {
+    let (id, product_id, catalog_id) = match parse_modifier_path(&path) {
+        Ok(ids) => ids,
+        Err(err) => return create_validation_error_response(err.as_ref()).into_response(),
+    };
+    let (name, limits, options) = match body.parse() {
+        Ok(fields) => fields,
+        Err(err) => return create_validation_error_response(err.as_ref()).into_response(),
+    };
+    let input = UpdateModifierInput {
+        id,
+        product_id,
+        catalog_id,
+        name,
+        limits,
+        options,
+    };
+
+    let pg_products = PgProducts::new(ctx.pool.clone());
+    let pg_extras = PgExtras::new(ctx.pool);
+    let mut service = ProductService::new(pg_products, pg_extras);
+
+    let group = match service.update_modifier(input).await {
+        Ok(group) => group,
+        Err(err) => {
+            eprintln!("Update product modifier error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(ModifierView::new(&group)).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct SelectionPath {
+    pub id: String,
+    pub catalog_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct SelectionBody {
+    pub extras: Vec<QuoteExtraBody>,
+}
+
+/// Check a selection of extras against the modifier groups of product,
+/// answering with the quote of a single unit when it is valid
+pub async fn validate_selection(
+    State(ctx): State<Context>,
+    Path(path): Path<SelectionPath>,
+    Json(body): Json<SelectionBody>,
+) -> impl IntoResponse {
+    let id = match product::Id::parse_str(&path.id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let catalog_id = match catalog::Id::parse_str(&path.catalog_id) {
+        Ok(catalog_id) => catalog_id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let extras = match parse_selections(&body.extras) {
+        Ok(extras) => extras,
+        Err(err) => return create_validation_error_response(err.as_ref()).into_response(),
+    };
+    let input = ValidateSelectionInput {
+        id,
+        catalog_id,
+        extras,
+    };
+
+    let pg_products = PgProducts::new(ctx.pool.clone());
+    let pg_extras = PgExtras::new(ctx.pool);
+    let service = ProductService::new(pg_products, pg_extras);
+
+    let quote = match service.validate_selection(input).await {
+        Ok(quote) => quote,
+        Err(err) => {
+            eprintln!("Validate product selection error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(QuoteView::new(&quote)).into_response()
+}
+
+fn parse_modifier_path(
+    path: &ModifierPath,
+) -> Result<(modifier::Id, product::Id, catalog::Id), Box<dyn std::error::Error>> {
+    let id = modifier::Id::parse_str(&path.modifier_id)?;
+    let product_id = product::Id::parse_str(&path.id)?;
+    let catalog_id = catalog::Id::parse_str(&path.catalog_id)?;
+
+    Ok((id, product_id, catalog_id))
+}
+
+fn parse_max_quantities(
+    bodies: &[MaxQuantityBody],
+) -> Result<Vec<(extra::Id, Quantity)>, Box<dyn std::error::Error>> {
+    if bodies.len() > product::Extras::MAX_LEN {
+        return Err(Box::new(product::ExtrasError::Length));
+    }
+
+    let mut max_quantities = Vec::with_capacity(bodies.len());
+    for body in bodies {
+        let extra_id = extra::Id::parse_str(&body.extra_id)?;
+        let max_quantity = Quantity::new(body.max_quantity)?;
+        max_quantities.push((extra_id, max_quantity));
+    }
+
+    Ok(max_quantities)
+}
+
+fn parse_price_overrides(
+    bodies: &[PriceOverrideBody],
+) -> Result<Vec<(extra::Id, Money)>, Box<dyn std::error::Error>> {
+    if bodies.len() > product::Extras::MAX_LEN {
+        return Err(Box::new(product::ExtrasError::Length));
+    }
+
+    let mut price_overrides = Vec::with_capacity(bodies.len());
+    for body in bodies {
+        let extra_id = extra::Id::parse_str(&body.extra_id)?;
+        let price = body.price.parse()?;
+        price_overrides.push((extra_id, price));
+    }
+
+    Ok(price_overrides)
+}
+
+fn parse_selections(
+    bodies: &[QuoteExtraBody],
+) -> Result<Vec<product::ExtraSelection>, Box<dyn std::error::Error>> {
+    if bodies.len() > product::Extras::MAX_LEN {
+        return Err(Box::new(product::ExtrasError::Length));
+    }
+
+    let mut selections = Vec::with_capacity(bodies.len());
+    for body in bodies {
+        selections.push(product::ExtraSelection {
+            id: extra::Id::parse_str(&body.id)?,
+            quantity: Quantity::new(body.quantity)?,
+        });
+    }
+
+    Ok(selections)
+}
+
+/// When a product is offered, e.g. `{ "windows": [{ "weekdays": ["saturday"],
+/// "starts_at": "08:00", "ends_at": "11:00" }], "starts_on": "2024-12-01",
+/// "time_zone": "America/Sao_Paulo" }`
+#[derive(Clone, Debug, Deserialize)]
+pub struct ScheduleBody {
+    #[serde(default)]
+    pub windows: Vec<ScheduleWindowBody>,
+    pub starts_on: Option<String>,
+    pub ends_on: Option<String>,
+    pub time_zone: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ScheduleWindowBody {
+    pub weekdays: Vec<String>,
+    pub starts_at: String,
+    pub ends_at: String,
+}
+
+impl ScheduleBody {
+    /// Try parsing body into [`product::Schedule`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if any window, date or the schedule itself is not
+    /// valid
+    pub fn parse(&self) -> Result<product::Schedule, ParseScheduleError> {
+        let zone = schedule::Zone::parse_str(&self.time_zone)?;
+        let windows = self
+            .windows
+            .iter()
+            .map(|window| {
+                parse_window_in(&window.weekdays, &window.starts_at, &window.ends_at, zone)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let starts_on = self.starts_on.as_deref().map(parse_date).transpose()?;
+        let ends_on = self.ends_on.as_deref().map(parse_date).transpose()?;
+
+        Ok(product::Schedule::new(windows, starts_on, ends_on, zone)?)
+    }
+}
+
+#[derive(Clone, Debug, Error, PartialEq)]
+pub enum ParseScheduleError {
+    #[error(transparent)]
+    Date(#[from] ParseDateError),
+    #[error(transparent)]
+    Schedule(#[from] product::ScheduleError),
+    #[error(transparent)]
+    Window(#[from] ParseWindowError),
+    #[error(transparent)]
+    Zone(#[from] schedule::ParseZoneError),
+}
+
+async fn create_list_response(
+    ctx: &Context,
+    catalog_id: Option<catalog::Id>,
+    query: ListQuery,
+) -> Response {
+    let page = match query.page {
+        Some(0) | None => NonZeroU32::new(1).unwrap(),
+        Some(page) => NonZeroU32::new(page).expect("Page is not zero"),
+    };
+    let limit = match query.limit {
+        Some(0) | None => NonZeroU8::new(10).unwrap(),
+        Some(limit) => NonZeroU8::new(limit).expect("Limit is not zero"),
+    };
+    let kind = match query.kind.as_deref().map(product::Kind::parse_str) {
+        Some(Ok(kind)) => Some(kind),
+        Some(Err(err)) => return create_validation_error_response(&err).into_response(),
+        None => None,
+    };
+    let currency = query
+        .currency
+        .unwrap_or_else(|| ctx.base_currency.to_string());
+    let min_price = match parse_price(query.min_price.as_deref(), &currency) {
+        Ok(min_price) => min_price,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let max_price = match parse_price(query.max_price.as_deref(), &currency) {
+        Ok(max_price) => max_price,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let extra_id = match query.extra_id.as_deref().map(extra::Id::parse_str) {
+        Some(Ok(extra_id)) => Some(extra_id),
+        Some(Err(err)) => return create_validation_error_response(&err).into_response(),
+        None => None,
+    };
+    let sort = match query.sort.as_deref().map(Sort::parse_str) {
+        Some(Ok(sort)) => sort,
+        Some(Err(err)) => return create_validation_error_response(&err).into_response(),
+        None => Sort::default(),
+    };
+    let input = ListInput {
+        page,
+        limit,
+        catalog_id,
+        kind,
+        name: query.name,
+        min_price,
+        max_price,
+        extra_id,
+        enabled: query.enabled,
+        sort,
+    };
+
+    let pg_products = PgProducts::new(ctx.pool.clone());
+    let pg_extras = PgExtras::new(ctx.pool.clone());
+    let service = ProductService::new(pg_products, pg_extras);
+    let pagination = match service.list(input).await {
+        Ok(pagination) => pagination,
+        Err(err) => {
+            eprintln!("List products error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    match create_taxed_views(ctx, &pagination.items).await {
+        Ok(views) => Json(PaginationView::new(&pagination, views)).into_response(),
+        Err(response) => response,
+    }
+}
+
+async fn create_taxed_view_response(ctx: &Context, product: &product::Product) -> Response {
+    match create_taxed_views(ctx, std::slice::from_ref(product)).await {
+        Ok(mut views) => Json(views.remove(0)).into_response(),
+        Err(response) => response,
+    }
+}
+
+/// Same as [`create_taxed_view_response`] for a `product` just written,
+/// falling back to its plain view when reporting taxes, stock or ingredients
+/// fails, since the write itself already succeeded
+async fn create_written_view_response(ctx: &Context, product: &product::Product) -> Response {
+    match create_taxed_views(ctx, std::slice::from_ref(product)).await {
+        Ok(mut views) => Json(views.remove(0)).into_response(),
+        Err(_) => Json(ProductView::new(product)).into_response(),
+    }
+}
+
+/// Create views of `products` reporting their taxes, stock, ingredients and
+/// schedule, or the response to fail with
+async fn create_taxed_views<'a>(
+    ctx: &Context,
+    products: &'a [product::Product],
+) -> Result<Vec<ProductView<'a>>, Response> {
+    let service = TaxService::new(PgTaxRules::new(ctx.pool.clone()));
+    let tax_rules = match service.all().await {
+        Ok(tax_rules) => tax_rules,
+        Err(err) => {
+            eprintln!("All tax rules error: {err:?}");
+            return Err(create_tax_error_response(err).into_response());
+        }
+    };
+
+    let products_ids = products
+        .iter()
+        .map(product::Product::id)
+        .collect::<Vec<_>>();
+    let pg_stocks = PgStocks::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool.clone());
+    let service = StockService::new(pg_stocks, pg_products);
+    let input = AllStocksInput {
+        products_ids: products_ids.clone(),
+    };
+    let stocks = match service.all(input).await {
+        Ok(stocks) => stocks,
+        Err(err) => {
+            eprintln!("All stocks error: {err:?}");
+            return Err(create_stock_error_response(err).into_response());
+        }
+    };
+
+    let pg_ingredients = PgIngredients::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool.clone());
+    let pg_extras = PgExtras::new(ctx.pool.clone());
+    let service = IngredientService::new(pg_ingredients, pg_products, pg_extras);
+    let input = ExhaustedInput { products_ids };
+    let exhausted = match service.exhausted(input).await {
+        Ok(exhausted) => exhausted,
+        Err(err) => {
+            eprintln!("Exhausted products error: {err:?}");
+            return Err(create_ingredient_error_response(err).into_response());
+        }
+    };
+
+    let now = OffsetDateTime::now_utc();
+    let mut views = Vec::with_capacity(products.len());
+    for product in products {
+        let mut view = match ProductView::with_taxes(product, &tax_rules) {
+            Ok(view) => view,
+            Err(err) => {
+                eprintln!("Product taxes error: {err:?}");
+                return Err(create_tax_error_response(err).into_response());
+            }
+        };
+        view.apply_stocks(product, &stocks);
+        view.apply_ingredients(product, &exhausted);
+        view.apply_schedule(product, now);
+        views.push(view);
+    }
+
+    Ok(views)
 }
 
 pub fn create_error_response(err: product::Error) -> impl IntoResponse {
@@ -218,3 +1182,35 @@ fn create_validation_error_response(err: &dyn std::error::Error) -> impl IntoRes
     let body = ApiError::new("Validation", err.to_string());
     (StatusCode::BAD_GATEWAY, Json(body))
 }
+
+#[cfg(test)]
+mod tests {
+    use axum::body::to_bytes;
+    use domain::core::money::Currency;
+    use sqlx::PgPool;
+
+    use super::*;
+
+    #[sqlx::test(fixtures("./api/fixtures/seed.sql"))]
+    async fn find_works(pool: PgPool) {
+        let ctx = Context {
+            pool,
+            base_currency: Currency::BRL,
+            payment_provider: None,
+        };
+        let path = FindPath {
+            id: "0190ec14-0af8-71d1-9554-f1e5249ae3a2".to_string(),
+            catalog_id: "0190ec30-286b-7211-aadb-003fc0449734".to_string(),
+        };
+
+        let response = find(State(ctx), Path(path)).await.into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("Read response body");
+        let body = String::from_utf8(body.to_vec()).expect("Valid response body");
+        assert!(body.contains("0190ec14-0af8-71d1-9554-f1e5249ae3a2"));
+        assert!(body.contains("Cheese Burger"));
+    }
+}
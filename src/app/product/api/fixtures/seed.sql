@@ -0,0 +1,7 @@
+insert into catalog (id, name, description, created_at, updated_at)
+values
+    ('0190ec30-286b-7211-aadb-003fc0449734', 'Burgers', null, now(), now());
+
+insert into product (id, catalog_id, name, price, kind, created_at, updated_at)
+values
+    ('0190ec14-0af8-71d1-9554-f1e5249ae3a2', '0190ec30-286b-7211-aadb-003fc0449734', 'Cheese Burger', 2000, 'burger', now(), now());
\ No newline at end of file
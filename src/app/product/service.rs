@@ -1,9 +1,19 @@
 mod dto;
 
-pub use dto::{CreateInput, DeleteInput, ExtrasIds, FindInput, UpdateInput};
+pub use dto::{
+    CreateInput, CreateModifierInput, CreateVariantInput, DeleteInput, DeleteModifierInput,
+    DeleteVariantInput, ExtrasIds, FindInput, FindModifierInput, FindVariantInput, ListInput,
+    QuoteInput, ScheduleUpdate, UpdateInput, UpdateModifierInput, UpdateVariantInput,
+    ValidateSelectionInput,
+};
 
+use domain::catalog;
+use domain::core::money::{Money, MoneyError};
+use domain::core::quantity::Quantity;
 use domain::extra;
+use domain::modifier;
 use domain::product;
+use domain::variant;
 
 #[derive(Clone, Debug)]
 pub struct ProductService<T, U> {
@@ -19,16 +29,22 @@ impl<T: product::Repository, U: extra::Repository> ProductService<T, U> {
 
 impl<T: product::Repository, U: extra::Repository> ProductService<T, U> {
     pub async fn create(&mut self, input: CreateInput) -> Result<product::Product, product::Error> {
-        let found_extras = self.find_extras(input.extras_ids.as_slice()).await?;
-        let extras = product::Extras::new(found_extras).map_err(product::Error::any)?;
+        let found_extras = self
+            .find_extras(input.catalog_id, input.extras_ids.as_slice())
+            .await?;
+        let mut extras = product::Extras::new(found_extras).map_err(product::Error::any)?;
+        limit_extras(&mut extras, &input.max_quantities)?;
+        override_prices(&mut extras, &input.price_overrides, input.price)?;
 
-        let product = product::Product::new(
+        let mut product = product::Product::new(
             input.catalog_id,
             input.name,
             input.price,
             input.kind,
             extras,
         );
+        product.available = input.available;
+        product.schedule = input.schedule;
 
         self.products.create(&product).await?;
 
@@ -43,33 +59,349 @@ impl<T: product::Repository, U: extra::Repository> ProductService<T, U> {
         self.products.find(input.id, input.catalog_id).await
     }
 
+    pub async fn list(&self, input: ListInput) -> Result<product::Pagination, product::Error> {
+        let query = product::ListQuery {
+            page: input.page,
+            limit: input.limit,
+            catalog_id: input.catalog_id,
+            kind: input.kind,
+            name: input.name,
+            min_price: input.min_price,
+            max_price: input.max_price,
+            extra_id: input.extra_id,
+            enabled: input.enabled,
+            sort: input.sort,
+        };
+        self.products.list(query).await
+    }
+
+    pub async fn quote(&self, input: QuoteInput) -> Result<product::Quote, product::Error> {
+        let product = self.products.find(input.id, input.catalog_id).await?;
+        product.quote(input.quantity, &input.extras)
+    }
+
     pub async fn update(&mut self, input: UpdateInput) -> Result<product::Product, product::Error> {
         let mut product = self.products.find(input.id, input.catalog_id).await?;
 
-        let extras = self.find_extras(input.extras_ids.as_slice()).await?;
-        let product_extras = product::Extras::new(extras).map_err(product::Error::any)?;
+        let extras = self
+            .find_extras(product.catalog_id(), input.extras_ids.as_slice())
+            .await?;
+        let mut product_extras = product::Extras::new(extras).map_err(product::Error::any)?;
+
+        // extras kept bound keep their maximum quantity and price unless new
+        // ones are provided
+        let max_quantities = input.max_quantities.unwrap_or_else(|| {
+            product
+                .extras
+                .iter()
+                .filter(|extra| product_extras.find(extra.id()).is_some())
+                .map(|extra| (extra.id(), product.extras.max_quantity(extra.id())))
+                .collect()
+        });
+        let price_overrides = input.price_overrides.unwrap_or_else(|| {
+            product
+                .extras
+                .iter()
+                .filter(|extra| product.extras.is_overridden(extra.id()))
+                .filter(|extra| product_extras.find(extra.id()).is_some())
+                .map(|extra| (extra.id(), extra.price))
+                .collect()
+        });
+        limit_extras(&mut product_extras, &max_quantities)?;
+        override_prices(&mut product_extras, &price_overrides, input.price)?;
 
         product.name = input.name;
         product.price = input.price;
         product.kind = input.kind;
         product.extras = product_extras;
+        if let Some(available) = input.available {
+            product.available = available;
+        }
+        match input.schedule {
+            ScheduleUpdate::Keep => {}
+            ScheduleUpdate::Remove => product.schedule = None,
+            ScheduleUpdate::Replace(schedule) => product.schedule = Some(schedule),
+        }
         product.metadata.update();
+        product
+            .modifiers
+            .check_bound(&product.extras)
+            .map_err(product::Error::modifiers)?;
 
         self.products.update(&product).await?;
 
         Ok(product)
     }
 
+    /// Create a variant of product, whose name must not be taken by any
+    /// other variant of the same product
+    pub async fn create_variant(
+        &mut self,
+        input: CreateVariantInput,
+    ) -> Result<(product::Product, variant::Variant), product::Error> {
+        let mut product = self
+            .products
+            .find(input.product_id, input.catalog_id)
+            .await?;
+
+        let variant = variant::Variant::new(product.id(), input.name, input.pricing, input.price);
+        variant
+            .price_for(product.price)
+            .map_err(product::Error::money)?;
+
+        let mut variants = product.variants.as_slice().to_vec();
+        variants.push(variant.clone());
+        product.variants = variant::Variants::new(variants).map_err(product::Error::variants)?;
+
+        self.products.create_variant(&variant).await?;
+
+        Ok((product, variant))
+    }
+
+    pub async fn delete_variant(
+        &mut self,
+        input: DeleteVariantInput,
+    ) -> Result<(product::Product, variant::Variant), product::Error> {
+        let product = self
+            .products
+            .find(input.product_id, input.catalog_id)
+            .await?;
+        let variant = self.products.delete_variant(input.id, product.id()).await?;
+
+        Ok((product, variant))
+    }
+
+    pub async fn find_variant(
+        &self,
+        input: FindVariantInput,
+    ) -> Result<(product::Product, variant::Variant), product::Error> {
+        let product = self
+            .products
+            .find(input.product_id, input.catalog_id)
+            .await?;
+        let variant = product
+            .variants
+            .find(input.id)
+            .cloned()
+            .ok_or(product::Error::variant_not_found(input.id))?;
+
+        Ok((product, variant))
+    }
+
+    /// Update a variant of product, whose new name must not be taken by any
+    /// other variant of the same product
+    pub async fn update_variant(
+        &mut self,
+        input: UpdateVariantInput,
+    ) -> Result<(product::Product, variant::Variant), product::Error> {
+        let mut product = self
+            .products
+            .find(input.product_id, input.catalog_id)
+            .await?;
+
+        let mut variant = product
+            .variants
+            .find(input.id)
+            .cloned()
+            .ok_or(product::Error::variant_not_found(input.id))?;
+        variant.name = input.name;
+        variant.pricing = input.pricing;
+        variant.price = input.price;
+        variant.metadata.update();
+        variant
+            .price_for(product.price)
+            .map_err(product::Error::money)?;
+
+        let variants = product
+            .variants
+            .iter()
+            .map(|other| {
+                if other.id() == variant.id() {
+                    variant.clone()
+                } else {
+                    other.clone()
+                }
+            })
+            .collect();
+        product.variants = variant::Variants::new(variants).map_err(product::Error::variants)?;
+
+        self.products.update_variant(&variant).await?;
+
+        Ok((product, variant))
+    }
+
+    /// Create a modifier group of product, which may only offer extras bound
+    /// to product and not offered by any other of its groups
+    pub async fn create_modifier(
+        &mut self,
+        input: CreateModifierInput,
+    ) -> Result<modifier::Group, product::Error> {
+        let mut product = self
+            .products
+            .find(input.product_id, input.catalog_id)
+            .await?;
+
+        let group = modifier::Group::new(product.id(), input.name, input.limits, input.options);
+
+        let mut groups = product.modifiers.as_slice().to_vec();
+        groups.push(group.clone());
+        product.modifiers = modifier::Groups::new(groups).map_err(product::Error::modifiers)?;
+        product
+            .modifiers
+            .check_bound(&product.extras)
+            .map_err(product::Error::modifiers)?;
+
+        self.products.create_modifier(&group).await?;
+
+        Ok(group)
+    }
+
+    pub async fn delete_modifier(
+        &mut self,
+        input: DeleteModifierInput,
+    ) -> Result<modifier::Group, product::Error> {
+        let product = self
+            .products
+            .find(input.product_id, input.catalog_id)
+            .await?;
+        self.products.delete_modifier(input.id, product.id()).await
+    }
+
+    pub async fn find_modifier(
+        &self,
+        input: FindModifierInput,
+    ) -> Result<modifier::Group, product::Error> {
+        let product = self
+            .products
+            .find(input.product_id, input.catalog_id)
+            .await?;
+
+        product
+            .modifiers
+            .find(input.id)
+            .cloned()
+            .ok_or(product::Error::modifier_not_found(input.id))
+    }
+
+    /// Update a modifier group of product, which may only offer extras bound
+    /// to product and not offered by any other of its groups
+    pub async fn update_modifier(
+        &mut self,
+        input: UpdateModifierInput,
+    ) -> Result<modifier::Group, product::Error> {
+        let mut product = self
+            .products
+            .find(input.product_id, input.catalog_id)
+            .await?;
+
+        let mut group = product
+            .modifiers
+            .find(input.id)
+            .cloned()
+            .ok_or(product::Error::modifier_not_found(input.id))?;
+        group.name = input.name;
+        group.limits = input.limits;
+        group.options = input.options;
+        group.metadata.update();
+
+        let groups = product
+            .modifiers
+            .iter()
+            .map(|other| {
+                if other.id() == group.id() {
+                    group.clone()
+                } else {
+                    other.clone()
+                }
+            })
+            .collect();
+        product.modifiers = modifier::Groups::new(groups).map_err(product::Error::modifiers)?;
+        product
+            .modifiers
+            .check_bound(&product.extras)
+            .map_err(product::Error::modifiers)?;
+
+        self.products.update_modifier(&group).await?;
+
+        Ok(group)
+    }
+
+    /// Validate a customer selection of extras against the maximum quantity
+    /// of each extra and the modifier groups of product, pricing a single
+    /// unit of product with it
+    pub async fn validate_selection(
+        &self,
+        input: ValidateSelectionInput,
+    ) -> Result<product::Quote, product::Error> {
+        let product = self.products.find(input.id, input.catalog_id).await?;
+        let single = Quantity::new(1).map_err(product::Error::any)?;
+        product.quote(single, &input.extras)
+    }
+
+    /// Find extras with `extras_ids`, which must be either global or scoped
+    /// to the catalog with `catalog_id`
     async fn find_extras(
         &self,
+        catalog_id: catalog::Id,
         extras_ids: &[extra::Id],
     ) -> Result<Vec<extra::Extra>, product::Error> {
-        self.extras
+        let extras = self
+            .extras
             .find_many(extras_ids)
             .await
             .map_err(|err| match err {
                 extra::Error::NotFound(id) => product::Error::extra_not_found(id),
                 err => product::Error::any(err),
-            })
+            })?;
+
+        if let Some(extra) = extras.iter().find(|e| !e.is_available_to(catalog_id)) {
+            return Err(product::Error::extra_out_of_scope(extra.id(), catalog_id));
+        }
+
+        Ok(extras)
+    }
+}
+
+/// Set the maximum quantity of `extras`, where every limited extra must be
+/// among them
+fn limit_extras(
+    extras: &mut product::Extras,
+    max_quantities: &[(extra::Id, Quantity)],
+) -> Result<(), product::Error> {
+    for &(extra_id, max_quantity) in max_quantities {
+        extras
+            .set_max_quantity(extra_id, max_quantity)
+            .map_err(|err| match err {
+                product::ExtrasError::Unbound(id) => product::Error::extra_not_found(id),
+                err @ product::ExtrasError::Length => product::Error::any(err),
+            })?;
     }
+
+    Ok(())
+}
+
+/// Override the price of `extras`, where every overridden extra must be
+/// among them and priced in the currency of the product `price`
+fn override_prices(
+    extras: &mut product::Extras,
+    prices: &[(extra::Id, Money)],
+    price: Money,
+) -> Result<(), product::Error> {
+    for &(extra_id, extra_price) in prices {
+        if extra_price.currency() != price.currency() {
+            return Err(product::Error::money(MoneyError::CurrencyMismatch {
+                expected: price.currency(),
+                found: extra_price.currency(),
+            }));
+        }
+
+        extras
+            .override_price(extra_id, extra_price)
+            .map_err(|err| match err {
+                product::ExtrasError::Unbound(id) => product::Error::extra_not_found(id),
+                err @ product::ExtrasError::Length => product::Error::any(err),
+            })?;
+    }
+
+    Ok(())
 }
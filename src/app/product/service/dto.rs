@@ -1,16 +1,30 @@
+use std::num::{NonZeroU32, NonZeroU8};
+
 use thiserror::Error;
 
 use domain::catalog;
+use domain::core::money::Money;
+use domain::core::quantity::Quantity;
+use domain::core::sort::Sort;
 use domain::extra;
+use domain::modifier;
 use domain::product;
+use domain::variant;
 
 #[derive(Clone, Debug)]
 pub struct CreateInput {
     pub catalog_id: catalog::Id,
     pub name: product::Name,
-    pub price: product::Price,
+    pub price: Money,
     pub kind: product::Kind,
     pub extras_ids: ExtrasIds,
+    /// Maximum quantity of some of the extras, the others being selectable
+    /// up to [`Quantity::MAX`]
+    pub max_quantities: Vec<(extra::Id, Quantity)>,
+    /// Price of some of the extras when bound to product
+    pub price_overrides: Vec<(extra::Id, Money)>,
+    pub available: bool,
+    pub schedule: Option<product::Schedule>,
 }
 
 #[derive(Clone, Debug)]
@@ -25,14 +39,126 @@ pub struct FindInput {
     pub catalog_id: catalog::Id,
 }
 
+#[derive(Clone, Debug)]
+pub struct ListInput {
+    pub page: NonZeroU32,
+    pub limit: NonZeroU8,
+    pub catalog_id: Option<catalog::Id>,
+    pub kind: Option<product::Kind>,
+    pub name: Option<String>,
+    pub min_price: Option<Money>,
+    pub max_price: Option<Money>,
+    pub extra_id: Option<extra::Id>,
+    pub enabled: Option<bool>,
+    pub sort: Sort,
+}
+
+#[derive(Clone, Debug)]
+pub struct QuoteInput {
+    pub id: product::Id,
+    pub catalog_id: catalog::Id,
+    pub quantity: Quantity,
+    pub extras: Vec<product::ExtraSelection>,
+}
+
 #[derive(Clone, Debug)]
 pub struct UpdateInput {
     pub id: product::Id,
     pub catalog_id: catalog::Id,
     pub name: product::Name,
-    pub price: product::Price,
+    pub price: Money,
     pub kind: product::Kind,
     pub extras_ids: ExtrasIds,
+    /// Maximum quantity of some of the extras, where [`None`] keeps the ones
+    /// of extras still bound to product
+    pub max_quantities: Option<Vec<(extra::Id, Quantity)>>,
+    /// Price of some of the extras when bound to product, where [`None`]
+    /// keeps the ones of extras still bound to product
+    pub price_overrides: Option<Vec<(extra::Id, Money)>>,
+    /// Whether product is enabled, where [`None`] keeps the current flag
+    pub available: Option<bool>,
+    pub schedule: ScheduleUpdate,
+}
+
+/// What to do with the schedule of a product being updated
+#[derive(Clone, Debug)]
+pub enum ScheduleUpdate {
+    Keep,
+    Remove,
+    Replace(product::Schedule),
+}
+
+#[derive(Clone, Debug)]
+pub struct CreateVariantInput {
+    pub product_id: product::Id,
+    pub catalog_id: catalog::Id,
+    pub name: variant::Name,
+    pub pricing: variant::Pricing,
+    pub price: Money,
+}
+
+#[derive(Clone, Debug)]
+pub struct DeleteVariantInput {
+    pub id: variant::Id,
+    pub product_id: product::Id,
+    pub catalog_id: catalog::Id,
+}
+
+#[derive(Clone, Debug)]
+pub struct FindVariantInput {
+    pub id: variant::Id,
+    pub product_id: product::Id,
+    pub catalog_id: catalog::Id,
+}
+
+#[derive(Clone, Debug)]
+pub struct UpdateVariantInput {
+    pub id: variant::Id,
+    pub product_id: product::Id,
+    pub catalog_id: catalog::Id,
+    pub name: variant::Name,
+    pub pricing: variant::Pricing,
+    pub price: Money,
+}
+
+#[derive(Clone, Debug)]
+pub struct CreateModifierInput {
+    pub product_id: product::Id,
+    pub catalog_id: catalog::Id,
+    pub name: modifier::Name,
+    pub limits: modifier::Limits,
+    pub options: modifier::Options,
+}
+
+#[derive(Clone, Debug)]
+pub struct DeleteModifierInput {
+    pub id: modifier::Id,
+    pub product_id: product::Id,
+    pub catalog_id: catalog::Id,
+}
+
+#[derive(Clone, Debug)]
+pub struct FindModifierInput {
+    pub id: modifier::Id,
+    pub product_id: product::Id,
+    pub catalog_id: catalog::Id,
+}
+
+#[derive(Clone, Debug)]
+pub struct UpdateModifierInput {
+    pub id: modifier::Id,
+    pub product_id: product::Id,
+    pub catalog_id: catalog::Id,
+    pub name: modifier::Name,
+    pub limits: modifier::Limits,
+    pub options: modifier::Options,
+}
+
+#[derive(Clone, Debug)]
+pub struct ValidateSelectionInput {
+    pub id: product::Id,
+    pub catalog_id: catalog::Id,
+    pub extras: Vec<product::ExtraSelection>,
 }
 
 #[derive(Clone, Debug)]
@@ -41,7 +167,7 @@ pub struct ExtrasIds(Vec<extra::Id>);
 impl ExtrasIds {
     pub const MAX_LEN: usize = product::Extras::MAX_LEN;
 
-    pub fn parse(ids: &[String]) -> Result<Self, ParseExtrasIdsError> {
+    pub fn parse(ids: &[String]) -> Result<Self, ParseExtrasIdsError<'_>> {
         if ids.len() > Self::MAX_LEN {
             return Err(ParseExtrasIdsError::Length);
         }
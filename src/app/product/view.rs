@@ -1,15 +1,52 @@
+use domain::core::money::Money;
+use domain::core::schedule;
 use domain::extra;
+use domain::happy_hour;
+use domain::ingredient;
+use domain::modifier;
 use domain::product;
+use domain::promotion;
+use domain::stock;
+use domain::tax;
+use domain::variant;
 use serde::Serialize;
-use time::OffsetDateTime;
+use time::{Date, OffsetDateTime};
 use uuid::Uuid;
 
+use crate::app::happy_hour::view::EffectivePriceView;
+use crate::app::promotion::view::PromotedView;
+use crate::app::tax::view::BreakdownView;
+use crate::app::{MoneyView, DATE_FORMAT, TIME_FORMAT};
+
 #[derive(Clone, Debug, Serialize)]
 pub struct ProductView<'a> {
     pub id: Uuid,
     pub name: &'a str,
-    pub price: u64,
+    pub price: MoneyView,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub taxes: Option<BreakdownView>,
+    /// Price after the winning promotion, when any applies
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub promoted: Option<PromotedView>,
+    /// Price in effect at the requested time, according to happy hours
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effective: Option<EffectivePriceView>,
+    /// Units available, on hand minus held, when stock of product is tracked
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stock: Option<u32>,
+    pub out_of_stock: bool,
+    /// Whether product is offered, being `false` when it is disabled, out of
+    /// its schedule or its recipe needs more of some ingredient than there is
+    /// on hand
+    pub available: bool,
+    /// Whether product is enabled at all, regardless of schedule and
+    /// ingredients
+    pub enabled: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule: Option<ScheduleView>,
     pub extras: Vec<ExtraView<'a>>,
+    pub variants: Vec<VariantView<'a>>,
+    pub modifiers: Vec<ModifierView<'a>>,
     #[serde(with = "time::serde::rfc3339")]
     pub created_at: OffsetDateTime,
     #[serde(with = "time::serde::rfc3339")]
@@ -21,20 +58,187 @@ impl<'a> ProductView<'a> {
         Self {
             id: product.id().uuid(),
             name: product.name.as_str(),
-            // TODO: maybe converting to cents is not really a good idea
-            price: product.price.to_cents(),
-            extras: product.extras.iter().map(ExtraView::new).collect(),
+            price: MoneyView::new(product.price),
+            taxes: None,
+            promoted: None,
+            effective: None,
+            stock: None,
+            out_of_stock: false,
+            available: product.available,
+            enabled: product.available,
+            schedule: product.schedule.as_ref().map(ScheduleView::new),
+            extras: product
+                .extras
+                .iter()
+                .map(|extra| {
+                    let mut view = ExtraView::new(extra);
+                    view.max_quantity = Some(product.extras.max_quantity(extra.id()).value());
+                    view
+                })
+                .collect(),
+            variants: product
+                .variants
+                .iter()
+                .map(|variant| VariantView::new(variant, product.price))
+                .collect(),
+            modifiers: product.modifiers.iter().map(ModifierView::new).collect(),
             created_at: product.metadata.created_at(),
             updated_at: product.metadata.updated_at(),
         }
     }
+
+    /// Same as [`ProductView::new`], also reporting net, tax and gross
+    /// amounts of product and its extras according to `rules`
+    pub fn with_taxes(
+        product: &'a product::Product,
+        rules: &tax::Rules,
+    ) -> Result<Self, tax::Error> {
+        let mut view = Self::new(product);
+        let breakdown = rules.breakdown(product, product.price)?;
+        view.taxes = Some(BreakdownView::new(breakdown));
+
+        for (extra_view, extra) in view.extras.iter_mut().zip(product.extras.iter()) {
+            let breakdown = rules.breakdown(product, extra.price)?;
+            extra_view.taxes = Some(BreakdownView::new(breakdown));
+        }
+
+        Ok(view)
+    }
+
+    /// Report promoted prices of product and its extras according to the
+    /// `promotions` active `at`
+    pub fn apply_promotions(
+        &mut self,
+        product: &product::Product,
+        promotions: &promotion::Promotions,
+        at: OffsetDateTime,
+    ) -> Result<(), promotion::Error> {
+        let applied = promotions.best_for_product(product, at)?;
+        self.promoted = applied.as_ref().map(PromotedView::new);
+
+        for (extra_view, extra) in self.extras.iter_mut().zip(product.extras.iter()) {
+            let applied = promotions.best_for_extra(extra, at)?;
+            extra_view.promoted = applied.as_ref().map(PromotedView::new);
+        }
+
+        Ok(())
+    }
+
+    /// Report price of product in effect `at` according to `happy_hours`
+    pub fn apply_happy_hours(
+        &mut self,
+        product: &product::Product,
+        happy_hours: &happy_hour::HappyHours,
+        at: OffsetDateTime,
+    ) -> Result<(), happy_hour::Error> {
+        let applied = happy_hours.best_for(product, at)?;
+        self.effective = Some(EffectivePriceView::new(product.price, applied.as_ref()));
+
+        Ok(())
+    }
+
+    /// Report units available of product according to `stocks`, where products
+    /// not tracked are never out of stock
+    pub fn apply_stocks(&mut self, product: &product::Product, stocks: &stock::Stocks) {
+        let stock = stocks.find(product.id());
+        self.stock = stock.map(stock::Stock::available);
+        self.out_of_stock = stock.is_some_and(stock::Stock::is_out_of_stock);
+    }
+
+    /// Report product as unavailable when it is among `exhausted` products
+    pub fn apply_ingredients(
+        &mut self,
+        product: &product::Product,
+        exhausted: &ingredient::Exhausted,
+    ) {
+        self.available &= !exhausted.contains(product.id());
+    }
+
+    /// Report product as unavailable when it is not offered `at`, according
+    /// to its schedule
+    pub fn apply_schedule(&mut self, product: &product::Product, at: OffsetDateTime) {
+        self.available &= product.is_available_at(at);
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct PaginationView<'a> {
+    pub count: u64,
+    pub page: u32,
+    pub limit: u8,
+    pub items: Vec<ProductView<'a>>,
+}
+
+impl<'a> PaginationView<'a> {
+    pub fn new(pagination: &product::Pagination, items: Vec<ProductView<'a>>) -> Self {
+        Self {
+            count: pagination.count,
+            page: pagination.page.into(),
+            limit: pagination.limit.into(),
+            items,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ScheduleView {
+    pub windows: Vec<ScheduleWindowView>,
+    pub starts_on: Option<String>,
+    pub ends_on: Option<String>,
+    pub time_zone: &'static str,
+}
+
+impl ScheduleView {
+    pub fn new(schedule: &product::Schedule) -> Self {
+        let format_date = |date: Date| date.format(DATE_FORMAT).unwrap_or_default();
+        Self {
+            windows: schedule
+                .windows()
+                .iter()
+                .map(ScheduleWindowView::new)
+                .collect(),
+            starts_on: schedule.starts_on().map(format_date),
+            ends_on: schedule.ends_on().map(format_date),
+            time_zone: schedule.zone().as_str(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ScheduleWindowView {
+    pub weekdays: Vec<&'static str>,
+    pub starts_at: String,
+    pub ends_at: String,
+}
+
+impl ScheduleWindowView {
+    pub fn new(window: &schedule::Window) -> Self {
+        Self {
+            weekdays: window
+                .weekdays()
+                .iter()
+                .map(schedule::Weekdays::day_as_str)
+                .collect(),
+            starts_at: window.starts_at().format(TIME_FORMAT).unwrap_or_default(),
+            ends_at: window.ends_at().format(TIME_FORMAT).unwrap_or_default(),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize)]
 pub struct ExtraView<'a> {
     pub id: Uuid,
     pub name: &'a str,
-    pub price: u64,
+    pub price: MoneyView,
+    /// How many of extra can be selected for a single unit of product, when
+    /// viewed along with it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_quantity: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub taxes: Option<BreakdownView>,
+    /// Price after the winning promotion, when any applies
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub promoted: Option<PromotedView>,
     #[serde(with = "time::serde::rfc3339")]
     pub created_at: OffsetDateTime,
     #[serde(with = "time::serde::rfc3339")]
@@ -46,10 +250,113 @@ impl<'a> ExtraView<'a> {
         Self {
             id: extra.id().uuid(),
             name: extra.name.as_str(),
-            // TODO: maybe converting to cents is not really a good idea
-            price: extra.price.to_cents(),
+            price: MoneyView::new(extra.price),
+            max_quantity: None,
+            taxes: None,
+            promoted: None,
             created_at: extra.metadata.created_at(),
             updated_at: extra.metadata.updated_at(),
         }
     }
 }
+
+#[derive(Clone, Debug, Serialize)]
+pub struct VariantView<'a> {
+    pub id: Uuid,
+    pub name: &'a str,
+    pub pricing: &'a str,
+    /// Price of variant itself, which for `delta` pricing is added to the
+    /// product price
+    pub amount: MoneyView,
+    /// Price variant is sold for
+    pub price: MoneyView,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime,
+}
+
+impl<'a> VariantView<'a> {
+    pub fn new(variant: &'a variant::Variant, base: Money) -> Self {
+        Self {
+            id: variant.id().uuid(),
+            name: variant.name.as_str(),
+            pricing: variant.pricing.as_str(),
+            amount: MoneyView::new(variant.price),
+            price: MoneyView::new(variant.price_for(base).unwrap_or(variant.price)),
+            created_at: variant.metadata.created_at(),
+            updated_at: variant.metadata.updated_at(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ModifierView<'a> {
+    pub id: Uuid,
+    pub name: &'a str,
+    pub min: u8,
+    pub max: u8,
+    pub extras_ids: Vec<Uuid>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime,
+}
+
+impl<'a> ModifierView<'a> {
+    pub fn new(group: &'a modifier::Group) -> Self {
+        Self {
+            id: group.id().uuid(),
+            name: group.name.as_str(),
+            min: group.limits.min(),
+            max: group.limits.max(),
+            extras_ids: group.options.iter().map(extra::Id::uuid).collect(),
+            created_at: group.metadata.created_at(),
+            updated_at: group.metadata.updated_at(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct QuoteView<'a> {
+    pub product_id: Uuid,
+    pub price: MoneyView,
+    pub quantity: u16,
+    pub extras: Vec<QuoteExtraView<'a>>,
+    pub subtotal: MoneyView,
+    pub total: MoneyView,
+}
+
+impl<'a> QuoteView<'a> {
+    pub fn new(quote: &'a product::Quote) -> Self {
+        Self {
+            product_id: quote.product_id().uuid(),
+            price: MoneyView::new(quote.price()),
+            quantity: quote.quantity().value(),
+            extras: quote.extras().iter().map(QuoteExtraView::new).collect(),
+            subtotal: MoneyView::new(quote.subtotal()),
+            total: MoneyView::new(quote.total()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct QuoteExtraView<'a> {
+    pub id: Uuid,
+    pub name: &'a str,
+    pub price: MoneyView,
+    pub quantity: u16,
+    pub total: MoneyView,
+}
+
+impl<'a> QuoteExtraView<'a> {
+    pub fn new(extra: &'a product::QuoteExtra) -> Self {
+        Self {
+            id: extra.id.uuid(),
+            name: extra.name.as_str(),
+            price: MoneyView::new(extra.price),
+            quantity: extra.quantity.value(),
+            total: MoneyView::new(extra.total),
+        }
+    }
+}
@@ -0,0 +1,248 @@
+use axum::extract::{Json, Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::Deserialize;
+
+use domain::promotion;
+
+use super::service::{
+    parse_discount, parse_target, parse_validity, CreateInput, DeleteInput, FindInput,
+    PromotionService, UpdateInput,
+};
+use super::view::PromotionView;
+use crate::app::{ApiError, MoneyBody};
+use crate::infra::PgPromotions;
+use crate::Context;
+
+pub async fn all(State(ctx): State<Context>) -> Response {
+    let service = PromotionService::new(PgPromotions::new(ctx.pool));
+    let promotions = match service.all().await {
+        Ok(promotions) => promotions,
+        Err(err) => {
+            eprintln!("All promotions error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    let views = promotions
+        .iter()
+        .map(PromotionView::new)
+        .collect::<Vec<_>>();
+    Json(views).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct DiscountBody {
+    pub percentage: Option<String>,
+    pub fixed: Option<MoneyBody>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct TargetBody {
+    pub catalog_id: Option<String>,
+    pub kind: Option<String>,
+    pub product_id: Option<String>,
+    pub extra_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CreateBody {
+    pub name: String,
+    pub discount: DiscountBody,
+    pub targets: Vec<TargetBody>,
+    pub priority: Option<u8>,
+    pub starts_at: Option<String>,
+    pub ends_at: Option<String>,
+}
+
+pub async fn create(State(ctx): State<Context>, Json(body): Json<CreateBody>) -> Response {
+    let name = match promotion::Name::new(body.name) {
+        Ok(name) => name,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let discount = match parse_discount(
+        body.discount.percentage.as_deref(),
+        body.discount.fixed.as_ref(),
+    ) {
+        Ok(discount) => discount,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let targets = match parse_targets(&body.targets) {
+        Ok(targets) => targets,
+        Err(err) => return create_validation_error_response(err.as_ref()).into_response(),
+    };
+    let validity = match parse_validity(body.starts_at.as_deref(), body.ends_at.as_deref()) {
+        Ok(validity) => validity,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = CreateInput {
+        name,
+        discount,
+        targets,
+        validity,
+        priority: body.priority.unwrap_or_default(),
+    };
+
+    let mut service = PromotionService::new(PgPromotions::new(ctx.pool));
+    let created_promotion = match service.create(input).await {
+        Ok(promotion) => promotion,
+        Err(err) => {
+            eprintln!("Create promotion error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(PromotionView::new(&created_promotion)).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct DeletePath {
+    pub id: String,
+}
+
+pub async fn delete(State(ctx): State<Context>, Path(path): Path<DeletePath>) -> Response {
+    let id = match promotion::Id::parse_str(&path.id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = DeleteInput { id };
+
+    let mut service = PromotionService::new(PgPromotions::new(ctx.pool));
+    let deleted_promotion = match service.delete(input).await {
+        Ok(promotion) => promotion,
+        Err(err) => {
+            eprintln!("Delete promotion error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(PromotionView::new(&deleted_promotion)).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct FindPath {
+    pub id: String,
+}
+
+pub async fn find(State(ctx): State<Context>, Path(path): Path<FindPath>) -> Response {
+    let id = match promotion::Id::parse_str(&path.id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = FindInput { id };
+
+    let service = PromotionService::new(PgPromotions::new(ctx.pool));
+    let found_promotion = match service.find(input).await {
+        Ok(promotion) => promotion,
+        Err(err) => {
+            eprintln!("Find promotion error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(PromotionView::new(&found_promotion)).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UpdatePath {
+    pub id: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UpdateBody {
+    pub name: String,
+    pub discount: DiscountBody,
+    pub targets: Vec<TargetBody>,
+    pub priority: Option<u8>,
+    pub starts_at: Option<String>,
+    pub ends_at: Option<String>,
+}
+
+pub async fn update(
+    State(ctx): State<Context>,
+    Path(path): Path<UpdatePath>,
+    Json(body): Json<UpdateBody>,
+) -> Response {
+    let id = match promotion::Id::parse_str(&path.id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let name = match promotion::Name::new(body.name) {
+        Ok(name) => name,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let discount = match parse_discount(
+        body.discount.percentage.as_deref(),
+        body.discount.fixed.as_ref(),
+    ) {
+        Ok(discount) => discount,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let targets = match parse_targets(&body.targets) {
+        Ok(targets) => targets,
+        Err(err) => return create_validation_error_response(err.as_ref()).into_response(),
+    };
+    let validity = match parse_validity(body.starts_at.as_deref(), body.ends_at.as_deref()) {
+        Ok(validity) => validity,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = UpdateInput {
+        id,
+        name,
+        discount,
+        targets,
+        validity,
+        priority: body.priority.unwrap_or_default(),
+    };
+
+    let mut service = PromotionService::new(PgPromotions::new(ctx.pool));
+    let updated_promotion = match service.update(input).await {
+        Ok(promotion) => promotion,
+        Err(err) => {
+            eprintln!("Update promotion error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(PromotionView::new(&updated_promotion)).into_response()
+}
+
+fn parse_targets(bodies: &[TargetBody]) -> Result<promotion::Targets, Box<dyn std::error::Error>> {
+    let targets = bodies
+        .iter()
+        .map(|body| {
+            parse_target(
+                body.catalog_id.as_deref(),
+                body.kind.as_deref(),
+                body.product_id.as_deref(),
+                body.extra_id.as_deref(),
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(promotion::Targets::new(targets)?)
+}
+
+pub fn create_error_response(err: promotion::Error) -> impl IntoResponse {
+    use promotion::Error;
+
+    match err {
+        Error::Conflict(kind) => (
+            StatusCode::CONFLICT,
+            Json(ApiError::new("Conflict", kind.to_string())),
+        ),
+        Error::Internal(_) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new("Internal", "Internal server error")),
+        ),
+        Error::NotFound(kind) => (
+            StatusCode::NOT_FOUND,
+            Json(ApiError::new("NotFound", kind.to_string())),
+        ),
+    }
+}
+
+fn create_validation_error_response(err: &dyn std::error::Error) -> impl IntoResponse {
+    let body = ApiError::new("Validation", err.to_string());
+    (StatusCode::BAD_REQUEST, Json(body))
+}
@@ -0,0 +1,76 @@
+mod dto;
+
+pub(crate) use dto::parse_discount;
+pub use dto::{
+    parse_target, parse_validity, ActiveInput, CreateInput, DeleteInput, FindInput, UpdateInput,
+};
+
+use domain::promotion;
+
+#[derive(Clone, Debug)]
+pub struct PromotionService<T> {
+    promotions: T,
+}
+
+impl<T: promotion::Repository> PromotionService<T> {
+    pub fn new(promotions: T) -> Self {
+        Self { promotions }
+    }
+}
+
+impl<T: promotion::Repository> PromotionService<T> {
+    pub async fn active(
+        &self,
+        input: ActiveInput,
+    ) -> Result<promotion::Promotions, promotion::Error> {
+        self.promotions.active(input.at).await
+    }
+
+    pub async fn all(&self) -> Result<Vec<promotion::Promotion>, promotion::Error> {
+        self.promotions.all().await
+    }
+
+    pub async fn create(
+        &mut self,
+        input: CreateInput,
+    ) -> Result<promotion::Promotion, promotion::Error> {
+        let promotion = promotion::Promotion::new(
+            input.name,
+            input.discount,
+            input.targets,
+            input.validity,
+            input.priority,
+        );
+        self.promotions.create(&promotion).await?;
+
+        Ok(promotion)
+    }
+
+    pub async fn delete(
+        &mut self,
+        input: DeleteInput,
+    ) -> Result<promotion::Promotion, promotion::Error> {
+        self.promotions.delete(input.id).await
+    }
+
+    pub async fn find(&self, input: FindInput) -> Result<promotion::Promotion, promotion::Error> {
+        self.promotions.find(input.id).await
+    }
+
+    pub async fn update(
+        &mut self,
+        input: UpdateInput,
+    ) -> Result<promotion::Promotion, promotion::Error> {
+        let mut promotion = self.promotions.find(input.id).await?;
+        promotion.name = input.name;
+        promotion.discount = input.discount;
+        promotion.targets = input.targets;
+        promotion.validity = input.validity;
+        promotion.priority = input.priority;
+        promotion.metadata.update();
+
+        self.promotions.update(&promotion).await?;
+
+        Ok(promotion)
+    }
+}
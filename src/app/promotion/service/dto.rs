@@ -0,0 +1,134 @@
+use thiserror::Error;
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+use domain::catalog;
+use domain::core::money::ParseMoneyError;
+use domain::extra;
+use domain::product;
+use domain::promotion;
+
+use crate::app::MoneyBody;
+
+#[derive(Clone, Debug)]
+pub struct ActiveInput {
+    pub at: OffsetDateTime,
+}
+
+#[derive(Clone, Debug)]
+pub struct CreateInput {
+    pub name: promotion::Name,
+    pub discount: promotion::Discount,
+    pub targets: promotion::Targets,
+    pub validity: promotion::Validity,
+    pub priority: u8,
+}
+
+#[derive(Clone, Debug)]
+pub struct DeleteInput {
+    pub id: promotion::Id,
+}
+
+#[derive(Clone, Debug)]
+pub struct FindInput {
+    pub id: promotion::Id,
+}
+
+#[derive(Clone, Debug)]
+pub struct UpdateInput {
+    pub id: promotion::Id,
+    pub name: promotion::Name,
+    pub discount: promotion::Discount,
+    pub targets: promotion::Targets,
+    pub validity: promotion::Validity,
+    pub priority: u8,
+}
+
+/// Try parsing a [`promotion::Discount`] where exactly one of `percentage`
+/// or `fixed` must be provided
+pub(crate) fn parse_discount(
+    percentage: Option<&str>,
+    fixed: Option<&MoneyBody>,
+) -> Result<promotion::Discount, ParseDiscountError> {
+    match (percentage, fixed) {
+        (Some(percentage), None) => promotion::Percentage::parse_str(percentage)
+            .map(promotion::Discount::Percentage)
+            .map_err(ParseDiscountError::Percentage),
+        (None, Some(fixed)) => {
+            let amount = fixed.parse().map_err(ParseDiscountError::Money)?;
+            promotion::Discount::fixed(amount).map_err(ParseDiscountError::Fixed)
+        }
+        _ => Err(ParseDiscountError::Ambiguous),
+    }
+}
+
+/// Try parsing a [`promotion::Target`] where exactly one of `catalog_id`,
+/// `kind`, `product_id` or `extra_id` must be provided
+pub fn parse_target(
+    catalog_id: Option<&str>,
+    kind: Option<&str>,
+    product_id: Option<&str>,
+    extra_id: Option<&str>,
+) -> Result<promotion::Target, ParseTargetError> {
+    match (catalog_id, kind, product_id, extra_id) {
+        (Some(catalog_id), None, None, None) => catalog::Id::parse_str(catalog_id)
+            .map(promotion::Target::Catalog)
+            .map_err(ParseTargetError::CatalogId),
+        (None, Some(kind), None, None) => product::Kind::parse_str(kind)
+            .map(promotion::Target::Kind)
+            .map_err(ParseTargetError::Kind),
+        (None, None, Some(product_id), None) => product::Id::parse_str(product_id)
+            .map(promotion::Target::Product)
+            .map_err(ParseTargetError::ProductId),
+        (None, None, None, Some(extra_id)) => extra::Id::parse_str(extra_id)
+            .map(promotion::Target::Extra)
+            .map_err(ParseTargetError::ExtraId),
+        _ => Err(ParseTargetError::Ambiguous),
+    }
+}
+
+/// Try parsing a [`promotion::Validity`] from optional RFC 3339 bounds
+pub fn parse_validity(
+    starts_at: Option<&str>,
+    ends_at: Option<&str>,
+) -> Result<promotion::Validity, ParseValidityError> {
+    let parse = |value: &str| OffsetDateTime::parse(value, &Rfc3339);
+    let starts_at = starts_at.map(parse).transpose()?;
+    let ends_at = ends_at.map(parse).transpose()?;
+
+    promotion::Validity::new(starts_at, ends_at).map_err(ParseValidityError::Range)
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum ParseDiscountError {
+    #[error("Discount must be either a percentage or a fixed amount")]
+    Ambiguous,
+    #[error(transparent)]
+    Fixed(promotion::DiscountError),
+    #[error(transparent)]
+    Money(ParseMoneyError),
+    #[error(transparent)]
+    Percentage(promotion::PercentageError),
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum ParseTargetError {
+    #[error("Promotion target must be exactly one of a catalog, kind, product or extra")]
+    Ambiguous,
+    #[error(transparent)]
+    CatalogId(catalog::ParseIdError),
+    #[error(transparent)]
+    ExtraId(extra::IdError),
+    #[error(transparent)]
+    Kind(product::ParseKindError),
+    #[error(transparent)]
+    ProductId(product::ParseIdError),
+}
+
+#[derive(Clone, Debug, Error, PartialEq)]
+pub enum ParseValidityError {
+    #[error("Validity bounds must be RFC 3339 date times: {0}")]
+    Format(#[from] time::error::Parse),
+    #[error(transparent)]
+    Range(promotion::ValidityError),
+}
@@ -0,0 +1,114 @@
+use serde::Serialize;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use domain::promotion;
+
+use crate::app::MoneyView;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct PromotionView<'a> {
+    pub id: Uuid,
+    pub name: &'a str,
+    pub discount: DiscountView,
+    pub targets: Vec<TargetView<'a>>,
+    pub priority: u8,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub starts_at: Option<OffsetDateTime>,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub ends_at: Option<OffsetDateTime>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime,
+}
+
+impl<'a> PromotionView<'a> {
+    pub fn new(promotion: &'a promotion::Promotion) -> Self {
+        Self {
+            id: promotion.id().uuid(),
+            name: promotion.name.as_str(),
+            discount: DiscountView::new(promotion.discount),
+            targets: promotion.targets.iter().map(TargetView::new).collect(),
+            priority: promotion.priority,
+            starts_at: promotion.validity.starts_at(),
+            ends_at: promotion.validity.ends_at(),
+            created_at: promotion.metadata.created_at(),
+            updated_at: promotion.metadata.updated_at(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct DiscountView {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub percentage: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixed: Option<MoneyView>,
+}
+
+impl DiscountView {
+    pub fn new(discount: promotion::Discount) -> Self {
+        match discount {
+            promotion::Discount::Percentage(percentage) => Self {
+                percentage: Some(percentage.to_string()),
+                fixed: None,
+            },
+            promotion::Discount::Fixed(amount) => Self {
+                percentage: None,
+                fixed: Some(MoneyView::new(amount)),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct TargetView<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub catalog_id: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_id: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extra_id: Option<Uuid>,
+}
+
+impl<'a> TargetView<'a> {
+    pub fn new(target: &'a promotion::Target) -> Self {
+        let mut view = Self {
+            catalog_id: None,
+            kind: None,
+            product_id: None,
+            extra_id: None,
+        };
+        match target {
+            promotion::Target::Catalog(catalog_id) => view.catalog_id = Some(catalog_id.uuid()),
+            promotion::Target::Kind(kind) => view.kind = Some(kind.as_str()),
+            promotion::Target::Product(product_id) => view.product_id = Some(product_id.uuid()),
+            promotion::Target::Extra(extra_id) => view.extra_id = Some(extra_id.uuid()),
+        }
+
+        view
+    }
+}
+
+/// Price after the winning promotion was applied
+#[derive(Clone, Debug, Serialize)]
+pub struct PromotedView {
+    pub price: MoneyView,
+    pub discount: MoneyView,
+    pub promotion_id: Uuid,
+    pub promotion_name: String,
+}
+
+impl PromotedView {
+    pub fn new(applied: &promotion::Applied) -> Self {
+        Self {
+            price: MoneyView::new(applied.price),
+            discount: MoneyView::new(applied.discount),
+            promotion_id: applied.promotion_id.uuid(),
+            promotion_name: applied.name.to_string(),
+        }
+    }
+}
@@ -0,0 +1,285 @@
+use std::num::{NonZeroU32, NonZeroU8};
+
+use axum::extract::{Json, Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::Deserialize;
+
+use domain::catalog;
+use domain::core::quantity::Quantity;
+use domain::product;
+use domain::sale;
+
+use super::service::{CreateInput, FindInput, LineInput, ListInput, RefundInput, SaleService};
+use super::view::{PaginationView, RefundView, SaleView};
+use crate::app::product::service::ExtrasIds;
+use crate::app::ApiError;
+use crate::infra::{PgProducts, PgSales};
+use crate::Context;
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CreateBody {
+    pub lines: Vec<CreateLineBody>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CreateLineBody {
+    pub catalog_id: String,
+    pub product_id: String,
+    pub extras_ids: Vec<String>,
+    pub quantity: u16,
+}
+
+pub async fn create(State(ctx): State<Context>, Json(body): Json<CreateBody>) -> Response {
+    if body.lines.is_empty() {
+        return create_validation_error_response(&sale::LinesError::Empty).into_response();
+    }
+    if body.lines.len() > sale::Lines::MAX_LEN {
+        return create_validation_error_response(&sale::LinesError::Length).into_response();
+    }
+
+    let mut lines = Vec::with_capacity(body.lines.len());
+    for line in &body.lines {
+        let catalog_id = match catalog::Id::parse_str(&line.catalog_id) {
+            Ok(catalog_id) => catalog_id,
+            Err(err) => return create_validation_error_response(&err).into_response(),
+        };
+        let product_id = match product::Id::parse_str(&line.product_id) {
+            Ok(product_id) => product_id,
+            Err(err) => return create_validation_error_response(&err).into_response(),
+        };
+        let extras_ids = match ExtrasIds::parse(&line.extras_ids) {
+            Ok(extras_ids) => extras_ids,
+            Err(err) => return create_validation_error_response(&err).into_response(),
+        };
+        let quantity = match Quantity::new(line.quantity) {
+            Ok(quantity) => quantity,
+            Err(err) => return create_validation_error_response(&err).into_response(),
+        };
+
+        lines.push(LineInput {
+            catalog_id,
+            product_id,
+            extras_ids,
+            quantity,
+        });
+    }
+    let input = CreateInput { lines };
+
+    let pg_sales = PgSales::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool);
+    let mut service = SaleService::new(pg_sales, pg_products);
+
+    let created_sale = match service.create(input).await {
+        Ok(sale) => sale,
+        Err(err) => {
+            eprintln!("Create sale error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    create_view_response(&created_sale)
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct FindPath {
+    pub id: String,
+}
+
+pub async fn find(State(ctx): State<Context>, Path(path): Path<FindPath>) -> Response {
+    let id = match sale::Id::parse_str(&path.id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = FindInput { id };
+
+    let pg_sales = PgSales::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool);
+    let service = SaleService::new(pg_sales, pg_products);
+
+    let found_sale = match service.find(input).await {
+        Ok(sale) => sale,
+        Err(err) => {
+            eprintln!("Find sale error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    create_view_response(&found_sale)
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListQuery {
+    pub page: Option<u32>,
+    pub limit: Option<u8>,
+}
+
+pub async fn list(State(ctx): State<Context>, Query(query): Query<ListQuery>) -> Response {
+    let page = match query.page {
+        Some(0) | None => NonZeroU32::new(1).unwrap(),
+        Some(page) => NonZeroU32::new(page).expect("Page is not zero"),
+    };
+    let limit = match query.limit {
+        Some(0) | None => NonZeroU8::new(10).unwrap(),
+        Some(limit) => NonZeroU8::new(limit).expect("Limit is not zero"),
+    };
+    let input = ListInput { page, limit };
+
+    let pg_sales = PgSales::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool);
+    let service = SaleService::new(pg_sales, pg_products);
+
+    let pagination = match service.list(input).await {
+        Ok(pagination) => pagination,
+        Err(err) => {
+            eprintln!("List sales error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    match PaginationView::new(&pagination) {
+        Ok(view) => Json(view).into_response(),
+        Err(err) => {
+            eprintln!("Sales pagination view error: {err:?}");
+            create_error_response(err).into_response()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RefundsPath {
+    pub id: String,
+}
+
+pub async fn refunds(State(ctx): State<Context>, Path(path): Path<RefundsPath>) -> Response {
+    let id = match sale::Id::parse_str(&path.id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = FindInput { id };
+
+    let pg_sales = PgSales::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool);
+    let service = SaleService::new(pg_sales, pg_products);
+
+    let found_sale = match service.find(input).await {
+        Ok(sale) => sale,
+        Err(err) => {
+            eprintln!("Find sale refunds error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    let views = found_sale
+        .refunds()
+        .iter()
+        .map(RefundView::new)
+        .collect::<Vec<_>>();
+
+    Json(views).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RefundPath {
+    pub id: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RefundBody {
+    pub reason: String,
+    /// Lines to partially refund, refunding everything left when absent
+    pub lines: Option<Vec<RefundLineBody>>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RefundLineBody {
+    pub line_id: String,
+    pub quantity: u16,
+}
+
+pub async fn refund(
+    State(ctx): State<Context>,
+    Path(path): Path<RefundPath>,
+    Json(body): Json<RefundBody>,
+) -> Response {
+    let id = match sale::Id::parse_str(&path.id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let reason = match sale::Reason::new(body.reason) {
+        Ok(reason) => reason,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let lines = match body.lines {
+        Some(lines_body) => {
+            let mut lines = Vec::with_capacity(lines_body.len());
+            for line in &lines_body {
+                let line_id = match sale::LineId::parse_str(&line.line_id) {
+                    Ok(line_id) => line_id,
+                    Err(err) => return create_validation_error_response(&err).into_response(),
+                };
+                let quantity = match Quantity::new(line.quantity) {
+                    Ok(quantity) => quantity,
+                    Err(err) => return create_validation_error_response(&err).into_response(),
+                };
+
+                lines.push(sale::RefundLine { line_id, quantity });
+            }
+
+            match sale::RefundLines::new(lines) {
+                Ok(lines) => Some(lines),
+                Err(err) => return create_validation_error_response(&err).into_response(),
+            }
+        }
+        None => None,
+    };
+    let input = RefundInput { id, reason, lines };
+
+    let pg_sales = PgSales::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool);
+    let mut service = SaleService::new(pg_sales, pg_products);
+
+    let refund = match service.refund(input).await {
+        Ok(refund) => refund,
+        Err(err) => {
+            eprintln!("Refund sale error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(RefundView::new(&refund)).into_response()
+}
+
+fn create_view_response(sale: &sale::Sale) -> Response {
+    match SaleView::new(sale) {
+        Ok(view) => Json(view).into_response(),
+        Err(err) => {
+            eprintln!("Sale view error: {err:?}");
+            create_error_response(err).into_response()
+        }
+    }
+}
+
+pub fn create_error_response(err: sale::Error) -> impl IntoResponse {
+    use sale::Error;
+
+    match err {
+        Error::Conflict(kind) => (
+            StatusCode::CONFLICT,
+            Json(ApiError::new("Conflict", kind.to_string())),
+        ),
+        Error::Internal(_) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new("Internal", "Internal server error")),
+        ),
+        Error::NotFound(kind) => (
+            StatusCode::NOT_FOUND,
+            Json(ApiError::new("NotFound", kind.to_string())),
+        ),
+    }
+}
+
+fn create_validation_error_response(err: &dyn std::error::Error) -> impl IntoResponse {
+    let body = ApiError::new("Validation", err.to_string());
+    (StatusCode::BAD_REQUEST, Json(body))
+}
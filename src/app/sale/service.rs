@@ -0,0 +1,77 @@
+mod dto;
+
+pub use dto::{CreateInput, FindInput, LineInput, ListInput, RefundInput};
+
+use domain::product;
+use domain::sale;
+
+#[derive(Clone, Debug)]
+pub struct SaleService<T, U> {
+    sales: T,
+    products: U,
+}
+
+impl<T: sale::Repository, U: product::Repository> SaleService<T, U> {
+    pub fn new(sales: T, products: U) -> Self {
+        Self { sales, products }
+    }
+}
+
+impl<T: sale::Repository, U: product::Repository> SaleService<T, U> {
+    pub async fn create(&mut self, input: CreateInput) -> Result<sale::Sale, sale::Error> {
+        let mut lines = Vec::with_capacity(input.lines.len());
+        for line_input in input.lines {
+            let product = self
+                .products
+                .find(line_input.product_id, line_input.catalog_id)
+                .await
+                .map_err(|err| match err {
+                    product::Error::NotFound(kind) => {
+                        sale::Error::NotFound(sale::NotFoundKind::Product(kind))
+                    }
+                    err => sale::Error::any(err),
+                })?;
+
+            let line = sale::Line::new(
+                &product,
+                line_input.extras_ids.as_slice(),
+                line_input.quantity,
+            )?;
+
+            lines.push(line);
+        }
+
+        let lines = sale::Lines::new(lines).map_err(sale::Error::any)?;
+        let sale = sale::Sale::new(lines);
+        // sales must be refundable in a single currency
+        sale.total()?;
+        self.sales.create(&sale).await?;
+
+        Ok(sale)
+    }
+
+    pub async fn find(&self, input: FindInput) -> Result<sale::Sale, sale::Error> {
+        self.sales.find(input.id).await
+    }
+
+    pub async fn list(&self, input: ListInput) -> Result<sale::Pagination, sale::Error> {
+        let query = sale::ListQuery {
+            page: input.page,
+            limit: input.limit,
+        };
+
+        self.sales.list(query).await
+    }
+
+    pub async fn refund(&mut self, input: RefundInput) -> Result<sale::Refund, sale::Error> {
+        let mut sale = self.sales.find(input.id).await?;
+        let refund = match input.lines {
+            Some(lines) => sale.refund_lines(input.reason, lines)?,
+            None => sale.refund_all(input.reason)?,
+        };
+
+        self.sales.refund(sale.id(), &refund).await?;
+
+        Ok(refund)
+    }
+}
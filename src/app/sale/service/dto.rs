@@ -0,0 +1,40 @@
+use std::num::{NonZeroU32, NonZeroU8};
+
+use domain::catalog;
+use domain::core::quantity::Quantity;
+use domain::product;
+use domain::sale;
+
+use crate::app::product::service::ExtrasIds;
+
+#[derive(Clone, Debug)]
+pub struct CreateInput {
+    pub lines: Vec<LineInput>,
+}
+
+#[derive(Clone, Debug)]
+pub struct LineInput {
+    pub catalog_id: catalog::Id,
+    pub product_id: product::Id,
+    pub extras_ids: ExtrasIds,
+    pub quantity: Quantity,
+}
+
+#[derive(Clone, Debug)]
+pub struct FindInput {
+    pub id: sale::Id,
+}
+
+#[derive(Clone, Debug)]
+pub struct ListInput {
+    pub page: NonZeroU32,
+    pub limit: NonZeroU8,
+}
+
+#[derive(Clone, Debug)]
+pub struct RefundInput {
+    pub id: sale::Id,
+    pub reason: sale::Reason,
+    /// Lines partially refunded, where [`None`] refunds everything left
+    pub lines: Option<sale::RefundLines>,
+}
@@ -0,0 +1,145 @@
+use serde::Serialize;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use domain::sale;
+
+use crate::app::MoneyView;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct SaleView<'a> {
+    pub id: Uuid,
+    pub lines: Vec<LineView<'a>>,
+    pub refunds: Vec<RefundView<'a>>,
+    pub total: MoneyView,
+    pub refunded: MoneyView,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime,
+}
+
+impl<'a> SaleView<'a> {
+    pub fn new(sale: &'a sale::Sale) -> Result<Self, sale::Error> {
+        Ok(Self {
+            id: sale.id().uuid(),
+            lines: sale
+                .lines()
+                .iter()
+                .map(|line| LineView::new(sale, line))
+                .collect::<Result<_, _>>()?,
+            refunds: sale.refunds().iter().map(RefundView::new).collect(),
+            total: MoneyView::new(sale.total()?),
+            refunded: MoneyView::new(sale.refunded()?),
+            created_at: sale.metadata.created_at(),
+            updated_at: sale.metadata.updated_at(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct LineView<'a> {
+    pub id: Uuid,
+    pub product_id: Uuid,
+    pub catalog_id: Uuid,
+    pub name: &'a str,
+    pub price: MoneyView,
+    pub quantity: u16,
+    /// Units not refunded yet
+    pub refundable: u16,
+    pub extras: Vec<LineExtraView<'a>>,
+    pub total: MoneyView,
+}
+
+impl<'a> LineView<'a> {
+    pub fn new(sale: &sale::Sale, line: &'a sale::Line) -> Result<Self, sale::Error> {
+        Ok(Self {
+            id: line.id().uuid(),
+            product_id: line.product_id().uuid(),
+            catalog_id: line.catalog_id().uuid(),
+            name: line.name().as_str(),
+            price: MoneyView::new(line.price()),
+            quantity: line.quantity().value(),
+            refundable: sale.refundable_quantity(line.id()).unwrap_or_default(),
+            extras: line.extras().iter().map(LineExtraView::new).collect(),
+            total: MoneyView::new(line.total()?),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct LineExtraView<'a> {
+    pub id: Uuid,
+    pub name: &'a str,
+    pub price: MoneyView,
+}
+
+impl<'a> LineExtraView<'a> {
+    pub fn new(extra: &'a sale::LineExtra) -> Self {
+        Self {
+            id: extra.id.uuid(),
+            name: extra.name.as_str(),
+            price: MoneyView::new(extra.price),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct RefundView<'a> {
+    pub id: Uuid,
+    pub reason: &'a str,
+    pub lines: Vec<RefundLineView>,
+    pub amount: MoneyView,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+}
+
+impl<'a> RefundView<'a> {
+    pub fn new(refund: &'a sale::Refund) -> Self {
+        Self {
+            id: refund.id().uuid(),
+            reason: refund.reason().as_str(),
+            lines: refund.lines().iter().map(RefundLineView::new).collect(),
+            amount: MoneyView::new(refund.amount()),
+            created_at: refund.created_at(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct RefundLineView {
+    pub line_id: Uuid,
+    pub quantity: u16,
+}
+
+impl RefundLineView {
+    pub fn new(line: &sale::RefundLine) -> Self {
+        Self {
+            line_id: line.line_id.uuid(),
+            quantity: line.quantity.value(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct PaginationView<'a> {
+    pub count: u64,
+    pub page: u32,
+    pub limit: u8,
+    pub items: Vec<SaleView<'a>>,
+}
+
+impl<'a> PaginationView<'a> {
+    pub fn new(pagination: &'a sale::Pagination) -> Result<Self, sale::Error> {
+        Ok(Self {
+            count: pagination.count,
+            page: pagination.page.into(),
+            limit: pagination.limit.into(),
+            items: pagination
+                .items
+                .iter()
+                .map(SaleView::new)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
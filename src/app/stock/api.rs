@@ -0,0 +1,177 @@
+use axum::extract::{Json, Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::Deserialize;
+
+use domain::catalog;
+use domain::product;
+use domain::stock;
+
+use super::service::{AdjustInput, FindInput, StockService};
+use super::view::StockView;
+use crate::app::ApiError;
+use crate::infra::{PgProducts, PgStocks};
+use crate::Context;
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct StockPath {
+    pub id: String,
+    pub catalog_id: String,
+}
+
+pub async fn find(State(ctx): State<Context>, Path(path): Path<StockPath>) -> Response {
+    let product_id = match product::Id::parse_str(&path.id) {
+        Ok(product_id) => product_id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let catalog_id = match catalog::Id::parse_str(&path.catalog_id) {
+        Ok(catalog_id) => catalog_id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = FindInput {
+        product_id,
+        catalog_id,
+    };
+
+    let pg_stocks = PgStocks::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool);
+    let service = StockService::new(pg_stocks, pg_products);
+
+    let found_stock = match service.find(input).await {
+        Ok(stock) => stock,
+        Err(err) => {
+            eprintln!("Find stock error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(StockView::new(&found_stock)).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct SetBody {
+    pub level: u32,
+}
+
+pub async fn set(
+    State(ctx): State<Context>,
+    Path(path): Path<StockPath>,
+    Json(body): Json<SetBody>,
+) -> Response {
+    let level = match stock::Level::new(body.level) {
+        Ok(level) => level,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let product_id = match product::Id::parse_str(&path.id) {
+        Ok(product_id) => product_id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let catalog_id = match catalog::Id::parse_str(&path.catalog_id) {
+        Ok(catalog_id) => catalog_id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = AdjustInput {
+        product_id,
+        catalog_id,
+        adjustment: stock::Adjustment::Set(level),
+    };
+
+    adjust(ctx, input).await
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct QuantityBody {
+    pub quantity: u32,
+}
+
+pub async fn increment(
+    State(ctx): State<Context>,
+    Path(path): Path<StockPath>,
+    Json(body): Json<QuantityBody>,
+) -> Response {
+    let quantity = match stock::Quantity::new(body.quantity) {
+        Ok(quantity) => quantity,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let product_id = match product::Id::parse_str(&path.id) {
+        Ok(product_id) => product_id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let catalog_id = match catalog::Id::parse_str(&path.catalog_id) {
+        Ok(catalog_id) => catalog_id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = AdjustInput {
+        product_id,
+        catalog_id,
+        adjustment: stock::Adjustment::Increment(quantity),
+    };
+
+    adjust(ctx, input).await
+}
+
+pub async fn consume(
+    State(ctx): State<Context>,
+    Path(path): Path<StockPath>,
+    Json(body): Json<QuantityBody>,
+) -> Response {
+    let quantity = match stock::Quantity::new(body.quantity) {
+        Ok(quantity) => quantity,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let product_id = match product::Id::parse_str(&path.id) {
+        Ok(product_id) => product_id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let catalog_id = match catalog::Id::parse_str(&path.catalog_id) {
+        Ok(catalog_id) => catalog_id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = AdjustInput {
+        product_id,
+        catalog_id,
+        adjustment: stock::Adjustment::Decrement(quantity),
+    };
+
+    adjust(ctx, input).await
+}
+
+async fn adjust(ctx: Context, input: AdjustInput) -> Response {
+    let pg_stocks = PgStocks::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool);
+    let mut service = StockService::new(pg_stocks, pg_products);
+
+    let adjusted_stock = match service.adjust(input).await {
+        Ok(stock) => stock,
+        Err(err) => {
+            eprintln!("Adjust stock error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(StockView::new(&adjusted_stock)).into_response()
+}
+
+pub fn create_error_response(err: stock::Error) -> impl IntoResponse {
+    use stock::Error;
+
+    match err {
+        Error::Conflict(kind) => (
+            StatusCode::CONFLICT,
+            Json(ApiError::new("Conflict", kind.to_string())),
+        ),
+        Error::Internal(_) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new("Internal", "Internal server error")),
+        ),
+        Error::NotFound(kind) => (
+            StatusCode::NOT_FOUND,
+            Json(ApiError::new("NotFound", kind.to_string())),
+        ),
+    }
+}
+
+fn create_validation_error_response(err: &dyn std::error::Error) -> impl IntoResponse {
+    let body = ApiError::new("Validation", err.to_string());
+    (StatusCode::BAD_REQUEST, Json(body))
+}
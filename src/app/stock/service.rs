@@ -0,0 +1,56 @@
+mod dto;
+
+pub use dto::{AdjustInput, AllInput, FindInput};
+
+use domain::catalog;
+use domain::product;
+use domain::stock;
+
+#[derive(Clone, Debug)]
+pub struct StockService<T, U> {
+    stocks: T,
+    products: U,
+}
+
+impl<T: stock::Repository, U: product::Repository> StockService<T, U> {
+    pub fn new(stocks: T, products: U) -> Self {
+        Self { stocks, products }
+    }
+}
+
+impl<T: stock::Repository, U: product::Repository> StockService<T, U> {
+    pub async fn adjust(&mut self, input: AdjustInput) -> Result<stock::Stock, stock::Error> {
+        self.ensure_product(input.product_id, input.catalog_id)
+            .await?;
+
+        self.stocks.adjust(input.product_id, input.adjustment).await
+    }
+
+    pub async fn all(&self, input: AllInput) -> Result<stock::Stocks, stock::Error> {
+        self.stocks.all(&input.products_ids).await
+    }
+
+    pub async fn find(&self, input: FindInput) -> Result<stock::Stock, stock::Error> {
+        self.ensure_product(input.product_id, input.catalog_id)
+            .await?;
+
+        self.stocks.find(input.product_id).await
+    }
+
+    async fn ensure_product(
+        &self,
+        product_id: product::Id,
+        catalog_id: catalog::Id,
+    ) -> Result<(), stock::Error> {
+        self.products
+            .find(product_id, catalog_id)
+            .await
+            .map(|_| ())
+            .map_err(|err| match err {
+                product::Error::NotFound(kind) => {
+                    stock::Error::NotFound(stock::NotFoundKind::Product(kind))
+                }
+                err => stock::Error::any(err),
+            })
+    }
+}
@@ -0,0 +1,21 @@
+use domain::catalog;
+use domain::product;
+use domain::stock;
+
+#[derive(Clone, Debug)]
+pub struct AdjustInput {
+    pub product_id: product::Id,
+    pub catalog_id: catalog::Id,
+    pub adjustment: stock::Adjustment,
+}
+
+#[derive(Clone, Debug)]
+pub struct AllInput {
+    pub products_ids: Vec<product::Id>,
+}
+
+#[derive(Clone, Debug)]
+pub struct FindInput {
+    pub product_id: product::Id,
+    pub catalog_id: catalog::Id,
+}
@@ -0,0 +1,32 @@
+use serde::Serialize;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use domain::stock;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct StockView {
+    pub product_id: Uuid,
+    pub level: u32,
+    pub held: u32,
+    pub available: u32,
+    pub out_of_stock: bool,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime,
+}
+
+impl StockView {
+    pub fn new(stock: &stock::Stock) -> Self {
+        Self {
+            product_id: stock.product_id().uuid(),
+            level: stock.level().value(),
+            held: stock.held(),
+            available: stock.available(),
+            out_of_stock: stock.is_out_of_stock(),
+            created_at: stock.metadata.created_at(),
+            updated_at: stock.metadata.updated_at(),
+        }
+    }
+}
@@ -0,0 +1,206 @@
+use axum::extract::{Json, Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::Deserialize;
+
+use domain::tax;
+
+use super::service::{parse_target, CreateInput, DeleteInput, FindInput, TaxService, UpdateInput};
+use super::view::TaxRuleView;
+use crate::app::ApiError;
+use crate::infra::PgTaxRules;
+use crate::Context;
+
+pub async fn all(State(ctx): State<Context>) -> Response {
+    let service = TaxService::new(PgTaxRules::new(ctx.pool));
+    let rules = match service.all().await {
+        Ok(rules) => rules,
+        Err(err) => {
+            eprintln!("All tax rules error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    let views = rules
+        .as_slice()
+        .iter()
+        .map(TaxRuleView::new)
+        .collect::<Vec<_>>();
+    Json(views).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CreateBody {
+    pub name: String,
+    pub kind: Option<String>,
+    pub catalog_id: Option<String>,
+    pub rate: String,
+    pub mode: String,
+}
+
+pub async fn create(State(ctx): State<Context>, Json(body): Json<CreateBody>) -> Response {
+    let name = match tax::Name::new(body.name) {
+        Ok(name) => name,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let target = match parse_target(body.kind.as_deref(), body.catalog_id.as_deref()) {
+        Ok(target) => target,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let rate = match tax::Rate::parse_str(&body.rate) {
+        Ok(rate) => rate,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let mode = match tax::Mode::parse_str(&body.mode) {
+        Ok(mode) => mode,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = CreateInput {
+        name,
+        target,
+        rate,
+        mode,
+    };
+
+    let mut service = TaxService::new(PgTaxRules::new(ctx.pool));
+    let created_rule = match service.create(input).await {
+        Ok(rule) => rule,
+        Err(err) => {
+            eprintln!("Create tax rule error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(TaxRuleView::new(&created_rule)).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct DeletePath {
+    pub id: String,
+}
+
+pub async fn delete(State(ctx): State<Context>, Path(path): Path<DeletePath>) -> Response {
+    let id = match tax::Id::parse_str(&path.id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = DeleteInput { id };
+
+    let mut service = TaxService::new(PgTaxRules::new(ctx.pool));
+    let deleted_rule = match service.delete(input).await {
+        Ok(rule) => rule,
+        Err(err) => {
+            eprintln!("Delete tax rule error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(TaxRuleView::new(&deleted_rule)).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct FindPath {
+    pub id: String,
+}
+
+pub async fn find(State(ctx): State<Context>, Path(path): Path<FindPath>) -> Response {
+    let id = match tax::Id::parse_str(&path.id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = FindInput { id };
+
+    let service = TaxService::new(PgTaxRules::new(ctx.pool));
+    let found_rule = match service.find(input).await {
+        Ok(rule) => rule,
+        Err(err) => {
+            eprintln!("Find tax rule error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(TaxRuleView::new(&found_rule)).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UpdatePath {
+    pub id: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UpdateBody {
+    pub name: String,
+    pub kind: Option<String>,
+    pub catalog_id: Option<String>,
+    pub rate: String,
+    pub mode: String,
+}
+
+pub async fn update(
+    State(ctx): State<Context>,
+    Path(path): Path<UpdatePath>,
+    Json(body): Json<UpdateBody>,
+) -> Response {
+    let id = match tax::Id::parse_str(&path.id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let name = match tax::Name::new(body.name) {
+        Ok(name) => name,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let target = match parse_target(body.kind.as_deref(), body.catalog_id.as_deref()) {
+        Ok(target) => target,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let rate = match tax::Rate::parse_str(&body.rate) {
+        Ok(rate) => rate,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let mode = match tax::Mode::parse_str(&body.mode) {
+        Ok(mode) => mode,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = UpdateInput {
+        id,
+        name,
+        target,
+        rate,
+        mode,
+    };
+
+    let mut service = TaxService::new(PgTaxRules::new(ctx.pool));
+    let updated_rule = match service.update(input).await {
+        Ok(rule) => rule,
+        Err(err) => {
+            eprintln!("Update tax rule error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(TaxRuleView::new(&updated_rule)).into_response()
+}
+
+pub fn create_error_response(err: tax::Error) -> impl IntoResponse {
+    use tax::Error;
+
+    match err {
+        Error::Conflict(kind) => (
+            StatusCode::CONFLICT,
+            Json(ApiError::new("Conflict", kind.to_string())),
+        ),
+        Error::Internal(_) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new("Internal", "Internal server error")),
+        ),
+        Error::NotFound(kind) => (
+            StatusCode::NOT_FOUND,
+            Json(ApiError::new("NotFound", kind.to_string())),
+        ),
+    }
+}
+
+fn create_validation_error_response(err: &dyn std::error::Error) -> impl IntoResponse {
+    let body = ApiError::new("Validation", err.to_string());
+    (StatusCode::BAD_REQUEST, Json(body))
+}
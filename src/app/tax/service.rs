@@ -0,0 +1,50 @@
+mod dto;
+
+pub use dto::{parse_target, CreateInput, DeleteInput, FindInput, UpdateInput};
+
+use domain::tax;
+
+#[derive(Clone, Debug)]
+pub struct TaxService<T> {
+    rules: T,
+}
+
+impl<T: tax::Repository> TaxService<T> {
+    pub fn new(rules: T) -> Self {
+        Self { rules }
+    }
+}
+
+impl<T: tax::Repository> TaxService<T> {
+    pub async fn all(&self) -> Result<tax::Rules, tax::Error> {
+        self.rules.all().await
+    }
+
+    pub async fn create(&mut self, input: CreateInput) -> Result<tax::TaxRule, tax::Error> {
+        let rule = tax::TaxRule::new(input.name, input.target, input.rate, input.mode);
+        self.rules.create(&rule).await?;
+
+        Ok(rule)
+    }
+
+    pub async fn delete(&mut self, input: DeleteInput) -> Result<tax::TaxRule, tax::Error> {
+        self.rules.delete(input.id).await
+    }
+
+    pub async fn find(&self, input: FindInput) -> Result<tax::TaxRule, tax::Error> {
+        self.rules.find(input.id).await
+    }
+
+    pub async fn update(&mut self, input: UpdateInput) -> Result<tax::TaxRule, tax::Error> {
+        let mut rule = self.rules.find(input.id).await?;
+        rule.name = input.name;
+        rule.target = input.target;
+        rule.rate = input.rate;
+        rule.mode = input.mode;
+        rule.metadata.update();
+
+        self.rules.update(&rule).await?;
+
+        Ok(rule)
+    }
+}
@@ -0,0 +1,59 @@
+use thiserror::Error;
+
+use domain::catalog;
+use domain::product;
+use domain::tax;
+
+#[derive(Clone, Debug)]
+pub struct CreateInput {
+    pub name: tax::Name,
+    pub target: tax::Target,
+    pub rate: tax::Rate,
+    pub mode: tax::Mode,
+}
+
+#[derive(Clone, Debug)]
+pub struct DeleteInput {
+    pub id: tax::Id,
+}
+
+#[derive(Clone, Debug)]
+pub struct FindInput {
+    pub id: tax::Id,
+}
+
+#[derive(Clone, Debug)]
+pub struct UpdateInput {
+    pub id: tax::Id,
+    pub name: tax::Name,
+    pub target: tax::Target,
+    pub rate: tax::Rate,
+    pub mode: tax::Mode,
+}
+
+/// Try parsing a [`tax::Target`] where exactly one of `kind` or `catalog_id`
+/// must be provided
+pub fn parse_target(
+    kind: Option<&str>,
+    catalog_id: Option<&str>,
+) -> Result<tax::Target, ParseTargetError> {
+    match (kind, catalog_id) {
+        (Some(kind), None) => product::Kind::parse_str(kind)
+            .map(tax::Target::Kind)
+            .map_err(ParseTargetError::Kind),
+        (None, Some(catalog_id)) => catalog::Id::parse_str(catalog_id)
+            .map(tax::Target::Catalog)
+            .map_err(ParseTargetError::CatalogId),
+        _ => Err(ParseTargetError::Ambiguous),
+    }
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum ParseTargetError {
+    #[error("Tax rule must target either a product kind or a catalog")]
+    Ambiguous,
+    #[error(transparent)]
+    CatalogId(catalog::ParseIdError),
+    #[error(transparent)]
+    Kind(product::ParseKindError),
+}
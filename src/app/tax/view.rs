@@ -0,0 +1,58 @@
+use serde::Serialize;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use domain::tax;
+
+use crate::app::MoneyView;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct TaxRuleView<'a> {
+    pub id: Uuid,
+    pub name: &'a str,
+    pub kind: Option<&'a str>,
+    pub catalog_id: Option<Uuid>,
+    pub rate: String,
+    pub mode: &'static str,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime,
+}
+
+impl<'a> TaxRuleView<'a> {
+    pub fn new(rule: &'a tax::TaxRule) -> Self {
+        let (kind, catalog_id) = match &rule.target {
+            tax::Target::Catalog(catalog_id) => (None, Some(catalog_id.uuid())),
+            tax::Target::Kind(kind) => (Some(kind.as_str()), None),
+        };
+
+        Self {
+            id: rule.id().uuid(),
+            name: rule.name.as_str(),
+            kind,
+            catalog_id,
+            rate: rule.rate.to_string(),
+            mode: rule.mode.as_str(),
+            created_at: rule.metadata.created_at(),
+            updated_at: rule.metadata.updated_at(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct BreakdownView {
+    pub net: MoneyView,
+    pub tax: MoneyView,
+    pub gross: MoneyView,
+}
+
+impl BreakdownView {
+    pub fn new(breakdown: tax::Breakdown) -> Self {
+        Self {
+            net: MoneyView::new(breakdown.net),
+            tax: MoneyView::new(breakdown.tax),
+            gross: MoneyView::new(breakdown.gross),
+        }
+    }
+}
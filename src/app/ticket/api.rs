@@ -0,0 +1,260 @@
+use std::num::{NonZeroU32, NonZeroU8};
+
+use axum::extract::{Json, Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::Deserialize;
+
+use domain::catalog;
+use domain::product;
+use domain::ticket;
+
+use super::service::{
+    CreateInput, DeleteInput, FindInput, ListInput, TicketService, TransitionInput, UpdateInput,
+};
+use super::view::{PaginationView, TicketView};
+use crate::app::product::service::ExtrasIds;
+use crate::app::ApiError;
+use crate::infra::{PgProducts, PgTickets};
+use crate::Context;
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CreateBody {
+    pub catalog_id: String,
+    pub product_id: String,
+    pub extras_ids: Vec<String>,
+}
+
+pub async fn create(State(ctx): State<Context>, Json(body): Json<CreateBody>) -> Response {
+    let catalog_id = match catalog::Id::parse_str(&body.catalog_id) {
+        Ok(catalog_id) => catalog_id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let product_id = match product::Id::parse_str(&body.product_id) {
+        Ok(product_id) => product_id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let extras_ids = match ExtrasIds::parse(&body.extras_ids) {
+        Ok(extras_ids) => extras_ids,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = CreateInput {
+        catalog_id,
+        product_id,
+        extras_ids,
+    };
+
+    let pg_tickets = PgTickets::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool);
+    let mut service = TicketService::new(pg_tickets, pg_products);
+
+    let created_ticket = match service.create(input).await {
+        Ok(ticket) => ticket,
+        Err(err) => {
+            eprintln!("Create ticket error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(TicketView::new(&created_ticket)).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct DeletePath {
+    pub id: String,
+}
+
+pub async fn delete(State(ctx): State<Context>, Path(path): Path<DeletePath>) -> Response {
+    let id = match ticket::Id::parse_str(&path.id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = DeleteInput { id };
+
+    let pg_tickets = PgTickets::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool);
+    let mut service = TicketService::new(pg_tickets, pg_products);
+
+    let deleted_ticket = match service.delete(input).await {
+        Ok(ticket) => ticket,
+        Err(err) => {
+            eprintln!("Delete ticket error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(TicketView::new(&deleted_ticket)).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct FindPath {
+    pub id: String,
+}
+
+pub async fn find(State(ctx): State<Context>, Path(path): Path<FindPath>) -> Response {
+    let id = match ticket::Id::parse_str(&path.id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = FindInput { id };
+
+    let pg_tickets = PgTickets::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool);
+    let service = TicketService::new(pg_tickets, pg_products);
+
+    let found_ticket = match service.find(input).await {
+        Ok(ticket) => ticket,
+        Err(err) => {
+            eprintln!("Find ticket error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(TicketView::new(&found_ticket)).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListQuery {
+    pub page: Option<u32>,
+    pub limit: Option<u8>,
+    pub status: Option<String>,
+}
+
+pub async fn list(State(ctx): State<Context>, Query(query): Query<ListQuery>) -> Response {
+    let page = match query.page {
+        Some(0) | None => NonZeroU32::new(1).unwrap(),
+        Some(page) => NonZeroU32::new(page).expect("Page is not zero"),
+    };
+    let limit = match query.limit {
+        Some(0) | None => NonZeroU8::new(10).unwrap(),
+        Some(limit) => NonZeroU8::new(limit).expect("Limit is not zero"),
+    };
+    let status = match query.status.as_deref().map(ticket::Status::parse_str) {
+        Some(Ok(status)) => Some(status),
+        Some(Err(err)) => return create_validation_error_response(&err).into_response(),
+        None => None,
+    };
+    let input = ListInput {
+        page,
+        limit,
+        status,
+    };
+
+    let pg_tickets = PgTickets::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool);
+    let service = TicketService::new(pg_tickets, pg_products);
+
+    let pagination = match service.list(input).await {
+        Ok(pagination) => pagination,
+        Err(err) => {
+            eprintln!("List tickets error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(PaginationView::new(&pagination)).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UpdatePath {
+    pub id: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UpdateBody {
+    pub extras_ids: Vec<String>,
+}
+
+pub async fn update(
+    State(ctx): State<Context>,
+    Path(path): Path<UpdatePath>,
+    Json(body): Json<UpdateBody>,
+) -> Response {
+    let id = match ticket::Id::parse_str(&path.id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let extras_ids = match ExtrasIds::parse(&body.extras_ids) {
+        Ok(extras_ids) => extras_ids,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = UpdateInput { id, extras_ids };
+
+    let pg_tickets = PgTickets::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool);
+    let mut service = TicketService::new(pg_tickets, pg_products);
+
+    let updated_ticket = match service.update(input).await {
+        Ok(ticket) => ticket,
+        Err(err) => {
+            eprintln!("Update ticket error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(TicketView::new(&updated_ticket)).into_response()
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct TransitionPath {
+    pub id: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct TransitionBody {
+    pub status: String,
+}
+
+pub async fn transition(
+    State(ctx): State<Context>,
+    Path(path): Path<TransitionPath>,
+    Json(body): Json<TransitionBody>,
+) -> Response {
+    let id = match ticket::Id::parse_str(&path.id) {
+        Ok(id) => id,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let status = match ticket::Status::parse_str(&body.status) {
+        Ok(status) => status,
+        Err(err) => return create_validation_error_response(&err).into_response(),
+    };
+    let input = TransitionInput { id, status };
+
+    let pg_tickets = PgTickets::new(ctx.pool.clone());
+    let pg_products = PgProducts::new(ctx.pool);
+    let mut service = TicketService::new(pg_tickets, pg_products);
+
+    let updated_ticket = match service.transition(input).await {
+        Ok(ticket) => ticket,
+        Err(err) => {
+            eprintln!("Transition ticket error: {err:?}");
+            return create_error_response(err).into_response();
+        }
+    };
+
+    Json(TicketView::new(&updated_ticket)).into_response()
+}
+
+fn create_error_response(err: ticket::Error) -> impl IntoResponse {
+    use ticket::Error;
+
+    match err {
+        Error::Conflict(kind) => (
+            StatusCode::CONFLICT,
+            Json(ApiError::new("Conflict", kind.to_string())),
+        ),
+        Error::Internal(_) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new("Internal", "Internal server error")),
+        ),
+        Error::NotFound(kind) => (
+            StatusCode::NOT_FOUND,
+            Json(ApiError::new("NotFound", kind.to_string())),
+        ),
+    }
+}
+
+fn create_validation_error_response(err: &dyn std::error::Error) -> impl IntoResponse {
+    let body = ApiError::new("Validation", err.to_string());
+    (StatusCode::BAD_REQUEST, Json(body))
+}
@@ -0,0 +1,78 @@
+mod dto;
+
+pub use dto::{CreateInput, DeleteInput, FindInput, ListInput, TransitionInput, UpdateInput};
+
+use domain::product;
+use domain::ticket;
+
+#[derive(Clone, Debug)]
+pub struct TicketService<T, U> {
+    tickets: T,
+    products: U,
+}
+
+impl<T: ticket::Repository, U: product::Repository> TicketService<T, U> {
+    pub fn new(tickets: T, products: U) -> Self {
+        Self { tickets, products }
+    }
+}
+
+impl<T: ticket::Repository, U: product::Repository> TicketService<T, U> {
+    pub async fn create(&mut self, input: CreateInput) -> Result<ticket::Ticket, ticket::Error> {
+        let product = self
+            .products
+            .find(input.product_id, input.catalog_id)
+            .await
+            .map_err(|err| match err {
+                product::Error::NotFound(kind) => {
+                    ticket::Error::NotFound(ticket::NotFoundKind::Product(kind))
+                }
+                err => ticket::Error::any(err),
+            })?;
+
+        let ticket = ticket::Ticket::new(product, input.extras_ids.as_slice())?;
+        self.tickets.create(&ticket).await?;
+
+        Ok(ticket)
+    }
+
+    pub async fn delete(&mut self, input: DeleteInput) -> Result<ticket::Ticket, ticket::Error> {
+        self.tickets.delete(input.id).await
+    }
+
+    pub async fn find(&self, input: FindInput) -> Result<ticket::Ticket, ticket::Error> {
+        self.tickets.find(input.id).await
+    }
+
+    pub async fn list(&self, input: ListInput) -> Result<ticket::Pagination, ticket::Error> {
+        let query = ticket::ListQuery {
+            page: input.page,
+            limit: input.limit,
+            status: input.status,
+        };
+
+        self.tickets.list(query).await
+    }
+
+    pub async fn update(&mut self, input: UpdateInput) -> Result<ticket::Ticket, ticket::Error> {
+        let mut ticket = self.tickets.find(input.id).await?;
+        let status = ticket.status();
+        ticket.set_extras(input.extras_ids.as_slice())?;
+        ticket.metadata.update();
+        self.tickets.update(&ticket, status).await?;
+
+        Ok(ticket)
+    }
+
+    pub async fn transition(
+        &mut self,
+        input: TransitionInput,
+    ) -> Result<ticket::Ticket, ticket::Error> {
+        let mut ticket = self.tickets.find(input.id).await?;
+        let status = ticket.status();
+        ticket.transition(input.status)?;
+        self.tickets.update(&ticket, status).await?;
+
+        Ok(ticket)
+    }
+}
@@ -0,0 +1,43 @@
+use std::num::{NonZeroU32, NonZeroU8};
+
+use domain::catalog;
+use domain::product;
+use domain::ticket;
+
+use crate::app::product::service::ExtrasIds;
+
+#[derive(Clone, Debug)]
+pub struct CreateInput {
+    pub catalog_id: catalog::Id,
+    pub product_id: product::Id,
+    pub extras_ids: ExtrasIds,
+}
+
+#[derive(Clone, Debug)]
+pub struct DeleteInput {
+    pub id: ticket::Id,
+}
+
+#[derive(Clone, Debug)]
+pub struct FindInput {
+    pub id: ticket::Id,
+}
+
+#[derive(Clone, Debug)]
+pub struct ListInput {
+    pub page: NonZeroU32,
+    pub limit: NonZeroU8,
+    pub status: Option<ticket::Status>,
+}
+
+#[derive(Clone, Debug)]
+pub struct UpdateInput {
+    pub id: ticket::Id,
+    pub extras_ids: ExtrasIds,
+}
+
+#[derive(Clone, Debug)]
+pub struct TransitionInput {
+    pub id: ticket::Id,
+    pub status: ticket::Status,
+}
@@ -0,0 +1,72 @@
+use serde::Serialize;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use domain::ticket;
+
+use crate::app::product::view::ExtraView;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct TicketView<'a> {
+    pub id: Uuid,
+    pub product_id: Uuid,
+    pub catalog_id: Uuid,
+    pub name: &'a str,
+    pub extras: Vec<ExtraView<'a>>,
+    pub status: &'a str,
+    #[serde(with = "time::serde::rfc3339")]
+    pub received_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub preparing_at: Option<OffsetDateTime>,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub ready_at: Option<OffsetDateTime>,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub handed_over_at: Option<OffsetDateTime>,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub cancelled_at: Option<OffsetDateTime>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime,
+}
+
+impl<'a> TicketView<'a> {
+    pub fn new(ticket: &'a ticket::Ticket) -> Self {
+        let product = ticket.product();
+        let timeline = ticket.timeline();
+        Self {
+            id: ticket.id().uuid(),
+            product_id: product.id().uuid(),
+            catalog_id: product.catalog_id().uuid(),
+            name: product.name.as_str(),
+            extras: ticket.extras().map(ExtraView::new).collect(),
+            status: ticket.status().as_str(),
+            received_at: timeline.received_at,
+            preparing_at: timeline.preparing_at,
+            ready_at: timeline.ready_at,
+            handed_over_at: timeline.handed_over_at,
+            cancelled_at: timeline.cancelled_at,
+            created_at: ticket.metadata.created_at(),
+            updated_at: ticket.metadata.updated_at(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct PaginationView<'a> {
+    pub count: u64,
+    pub page: u32,
+    pub limit: u8,
+    pub items: Vec<TicketView<'a>>,
+}
+
+impl<'a> PaginationView<'a> {
+    pub fn new(pagination: &'a ticket::Pagination) -> Self {
+        Self {
+            count: pagination.count,
+            page: pagination.page.into(),
+            limit: pagination.limit.into(),
+            items: pagination.items.iter().map(TicketView::new).collect(),
+        }
+    }
+}
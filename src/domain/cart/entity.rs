@@ -0,0 +1,456 @@
+use super::{ConflictKind, Error, Id, ItemId};
+use crate::core::metadata;
+use crate::core::money::{Currency, Money};
+use crate::core::quantity::Quantity;
+use crate::extra;
+use crate::product;
+
+/// Shopping cart whose items always reference the live [`product::Product`],
+/// so totals are computed from current prices instead of being stored
+#[derive(Clone, Debug)]
+pub struct Cart {
+    pub(super) id: Id,
+    currency: Currency,
+    items: Vec<Item>,
+    pub metadata: metadata::Metadata,
+}
+
+impl Cart {
+    pub const MAX_ITEMS: usize = 32;
+
+    #[must_use]
+    pub fn new(currency: Currency) -> Self {
+        Self {
+            id: Id::new(),
+            currency,
+            items: Vec::new(),
+            metadata: metadata::Metadata::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn config(config: CartConfig) -> Self {
+        Self {
+            id: config.id,
+            currency: config.currency,
+            items: config.items,
+            metadata: config.metadata,
+        }
+    }
+}
+
+impl Cart {
+    #[must_use]
+    pub fn id(&self) -> Id {
+        self.id
+    }
+
+    /// Currency every product added to cart must be priced in
+    #[must_use]
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    #[must_use]
+    pub fn items(&self) -> &[Item] {
+        &self.items
+    }
+
+    /// Add `product` with the selected `extras_ids` to cart
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if cart is already full, `product` is priced in
+    /// another currency or any of `extras_ids` is not bound to `product`
+    pub fn add_item(
+        &mut self,
+        product: product::Product,
+        extras_ids: &[extra::Id],
+        quantity: Quantity,
+    ) -> Result<&Item, Error> {
+        if self.items.len() >= Self::MAX_ITEMS {
+            return Err(Error::Conflict(ConflictKind::ItemsLength));
+        }
+
+        let selected_extras_ids = self.select_extras(&product, extras_ids)?;
+        self.items.push(Item {
+            id: ItemId::new(),
+            product,
+            extras_ids: selected_extras_ids,
+            quantity,
+        });
+
+        Ok(&self.items[self.items.len() - 1])
+    }
+
+    /// Change quantity of item with `item_id`
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if there is no item with `item_id` in cart
+    pub fn set_item_quantity(
+        &mut self,
+        item_id: ItemId,
+        quantity: Quantity,
+    ) -> Result<&Item, Error> {
+        let cart_id = self.id;
+        let item = self
+            .items
+            .iter_mut()
+            .find(|item| item.id == item_id)
+            .ok_or_else(|| Error::item_not_found(item_id, cart_id))?;
+
+        item.quantity = quantity;
+        Ok(item)
+    }
+
+    /// Replace the product of item with `item_id` by its current data, which
+    /// must still be priced in cart currency and offer the selected extras
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if there is no item with `item_id` in cart,
+    /// `product` is priced in another currency or any of the selected extras
+    /// is no longer bound to `product`
+    pub fn refresh_item(
+        &mut self,
+        item_id: ItemId,
+        product: product::Product,
+    ) -> Result<&Item, Error> {
+        let position = self
+            .items
+            .iter()
+            .position(|item| item.id == item_id)
+            .ok_or_else(|| Error::item_not_found(item_id, self.id))?;
+
+        let extras_ids = self.select_extras(&product, &self.items[position].extras_ids)?;
+        let item = &mut self.items[position];
+        item.product = product;
+        item.extras_ids = extras_ids;
+        Ok(item)
+    }
+
+    /// Remove item with `item_id` from cart
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if there is no item with `item_id` in cart
+    pub fn remove_item(&mut self, item_id: ItemId) -> Result<Item, Error> {
+        let position = self
+            .items
+            .iter()
+            .position(|item| item.id == item_id)
+            .ok_or_else(|| Error::item_not_found(item_id, self.id))?;
+
+        Ok(self.items.remove(position))
+    }
+
+    /// Deduplicated `extras_ids`, as long as `product` can be added to cart
+    /// with them, each extra being a single unit of it checked against the
+    /// modifier groups of `product`
+    fn select_extras(
+        &self,
+        product: &product::Product,
+        extras_ids: &[extra::Id],
+    ) -> Result<Vec<extra::Id>, Error> {
+        if product.price.currency() != self.currency {
+            return Err(Error::Conflict(ConflictKind::Currency {
+                expected: self.currency,
+                found: product.price.currency(),
+            }));
+        }
+
+        let mut selected_extras_ids: Vec<extra::Id> = Vec::with_capacity(extras_ids.len());
+        for extra_id in extras_ids {
+            if !product.extras.iter().any(|extra| extra.id() == *extra_id) {
+                return Err(Error::extra_not_found(*extra_id, product.id()));
+            }
+            if !selected_extras_ids.contains(extra_id) {
+                selected_extras_ids.push(*extra_id);
+            }
+        }
+
+        let selections = selected_extras_ids
+            .iter()
+            .map(|id| product::ExtraSelection::single(*id))
+            .collect::<Vec<_>>();
+        product
+            .modifiers
+            .validate(&selections)
+            .map_err(Error::selection)?;
+
+        Ok(selected_extras_ids)
+    }
+
+    /// Sum of every item total with current prices
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if any item is now priced in another currency or if
+    /// the sum overflows
+    pub fn total(&self) -> Result<Money, Error> {
+        let totals = self
+            .items
+            .iter()
+            .map(Item::total)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Money::checked_sum(self.currency, totals).map_err(Error::money)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct CartConfig {
+    pub id: Id,
+    pub currency: Currency,
+    pub items: Vec<Item>,
+    pub metadata: metadata::Metadata,
+}
+
+#[derive(Clone, Debug)]
+pub struct Item {
+    id: ItemId,
+    product: product::Product,
+    extras_ids: Vec<extra::Id>,
+    quantity: Quantity,
+}
+
+impl Item {
+    #[must_use]
+    pub fn config(config: ItemConfig) -> Self {
+        Self {
+            id: config.id,
+            product: config.product,
+            extras_ids: config.extras_ids,
+            quantity: config.quantity,
+        }
+    }
+}
+
+impl Item {
+    #[must_use]
+    pub fn id(&self) -> ItemId {
+        self.id
+    }
+
+    #[must_use]
+    pub fn product(&self) -> &product::Product {
+        &self.product
+    }
+
+    #[must_use]
+    pub fn extras_ids(&self) -> &[extra::Id] {
+        &self.extras_ids
+    }
+
+    #[must_use]
+    pub fn quantity(&self) -> Quantity {
+        self.quantity
+    }
+
+    /// Selected extras with their current data
+    pub fn extras(&self) -> impl Iterator<Item = &extra::Extra> {
+        self.product
+            .extras
+            .iter()
+            .filter(|extra| self.extras_ids.contains(&extra.id()))
+    }
+
+    /// Current price of a single unit of this item, including its extras
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if extras are priced in another currency or if the
+    /// sum overflows
+    pub fn unit_price(&self) -> Result<Money, Error> {
+        let price = self.product.price;
+        let extras_prices = self.extras().map(|extra| extra.price);
+        Money::checked_sum(price.currency(), extras_prices)
+            .and_then(|extras_price| price.checked_add(extras_price))
+            .map_err(Error::money)
+    }
+
+    /// Current price of this item considering its quantity
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if [`Item::unit_price`] fails or if the
+    /// multiplication overflows
+    pub fn total(&self) -> Result<Money, Error> {
+        self.unit_price()?
+            .checked_mul(self.quantity.value())
+            .map_err(Error::money)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ItemConfig {
+    pub id: ItemId,
+    pub product: product::Product,
+    pub extras_ids: Vec<extra::Id>,
+    pub quantity: Quantity,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog;
+
+    #[test]
+    fn add_item_works() {
+        let cheddar = mocked_extra("Cheddar", 200);
+        let product = mocked_product(vec![cheddar.clone(), mocked_extra("Bacon", 350)]);
+        let quantity = Quantity::new(2).expect("Valid quantity");
+
+        let mut cart = Cart::new(Currency::BRL);
+        let item = cart
+            .add_item(product, &[cheddar.id(), cheddar.id()], quantity)
+            .expect("Valid cart item");
+
+        assert_eq!(item.extras_ids(), &[cheddar.id()]);
+        assert_eq!(item.unit_price().ok(), Some(brl(2200)));
+        assert_eq!(cart.total().ok(), Some(brl(4400)));
+    }
+
+    #[test]
+    fn add_item_with_extra_not_found() {
+        use crate::cart::NotFoundKind;
+
+        let product = mocked_product(vec![mocked_extra("Cheddar", 200)]);
+        let product_id = product.id();
+        let extra_id = extra::Id::new();
+        let quantity = Quantity::new(1).expect("Valid quantity");
+
+        let mut cart = Cart::new(Currency::BRL);
+        let result = cart.add_item(product, &[extra_id], quantity);
+        assert!(matches!(result, Err(Error::NotFound(NotFoundKind::Extra {
+            extra_id: err_extra_id,
+            product_id: err_product_id
+        })) if err_extra_id == extra_id && err_product_id == product_id));
+    }
+
+    #[test]
+    fn add_item_with_selection_conflict() {
+        use crate::cart::ConflictKind;
+        use crate::modifier;
+
+        let ketchup = mocked_extra("Ketchup", 0);
+        let mustard = mocked_extra("Mustard", 0);
+        let mut product = mocked_product(vec![ketchup.clone(), mustard.clone()]);
+        let sauces = modifier::Group::new(
+            product.id(),
+            modifier::Name::new("Sauces").expect("Valid modifier group name"),
+            modifier::Limits::new(0, 1).expect("Valid limits"),
+            modifier::Options::new(vec![ketchup.id(), mustard.id()])
+                .expect("Valid modifier group options"),
+        );
+        product.modifiers = modifier::Groups::new(vec![sauces]).expect("Valid modifier groups");
+        let one = Quantity::new(1).expect("Valid quantity");
+
+        let mut cart = Cart::new(Currency::BRL);
+        let result = cart.add_item(product, &[ketchup.id(), mustard.id()], one);
+        assert!(matches!(
+            result,
+            Err(Error::Conflict(ConflictKind::Selection(
+                modifier::SelectionError::Exceeded { max: 1, .. }
+            )))
+        ));
+    }
+
+    #[test]
+    fn set_item_quantity_works() {
+        let mut cart = Cart::new(Currency::BRL);
+        let one = Quantity::new(1).expect("Valid quantity");
+        let item_id = cart
+            .add_item(mocked_product(vec![]), &[], one)
+            .map(Item::id)
+            .expect("Valid cart item");
+
+        let three = Quantity::new(3).expect("Valid quantity");
+        let result = cart.set_item_quantity(item_id, three).map(Item::quantity);
+        assert_eq!(result.ok(), Some(three));
+        assert_eq!(cart.total().ok(), Some(brl(6000)));
+
+        let result = cart.set_item_quantity(ItemId::new(), three);
+        assert!(matches!(result, Err(Error::NotFound(_))));
+    }
+
+    #[test]
+    fn remove_item_works() {
+        let mut cart = Cart::new(Currency::BRL);
+        let one = Quantity::new(1).expect("Valid quantity");
+        let item_id = cart
+            .add_item(mocked_product(vec![]), &[], one)
+            .map(Item::id)
+            .expect("Valid cart item");
+
+        assert_eq!(
+            cart.remove_item(item_id).map(|i| i.id()).ok(),
+            Some(item_id)
+        );
+        assert!(cart.items().is_empty());
+        assert!(matches!(cart.remove_item(item_id), Err(Error::NotFound(_))));
+    }
+
+    #[test]
+    fn refresh_item_works() {
+        let cheddar = mocked_extra("Cheddar", 200);
+        let product = mocked_product(vec![cheddar.clone()]);
+        let one = Quantity::new(1).expect("Valid quantity");
+
+        let mut cart = Cart::new(Currency::BRL);
+        let item_id = cart
+            .add_item(product.clone(), &[cheddar.id()], one)
+            .map(Item::id)
+            .expect("Valid cart item");
+
+        let mut repriced = product.clone();
+        repriced.price = brl(2500);
+        let result = cart.refresh_item(item_id, repriced).and_then(Item::total);
+        assert_eq!(result.ok(), Some(brl(2700)));
+
+        let mut unbound = product;
+        unbound.extras = product::Extras::new(vec![]).expect("Valid product extras");
+        let result = cart.refresh_item(item_id, unbound);
+        assert!(matches!(result, Err(Error::NotFound(_))));
+        assert_eq!(cart.total().ok(), Some(brl(2700)));
+    }
+
+    #[test]
+    fn add_item_with_currency_conflict() {
+        let mut cart = Cart::new(Currency::USD);
+        let one = Quantity::new(1).expect("Valid quantity");
+
+        let result = cart.add_item(mocked_product(vec![]), &[], one);
+        assert!(matches!(
+            result,
+            Err(Error::Conflict(ConflictKind::Currency {
+                expected: Currency::USD,
+                found: Currency::BRL
+            }))
+        ));
+        assert!(cart.items().is_empty());
+        assert_eq!(cart.total().ok(), Some(Money::zero(Currency::USD)));
+    }
+
+    fn brl(cents: i64) -> Money {
+        Money::from_minor(cents, Currency::BRL)
+    }
+
+    fn mocked_extra(name: &str, cents: i64) -> extra::Extra {
+        extra::Extra::new(
+            extra::Name::new(name).expect("Valid extra name"),
+            brl(cents),
+        )
+    }
+
+    fn mocked_product(extras: Vec<extra::Extra>) -> product::Product {
+        product::Product::new(
+            catalog::Id::new(),
+            product::Name::new("Cheese Burger").expect("Valid product name"),
+            brl(2000),
+            product::Kind::Burger,
+            product::Extras::new(extras).expect("Valid product extras"),
+        )
+    }
+}
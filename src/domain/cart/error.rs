@@ -0,0 +1,95 @@
+use thiserror::Error;
+
+use super::{Cart, Id, ItemId};
+use crate::core::money::{Currency, MoneyError};
+use crate::extra;
+use crate::modifier;
+use crate::product;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error(transparent)]
+    Conflict(ConflictKind),
+    #[error(transparent)]
+    Internal(Box<dyn std::error::Error>),
+    #[error(transparent)]
+    NotFound(NotFoundKind),
+}
+
+impl Error {
+    /// Utility function to create [`Error::Internal`] without manually
+    /// boxing the error
+    #[must_use]
+    pub fn any(err: impl Into<Box<dyn std::error::Error>>) -> Self {
+        Self::Internal(err.into())
+    }
+
+    #[must_use]
+    pub fn extra_not_found(extra_id: extra::Id, product_id: product::Id) -> Self {
+        Self::NotFound(NotFoundKind::Extra {
+            extra_id,
+            product_id,
+        })
+    }
+
+    #[must_use]
+    pub fn id_conflict(id: Id) -> Self {
+        Self::Conflict(ConflictKind::Id(id))
+    }
+
+    #[must_use]
+    pub fn id_not_found(id: Id) -> Self {
+        Self::NotFound(NotFoundKind::Id(id))
+    }
+
+    #[must_use]
+    pub fn item_not_found(item_id: ItemId, cart_id: Id) -> Self {
+        Self::NotFound(NotFoundKind::Item { item_id, cart_id })
+    }
+
+    /// Maps a failed [`Money`](crate::core::money::Money) operation, where
+    /// mixing currencies is a conflict and overflowing is unexpected
+    #[must_use]
+    pub fn money(err: MoneyError) -> Self {
+        match err {
+            MoneyError::CurrencyMismatch { expected, found } => {
+                Self::Conflict(ConflictKind::Currency { expected, found })
+            }
+            err @ MoneyError::Overflow => Self::any(err),
+        }
+    }
+
+    /// Maps a selection of extras breaking the rules of a modifier group to
+    /// a conflict
+    #[must_use]
+    pub fn selection(err: modifier::SelectionError) -> Self {
+        Self::Conflict(ConflictKind::Selection(err))
+    }
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum ConflictKind {
+    #[error("Cart with id `{0}` already exists")]
+    Id(Id),
+    #[error("Cart cannot have more than {len} items", len = Cart::MAX_ITEMS)]
+    ItemsLength,
+    #[error("Cart priced in `{expected}` cannot be combined with prices in `{found}`")]
+    Currency { expected: Currency, found: Currency },
+    #[error(transparent)]
+    Selection(modifier::SelectionError),
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum NotFoundKind {
+    #[error("Cart with id `{0}` not found")]
+    Id(Id),
+    #[error("Cart item with id `{item_id}` not found for cart {cart_id}")]
+    Item { item_id: ItemId, cart_id: Id },
+    #[error("Product extra with id `{extra_id}` not available for product {product_id}")]
+    Extra {
+        extra_id: extra::Id,
+        product_id: product::Id,
+    },
+    #[error(transparent)]
+    Product(product::NotFoundKind),
+}
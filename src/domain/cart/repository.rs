@@ -0,0 +1,10 @@
+use super::{Cart, Error, Id};
+
+// TODO: remove when stabilized
+#[allow(async_fn_in_trait)]
+pub trait Repository: Send + Clone {
+    async fn create(&mut self, cart: &Cart) -> Result<(), Error>;
+    async fn delete(&mut self, id: Id) -> Result<Cart, Error>;
+    async fn find(&self, id: Id) -> Result<Cart, Error>;
+    async fn update(&mut self, cart: &Cart) -> Result<(), Error>;
+}
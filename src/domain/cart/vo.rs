@@ -0,0 +1,135 @@
+use std::fmt;
+
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Id(Uuid);
+
+impl Id {
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::now_v7())
+    }
+
+    /// Try parsing `value` into [`Id`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `value` is not a valid [`Id`]
+    pub fn parse_str(value: &str) -> Result<Self, ParseIdError> {
+        match Uuid::parse_str(value) {
+            Ok(uuid) => Ok(Self(uuid)),
+            Err(_) => Err(ParseIdError(Box::from(value))),
+        }
+    }
+}
+
+impl Id {
+    #[must_use]
+    pub fn uuid(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for Id {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for Id {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<Uuid> for Id {
+    fn from(value: Uuid) -> Self {
+        Self(value)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ItemId(Uuid);
+
+impl ItemId {
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::now_v7())
+    }
+
+    /// Try parsing `value` into [`ItemId`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `value` is not a valid [`ItemId`]
+    pub fn parse_str(value: &str) -> Result<Self, ParseItemIdError> {
+        match Uuid::parse_str(value) {
+            Ok(uuid) => Ok(Self(uuid)),
+            Err(_) => Err(ParseItemIdError(Box::from(value))),
+        }
+    }
+}
+
+impl ItemId {
+    #[must_use]
+    pub fn uuid(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for ItemId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for ItemId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<Uuid> for ItemId {
+    fn from(value: Uuid) -> Self {
+        Self(value)
+    }
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[error("Provided string `{0}` is not a valid cart id")]
+pub struct ParseIdError(pub Box<str>);
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[error("Provided string `{0}` is not a valid cart item id")]
+pub struct ParseItemIdError(pub Box<str>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_id_works() {
+        let valid = Id::new().to_string();
+        assert!(Id::parse_str(&valid).is_ok());
+
+        let invalid = "invalid-id";
+        assert_eq!(
+            Id::parse_str(invalid),
+            Err(ParseIdError(Box::from(invalid)))
+        );
+    }
+
+    #[test]
+    fn parse_item_id_works() {
+        let valid = ItemId::new().to_string();
+        assert!(ItemId::parse_str(&valid).is_ok());
+
+        let invalid = "invalid-item-id";
+        assert_eq!(
+            ItemId::parse_str(invalid),
+            Err(ParseItemIdError(Box::from(invalid)))
+        );
+    }
+}
@@ -9,5 +9,6 @@ pub use error::{ConflictKind, Error, NotFoundKind};
 pub use repository::{ListQuery, Pagination, Repository};
 pub use variants::ProductCatalog;
 pub use vo::{
-    Description, DescriptionError, Id, Name, NameError, ParseIdError, Products, ProductsError,
+    Daypart, DaypartError, Description, DescriptionError, Id, Name, NameError, OpeningHours,
+    OpeningHoursError, ParseIdError, Products, ProductsError,
 };
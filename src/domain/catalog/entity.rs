@@ -1,4 +1,6 @@
-use super::{Description, Id, Name};
+use time::OffsetDateTime;
+
+use super::{Daypart, Description, Id, Name, OpeningHours};
 use crate::core::metadata;
 
 #[derive(Clone, Debug)]
@@ -6,6 +8,9 @@ pub struct Catalog {
     pub(super) id: Id,
     pub name: Name,
     pub description: Option<Description>,
+    pub hours: Option<OpeningHours>,
+    /// When catalog menu is served, being always when there is no daypart
+    pub daypart: Option<Daypart>,
     pub metadata: metadata::Metadata,
 }
 
@@ -16,6 +21,8 @@ impl Catalog {
             id: Id::new(),
             name,
             description,
+            hours: None,
+            daypart: None,
             metadata: metadata::Metadata::new(),
         }
     }
@@ -26,6 +33,8 @@ impl Catalog {
             id: config.id,
             name: config.name,
             description: config.description,
+            hours: config.hours,
+            daypart: config.daypart,
             metadata: config.metadata,
         }
     }
@@ -36,6 +45,27 @@ impl Catalog {
     pub fn id(&self) -> Id {
         self.id
     }
+
+    /// Whether catalog menu is served `at`, according to its daypart
+    #[must_use]
+    pub fn is_active_at(&self, at: OffsetDateTime) -> bool {
+        self.daypart
+            .as_ref()
+            .is_none_or(|daypart| daypart.contains(at))
+    }
+
+    /// Whether store is open `at`, being always open when it has no opening
+    /// hours
+    #[must_use]
+    pub fn is_open_at(&self, at: OffsetDateTime) -> bool {
+        self.hours.as_ref().is_none_or(|hours| hours.is_open_at(at))
+    }
+
+    /// First time store opens after `at`, if it has opening hours
+    #[must_use]
+    pub fn next_opening(&self, at: OffsetDateTime) -> Option<OffsetDateTime> {
+        self.hours.as_ref().and_then(|hours| hours.next_opening(at))
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -43,5 +73,7 @@ pub struct CatalogConfig {
     pub id: Id,
     pub name: Name,
     pub description: Option<Description>,
+    pub hours: Option<OpeningHours>,
+    pub daypart: Option<Daypart>,
     pub metadata: metadata::Metadata,
 }
@@ -1,5 +1,7 @@
 use std::num::{NonZeroU32, NonZeroU8};
 
+use time::OffsetDateTime;
+
 use super::{Catalog, ProductCatalog, Error, Id};
 
 #[allow(async_fn_in_trait)]
@@ -15,6 +17,8 @@ pub trait Repository: Send + Clone {
 pub struct ListQuery {
     pub page: NonZeroU32,
     pub limit: NonZeroU8,
+    /// Only list catalogs whose menu is served at this time, if set
+    pub active_at: Option<OffsetDateTime>,
 }
 
 #[derive(Clone, Debug)]
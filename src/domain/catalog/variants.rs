@@ -1,4 +1,5 @@
 use super::{Catalog, Products};
+use crate::exchange;
 
 #[derive(Clone, Debug)]
 pub struct ProductCatalog {
@@ -12,3 +13,64 @@ impl ProductCatalog {
         Self { catalog, products }
     }
 }
+
+impl ProductCatalog {
+    /// Convert prices of every product, its extras and variants with `rate`
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if any price is not in the base currency of `rate`
+    pub fn exchange(&mut self, rate: &exchange::ExchangeRate) -> Result<(), exchange::Error> {
+        for product in self.products.iter_mut() {
+            product.price = rate.convert(product.price)?;
+            for extra in product.extras.iter_mut() {
+                extra.price = rate.convert(extra.price)?;
+            }
+            for variant in product.variants.iter_mut() {
+                variant.price = rate.convert(variant.price)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::Name;
+    use crate::core::money::{Currency, Money};
+    use crate::extra;
+    use crate::product;
+
+    #[test]
+    fn exchange_works() {
+        let catalog = Catalog::new(Name::new("Burgers").expect("Valid catalog name"), None);
+        let extra = extra::Extra::new(
+            extra::Name::new("Cheddar").expect("Valid extra name"),
+            Money::from_minor(200, Currency::BRL),
+        );
+        let product = product::Product::new(
+            catalog.id(),
+            product::Name::new("Cheese Burger").expect("Valid product name"),
+            Money::from_minor(2000, Currency::BRL),
+            product::Kind::Burger,
+            product::Extras::new(vec![extra]).expect("Valid product extras"),
+        );
+        let products = Products::new(vec![product]).expect("Valid catalog products");
+        let mut product_catalog = ProductCatalog::new(catalog, products);
+
+        let rate = exchange::ExchangeRate::new(
+            exchange::Pair::new(Currency::BRL, Currency::USD).expect("Valid pair"),
+            exchange::Rate::parse_str("0.2").expect("Valid rate"),
+        );
+        product_catalog.exchange(&rate).expect("Valid exchange");
+
+        let product = &product_catalog.products.as_slice()[0];
+        assert_eq!(product.price, Money::from_minor(400, Currency::USD));
+        assert_eq!(
+            product.extras.as_slice()[0].price,
+            Money::from_minor(40, Currency::USD)
+        );
+    }
+}
@@ -1,8 +1,10 @@
 use std::fmt;
 
 use thiserror::Error;
+use time::{Date, OffsetDateTime, PrimitiveDateTime};
 use uuid::Uuid;
 
+use crate::core::schedule::{Window, Zone};
 use crate::core::string::trim_in_place;
 use crate::product;
 
@@ -160,12 +162,180 @@ impl Products {
         self.0.iter()
     }
 
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut product::Product> {
+        self.0.iter_mut()
+    }
+
     #[must_use]
     pub fn len(&self) -> usize {
         self.0.len()
     }
 }
 
+/// Weekly windows in which a catalog menu is served, such as breakfast or
+/// lunch, all local to `zone`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Daypart {
+    windows: Vec<Window>,
+    zone: Zone,
+}
+
+impl Daypart {
+    pub const MAX_WINDOWS: usize = 16;
+
+    /// Try creating a [`Daypart`] out of weekly `windows`
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if there are no windows, more windows than allowed
+    /// or some window is not in `zone`
+    pub fn new(windows: Vec<Window>, zone: Zone) -> Result<Self, DaypartError> {
+        if windows.is_empty() {
+            return Err(DaypartError::Empty);
+        }
+        if windows.len() > Self::MAX_WINDOWS {
+            return Err(DaypartError::Windows);
+        }
+        if windows.iter().any(|window| window.zone() != zone) {
+            return Err(DaypartError::Zone);
+        }
+
+        Ok(Self { windows, zone })
+    }
+}
+
+impl Daypart {
+    #[must_use]
+    pub fn windows(&self) -> &[Window] {
+        &self.windows
+    }
+
+    #[must_use]
+    pub fn zone(&self) -> Zone {
+        self.zone
+    }
+
+    #[must_use]
+    pub fn contains(&self, at: OffsetDateTime) -> bool {
+        self.windows.iter().any(|window| window.contains(at))
+    }
+}
+
+/// Weekly opening hours of the store behind a catalog, local to `zone`,
+/// along with dates it stays closed, such as holidays. An opening spanning
+/// midnight is closed when the date it starts is
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OpeningHours {
+    windows: Vec<Window>,
+    closures: Vec<Date>,
+    zone: Zone,
+}
+
+impl OpeningHours {
+    pub const MAX_WINDOWS: usize = 32;
+    pub const MAX_CLOSURES: usize = 64;
+
+    /// Try creating [`OpeningHours`] out of weekly `windows` and `closures`,
+    /// ignoring duplicated closures
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if there are no windows, if there are more windows
+    /// or closures than allowed or if some window is not in `zone`
+    pub fn new(
+        windows: Vec<Window>,
+        mut closures: Vec<Date>,
+        zone: Zone,
+    ) -> Result<Self, OpeningHoursError> {
+        if windows.is_empty() {
+            return Err(OpeningHoursError::Empty);
+        }
+        if windows.len() > Self::MAX_WINDOWS {
+            return Err(OpeningHoursError::Windows);
+        }
+        if windows.iter().any(|window| window.zone() != zone) {
+            return Err(OpeningHoursError::Zone);
+        }
+
+        closures.sort_unstable();
+        closures.dedup();
+        if closures.len() > Self::MAX_CLOSURES {
+            return Err(OpeningHoursError::Closures);
+        }
+
+        Ok(Self {
+            windows,
+            closures,
+            zone,
+        })
+    }
+}
+
+impl OpeningHours {
+    #[must_use]
+    pub fn windows(&self) -> &[Window] {
+        &self.windows
+    }
+
+    /// Dates store stays closed, in ascending order
+    #[must_use]
+    pub fn closures(&self) -> &[Date] {
+        &self.closures
+    }
+
+    #[must_use]
+    pub fn zone(&self) -> Zone {
+        self.zone
+    }
+
+    #[must_use]
+    pub fn is_closed_on(&self, date: Date) -> bool {
+        self.closures.binary_search(&date).is_ok()
+    }
+
+    #[must_use]
+    pub fn is_open_at(&self, at: OffsetDateTime) -> bool {
+        self.windows.iter().any(|window| {
+            window
+                .started_at(at)
+                .is_some_and(|started_at| !self.is_closed_on(started_at.date()))
+        })
+    }
+
+    /// First time store opens after `at`, skipping closures
+    #[must_use]
+    pub fn next_opening(&self, at: OffsetDateTime) -> Option<OffsetDateTime> {
+        // every closure may skip at most a week worth of openings
+        let days = 7 * (self.closures.len() + 1);
+
+        let mut date = self.zone.local(at).date();
+        for _ in 0..=days {
+            if !self.is_closed_on(date) {
+                let opening = self
+                    .windows
+                    .iter()
+                    .filter(|window| window.weekdays().contains(date.weekday()))
+                    // each date is resolved on its own, since the offset at
+                    // `at` may no longer apply after a daylight saving change
+                    .map(|window| {
+                        self.zone
+                            .resolve(PrimitiveDateTime::new(date, window.starts_at()))
+                    })
+                    .filter(|opening| *opening > at)
+                    .min();
+
+                if opening.is_some() {
+                    return opening;
+                }
+            }
+
+            date = date.next_day()?;
+        }
+
+        None
+    }
+}
+
 #[derive(Clone, Debug, Eq, Error, PartialEq)]
 #[error("Provided string `{0}` is not a valid product catalog id`")]
 pub struct ParseIdError(pub Box<str>);
@@ -182,6 +352,28 @@ pub enum DescriptionError {
     Length,
 }
 
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum DaypartError {
+    #[error("Catalog daypart must have at least one window")]
+    Empty,
+    #[error("Catalog daypart cannot have more than {len} windows", len = Daypart::MAX_WINDOWS)]
+    Windows,
+    #[error("Catalog daypart windows must be in the same time zone")]
+    Zone,
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum OpeningHoursError {
+    #[error("Store cannot have more than {len} closures", len = OpeningHours::MAX_CLOSURES)]
+    Closures,
+    #[error("Store opening hours must have at least one window")]
+    Empty,
+    #[error("Store cannot have more than {len} opening windows", len = OpeningHours::MAX_WINDOWS)]
+    Windows,
+    #[error("Store opening hours windows must be in the same time zone")]
+    Zone,
+}
+
 #[derive(Clone, Debug, Eq, Error, PartialEq)]
 pub enum ProductsError {
     #[error("Product catalog cannot have more than {len} products", len = Products::MAX_LEN)]
@@ -233,4 +425,87 @@ mod tests {
         let big = ["a"; Description::MAX_LEN + 1].join("");
         assert_eq!(Description::new(big), Err(DescriptionError::Length));
     }
+
+    #[test]
+    fn opening_hours_skip_closures() {
+        use time::macros::{date, datetime, time};
+        use time::Weekday;
+
+        use crate::core::schedule::Weekdays;
+
+        let zone = Zone::parse_str("America/Sao_Paulo").expect("Valid zone");
+        let weekdays =
+            Weekdays::new(&[Weekday::Tuesday, Weekday::Wednesday]).expect("Valid weekdays");
+        let window = Window::new(weekdays, time!(18:00), time!(2:00), zone).expect("Valid window");
+        // 2024-12-24 is a tuesday
+        let hours = OpeningHours::new(vec![window], vec![date!(2024 - 12 - 24)], zone)
+            .expect("Valid opening hours");
+
+        assert!(!hours.is_open_at(datetime!(2024-12-24 19:00 -3)));
+        assert!(!hours.is_open_at(datetime!(2024-12-25 01:00 -3)));
+        assert!(hours.is_open_at(datetime!(2024-12-26 01:00 -3)));
+        assert!(hours.is_open_at(datetime!(2024-12-17 23:00 UTC)));
+        assert_eq!(
+            hours.next_opening(datetime!(2024-12-24 12:00 -3)),
+            Some(datetime!(2024-12-25 18:00 -3))
+        );
+        assert_eq!(
+            hours.next_opening(datetime!(2024-12-25 19:00 -3)),
+            Some(datetime!(2024-12-31 18:00 -3))
+        );
+        assert_eq!(
+            OpeningHours::new(vec![], vec![], zone),
+            Err(OpeningHoursError::Empty)
+        );
+    }
+
+    #[test]
+    fn opening_hours_follow_daylight_saving_time() {
+        use time::macros::{datetime, time};
+        use time::Weekday;
+
+        use crate::core::schedule::Weekdays;
+
+        let zone = Zone::parse_str("America/New_York").expect("Valid zone");
+        let weekdays =
+            Weekdays::new(&[Weekday::Saturday, Weekday::Sunday]).expect("Valid weekdays");
+        let window = Window::new(weekdays, time!(18:00), time!(23:00), zone).expect("Valid window");
+        let hours = OpeningHours::new(vec![window], vec![], zone).expect("Valid opening hours");
+
+        // clocks spring forward on 2024-03-10 and fall back on 2024-11-03
+        assert_eq!(
+            hours.next_opening(datetime!(2024-03-09 19:00 -5)),
+            Some(datetime!(2024-03-10 18:00 -4))
+        );
+        assert_eq!(
+            hours.next_opening(datetime!(2024-11-02 19:00 -4)),
+            Some(datetime!(2024-11-03 18:00 -5))
+        );
+        assert!(hours.is_open_at(datetime!(2024-03-10 22:30 UTC)));
+        assert!(!hours.is_open_at(datetime!(2024-11-03 22:30 UTC)));
+    }
+
+    #[test]
+    fn daypart_contains() {
+        use time::macros::{datetime, time};
+        use time::Weekday;
+
+        use crate::core::schedule::Weekdays;
+
+        let zone = Zone::parse_str("America/Sao_Paulo").expect("Valid zone");
+        let weekdays = Weekdays::new(&[Weekday::Monday]).expect("Valid weekdays");
+        let breakfast =
+            Window::new(weekdays, time!(6:00), time!(10:30), zone).expect("Valid window");
+        let daypart = Daypart::new(vec![breakfast], zone).expect("Valid daypart");
+
+        // 2024-08-26 is a monday
+        assert!(daypart.contains(datetime!(2024-08-26 07:00 -3)));
+        assert!(daypart.contains(datetime!(2024-08-26 13:00 UTC)));
+        assert!(!daypart.contains(datetime!(2024-08-26 11:00 -3)));
+        assert!(!daypart.contains(datetime!(2024-08-27 07:00 -3)));
+        assert_eq!(
+            Daypart::new(vec![breakfast], Zone::UTC),
+            Err(DaypartError::Zone)
+        );
+    }
 }
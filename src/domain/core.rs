@@ -1,2 +1,6 @@
 pub mod metadata;
+pub mod money;
+pub mod quantity;
+pub mod schedule;
+pub mod sort;
 pub mod string;
@@ -0,0 +1,415 @@
+use std::fmt;
+use std::str::FromStr;
+
+use rust_decimal::{Decimal, RoundingStrategy};
+use thiserror::Error;
+
+/// ISO 4217 alphabetic currency code
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Currency([u8; 3]);
+
+impl Currency {
+    pub const BRL: Self = Self(*b"BRL");
+    pub const EUR: Self = Self(*b"EUR");
+    pub const USD: Self = Self(*b"USD");
+
+    /// Try parsing `value` into [`Currency`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `value` is not made of exactly three uppercase
+    /// ASCII letters
+    pub fn parse_str(value: &str) -> Result<Self, ParseCurrencyError> {
+        match value.as_bytes() {
+            [a, b, c] if [a, b, c].iter().all(|l| l.is_ascii_uppercase()) => Ok(Self([*a, *b, *c])),
+            _ => Err(ParseCurrencyError(Box::from(value))),
+        }
+    }
+}
+
+impl Currency {
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        // only uppercase ASCII letters are accepted when parsing
+        std::str::from_utf8(&self.0).unwrap_or_default()
+    }
+
+    /// Number of decimal digits of the currency minor unit
+    #[must_use]
+    pub fn minor_units(&self) -> u32 {
+        match &self.0 {
+            b"BIF" | b"CLP" | b"DJF" | b"GNF" | b"ISK" | b"JPY" | b"KMF" | b"KRW" | b"PYG"
+            | b"RWF" | b"UGX" | b"VND" | b"VUV" | b"XAF" | b"XOF" | b"XPF" => 0,
+            b"BHD" | b"IQD" | b"JOD" | b"KWD" | b"LYD" | b"OMR" | b"TND" => 3,
+            _ => 2,
+        }
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl TryFrom<&str> for Currency {
+    type Error = ParseCurrencyError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::parse_str(value)
+    }
+}
+
+/// How to round a [`Money`] amount to the minor unit of its [`Currency`]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Rounding {
+    /// Half to the nearest even digit, also known as banker's rounding
+    HalfEven,
+    /// Half away from zero
+    HalfUp,
+    /// Towards zero, truncating extra digits
+    Down,
+    /// Away from zero
+    Up,
+}
+
+impl Rounding {
+    fn strategy(self) -> RoundingStrategy {
+        match self {
+            Self::HalfEven => RoundingStrategy::MidpointNearestEven,
+            Self::HalfUp => RoundingStrategy::MidpointAwayFromZero,
+            Self::Down => RoundingStrategy::ToZero,
+            Self::Up => RoundingStrategy::AwayFromZero,
+        }
+    }
+}
+
+/// Monetary amount in a given [`Currency`]. Amounts are kept exact, so
+/// rounding only happens when explicitly requested with [`Money::round`]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Money {
+    amount: Decimal,
+    currency: Currency,
+}
+
+impl Money {
+    #[must_use]
+    pub fn new(amount: Decimal, currency: Currency) -> Self {
+        Self { amount, currency }
+    }
+
+    #[must_use]
+    pub fn zero(currency: Currency) -> Self {
+        Self::new(Decimal::ZERO, currency)
+    }
+
+    /// Create [`Money`] from an amount of the `currency` minor unit, e.g. cents
+    #[must_use]
+    pub fn from_minor(value: i64, currency: Currency) -> Self {
+        Self::new(Decimal::new(value, currency.minor_units()), currency)
+    }
+
+    /// Try parsing `amount` and `currency` into [`Money`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `amount` is not a non-negative decimal number, if
+    /// it has more decimal places than the `currency` minor unit or if
+    /// `currency` is not a valid [`Currency`]
+    pub fn parse(amount: &str, currency: &str) -> Result<Self, ParseMoneyError> {
+        let currency = Currency::parse_str(currency).map_err(ParseMoneyError::Currency)?;
+        let amount = Decimal::from_str(amount)
+            .map_err(|_| ParseMoneyError::Amount(Box::from(amount)))?
+            .normalize();
+
+        let money = Self::new(amount, currency);
+        if money.is_negative() {
+            return Err(ParseMoneyError::Negative);
+        }
+        if amount.scale() > currency.minor_units() {
+            return Err(ParseMoneyError::Precision(currency));
+        }
+
+        Ok(money)
+    }
+
+    /// Sum `values` that must all be in `currency`
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if any of `values` is in another currency or if the
+    /// sum overflows
+    pub fn checked_sum(
+        currency: Currency,
+        values: impl IntoIterator<Item = Self>,
+    ) -> Result<Self, MoneyError> {
+        values
+            .into_iter()
+            .try_fold(Self::zero(currency), Self::checked_add)
+    }
+}
+
+impl Money {
+    #[must_use]
+    pub fn amount(&self) -> Decimal {
+        self.amount
+    }
+
+    #[must_use]
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    #[must_use]
+    pub fn is_negative(&self) -> bool {
+        self.amount.is_sign_negative() && !self.amount.is_zero()
+    }
+
+    /// Amount with at least as many decimal places as the currency minor
+    /// unit, e.g. `12.50` instead of `12.5`
+    #[must_use]
+    pub fn format_amount(&self) -> String {
+        let mut amount = self.amount.normalize();
+        if amount.scale() < self.currency.minor_units() {
+            amount.rescale(self.currency.minor_units());
+        }
+
+        amount.to_string()
+    }
+
+    /// Add `other` to this money
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `other` is in another currency or if the sum overflows
+    pub fn checked_add(self, other: Self) -> Result<Self, MoneyError> {
+        self.ensure_currency(other.currency)?;
+        let amount = self
+            .amount
+            .checked_add(other.amount)
+            .ok_or(MoneyError::Overflow)?;
+
+        Ok(Self::new(amount, self.currency))
+    }
+
+    /// Subtract `other` from this money
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `other` is in another currency or if the
+    /// subtraction overflows
+    pub fn checked_sub(self, other: Self) -> Result<Self, MoneyError> {
+        self.ensure_currency(other.currency)?;
+        let amount = self
+            .amount
+            .checked_sub(other.amount)
+            .ok_or(MoneyError::Overflow)?;
+
+        Ok(Self::new(amount, self.currency))
+    }
+
+    /// Multiply this money by `quantity`
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if the multiplication overflows
+    pub fn checked_mul(self, quantity: u16) -> Result<Self, MoneyError> {
+        let amount = self
+            .amount
+            .checked_mul(Decimal::from(quantity))
+            .ok_or(MoneyError::Overflow)?;
+
+        Ok(Self::new(amount, self.currency))
+    }
+
+    /// Compute `rate` percent of this money, e.g. a `rate` of `12.5` is 12.5%.
+    /// The result is not rounded
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if the computation overflows
+    pub fn checked_percentage(self, rate: Decimal) -> Result<Self, MoneyError> {
+        let amount = self
+            .amount
+            .checked_mul(rate)
+            .and_then(|amount| amount.checked_div(Decimal::ONE_HUNDRED))
+            .ok_or(MoneyError::Overflow)?;
+
+        Ok(Self::new(amount, self.currency))
+    }
+
+    /// Convert this money into `currency` at `rate` units of `currency` per
+    /// unit of current currency. The result is not rounded
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if the computation overflows
+    pub fn checked_exchange(self, rate: Decimal, currency: Currency) -> Result<Self, MoneyError> {
+        let amount = self.amount.checked_mul(rate).ok_or(MoneyError::Overflow)?;
+
+        Ok(Self::new(amount, currency))
+    }
+
+    /// Round amount to the currency minor unit using `rounding`
+    #[must_use]
+    pub fn round(self, rounding: Rounding) -> Self {
+        let amount = self
+            .amount
+            .round_dp_with_strategy(self.currency.minor_units(), rounding.strategy());
+
+        Self::new(amount, self.currency)
+    }
+
+    fn ensure_currency(&self, currency: Currency) -> Result<(), MoneyError> {
+        if self.currency == currency {
+            return Ok(());
+        }
+
+        Err(MoneyError::CurrencyMismatch {
+            expected: self.currency,
+            found: currency,
+        })
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.format_amount(), self.currency)
+    }
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum MoneyError {
+    #[error("Expected money in `{expected}` but found `{found}`")]
+    CurrencyMismatch { expected: Currency, found: Currency },
+    #[error("Money operation overflowed")]
+    Overflow,
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[error("Provided string `{0}` is not a valid ISO 4217 currency code")]
+pub struct ParseCurrencyError(pub Box<str>);
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum ParseMoneyError {
+    #[error("Provided string `{0}` is not a valid money amount")]
+    Amount(Box<str>),
+    #[error(transparent)]
+    Currency(ParseCurrencyError),
+    #[error("Money amount cannot be negative")]
+    Negative,
+    #[error("Money amount has more decimal places than `{0}` allows")]
+    Precision(Currency),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_currency() {
+        for code in ["BRL", "USD", "JPY"] {
+            let currency = Currency::parse_str(code).expect("Valid currency");
+            assert_eq!(currency.as_str(), code);
+        }
+
+        for invalid in ["", "BR", "brl", "BRLL", "B1L"] {
+            assert_eq!(
+                Currency::parse_str(invalid),
+                Err(ParseCurrencyError(Box::from(invalid)))
+            );
+        }
+    }
+
+    #[test]
+    fn parse_money() {
+        let money = Money::parse("12.50", "BRL").expect("Valid money");
+        assert_eq!(money, Money::from_minor(1250, Currency::BRL));
+        assert_eq!(money.format_amount(), "12.50");
+        assert_eq!(money.to_string(), "12.50 BRL");
+
+        assert_eq!(
+            Money::parse("12.505", "BRL"),
+            Err(ParseMoneyError::Precision(Currency::BRL))
+        );
+        assert_eq!(Money::parse("-1.00", "BRL"), Err(ParseMoneyError::Negative));
+        assert!(Money::parse("1000", "JPY").is_ok());
+        assert!(Money::parse("10.5", "JPY").is_err());
+        assert!(matches!(
+            Money::parse("ten", "BRL"),
+            Err(ParseMoneyError::Amount(_))
+        ));
+    }
+
+    #[test]
+    fn checked_arithmetic() {
+        let price = Money::from_minor(1990, Currency::BRL);
+
+        assert_eq!(
+            price.checked_add(Money::from_minor(10, Currency::BRL)),
+            Ok(Money::from_minor(2000, Currency::BRL))
+        );
+        assert_eq!(
+            price.checked_sub(Money::from_minor(1990, Currency::BRL)),
+            Ok(Money::zero(Currency::BRL))
+        );
+        assert_eq!(
+            price.checked_mul(3),
+            Ok(Money::from_minor(5970, Currency::BRL))
+        );
+        assert_eq!(
+            price.checked_add(Money::from_minor(10, Currency::USD)),
+            Err(MoneyError::CurrencyMismatch {
+                expected: Currency::BRL,
+                found: Currency::USD
+            })
+        );
+
+        let max = Money::new(Decimal::MAX, Currency::BRL);
+        assert_eq!(max.checked_add(price), Err(MoneyError::Overflow));
+        assert_eq!(max.checked_mul(2), Err(MoneyError::Overflow));
+    }
+
+    #[test]
+    fn checked_sum_works() {
+        let values = [100, 250, 5].map(|v| Money::from_minor(v, Currency::BRL));
+        assert_eq!(
+            Money::checked_sum(Currency::BRL, values),
+            Ok(Money::from_minor(355, Currency::BRL))
+        );
+        assert!(Money::checked_sum(Currency::USD, values).is_err());
+    }
+
+    #[test]
+    fn percentage_and_rounding() {
+        let price = Money::from_minor(1999, Currency::BRL);
+        let tax = price
+            .checked_percentage(Decimal::new(125, 1))
+            .expect("Valid percentage");
+
+        // 12.5% of 19.99 is 2.49875
+        assert_eq!(tax.amount(), Decimal::new(249_875, 5));
+        assert_eq!(
+            tax.round(Rounding::HalfEven),
+            Money::from_minor(250, Currency::BRL)
+        );
+        assert_eq!(
+            tax.round(Rounding::Down),
+            Money::from_minor(249, Currency::BRL)
+        );
+        assert_eq!(
+            tax.round(Rounding::Up),
+            Money::from_minor(250, Currency::BRL)
+        );
+
+        let half = Money::new(Decimal::new(1225, 3), Currency::BRL);
+        assert_eq!(
+            half.round(Rounding::HalfEven),
+            Money::from_minor(122, Currency::BRL)
+        );
+        assert_eq!(
+            half.round(Rounding::HalfUp),
+            Money::from_minor(123, Currency::BRL)
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use std::fmt;
+
+use thiserror::Error;
+
+/// How many units of something are ordered, sold or selected at once
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Quantity(u16);
+
+impl Quantity {
+    pub const MAX: u16 = 99;
+
+    /// Try parsing `value` into [`Quantity`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `value` is zero or bigger than [`Quantity::MAX`]
+    pub fn new(value: u16) -> Result<Self, QuantityError> {
+        match value {
+            0 => Err(QuantityError::Zero),
+            v if v > Self::MAX => Err(QuantityError::Max),
+            v => Ok(Self(v)),
+        }
+    }
+}
+
+impl Quantity {
+    /// Biggest [`Quantity`], such as the default limit of a selection
+    #[must_use]
+    pub fn max() -> Self {
+        Self(Self::MAX)
+    }
+
+    /// Single unit, such as each extra chosen by id in carts and orders
+    #[must_use]
+    pub fn one() -> Self {
+        Self(1)
+    }
+
+    #[must_use]
+    pub fn value(&self) -> u16 {
+        self.0
+    }
+}
+
+impl fmt::Display for Quantity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum QuantityError {
+    #[error("Quantity cannot be zero")]
+    Zero,
+    #[error("Quantity cannot be bigger than {max}", max = Quantity::MAX)]
+    Max,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_quantity_works() {
+        for value in [1, 2, 50, Quantity::MAX] {
+            assert_eq!(Quantity::new(value).map(|q| q.value()), Ok(value));
+        }
+
+        assert_eq!(Quantity::new(0), Err(QuantityError::Zero));
+        assert_eq!(Quantity::new(Quantity::MAX + 1), Err(QuantityError::Max));
+    }
+}
@@ -0,0 +1,377 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use thiserror::Error;
+use time::{Duration, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
+use time_tz::{timezones, Offset, OffsetDateTimeExt, PrimitiveDateTimeExt, TimeZone, Tz};
+
+/// Non-empty set of days of the week, stored as a bit per day starting from
+/// monday
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Weekdays(u8);
+
+impl Weekdays {
+    const ALL: u8 = 0b111_1111;
+
+    /// Try creating [`Weekdays`] from `days`, ignoring duplicates
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `days` is empty
+    pub fn new(days: &[Weekday]) -> Result<Self, WeekdaysError> {
+        let bits = days.iter().fold(0, |bits, day| bits | Self::bit(*day));
+        Self::from_bits(bits)
+    }
+
+    /// Try creating [`Weekdays`] from its bits representation
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if no day is set or unknown bits are set
+    pub fn from_bits(bits: u8) -> Result<Self, WeekdaysError> {
+        if bits == 0 {
+            return Err(WeekdaysError::Empty);
+        }
+        if bits & !Self::ALL != 0 {
+            return Err(WeekdaysError::Bits(bits));
+        }
+
+        Ok(Self(bits))
+    }
+
+    /// Try parsing a lowercase weekday name, such as `monday`
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `value` is not a weekday name
+    pub fn parse_day(value: &str) -> Result<Weekday, ParseWeekdayError> {
+        match value {
+            "monday" => Ok(Weekday::Monday),
+            "tuesday" => Ok(Weekday::Tuesday),
+            "wednesday" => Ok(Weekday::Wednesday),
+            "thursday" => Ok(Weekday::Thursday),
+            "friday" => Ok(Weekday::Friday),
+            "saturday" => Ok(Weekday::Saturday),
+            "sunday" => Ok(Weekday::Sunday),
+            other => Err(ParseWeekdayError(Box::from(other))),
+        }
+    }
+
+    #[must_use]
+    pub fn day_as_str(day: Weekday) -> &'static str {
+        match day {
+            Weekday::Monday => "monday",
+            Weekday::Tuesday => "tuesday",
+            Weekday::Wednesday => "wednesday",
+            Weekday::Thursday => "thursday",
+            Weekday::Friday => "friday",
+            Weekday::Saturday => "saturday",
+            Weekday::Sunday => "sunday",
+        }
+    }
+
+    fn bit(day: Weekday) -> u8 {
+        1 << day.number_days_from_monday()
+    }
+}
+
+impl Weekdays {
+    #[must_use]
+    pub fn bits(&self) -> u8 {
+        self.0
+    }
+
+    #[must_use]
+    pub fn contains(&self, day: Weekday) -> bool {
+        self.0 & Self::bit(day) != 0
+    }
+
+    /// Days in the set, starting from monday
+    pub fn iter(&self) -> impl Iterator<Item = Weekday> + '_ {
+        let mut day = Weekday::Sunday;
+        (0..7)
+            .map(move |_| {
+                day = day.next();
+                day
+            })
+            .filter(|day| self.contains(*day))
+    }
+}
+
+/// IANA time zone, such as `America/Sao_Paulo`, whose UTC offset is only
+/// resolved for a given instant, following daylight saving time changes
+#[derive(Clone, Copy, Debug)]
+pub struct Zone(&'static Tz);
+
+impl Zone {
+    pub const UTC: Self = Self(timezones::db::UTC);
+
+    /// Try parsing an IANA time zone name into [`Zone`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `value` is not the name of an IANA time zone
+    pub fn parse_str(value: &str) -> Result<Self, ParseZoneError> {
+        // `get_by_name` also accepts windows zone names, like `UTC` for
+        // `Etc/UTC`, so only exact IANA names are looked up
+        timezones::iter()
+            .find(|tz| tz.name() == value)
+            .map(Self)
+            .ok_or_else(|| ParseZoneError(Box::from(value)))
+    }
+}
+
+impl Zone {
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        self.0.name()
+    }
+
+    /// UTC offset in effect in zone at `at`
+    #[must_use]
+    pub fn offset_at(&self, at: OffsetDateTime) -> UtcOffset {
+        self.0.get_offset_utc(&at).to_utc()
+    }
+
+    /// Same instant as `at`, in the offset zone has at that time
+    #[must_use]
+    pub fn local(&self, at: OffsetDateTime) -> OffsetDateTime {
+        at.to_timezone(self.0)
+    }
+
+    /// Instant in which wall clock of zone shows `local`. The earliest
+    /// instant is taken when clocks fall back and show it twice, and the
+    /// offset before the change is kept when clocks spring forward past it
+    #[must_use]
+    pub fn resolve(&self, local: PrimitiveDateTime) -> OffsetDateTime {
+        local
+            .assume_timezone(self.0)
+            .take_first()
+            .unwrap_or_else(|| {
+                let before = local.assume_utc() - Duration::DAY;
+                local.assume_offset(self.offset_at(before))
+            })
+    }
+}
+
+impl PartialEq for Zone {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for Zone {}
+
+impl Hash for Zone {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl fmt::Display for Zone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// Recurring period of the week in a time zone. A window ending at or
+/// before it starts spans midnight, belonging to the day it starts
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Window {
+    weekdays: Weekdays,
+    starts_at: Time,
+    ends_at: Time,
+    zone: Zone,
+}
+
+impl Window {
+    /// Try creating a [`Window`] from `starts_at` to `ends_at` on `weekdays`,
+    /// both local to `zone`
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `starts_at` and `ends_at` are the same
+    pub fn new(
+        weekdays: Weekdays,
+        starts_at: Time,
+        ends_at: Time,
+        zone: Zone,
+    ) -> Result<Self, WindowError> {
+        if starts_at == ends_at {
+            return Err(WindowError::Empty);
+        }
+
+        Ok(Self {
+            weekdays,
+            starts_at,
+            ends_at,
+            zone,
+        })
+    }
+}
+
+impl Window {
+    #[must_use]
+    pub fn weekdays(&self) -> Weekdays {
+        self.weekdays
+    }
+
+    #[must_use]
+    pub fn starts_at(&self) -> Time {
+        self.starts_at
+    }
+
+    #[must_use]
+    pub fn ends_at(&self) -> Time {
+        self.ends_at
+    }
+
+    #[must_use]
+    pub fn zone(&self) -> Zone {
+        self.zone
+    }
+
+    #[must_use]
+    pub fn contains(&self, at: OffsetDateTime) -> bool {
+        self.started_at(at).is_some()
+    }
+
+    /// When the occurrence of window containing `at` started, if any
+    #[must_use]
+    pub fn started_at(&self, at: OffsetDateTime) -> Option<OffsetDateTime> {
+        let local = self.zone.local(at);
+        let (day, time) = (local.weekday(), local.time());
+        let overnight = self.ends_at < self.starts_at;
+
+        let date = if self.weekdays.contains(day)
+            && self.starts_at <= time
+            && (overnight || time < self.ends_at)
+        {
+            local.date()
+        } else if overnight && self.weekdays.contains(day.previous()) && time < self.ends_at {
+            local.date().previous_day()?
+        } else {
+            return None;
+        };
+
+        Some(
+            self.zone
+                .resolve(PrimitiveDateTime::new(date, self.starts_at)),
+        )
+    }
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[error("Provided string `{0}` is not a valid weekday")]
+pub struct ParseWeekdayError(pub Box<str>);
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[error("Provided string `{0}` is not a valid IANA time zone")]
+pub struct ParseZoneError(pub Box<str>);
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum WeekdaysError {
+    #[error("Bits `{0:#b}` do not represent a set of weekdays")]
+    Bits(u8),
+    #[error("Schedule must happen on at least one weekday")]
+    Empty,
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum WindowError {
+    #[error("Schedule window cannot start and end at the same time")]
+    Empty,
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{Date, Month};
+
+    use super::*;
+
+    #[test]
+    fn new_weekdays() {
+        let weekdays = Weekdays::new(&[Weekday::Friday, Weekday::Monday, Weekday::Friday])
+            .expect("Valid weekdays");
+
+        assert_eq!(
+            weekdays.iter().collect::<Vec<_>>(),
+            vec![Weekday::Monday, Weekday::Friday]
+        );
+        assert_eq!(Weekdays::new(&[]), Err(WeekdaysError::Empty));
+        assert_eq!(
+            Weekdays::from_bits(0b1000_0001),
+            Err(WeekdaysError::Bits(0b1000_0001))
+        );
+    }
+
+    #[test]
+    fn parse_zone_works() {
+        let zone = Zone::parse_str("America/Sao_Paulo").expect("Valid zone");
+        assert_eq!(zone.as_str(), "America/Sao_Paulo");
+        assert_eq!(Zone::parse_str("UTC"), Ok(Zone::UTC));
+        assert!(Zone::parse_str("Etc/GMT+3").is_ok());
+        assert_eq!(
+            Zone::parse_str("-03:00"),
+            Err(ParseZoneError(Box::from("-03:00")))
+        );
+    }
+
+    #[test]
+    fn zone_follows_daylight_saving_time() {
+        let zone = Zone::parse_str("America/New_York").expect("Valid zone");
+
+        // 2024-03-10 02:00 is skipped and 2024-11-03 01:00 happens twice
+        assert_eq!(zone.offset_at(utc_on(3, 9, 12, 0)).whole_hours(), -5);
+        assert_eq!(zone.offset_at(utc_on(3, 11, 12, 0)).whole_hours(), -4);
+        assert_eq!(zone.resolve(local_on(3, 10, 2, 30)), utc_on(3, 10, 7, 30));
+        assert_eq!(zone.resolve(local_on(11, 3, 1, 30)), utc_on(11, 3, 5, 30));
+    }
+
+    #[test]
+    fn window_contains_in_zone() {
+        let weekdays = Weekdays::new(&[Weekday::Monday]).expect("Valid weekdays");
+        let zone = Zone::parse_str("America/Sao_Paulo").expect("Valid zone");
+        let window = Window::new(weekdays, time(17, 0), time(19, 0), zone).expect("Valid window");
+
+        // 2024-08-12 is a monday, and 20:30 UTC is 17:30 at -03:00
+        assert!(window.contains(utc(12, 20, 30)));
+        assert!(!window.contains(utc(12, 17, 30)));
+        assert!(!window.contains(utc(12, 22, 0)));
+        assert!(!window.contains(utc(13, 20, 30)));
+    }
+
+    #[test]
+    fn window_contains_past_midnight() {
+        let weekdays = Weekdays::new(&[Weekday::Friday]).expect("Valid weekdays");
+        let window =
+            Window::new(weekdays, time(22, 0), time(2, 0), Zone::UTC).expect("Valid window");
+
+        // 2024-08-16 is a friday
+        assert!(window.contains(utc(16, 23, 0)));
+        assert!(window.contains(utc(17, 1, 0)));
+        assert!(!window.contains(utc(17, 23, 0)));
+        assert!(!window.contains(utc(16, 1, 0)));
+        assert_eq!(window.started_at(utc(17, 1, 0)), Some(utc(16, 22, 0)));
+        assert_eq!(window.started_at(utc(16, 23, 0)), Some(utc(16, 22, 0)));
+        assert_eq!(window.started_at(utc(16, 21, 0)), None);
+    }
+
+    fn time(hour: u8, minute: u8) -> Time {
+        Time::from_hms(hour, minute, 0).expect("Valid time")
+    }
+
+    fn utc(day: u8, hour: u8, minute: u8) -> OffsetDateTime {
+        utc_on(8, day, hour, minute)
+    }
+
+    fn utc_on(month: u8, day: u8, hour: u8, minute: u8) -> OffsetDateTime {
+        local_on(month, day, hour, minute).assume_utc()
+    }
+
+    fn local_on(month: u8, day: u8, hour: u8, minute: u8) -> PrimitiveDateTime {
+        let month = Month::try_from(month).expect("Valid month");
+        let date = Date::from_calendar_date(2024, month, day).expect("Valid date");
+        PrimitiveDateTime::new(date, time(hour, minute))
+    }
+}
@@ -0,0 +1,62 @@
+use thiserror::Error;
+
+/// Order of listed items, such as products or their extras
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Sort {
+    /// Alphabetically by name
+    Name,
+    /// Cheapest first
+    Price,
+    /// Newest first
+    #[default]
+    CreatedAt,
+}
+
+impl Sort {
+    /// Try parsing `value` into [`Sort`]
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseSortError`] when `value` cannot be parsed to [`Sort`]
+    pub fn parse_str(value: &str) -> Result<Self, ParseSortError> {
+        match value {
+            "name" => Ok(Self::Name),
+            "price" => Ok(Self::Price),
+            "created_at" => Ok(Self::CreatedAt),
+            other => Err(ParseSortError(Box::from(other))),
+        }
+    }
+}
+
+impl Sort {
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Name => "name",
+            Self::Price => "price",
+            Self::CreatedAt => "created_at",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[error("Provided string `{0}` is not a valid sort")]
+pub struct ParseSortError(pub Box<str>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sort_back_and_forth_str() {
+        for sort in [Sort::Name, Sort::Price, Sort::CreatedAt] {
+            assert_eq!(Sort::parse_str(sort.as_str()), Ok(sort));
+        }
+
+        let invalid = "updated_at";
+        assert_eq!(
+            Sort::parse_str(invalid),
+            Err(ParseSortError(Box::from(invalid)))
+        );
+    }
+}
@@ -0,0 +1,9 @@
+mod entity;
+mod error;
+mod repository;
+mod vo;
+
+pub use entity::{ExchangeRate, ExchangeRateConfig};
+pub use error::{ConflictKind, Error};
+pub use repository::Repository;
+pub use vo::{Pair, PairError, Rate, RateError};
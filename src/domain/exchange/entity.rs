@@ -0,0 +1,119 @@
+use super::{ConflictKind, Error, Pair, Rate};
+use crate::core::metadata;
+use crate::core::money::{Money, Rounding};
+
+/// Rate used to show prices in a currency other than the one they are
+/// registered in
+#[derive(Clone, Debug)]
+pub struct ExchangeRate {
+    pair: Pair,
+    pub rate: Rate,
+    pub metadata: metadata::Metadata,
+}
+
+impl ExchangeRate {
+    #[must_use]
+    pub fn new(pair: Pair, rate: Rate) -> Self {
+        Self {
+            pair,
+            rate,
+            metadata: metadata::Metadata::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn config(config: ExchangeRateConfig) -> Self {
+        Self {
+            pair: config.pair,
+            rate: config.rate,
+            metadata: config.metadata,
+        }
+    }
+}
+
+impl ExchangeRate {
+    #[must_use]
+    pub fn pair(&self) -> Pair {
+        self.pair
+    }
+
+    /// Convert `money` into the quote currency of the pair, rounding half to
+    /// even to its minor unit
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `money` is not in the base currency of the pair
+    /// or if the conversion overflows
+    pub fn convert(&self, money: Money) -> Result<Money, Error> {
+        if money.currency() != self.pair.from() {
+            return Err(Error::Conflict(ConflictKind::Currency {
+                expected: self.pair.from(),
+                found: money.currency(),
+            }));
+        }
+
+        money
+            .checked_exchange(self.rate.value(), self.pair.to())
+            .map(|converted| converted.round(Rounding::HalfEven))
+            .map_err(Error::money)
+    }
+
+    /// Replace rate, e.g. when a newer quotation is uploaded
+    pub fn set_rate(&mut self, rate: Rate) {
+        self.rate = rate;
+        self.metadata.update();
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ExchangeRateConfig {
+    pub pair: Pair,
+    pub rate: Rate,
+    pub metadata: metadata::Metadata,
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use super::*;
+    use crate::core::money::Currency;
+
+    #[test]
+    fn convert_works() {
+        let rate = mocked_rate("0.1825");
+
+        let converted = rate
+            .convert(Money::from_minor(1990, Currency::BRL))
+            .expect("Valid conversion");
+        // 19.90 * 0.1825 = 3.631750
+        assert_eq!(converted, Money::from_minor(363, Currency::USD));
+
+        let converted = rate
+            .convert(Money::new(Decimal::new(10, 0), Currency::BRL))
+            .expect("Valid conversion");
+        // 10 * 0.1825 = 1.825, rounded half to even
+        assert_eq!(converted, Money::from_minor(182, Currency::USD));
+    }
+
+    #[test]
+    fn convert_with_currency_conflict() {
+        let rate = mocked_rate("0.1825");
+
+        let result = rate.convert(Money::from_minor(1990, Currency::EUR));
+        assert!(matches!(
+            result,
+            Err(Error::Conflict(ConflictKind::Currency {
+                expected: Currency::BRL,
+                found: Currency::EUR
+            }))
+        ));
+    }
+
+    fn mocked_rate(rate: &str) -> ExchangeRate {
+        ExchangeRate::new(
+            Pair::new(Currency::BRL, Currency::USD).expect("Valid pair"),
+            Rate::parse_str(rate).expect("Valid rate"),
+        )
+    }
+}
@@ -0,0 +1,41 @@
+use thiserror::Error;
+
+use super::Pair;
+use crate::core::money::{Currency, MoneyError};
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error(transparent)]
+    Conflict(ConflictKind),
+    #[error(transparent)]
+    Internal(Box<dyn std::error::Error>),
+    #[error("Exchange rate for `{0}` not found")]
+    NotFound(Pair),
+}
+
+impl Error {
+    /// Utility function to create [`Error::Internal`] without manually
+    /// boxing the error
+    #[must_use]
+    pub fn any(err: impl Into<Box<dyn std::error::Error>>) -> Self {
+        Self::Internal(err.into())
+    }
+
+    /// Map a [`MoneyError`] into [`Error`], keeping currency mismatches as
+    /// conflicts and anything else as internal
+    #[must_use]
+    pub fn money(err: MoneyError) -> Self {
+        match err {
+            MoneyError::CurrencyMismatch { expected, found } => {
+                Self::Conflict(ConflictKind::Currency { expected, found })
+            }
+            MoneyError::Overflow => Self::any(err),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum ConflictKind {
+    #[error("Exchange rate from `{expected}` cannot convert prices in `{found}`")]
+    Currency { expected: Currency, found: Currency },
+}
@@ -0,0 +1,11 @@
+use super::error::Error;
+use super::{ExchangeRate, Pair};
+
+// TODO: remove when stabilized
+#[allow(async_fn_in_trait)]
+pub trait Repository: Send + Clone {
+    async fn find(&self, pair: Pair) -> Result<ExchangeRate, Error>;
+    async fn list(&self) -> Result<Vec<ExchangeRate>, Error>;
+    /// Save `rates`, replacing any rate already registered for the same pair
+    async fn save_many(&mut self, rates: &[ExchangeRate]) -> Result<(), Error>;
+}
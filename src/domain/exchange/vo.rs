@@ -0,0 +1,130 @@
+use std::fmt;
+use std::str::FromStr;
+
+use rust_decimal::Decimal;
+use thiserror::Error;
+
+use crate::core::money::Currency;
+
+/// Ordered pair of distinct currencies an exchange rate converts between
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Pair {
+    from: Currency,
+    to: Currency,
+}
+
+impl Pair {
+    /// Try creating a [`Pair`] converting `from` into `to`
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `from` and `to` are the same currency
+    pub fn new(from: Currency, to: Currency) -> Result<Self, PairError> {
+        if from == to {
+            return Err(PairError::Same(from));
+        }
+
+        Ok(Self { from, to })
+    }
+}
+
+impl Pair {
+    #[must_use]
+    pub fn from(&self) -> Currency {
+        self.from
+    }
+
+    #[must_use]
+    pub fn to(&self) -> Currency {
+        self.to
+    }
+}
+
+impl fmt::Display for Pair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.from, self.to)
+    }
+}
+
+/// Units of the quote currency bought by one unit of the base currency
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Rate(Decimal);
+
+impl Rate {
+    /// Try creating a [`Rate`] from `value`
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `value` is not greater than zero
+    pub fn new(value: Decimal) -> Result<Self, RateError> {
+        if value <= Decimal::ZERO {
+            return Err(RateError::NotPositive);
+        }
+
+        Ok(Self(value.normalize()))
+    }
+
+    /// Try parsing `value` into [`Rate`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `value` is not a decimal number greater than zero
+    pub fn parse_str(value: &str) -> Result<Self, RateError> {
+        let decimal = Decimal::from_str(value).map_err(|_| RateError::Parse(Box::from(value)))?;
+        Self::new(decimal)
+    }
+}
+
+impl Rate {
+    #[must_use]
+    pub fn value(&self) -> Decimal {
+        self.0
+    }
+}
+
+impl fmt::Display for Rate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum PairError {
+    #[error("Cannot exchange `{0}` into itself")]
+    Same(Currency),
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum RateError {
+    #[error("Exchange rate must be greater than zero")]
+    NotPositive,
+    #[error("Provided string `{0}` is not a valid exchange rate")]
+    Parse(Box<str>),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_rate() {
+        let rate = Rate::parse_str("0.1850").expect("Valid rate");
+        assert_eq!(rate.value(), Decimal::new(185, 3));
+        assert_eq!(rate.to_string(), "0.185");
+
+        assert_eq!(Rate::parse_str("0"), Err(RateError::NotPositive));
+        assert_eq!(Rate::parse_str("-1.5"), Err(RateError::NotPositive));
+        assert!(matches!(Rate::parse_str("ten"), Err(RateError::Parse(_))));
+    }
+
+    #[test]
+    fn new_pair() {
+        let pair = Pair::new(Currency::BRL, Currency::USD).expect("Valid pair");
+        assert_eq!(pair.to_string(), "BRL/USD");
+
+        assert_eq!(
+            Pair::new(Currency::BRL, Currency::BRL),
+            Err(PairError::Same(Currency::BRL))
+        );
+    }
+}
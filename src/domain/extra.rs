@@ -5,5 +5,5 @@ mod vo;
 
 pub use entity::{ExtraConfig, Extra};
 pub use error::{ConflictKind, Error, ValidationKind};
-pub use repository::Repository;
-pub use vo::{Id, IdError, Name, NameError, Price};
+pub use repository::{ListQuery, Pagination, Repository, Usage};
+pub use vo::{Category, CategoryError, Id, IdError, Name, NameError};
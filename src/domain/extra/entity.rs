@@ -1,21 +1,29 @@
-use super::{Id, Name, Price};
+use super::{Category, Id, Name};
+use crate::catalog;
 use crate::core::metadata;
+use crate::core::money::Money;
 
 #[derive(Clone, Debug)]
 pub struct Extra {
     pub(super) id: Id,
     pub name: Name,
-    pub price: Price,
+    pub price: Money,
+    /// Catalog the extra is restricted to, being available to every catalog
+    /// when there is none
+    pub catalog_id: Option<catalog::Id>,
+    pub category: Option<Category>,
     pub metadata: metadata::Metadata,
 }
 
 impl Extra {
     #[must_use]
-    pub fn new(name: Name, price: Price) -> Self {
+    pub fn new(name: Name, price: Money) -> Self {
         Self {
             id: Id::new(),
             name,
             price,
+            catalog_id: None,
+            category: None,
             metadata: metadata::Metadata::new(),
         }
     }
@@ -26,6 +34,8 @@ impl Extra {
             id: config.id,
             name: config.name,
             price: config.price,
+            catalog_id: config.catalog_id,
+            category: config.category,
             metadata: config.metadata,
         }
     }
@@ -36,12 +46,21 @@ impl Extra {
     pub fn id(&self) -> Id {
         self.id
     }
+
+    /// Whether extra may be offered by products of the catalog with
+    /// `catalog_id`
+    #[must_use]
+    pub fn is_available_to(&self, catalog_id: catalog::Id) -> bool {
+        self.catalog_id.is_none_or(|id| id == catalog_id)
+    }
 }
 
 #[derive(Clone, Debug)]
 pub struct ExtraConfig {
     pub id: Id,
     pub name: Name,
-    pub price: Price,
+    pub price: Money,
+    pub catalog_id: Option<catalog::Id>,
+    pub category: Option<Category>,
     pub metadata: metadata::Metadata,
 }
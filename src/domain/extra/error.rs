@@ -1,6 +1,7 @@
 use thiserror::Error;
 
-use super::{Id, IdError, Name, NameError};
+use super::{CategoryError, Id, IdError, Name, NameError, Usage};
+use crate::catalog;
 
 #[derive(Debug, Error)]
 pub enum Error {
@@ -8,6 +9,8 @@ pub enum Error {
     Conflict(ConflictKind),
     #[error("Product extra with id `{0}` not found")]
     NotFound(Id),
+    #[error("Catalog with id `{0}` not found")]
+    CatalogNotFound(catalog::Id),
     #[error(transparent)]
     Internal(Box<dyn std::error::Error>),
 }
@@ -23,6 +26,11 @@ impl Error {
         Self::Conflict(ConflictKind::Id(id))
     }
 
+    #[must_use]
+    pub fn in_use(id: Id, usages: Vec<Usage>) -> Self {
+        Self::Conflict(ConflictKind::InUse { id, usages })
+    }
+
     #[must_use]
     pub fn name_conflict(name: Name) -> Self {
         Self::Conflict(ConflictKind::Name(name))
@@ -33,12 +41,27 @@ impl Error {
 pub enum ConflictKind {
     #[error("Product extra with id `{0}` already exists")]
     Id(Id),
-    #[error("Product extra with name `{0}` already exists")]
+    #[error("Product extra with name `{0}` already exists in its scope")]
     Name(Name),
+    #[error(
+        "Product extra with id `{id}` is used by products {}",
+        display_usages(usages)
+    )]
+    InUse { id: Id, usages: Vec<Usage> },
+}
+
+fn display_usages(usages: &[Usage]) -> String {
+    usages
+        .iter()
+        .map(|usage| format!("`{}` ({})", usage.product_name, usage.product_id))
+        .collect::<Vec<_>>()
+        .join(", ")
 }
 
 #[derive(Clone, Debug, Eq, Error, PartialEq)]
 pub enum ValidationKind {
+    #[error(transparent)]
+    Category(CategoryError),
     #[error(transparent)]
     Id(IdError),
     #[error(transparent)]
@@ -1,13 +1,53 @@
+use std::num::{NonZeroU32, NonZeroU8};
+
 use super::error::Error;
-use super::{Id, Extra};
+use super::{Category, Extra, Id};
+use crate::core::money::Money;
+use crate::core::sort::Sort;
+use crate::{catalog, product};
 
 // TODO: remove when stabilized
 #[allow(async_fn_in_trait)]
 pub trait Repository: Send + Clone {
-    async fn all(&self) -> Result<Vec<Extra>, Error>;
     async fn create(&mut self, extra: &Extra) -> Result<(), Error>;
-    async fn delete(&mut self, id: Id) -> Result<Extra, Error>;
+    /// Delete extra, refusing to while products offer it unless `force` is
+    /// set, which unbinds it from them in the same transaction
+    async fn delete(&mut self, id: Id, force: bool) -> Result<Extra, Error>;
     async fn find(&self, id: Id) -> Result<Extra, Error>;
     async fn find_many(&self, ids: &[Id]) -> Result<Vec<Extra>, Error>;
+    async fn list(&self, query: ListQuery) -> Result<Pagination, Error>;
     async fn update(&mut self, extra: &Extra) -> Result<(), Error>;
+    async fn usages(&self, id: Id) -> Result<Vec<Usage>, Error>;
+}
+
+#[derive(Clone, Debug)]
+pub struct ListQuery {
+    pub page: NonZeroU32,
+    pub limit: NonZeroU8,
+    /// Only extras available to the catalog, either scoped to it or global
+    pub catalog_id: Option<catalog::Id>,
+    pub category: Option<Category>,
+    /// Only extras whose name starts with it, ignoring case
+    pub name: Option<String>,
+    /// Only extras priced in the same currency for at least this amount
+    pub min_price: Option<Money>,
+    /// Only extras priced in the same currency for at most this amount
+    pub max_price: Option<Money>,
+    pub sort: Sort,
+}
+
+#[derive(Clone, Debug)]
+pub struct Pagination {
+    pub count: u64,
+    pub page: NonZeroU32,
+    pub limit: NonZeroU8,
+    pub items: Vec<Extra>,
+}
+
+/// Product offering an extra
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Usage {
+    pub product_id: product::Id,
+    pub catalog_id: catalog::Id,
+    pub product_name: product::Name,
 }
@@ -1,6 +1,5 @@
 use std::fmt;
 
-use rust_decimal::Decimal;
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -89,47 +88,52 @@ impl fmt::Display for Name {
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
-pub struct Price(Decimal);
-
-impl Price {
-    #[must_use]
-    pub fn new(value: Decimal) -> Self {
-        Self(value.trunc_with_scale(2))
-    }
-
-    #[must_use]
-    pub fn from_cents(value: u64) -> Self {
-        let num = i64::try_from(value).unwrap_or(i64::MAX);
-        Self(Decimal::new(num, 2))
-    }
+/// Kind of extra, such as a sauce poured over a burger or a side served
+/// along with it
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Category {
+    Sauce,
+    Side,
+    Topping,
 }
 
-impl Price {
-    #[must_use]
-    pub fn decimal(&self) -> Decimal {
-        self.0
+impl Category {
+    /// Try parsing `value` into [`Category`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `value` is not a known [`Category`]
+    pub fn parse_str(value: &str) -> Result<Self, CategoryError> {
+        match value {
+            "sauce" => Ok(Self::Sauce),
+            "side" => Ok(Self::Side),
+            "topping" => Ok(Self::Topping),
+            other => Err(CategoryError::Parse(Box::from(other))),
+        }
     }
+}
 
-    // TODO: maybe i should return an error here and improve performance
+impl Category {
     #[must_use]
-    pub fn to_cents(&self) -> u64 {
-        use rust_decimal::prelude::ToPrimitive;
-        let cents = self.0.saturating_mul(Decimal::new(100, 0));
-        cents.to_u64().unwrap_or_default()
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Sauce => "sauce",
+            Self::Side => "side",
+            Self::Topping => "topping",
+        }
     }
 }
 
-impl From<Decimal> for Price {
-    fn from(value: Decimal) -> Self {
-        Self::new(value)
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
     }
 }
 
-impl fmt::Display for Price {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.0.fmt(f)
-    }
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum CategoryError {
+    #[error("Provided string `{0}` is not a valid product extra category")]
+    Parse(Box<str>),
 }
 
 #[derive(Clone, Debug, Eq, Error, PartialEq)]
@@ -167,4 +171,12 @@ mod tests {
             assert_eq!(name.as_ref().map(Name::as_str), Ok(n.trim()));
         }
     }
+
+    #[test]
+    fn parse_category_works() {
+        for category in [Category::Sauce, Category::Side, Category::Topping] {
+            assert_eq!(Category::parse_str(category.as_str()), Ok(category));
+        }
+        assert!(Category::parse_str("drink").is_err());
+    }
 }
@@ -0,0 +1,15 @@
+mod entity;
+mod error;
+mod pricing;
+mod repository;
+mod vo;
+
+pub use entity::{HappyHour, HappyHourConfig};
+pub use error::{ConflictKind, Error, NotFoundKind};
+pub use pricing::{Applied, HappyHours};
+pub use repository::Repository;
+pub use vo::{Id, Name, NameError, ParseIdError};
+
+pub use crate::core::schedule::{
+    ParseWeekdayError, ParseZoneError, Weekdays, WeekdaysError, Window, WindowError, Zone,
+};
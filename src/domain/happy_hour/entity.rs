@@ -0,0 +1,101 @@
+use time::OffsetDateTime;
+
+use super::{Error, Id, Name, Window};
+use crate::catalog;
+use crate::core::metadata;
+use crate::core::money::{Money, Rounding};
+use crate::product;
+use crate::promotion::Percentage;
+
+/// Scheduled discount over the prices of a catalog products, optionally
+/// restricted to a single kind, such as 20% off japanese food on weekday
+/// evenings
+#[derive(Clone, Debug)]
+pub struct HappyHour {
+    pub(super) id: Id,
+    catalog_id: catalog::Id,
+    pub name: Name,
+    pub kind: Option<product::Kind>,
+    pub percentage: Percentage,
+    pub window: Window,
+    pub metadata: metadata::Metadata,
+}
+
+impl HappyHour {
+    #[must_use]
+    pub fn new(
+        catalog_id: catalog::Id,
+        name: Name,
+        kind: Option<product::Kind>,
+        percentage: Percentage,
+        window: Window,
+    ) -> Self {
+        Self {
+            id: Id::new(),
+            catalog_id,
+            name,
+            kind,
+            percentage,
+            window,
+            metadata: metadata::Metadata::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn config(config: HappyHourConfig) -> Self {
+        Self {
+            id: config.id,
+            catalog_id: config.catalog_id,
+            name: config.name,
+            kind: config.kind,
+            percentage: config.percentage,
+            window: config.window,
+            metadata: config.metadata,
+        }
+    }
+}
+
+impl HappyHour {
+    #[must_use]
+    pub fn id(&self) -> Id {
+        self.id
+    }
+
+    #[must_use]
+    pub fn catalog_id(&self) -> catalog::Id {
+        self.catalog_id
+    }
+
+    #[must_use]
+    pub fn matches(&self, product: &product::Product) -> bool {
+        product.catalog_id() == self.catalog_id && self.kind.is_none_or(|kind| product.kind == kind)
+    }
+
+    #[must_use]
+    pub fn is_active_at(&self, at: OffsetDateTime) -> bool {
+        self.window.contains(at)
+    }
+
+    /// Amount taken off `price` during happy hour
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if the computation overflows
+    pub fn discount_for(&self, price: Money) -> Result<Money, Error> {
+        price
+            .checked_percentage(self.percentage.value())
+            .map(|discount| discount.round(Rounding::HalfUp))
+            .map_err(Error::any)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct HappyHourConfig {
+    pub id: Id,
+    pub catalog_id: catalog::Id,
+    pub name: Name,
+    pub kind: Option<product::Kind>,
+    pub percentage: Percentage,
+    pub window: Window,
+    pub metadata: metadata::Metadata,
+}
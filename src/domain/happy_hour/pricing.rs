@@ -0,0 +1,156 @@
+use time::OffsetDateTime;
+
+use super::{Error, HappyHour, Id, Name};
+use crate::core::money::Money;
+use crate::product;
+
+/// Happy hour in effect over a price, with the resulting effective price
+#[derive(Clone, Debug)]
+pub struct Applied {
+    pub happy_hour_id: Id,
+    pub name: Name,
+    pub discount: Money,
+    pub price: Money,
+}
+
+/// Set of happy hours, resolving which one is in effect for each product
+#[derive(Clone, Debug, Default)]
+pub struct HappyHours(Vec<HappyHour>);
+
+impl HappyHours {
+    #[must_use]
+    pub fn new(happy_hours: Vec<HappyHour>) -> Self {
+        Self(happy_hours)
+    }
+}
+
+impl HappyHours {
+    #[must_use]
+    pub fn as_slice(&self) -> &[HappyHour] {
+        &self.0
+    }
+
+    /// Happy hour giving the biggest discount to `product` at `at`, where ties
+    /// are broken by the oldest happy hour
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if computing any discount overflows
+    pub fn best_for(
+        &self,
+        product: &product::Product,
+        at: OffsetDateTime,
+    ) -> Result<Option<Applied>, Error> {
+        let mut best: Option<(&HappyHour, Money)> = None;
+        for happy_hour in &self.0 {
+            if !happy_hour.matches(product) || !happy_hour.is_active_at(at) {
+                continue;
+            }
+
+            let discount = happy_hour.discount_for(product.price)?;
+            let wins = best.is_none_or(|(current, current_discount)| {
+                (discount.amount(), current.id()) > (current_discount.amount(), happy_hour.id())
+            });
+            if wins {
+                best = Some((happy_hour, discount));
+            }
+        }
+
+        best.map(|(happy_hour, discount)| {
+            let price = product.price.checked_sub(discount).map_err(Error::any)?;
+            Ok(Applied {
+                happy_hour_id: happy_hour.id(),
+                name: happy_hour.name.clone(),
+                discount,
+                price,
+            })
+        })
+        .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{Date, Month, PrimitiveDateTime, Time, Weekday};
+
+    use super::*;
+    use crate::catalog;
+    use crate::core::money::Currency;
+    use crate::happy_hour::{Weekdays, Window, Zone};
+    use crate::promotion::Percentage;
+
+    #[test]
+    fn best_for_works() {
+        let catalog_id = catalog::Id::new();
+        let product = mocked_product(catalog_id, product::Kind::Japanese);
+        let all_products = mocked_happy_hour(catalog_id, None, "10");
+        let japanese = mocked_happy_hour(catalog_id, Some(product::Kind::Japanese), "20");
+        let happy_hours = HappyHours::new(vec![all_products, japanese.clone()]);
+
+        // 2024-08-12 is a monday
+        let applied = happy_hours
+            .best_for(&product, at(12, 18))
+            .expect("Valid pricing")
+            .expect("Happy hour in effect");
+        assert_eq!(applied.happy_hour_id, japanese.id());
+        assert_eq!(applied.price, Money::from_minor(3200, Currency::BRL));
+
+        let applied = happy_hours
+            .best_for(&product, at(12, 20))
+            .expect("Valid pricing");
+        assert!(applied.is_none());
+    }
+
+    #[test]
+    fn best_for_ignores_other_catalogs_and_kinds() {
+        let product = mocked_product(catalog::Id::new(), product::Kind::Burger);
+        let happy_hours = HappyHours::new(vec![
+            mocked_happy_hour(catalog::Id::new(), None, "10"),
+            mocked_happy_hour(product.catalog_id(), Some(product::Kind::Japanese), "20"),
+        ]);
+
+        let applied = happy_hours
+            .best_for(&product, at(12, 18))
+            .expect("Valid pricing");
+        assert!(applied.is_none());
+    }
+
+    fn at(day: u8, hour: u8) -> OffsetDateTime {
+        let date = Date::from_calendar_date(2024, Month::August, day).expect("Valid date");
+        let time = Time::from_hms(hour, 0, 0).expect("Valid time");
+        PrimitiveDateTime::new(date, time).assume_utc()
+    }
+
+    fn mocked_product(catalog_id: catalog::Id, kind: product::Kind) -> product::Product {
+        product::Product::new(
+            catalog_id,
+            product::Name::new("Sushi combo").expect("Valid product name"),
+            Money::from_minor(4000, Currency::BRL),
+            kind,
+            product::Extras::default(),
+        )
+    }
+
+    fn mocked_happy_hour(
+        catalog_id: catalog::Id,
+        kind: Option<product::Kind>,
+        percentage: &str,
+    ) -> HappyHour {
+        let weekdays = Weekdays::new(&[Weekday::Monday, Weekday::Tuesday]).expect("Valid weekdays");
+        let window = Window::new(
+            weekdays,
+            Time::from_hms(17, 0, 0).expect("Valid time"),
+            Time::from_hms(19, 0, 0).expect("Valid time"),
+            Zone::UTC,
+        )
+        .expect("Valid window");
+
+        HappyHour::new(
+            catalog_id,
+            Name::new("Happy hour").expect("Valid happy hour name"),
+            kind,
+            Percentage::parse_str(percentage).expect("Valid percentage"),
+            window,
+        )
+    }
+}
@@ -0,0 +1,13 @@
+use super::error::Error;
+use super::{HappyHour, HappyHours, Id};
+use crate::catalog;
+
+// TODO: remove when stabilized
+#[allow(async_fn_in_trait)]
+pub trait Repository: Send + Clone {
+    async fn all(&self, catalog_id: catalog::Id) -> Result<HappyHours, Error>;
+    async fn create(&mut self, happy_hour: &HappyHour) -> Result<(), Error>;
+    async fn delete(&mut self, id: Id, catalog_id: catalog::Id) -> Result<HappyHour, Error>;
+    async fn find(&self, id: Id, catalog_id: catalog::Id) -> Result<HappyHour, Error>;
+    async fn update(&mut self, happy_hour: &HappyHour) -> Result<(), Error>;
+}
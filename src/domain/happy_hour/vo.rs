@@ -0,0 +1,102 @@
+use std::fmt;
+
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::core::string::trim_in_place;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Id(Uuid);
+
+impl Id {
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::now_v7())
+    }
+
+    /// Try parsing `value` into [`Id`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `value` is not a valid [`Id`]
+    pub fn parse_str(value: &str) -> Result<Self, ParseIdError> {
+        Uuid::parse_str(value)
+            .map(Self)
+            .map_err(|_| ParseIdError(Box::from(value)))
+    }
+}
+
+impl Id {
+    #[must_use]
+    pub fn uuid(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for Id {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for Id {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<Uuid> for Id {
+    fn from(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Name(String);
+
+impl Name {
+    pub const MAX_LEN: usize = 64;
+
+    /// Try parsing `name` into [`Name`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `name` is empty or longer than [`Name::MAX_LEN`]
+    pub fn new(name: impl Into<String>) -> Result<Self, NameError> {
+        let mut name: String = name.into();
+        trim_in_place(&mut name);
+        if name.is_empty() {
+            return Err(NameError::Empty);
+        }
+        if name.len() > Self::MAX_LEN {
+            return Err(NameError::Length);
+        }
+
+        Ok(Self(name))
+    }
+}
+
+impl Name {
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[error("Provided string `{0}` is not a valid happy hour id")]
+pub struct ParseIdError(pub Box<str>);
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum NameError {
+    #[error("Happy hour name cannot be empty")]
+    Empty,
+    #[error("Happy hour name cannot have more than {len} characters", len = Name::MAX_LEN)]
+    Length,
+}
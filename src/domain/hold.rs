@@ -0,0 +1,9 @@
+mod entity;
+mod error;
+mod repository;
+mod vo;
+
+pub use entity::{Hold, HoldConfig};
+pub use error::{ConflictKind, Error, NotFoundKind};
+pub use repository::Repository;
+pub use vo::{Id, ParseIdError, Window, WindowError};
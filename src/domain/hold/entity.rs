@@ -0,0 +1,96 @@
+use time::OffsetDateTime;
+
+use super::{Id, Window};
+use crate::core::metadata;
+use crate::product;
+use crate::stock;
+
+/// Units of a product reserved until `expires_at`, after which they are
+/// released and available again
+#[derive(Clone, Debug)]
+pub struct Hold {
+    pub(super) id: Id,
+    product_id: product::Id,
+    quantity: stock::Quantity,
+    expires_at: OffsetDateTime,
+    pub metadata: metadata::Metadata,
+}
+
+impl Hold {
+    #[must_use]
+    pub fn new(product_id: product::Id, quantity: stock::Quantity, window: Window) -> Self {
+        let metadata = metadata::Metadata::new();
+        Self {
+            id: Id::new(),
+            product_id,
+            quantity,
+            expires_at: metadata.created_at() + window.duration(),
+            metadata,
+        }
+    }
+
+    #[must_use]
+    pub fn config(config: HoldConfig) -> Self {
+        Self {
+            id: config.id,
+            product_id: config.product_id,
+            quantity: config.quantity,
+            expires_at: config.expires_at,
+            metadata: config.metadata,
+        }
+    }
+}
+
+impl Hold {
+    #[must_use]
+    pub fn id(&self) -> Id {
+        self.id
+    }
+
+    #[must_use]
+    pub fn product_id(&self) -> product::Id {
+        self.product_id
+    }
+
+    #[must_use]
+    pub fn quantity(&self) -> stock::Quantity {
+        self.quantity
+    }
+
+    #[must_use]
+    pub fn expires_at(&self) -> OffsetDateTime {
+        self.expires_at
+    }
+
+    #[must_use]
+    pub fn is_expired_at(&self, at: OffsetDateTime) -> bool {
+        at >= self.expires_at
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct HoldConfig {
+    pub id: Id,
+    pub product_id: product::Id,
+    pub quantity: stock::Quantity,
+    pub expires_at: OffsetDateTime,
+    pub metadata: metadata::Metadata,
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn is_expired_at_works() {
+        let quantity = stock::Quantity::new(1).expect("Valid stock quantity");
+        let window = Window::from_seconds(60).expect("Valid hold window");
+        let hold = Hold::new(product::Id::new(), quantity, window);
+
+        let created_at = hold.metadata.created_at();
+        assert!(!hold.is_expired_at(created_at + Duration::seconds(59)));
+        assert!(hold.is_expired_at(created_at + Duration::seconds(60)));
+    }
+}
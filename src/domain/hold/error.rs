@@ -0,0 +1,63 @@
+use thiserror::Error;
+
+use super::Id;
+use crate::product;
+use crate::stock;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error(transparent)]
+    Conflict(ConflictKind),
+    #[error(transparent)]
+    Internal(Box<dyn std::error::Error>),
+    #[error(transparent)]
+    NotFound(NotFoundKind),
+}
+
+impl Error {
+    /// Utility function to create [`Error::Internal`] without manually
+    /// boxing the error
+    #[must_use]
+    pub fn any(err: impl Into<Box<dyn std::error::Error>>) -> Self {
+        Self::Internal(err.into())
+    }
+
+    #[must_use]
+    pub fn id_conflict(id: Id) -> Self {
+        Self::Conflict(ConflictKind::Id(id))
+    }
+
+    #[must_use]
+    pub fn id_not_found(id: Id) -> Self {
+        Self::NotFound(NotFoundKind::Id(id))
+    }
+
+    /// Maps a failed [`stock`] operation, such as holding more units than
+    /// available
+    #[must_use]
+    pub fn stock(err: stock::Error) -> Self {
+        match err {
+            stock::Error::Conflict(kind) => Self::Conflict(ConflictKind::Stock(kind)),
+            stock::Error::Internal(err) => Self::Internal(err),
+            stock::Error::NotFound(kind) => Self::NotFound(NotFoundKind::Stock(kind)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum ConflictKind {
+    #[error("Hold with id `{0}` already exists")]
+    Id(Id),
+    #[error(transparent)]
+    Stock(stock::ConflictKind),
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum NotFoundKind {
+    #[error("Hold with id `{0}` not found")]
+    Id(Id),
+    #[error(transparent)]
+    Product(product::NotFoundKind),
+    #[error(transparent)]
+    Stock(stock::NotFoundKind),
+}
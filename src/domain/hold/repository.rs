@@ -0,0 +1,15 @@
+use time::OffsetDateTime;
+
+use super::{Error, Hold, Id};
+
+// TODO: remove when stabilized
+#[allow(async_fn_in_trait)]
+pub trait Repository: Send + Clone {
+    /// Persist `hold` only if its product has enough units available, even
+    /// when other holds are created concurrently
+    async fn create(&mut self, hold: &Hold) -> Result<(), Error>;
+    async fn delete(&mut self, id: Id) -> Result<Hold, Error>;
+    async fn find(&self, id: Id) -> Result<Hold, Error>;
+    /// Delete holds expired `at`, returning how many were released
+    async fn release_expired(&mut self, at: OffsetDateTime) -> Result<u64, Error>;
+}
@@ -0,0 +1,117 @@
+use std::fmt;
+
+use thiserror::Error;
+use time::Duration;
+use uuid::Uuid;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Id(Uuid);
+
+impl Id {
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::now_v7())
+    }
+
+    /// Try parsing `value` into [`Id`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `value` is not a valid [`Id`]
+    pub fn parse_str(value: &str) -> Result<Self, ParseIdError> {
+        match Uuid::parse_str(value) {
+            Ok(uuid) => Ok(Self(uuid)),
+            Err(_) => Err(ParseIdError(Box::from(value))),
+        }
+    }
+}
+
+impl Id {
+    #[must_use]
+    pub fn uuid(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for Id {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for Id {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<Uuid> for Id {
+    fn from(value: Uuid) -> Self {
+        Self(value)
+    }
+}
+
+/// How long a hold keeps its units reserved
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Window(Duration);
+
+impl Window {
+    pub const DEFAULT_SECONDS: u32 = 10 * 60;
+    pub const MAX_SECONDS: u32 = 24 * 60 * 60;
+
+    /// Try parsing `seconds` into [`Window`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `seconds` is zero or bigger than [`Window::MAX_SECONDS`]
+    pub fn from_seconds(seconds: u32) -> Result<Self, WindowError> {
+        match seconds {
+            0 => Err(WindowError::Zero),
+            s if s > Self::MAX_SECONDS => Err(WindowError::Max),
+            s => Ok(Self(Duration::seconds(i64::from(s)))),
+        }
+    }
+}
+
+impl Window {
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        self.0
+    }
+}
+
+impl Default for Window {
+    fn default() -> Self {
+        Self(Duration::seconds(i64::from(Self::DEFAULT_SECONDS)))
+    }
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[error("Provided string `{0}` is not a valid hold id")]
+pub struct ParseIdError(pub Box<str>);
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum WindowError {
+    #[error("Hold window cannot be zero")]
+    Zero,
+    #[error("Hold window cannot be longer than {max} seconds", max = Window::MAX_SECONDS)]
+    Max,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn window_from_seconds_works() {
+        assert_eq!(
+            Window::from_seconds(90).map(|window| window.duration()),
+            Ok(Duration::seconds(90))
+        );
+        assert_eq!(Window::from_seconds(0), Err(WindowError::Zero));
+        assert_eq!(
+            Window::from_seconds(Window::MAX_SECONDS + 1),
+            Err(WindowError::Max)
+        );
+    }
+}
@@ -0,0 +1,12 @@
+mod entity;
+mod error;
+mod repository;
+mod vo;
+
+pub use entity::{Consumption, Exhausted, Ingredient, IngredientConfig};
+pub use error::{ConflictKind, Error, NotFoundKind};
+pub use repository::Repository;
+pub use vo::{
+    Amount, AmountError, Id, Level, LevelError, Name, NameError, ParseIdError, ParseUnitError,
+    Portion, Recipe, RecipeError, Unit,
+};
@@ -0,0 +1,196 @@
+use std::collections::BTreeMap;
+
+use super::{ConflictKind, Error, Id, Level, Name, Recipe, Unit};
+use crate::core::metadata;
+use crate::product;
+
+#[derive(Clone, Debug)]
+pub struct Ingredient {
+    pub(super) id: Id,
+    pub name: Name,
+    pub unit: Unit,
+    level: Level,
+    pub metadata: metadata::Metadata,
+}
+
+impl Ingredient {
+    #[must_use]
+    pub fn new(name: Name, unit: Unit, level: Level) -> Self {
+        Self {
+            id: Id::new(),
+            name,
+            unit,
+            level,
+            metadata: metadata::Metadata::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn config(config: IngredientConfig) -> Self {
+        Self {
+            id: config.id,
+            name: config.name,
+            unit: config.unit,
+            level: config.level,
+            metadata: config.metadata,
+        }
+    }
+}
+
+impl Ingredient {
+    #[must_use]
+    pub fn id(&self) -> Id {
+        self.id
+    }
+
+    #[must_use]
+    pub fn level(&self) -> Level {
+        self.level
+    }
+
+    #[must_use]
+    pub fn is_exhausted(&self) -> bool {
+        self.level == Level::ZERO
+    }
+
+    pub fn set_level(&mut self, level: Level) {
+        self.level = level;
+    }
+
+    /// Deplete `amount` of ingredient from its level
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `amount` is bigger than ingredient level
+    pub fn consume(&mut self, amount: u64) -> Result<(), Error> {
+        let available = self.level.value();
+        let level = u32::try_from(amount)
+            .ok()
+            .and_then(|amount| available.checked_sub(amount))
+            .ok_or(Error::Conflict(ConflictKind::Insufficient {
+                ingredient_id: self.id,
+                requested: amount,
+                available,
+            }))?;
+
+        self.level = Level::new(level).map_err(Error::any)?;
+        self.metadata.update();
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct IngredientConfig {
+    pub id: Id,
+    pub name: Name,
+    pub unit: Unit,
+    pub level: Level,
+    pub metadata: metadata::Metadata,
+}
+
+/// Total amount of each ingredient used to prepare products with their
+/// extras, sorted by ingredient id
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Consumption(BTreeMap<Id, u64>);
+
+impl Consumption {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add ingredients used to prepare `recipe` as many `times` as requested
+    pub fn add(&mut self, recipe: &Recipe, times: u16) {
+        for portion in recipe.iter() {
+            let amount = u64::from(portion.amount.value()) * u64::from(times);
+            *self.0.entry(portion.ingredient_id).or_default() += amount;
+        }
+    }
+}
+
+impl Consumption {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Id, u64)> + '_ {
+        self.0.iter().map(|(id, amount)| (*id, *amount))
+    }
+
+    #[must_use]
+    pub fn ingredients_ids(&self) -> Vec<Id> {
+        self.0.keys().copied().collect()
+    }
+}
+
+/// Products that cannot be prepared, since their recipes need more of some
+/// ingredient than there is on hand
+#[derive(Clone, Debug, Default)]
+pub struct Exhausted(Vec<product::Id>);
+
+impl Exhausted {
+    #[must_use]
+    pub fn new(products_ids: Vec<product::Id>) -> Self {
+        Self(products_ids)
+    }
+}
+
+impl Exhausted {
+    #[must_use]
+    pub fn contains(&self, product_id: product::Id) -> bool {
+        self.0.contains(&product_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ingredient::{Amount, Portion};
+
+    fn recipe(portions: &[(Id, u32)]) -> Recipe {
+        let portions = portions
+            .iter()
+            .map(|(ingredient_id, amount)| Portion {
+                ingredient_id: *ingredient_id,
+                amount: Amount::new(*amount).expect("Valid ingredient amount"),
+            })
+            .collect();
+
+        Recipe::new(portions).expect("Valid recipe")
+    }
+
+    #[test]
+    fn consumption_add_sums_amounts() {
+        let bun = Id::new();
+        let beef = Id::new();
+        let cheddar = Id::new();
+
+        let mut consumption = Consumption::new();
+        consumption.add(&recipe(&[(bun, 1), (beef, 150)]), 2);
+        consumption.add(&recipe(&[(cheddar, 30), (beef, 50)]), 2);
+
+        let mut expected = vec![(bun, 2), (beef, 400), (cheddar, 60)];
+        expected.sort_by_key(|(id, _)| *id);
+        assert_eq!(consumption.iter().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn consume_with_insufficient_level() {
+        let name = Name::new("Beef").expect("Valid ingredient name");
+        let level = Level::new(100).expect("Valid ingredient level");
+        let mut ingredient = Ingredient::new(name, Unit::Gram, level);
+
+        assert!(matches!(
+            ingredient.consume(150),
+            Err(Error::Conflict(ConflictKind::Insufficient {
+                requested: 150,
+                available: 100,
+                ..
+            }))
+        ));
+
+        assert!(ingredient.consume(100).is_ok());
+        assert!(ingredient.is_exhausted());
+    }
+}
@@ -0,0 +1,29 @@
+use super::{Consumption, Error, Exhausted, Id, Ingredient, Recipe};
+use crate::extra;
+use crate::product;
+
+// TODO: remove when stabilized
+#[allow(async_fn_in_trait)]
+pub trait Repository: Send + Clone {
+    async fn all(&self) -> Result<Vec<Ingredient>, Error>;
+    async fn create(&mut self, ingredient: &Ingredient) -> Result<(), Error>;
+    async fn delete(&mut self, id: Id) -> Result<Ingredient, Error>;
+    async fn find(&self, id: Id) -> Result<Ingredient, Error>;
+    async fn find_many(&self, ids: &[Id]) -> Result<Vec<Ingredient>, Error>;
+    async fn update(&mut self, ingredient: &Ingredient) -> Result<(), Error>;
+    async fn product_recipe(&self, product_id: product::Id) -> Result<Recipe, Error>;
+    async fn set_product_recipe(
+        &mut self,
+        product_id: product::Id,
+        recipe: &Recipe,
+    ) -> Result<(), Error>;
+    async fn extra_recipe(&self, extra_id: extra::Id) -> Result<Recipe, Error>;
+    async fn set_extra_recipe(&mut self, extra_id: extra::Id, recipe: &Recipe)
+        -> Result<(), Error>;
+    /// Deplete every ingredient of `consumption` at once, failing without
+    /// depleting any when one of them is insufficient
+    async fn consume(&mut self, consumption: &Consumption) -> Result<Vec<Ingredient>, Error>;
+    /// Find which of `products_ids` have an exhausted ingredient in their
+    /// recipes
+    async fn exhausted(&self, products_ids: &[product::Id]) -> Result<Exhausted, Error>;
+}
@@ -0,0 +1,320 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::core::string::trim_in_place;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Id(Uuid);
+
+impl Id {
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::now_v7())
+    }
+
+    /// Try parsing `value` into [`Id`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `value` is not a valid [`Id`]
+    pub fn parse_str(value: &str) -> Result<Self, ParseIdError> {
+        match Uuid::parse_str(value) {
+            Ok(uuid) => Ok(Self(uuid)),
+            Err(_) => Err(ParseIdError(Box::from(value))),
+        }
+    }
+}
+
+impl Id {
+    #[must_use]
+    pub fn uuid(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for Id {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for Id {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<Uuid> for Id {
+    fn from(value: Uuid) -> Self {
+        Self(value)
+    }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Name(String);
+
+impl Name {
+    pub const MAX_LEN: usize = 64;
+
+    /// Try parsing `name` into [`Name`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `name` is empty or longer than [`Name::MAX_LEN`]
+    pub fn new(name: impl Into<String>) -> Result<Self, NameError> {
+        let mut name: String = name.into();
+        trim_in_place(&mut name);
+
+        if name.is_empty() {
+            return Err(NameError::Empty);
+        } else if name.len() > Self::MAX_LEN {
+            return Err(NameError::Length);
+        }
+
+        Ok(Self(name))
+    }
+}
+
+impl Name {
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Unit ingredient amounts are measured in
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Unit {
+    Gram,
+    Milliliter,
+    Piece,
+}
+
+impl Unit {
+    /// Try parsing `value` into [`Unit`]
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseUnitError`] when `value` cannot be parsed to [`Unit`]
+    pub fn parse_str(value: &str) -> Result<Self, ParseUnitError> {
+        match value {
+            "gram" => Ok(Self::Gram),
+            "milliliter" => Ok(Self::Milliliter),
+            "piece" => Ok(Self::Piece),
+            other => Err(ParseUnitError(Box::from(other))),
+        }
+    }
+}
+
+impl Unit {
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Gram => "gram",
+            Self::Milliliter => "milliliter",
+            Self::Piece => "piece",
+        }
+    }
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// Amount of an ingredient on hand, measured in its [`Unit`]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Level(u32);
+
+impl Level {
+    pub const MAX: u32 = 100_000_000;
+    pub const ZERO: Self = Self(0);
+
+    /// Try parsing `value` into [`Level`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `value` is bigger than [`Level::MAX`]
+    pub fn new(value: u32) -> Result<Self, LevelError> {
+        if value > Self::MAX {
+            return Err(LevelError);
+        }
+
+        Ok(Self(value))
+    }
+}
+
+impl Level {
+    #[must_use]
+    pub fn value(&self) -> u32 {
+        self.0
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Amount of an ingredient used by a single unit of a recipe, measured in
+/// the ingredient [`Unit`]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Amount(u32);
+
+impl Amount {
+    /// Try parsing `value` into [`Amount`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `value` is zero or bigger than [`Level::MAX`]
+    pub fn new(value: u32) -> Result<Self, AmountError> {
+        match value {
+            0 => Err(AmountError::Zero),
+            v if v > Level::MAX => Err(AmountError::Max),
+            v => Ok(Self(v)),
+        }
+    }
+}
+
+impl Amount {
+    #[must_use]
+    pub fn value(&self) -> u32 {
+        self.0
+    }
+}
+
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Portion {
+    pub ingredient_id: Id,
+    pub amount: Amount,
+}
+
+/// Ingredients used by a product or an extra, each one at most once
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Recipe(Vec<Portion>);
+
+impl Recipe {
+    pub const MAX_LEN: usize = 32;
+
+    /// Try parsing `portions` into [`Recipe`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `portions` has more than [`Recipe::MAX_LEN`]
+    /// items or repeats an ingredient
+    pub fn new(portions: Vec<Portion>) -> Result<Self, RecipeError> {
+        if portions.len() > Self::MAX_LEN {
+            return Err(RecipeError::Length);
+        }
+
+        let mut ingredients_ids = HashSet::with_capacity(portions.len());
+        for portion in &portions {
+            if !ingredients_ids.insert(portion.ingredient_id) {
+                return Err(RecipeError::Duplicated(portion.ingredient_id));
+            }
+        }
+
+        Ok(Self(portions))
+    }
+}
+
+impl Recipe {
+    #[must_use]
+    pub fn as_slice(&self) -> &[Portion] {
+        &self.0
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Portion> {
+        self.0.iter()
+    }
+
+    #[must_use]
+    pub fn ingredients_ids(&self) -> Vec<Id> {
+        self.0.iter().map(|portion| portion.ingredient_id).collect()
+    }
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[error("Provided string `{0}` is not a valid ingredient id")]
+pub struct ParseIdError(pub Box<str>);
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[error("Provided string `{0}` is not a valid ingredient unit")]
+pub struct ParseUnitError(pub Box<str>);
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum NameError {
+    #[error("Ingredient name cannot be empty")]
+    Empty,
+    #[error("Ingredient name cannot have more than {len} characters", len = Name::MAX_LEN)]
+    Length,
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[error("Ingredient level cannot be bigger than {max}", max = Level::MAX)]
+pub struct LevelError;
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum AmountError {
+    #[error("Ingredient amount cannot be zero")]
+    Zero,
+    #[error("Ingredient amount cannot be bigger than {max}", max = Level::MAX)]
+    Max,
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum RecipeError {
+    #[error("Recipe cannot have more than {len} ingredients", len = Recipe::MAX_LEN)]
+    Length,
+    #[error("Ingredient `{0}` cannot appear more than once in a recipe")]
+    Duplicated(Id),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_name_works() {
+        assert_eq!(Name::new("  Bun ").map(|name| name.0), Ok("Bun".into()));
+        assert_eq!(Name::new("   "), Err(NameError::Empty));
+
+        let big_name = "a".repeat(Name::MAX_LEN + 1);
+        assert_eq!(Name::new(big_name), Err(NameError::Length));
+    }
+
+    #[test]
+    fn new_recipe_with_duplicated_ingredient() {
+        let ingredient_id = Id::new();
+        let amount = Amount::new(150).expect("Valid ingredient amount");
+        let portion = Portion {
+            ingredient_id,
+            amount,
+        };
+
+        let result = Recipe::new(vec![portion, portion]);
+        assert_eq!(result, Err(RecipeError::Duplicated(ingredient_id)));
+    }
+}
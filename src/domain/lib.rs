@@ -3,7 +3,21 @@
 // TODO: remove later
 #![allow(dead_code)]
 
+pub mod cart;
 pub mod catalog;
 pub mod core;
+pub mod exchange;
 pub mod extra;
+pub mod happy_hour;
+pub mod hold;
+pub mod ingredient;
+pub mod modifier;
+pub mod order;
+pub mod payment;
 pub mod product;
+pub mod promotion;
+pub mod sale;
+pub mod stock;
+pub mod tax;
+pub mod ticket;
+pub mod variant;
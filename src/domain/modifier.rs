@@ -0,0 +1,8 @@
+mod entity;
+mod vo;
+
+pub use entity::{Group, GroupConfig};
+pub use vo::{
+    Groups, GroupsError, Id, Limits, LimitsError, Name, NameError, Options, OptionsError,
+    ParseIdError, SelectionError,
+};
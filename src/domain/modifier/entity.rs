@@ -0,0 +1,74 @@
+use super::{Id, Limits, Name, Options};
+use crate::core::metadata;
+use crate::product;
+
+/// Group of extras a product is customized with, such as "Sauces", along with
+/// how many of them must be selected
+#[derive(Clone, Debug)]
+pub struct Group {
+    pub(super) id: Id,
+    pub(super) product_id: product::Id,
+    pub name: Name,
+    pub limits: Limits,
+    pub options: Options,
+    pub metadata: metadata::Metadata,
+}
+
+impl Group {
+    #[must_use]
+    pub fn new(product_id: product::Id, name: Name, limits: Limits, options: Options) -> Self {
+        Self {
+            id: Id::new(),
+            product_id,
+            name,
+            limits,
+            options,
+            metadata: metadata::Metadata::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn config(config: GroupConfig) -> Self {
+        Self {
+            id: config.id,
+            product_id: config.product_id,
+            name: config.name,
+            limits: config.limits,
+            options: config.options,
+            metadata: config.metadata,
+        }
+    }
+}
+
+impl Group {
+    #[must_use]
+    pub fn id(&self) -> Id {
+        self.id
+    }
+
+    #[must_use]
+    pub fn product_id(&self) -> product::Id {
+        self.product_id
+    }
+
+    /// Number of extras from group among `selections`, where each extra
+    /// counts as many times as it is selected
+    #[must_use]
+    pub fn count(&self, selections: &[product::ExtraSelection]) -> u32 {
+        selections
+            .iter()
+            .filter(|selection| self.options.contains(selection.id))
+            .map(|selection| u32::from(selection.quantity.value()))
+            .sum()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct GroupConfig {
+    pub id: Id,
+    pub product_id: product::Id,
+    pub name: Name,
+    pub limits: Limits,
+    pub options: Options,
+    pub metadata: metadata::Metadata,
+}
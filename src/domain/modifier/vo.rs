@@ -0,0 +1,444 @@
+use std::fmt;
+
+use thiserror::Error;
+use uuid::Uuid;
+
+use super::Group;
+use crate::core::string::trim_in_place;
+use crate::extra;
+use crate::product;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Id(Uuid);
+
+impl Id {
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::now_v7())
+    }
+
+    /// Try parsing `value` into [`Id`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `value` cannot be parsed into [`Id`]
+    pub fn parse_str(value: &str) -> Result<Self, ParseIdError> {
+        match Uuid::parse_str(value) {
+            Ok(uuid) => Ok(Self(uuid)),
+            Err(_) => Err(ParseIdError(Box::from(value))),
+        }
+    }
+}
+
+impl Id {
+    #[must_use]
+    pub fn uuid(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for Id {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for Id {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<Uuid> for Id {
+    fn from(value: Uuid) -> Self {
+        Self(value)
+    }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Name(String);
+
+impl Name {
+    pub const MAX_LEN: usize = 32;
+
+    /// Try parsing `name` into [`Name`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `name` is empty or longer than [`Name::MAX_LEN`]
+    pub fn new(name: impl Into<String>) -> Result<Self, NameError> {
+        let mut name: String = name.into();
+        trim_in_place(&mut name);
+
+        if name.is_empty() {
+            return Err(NameError::Empty);
+        }
+        if name.len() > Self::MAX_LEN {
+            return Err(NameError::Length);
+        }
+
+        Ok(Self(name))
+    }
+}
+
+impl Name {
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// How many extras of a group must be selected, e.g. "pick 1" is `1..=1`
+/// and "up to 3" is `0..=3`
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Limits {
+    min: u8,
+    max: u8,
+}
+
+impl Limits {
+    pub const MAX: u8 = 32;
+
+    /// Try parsing `min` and `max` into [`Limits`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `max` is zero or bigger than [`Limits::MAX`], or
+    /// if `min` is bigger than `max`
+    pub fn new(min: u8, max: u8) -> Result<Self, LimitsError> {
+        if max == 0 || max > Self::MAX {
+            return Err(LimitsError::Max);
+        }
+        if min > max {
+            return Err(LimitsError::Range);
+        }
+
+        Ok(Self { min, max })
+    }
+}
+
+impl Limits {
+    #[must_use]
+    pub fn min(&self) -> u8 {
+        self.min
+    }
+
+    #[must_use]
+    pub fn max(&self) -> u8 {
+        self.max
+    }
+
+    /// Whether `count` extras are a valid selection
+    #[must_use]
+    pub fn contains(&self, count: u32) -> bool {
+        (u32::from(self.min)..=u32::from(self.max)).contains(&count)
+    }
+}
+
+/// Extras offered by a group, which never repeat
+#[derive(Clone, Debug, Default)]
+pub struct Options(Vec<extra::Id>);
+
+impl Options {
+    pub const MAX_LEN: usize = product::Extras::MAX_LEN;
+
+    /// Try parsing `extras_ids` into [`Options`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `extras_ids` is empty, has more items than
+    /// allowed or has a repeated extra
+    pub fn new(extras_ids: Vec<extra::Id>) -> Result<Self, OptionsError> {
+        if extras_ids.is_empty() {
+            return Err(OptionsError::Empty);
+        }
+        if extras_ids.len() > Self::MAX_LEN {
+            return Err(OptionsError::Length);
+        }
+        for (i, extra_id) in extras_ids.iter().enumerate() {
+            if extras_ids[..i].contains(extra_id) {
+                return Err(OptionsError::Repeated(*extra_id));
+            }
+        }
+
+        Ok(Self(extras_ids))
+    }
+}
+
+impl Options {
+    #[must_use]
+    pub fn as_slice(&self) -> &[extra::Id] {
+        &self.0
+    }
+
+    #[must_use]
+    pub fn contains(&self, extra_id: extra::Id) -> bool {
+        self.0.contains(&extra_id)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &extra::Id> {
+        self.0.iter()
+    }
+}
+
+/// Modifier groups of a product, which never share a name nor an extra
+#[derive(Clone, Debug, Default)]
+pub struct Groups(Vec<Group>);
+
+impl Groups {
+    pub const MAX_LEN: usize = 8;
+
+    /// Try parsing `groups` into [`Groups`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `groups` has more items than allowed, if two of
+    /// them share a name or if an extra is offered by more than one of them
+    pub fn new(groups: Vec<Group>) -> Result<Self, GroupsError> {
+        if groups.len() > Self::MAX_LEN {
+            return Err(GroupsError::Length);
+        }
+        for (i, group) in groups.iter().enumerate() {
+            let previous = &groups[..i];
+            if previous.iter().any(|other| other.name == group.name) {
+                return Err(GroupsError::Name(group.name.clone()));
+            }
+            let shared = group.options.iter().find(|&&extra_id| {
+                previous
+                    .iter()
+                    .any(|other| other.options.contains(extra_id))
+            });
+            if let Some(extra_id) = shared {
+                return Err(GroupsError::Extra(*extra_id));
+            }
+        }
+
+        Ok(Self(groups))
+    }
+}
+
+impl Groups {
+    #[must_use]
+    pub fn as_slice(&self) -> &[Group] {
+        &self.0
+    }
+
+    #[must_use]
+    pub fn find(&self, id: Id) -> Option<&Group> {
+        self.0.iter().find(|group| group.id() == id)
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Group> {
+        self.0.iter()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Check every extra offered by groups is among `extras` of product
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] with the first offered extra not in `extras`
+    pub fn check_bound(&self, extras: &product::Extras) -> Result<(), GroupsError> {
+        let unbound = self
+            .0
+            .iter()
+            .flat_map(|group| group.options.iter())
+            .find(|&&extra_id| extras.iter().all(|extra| extra.id() != extra_id));
+
+        match unbound {
+            Some(extra_id) => Err(GroupsError::Unbound(*extra_id)),
+            None => Ok(()),
+        }
+    }
+
+    /// Check `selections` of extras against the limits of every group, where
+    /// extras not offered by any group are left unchecked
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] with the first group having too few or too many of
+    /// its extras selected
+    pub fn validate(&self, selections: &[product::ExtraSelection]) -> Result<(), SelectionError> {
+        for group in &self.0 {
+            let count = group.count(selections);
+            if count < u32::from(group.limits.min()) {
+                return Err(SelectionError::Missing {
+                    group: group.name.clone(),
+                    min: group.limits.min(),
+                });
+            }
+            if count > u32::from(group.limits.max()) {
+                return Err(SelectionError::Exceeded {
+                    group: group.name.clone(),
+                    max: group.limits.max(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[error("Provided string `{0}` is not a valid modifier group id")]
+pub struct ParseIdError(pub Box<str>);
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum NameError {
+    #[error("Modifier group name cannot be empty")]
+    Empty,
+    #[error("Modifier group name cannot have more than {len} characters", len = Name::MAX_LEN)]
+    Length,
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum LimitsError {
+    #[error("Modifier group maximum must be between 1 and {max}", max = Limits::MAX)]
+    Max,
+    #[error("Modifier group minimum cannot be bigger than its maximum")]
+    Range,
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum OptionsError {
+    #[error("Modifier group must offer at least one extra")]
+    Empty,
+    #[error("Modifier group cannot offer more than {len} extras", len = Options::MAX_LEN)]
+    Length,
+    #[error("Modifier group offers extra `{0}` more than once")]
+    Repeated(extra::Id),
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum GroupsError {
+    #[error("Product cannot have more than {len} modifier groups", len = Groups::MAX_LEN)]
+    Length,
+    #[error("Product already has a modifier group named `{0}`")]
+    Name(Name),
+    #[error("Extra `{0}` is already offered by another modifier group of product")]
+    Extra(extra::Id),
+    #[error("Extra `{0}` offered by a modifier group is not bound to product")]
+    Unbound(extra::Id),
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum SelectionError {
+    #[error("Modifier group `{group}` requires at least {min} extras selected")]
+    Missing { group: Name, min: u8 },
+    #[error("Modifier group `{group}` allows at most {max} extras selected")]
+    Exceeded { group: Name, max: u8 },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::quantity::Quantity;
+
+    fn group(name: &str, limits: Limits, extras_ids: &[extra::Id]) -> Group {
+        Group::new(
+            product::Id::new(),
+            Name::new(name).expect("Valid modifier group name"),
+            limits,
+            Options::new(extras_ids.to_vec()).expect("Valid modifier group options"),
+        )
+    }
+
+    fn select(id: extra::Id, quantity: u16) -> product::ExtraSelection {
+        product::ExtraSelection {
+            id,
+            quantity: Quantity::new(quantity).expect("Valid quantity"),
+        }
+    }
+
+    #[test]
+    fn new_limits_works() {
+        assert!(Limits::new(0, 3).is_ok());
+        assert!(Limits::new(1, 1).is_ok());
+        assert_eq!(Limits::new(0, 0), Err(LimitsError::Max));
+        assert_eq!(Limits::new(0, Limits::MAX + 1), Err(LimitsError::Max));
+        assert_eq!(Limits::new(2, 1), Err(LimitsError::Range));
+    }
+
+    #[test]
+    fn new_options_works() {
+        let extra_id = extra::Id::new();
+        assert_eq!(Options::new(vec![]).err(), Some(OptionsError::Empty));
+        assert_eq!(
+            Options::new(vec![extra_id, extra::Id::new(), extra_id]).err(),
+            Some(OptionsError::Repeated(extra_id))
+        );
+    }
+
+    #[test]
+    fn new_groups_with_shared_extra() {
+        let limits = Limits::new(0, 1).expect("Valid limits");
+        let (ketchup, bacon) = (extra::Id::new(), extra::Id::new());
+
+        let sauces = group("Sauces", limits, &[ketchup]);
+        let toppings = group("Toppings", limits, &[bacon, ketchup]);
+        assert_eq!(
+            Groups::new(vec![sauces.clone(), toppings]).err(),
+            Some(GroupsError::Extra(ketchup))
+        );
+
+        let sauces_again = group("Sauces", limits, &[bacon]);
+        assert_eq!(
+            Groups::new(vec![sauces, sauces_again]).err(),
+            Some(GroupsError::Name(
+                Name::new("Sauces").expect("Valid modifier group name")
+            ))
+        );
+    }
+
+    #[test]
+    fn validate_selections_works() {
+        let (ketchup, mustard, bacon, onion) = (
+            extra::Id::new(),
+            extra::Id::new(),
+            extra::Id::new(),
+            extra::Id::new(),
+        );
+        let sauces = group(
+            "Sauces",
+            Limits::new(1, 1).expect("Valid limits"),
+            &[ketchup, mustard],
+        );
+        let toppings = group(
+            "Toppings",
+            Limits::new(0, 3).expect("Valid limits"),
+            &[bacon, onion],
+        );
+        let groups = Groups::new(vec![sauces, toppings]).expect("Valid modifier groups");
+
+        assert!(groups
+            .validate(&[select(ketchup, 1), select(bacon, 2), select(onion, 1)])
+            .is_ok());
+        assert!(matches!(
+            groups.validate(&[select(bacon, 1)]),
+            Err(SelectionError::Missing { min: 1, .. })
+        ));
+        assert!(matches!(
+            groups.validate(&[select(ketchup, 1), select(mustard, 1)]),
+            Err(SelectionError::Exceeded { max: 1, .. })
+        ));
+        assert!(matches!(
+            groups.validate(&[select(mustard, 1), select(bacon, 2), select(onion, 2)]),
+            Err(SelectionError::Exceeded { max: 3, .. })
+        ));
+    }
+}
@@ -0,0 +1,306 @@
+use super::{Error, Id, ItemId, Items};
+use crate::catalog;
+use crate::core::metadata;
+use crate::core::money::Money;
+use crate::core::quantity::Quantity;
+use crate::extra;
+use crate::product;
+
+#[derive(Clone, Debug)]
+pub struct Order {
+    pub(super) id: Id,
+    pub items: Items,
+    pub metadata: metadata::Metadata,
+}
+
+impl Order {
+    #[must_use]
+    pub fn new(items: Items) -> Self {
+        Self {
+            id: Id::new(),
+            items,
+            metadata: metadata::Metadata::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn config(config: OrderConfig) -> Self {
+        Self {
+            id: config.id,
+            items: config.items,
+            metadata: config.metadata,
+        }
+    }
+}
+
+impl Order {
+    #[must_use]
+    pub fn id(&self) -> Id {
+        self.id
+    }
+
+    /// Sum of every item total, already considering quantities
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if items are priced in different currencies or if
+    /// the sum overflows
+    pub fn total(&self) -> Result<Money, Error> {
+        let currency = self.items.as_slice()[0].price().currency();
+        let totals = self
+            .items
+            .iter()
+            .map(Item::total)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Money::checked_sum(currency, totals).map_err(Error::money)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct OrderConfig {
+    pub id: Id,
+    pub items: Items,
+    pub metadata: metadata::Metadata,
+}
+
+/// Snapshot of a [`product::Product`] at the moment it was ordered, so later
+/// changes to the product do not rewrite the order history
+#[derive(Clone, Debug)]
+pub struct Item {
+    id: ItemId,
+    snapshot: product::Snapshot,
+    quantity: Quantity,
+}
+
+impl Item {
+    /// Try creating an [`Item`] from `product` with the chosen `extras_ids`
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if any of `extras_ids` is not one of `product`
+    /// extras or is repeated, or if, taking a single unit of each, they break
+    /// the rules of any modifier group of `product`
+    pub fn new(
+        product: &product::Product,
+        extras_ids: &[extra::Id],
+        quantity: Quantity,
+    ) -> Result<Self, Error> {
+        let snapshot = product.snapshot(extras_ids).map_err(Error::snapshot)?;
+
+        Ok(Self {
+            id: ItemId::new(),
+            snapshot,
+            quantity,
+        })
+    }
+
+    #[must_use]
+    pub fn config(config: ItemConfig) -> Self {
+        Self {
+            id: config.id,
+            snapshot: product::Snapshot {
+                product_id: config.product_id,
+                catalog_id: config.catalog_id,
+                name: config.name,
+                price: config.price,
+                extras: config.extras,
+            },
+            quantity: config.quantity,
+        }
+    }
+}
+
+impl Item {
+    #[must_use]
+    pub fn id(&self) -> ItemId {
+        self.id
+    }
+
+    #[must_use]
+    pub fn product_id(&self) -> product::Id {
+        self.snapshot.product_id
+    }
+
+    #[must_use]
+    pub fn catalog_id(&self) -> catalog::Id {
+        self.snapshot.catalog_id
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &product::Name {
+        &self.snapshot.name
+    }
+
+    #[must_use]
+    pub fn price(&self) -> Money {
+        self.snapshot.price
+    }
+
+    #[must_use]
+    pub fn quantity(&self) -> Quantity {
+        self.quantity
+    }
+
+    #[must_use]
+    pub fn extras(&self) -> &[ItemExtra] {
+        &self.snapshot.extras
+    }
+
+    /// Price of a single unit of this item, including its extras
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if extras are priced in another currency or if the
+    /// sum overflows
+    pub fn unit_price(&self) -> Result<Money, Error> {
+        self.snapshot.unit_price().map_err(Error::money)
+    }
+
+    /// Price of this item considering its quantity
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if [`Item::unit_price`] fails or if the
+    /// multiplication overflows
+    pub fn total(&self) -> Result<Money, Error> {
+        self.unit_price()?
+            .checked_mul(self.quantity.value())
+            .map_err(Error::money)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ItemConfig {
+    pub id: ItemId,
+    pub product_id: product::Id,
+    pub catalog_id: catalog::Id,
+    pub name: product::Name,
+    pub price: Money,
+    pub quantity: Quantity,
+    pub extras: Vec<ItemExtra>,
+}
+
+/// Snapshot of an [`extra::Extra`] chosen for an [`Item`]
+pub type ItemExtra = product::ExtraSnapshot;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::money::Currency;
+    use crate::order::ConflictKind;
+
+    #[test]
+    fn new_item_works() {
+        let cheddar = extra::Extra::new(
+            extra::Name::new("Cheddar").expect("Valid extra name"),
+            Money::from_minor(200, Currency::BRL),
+        );
+        let bacon = extra::Extra::new(
+            extra::Name::new("Bacon").expect("Valid extra name"),
+            Money::from_minor(350, Currency::BRL),
+        );
+        let product = mocked_product(vec![cheddar.clone(), bacon]);
+        let quantity = Quantity::new(2).expect("Valid quantity");
+
+        let item = Item::new(&product, &[cheddar.id()], quantity).expect("Valid order item");
+        assert_eq!(item.product_id(), product.id());
+        assert_eq!(item.extras(), &[ItemExtra::from(&cheddar)]);
+        assert_eq!(
+            item.unit_price().ok(),
+            Some(Money::from_minor(2200, Currency::BRL))
+        );
+        assert_eq!(
+            item.total().ok(),
+            Some(Money::from_minor(4400, Currency::BRL))
+        );
+    }
+
+    #[test]
+    fn total_with_currency_conflict() {
+        let cheddar = extra::Extra::new(
+            extra::Name::new("Cheddar").expect("Valid extra name"),
+            Money::from_minor(200, Currency::USD),
+        );
+        let product = mocked_product(vec![cheddar.clone()]);
+        let quantity = Quantity::new(1).expect("Valid quantity");
+
+        let item = Item::new(&product, &[cheddar.id()], quantity).expect("Valid order item");
+        let order = Order::new(Items::new(vec![item]).expect("Valid order items"));
+        assert!(matches!(
+            order.total(),
+            Err(Error::Conflict(ConflictKind::Currency {
+                expected: Currency::BRL,
+                found: Currency::USD
+            }))
+        ));
+    }
+
+    #[test]
+    fn new_item_with_extra_not_found() {
+        use crate::order::NotFoundKind;
+
+        let product = mocked_product(vec![]);
+        let extra_id = extra::Id::new();
+        let quantity = Quantity::new(1).expect("Valid quantity");
+
+        let result = Item::new(&product, &[extra_id], quantity);
+        assert!(matches!(result, Err(Error::NotFound(NotFoundKind::Extra {
+            extra_id: err_extra_id,
+            product_id
+        })) if err_extra_id == extra_id && product_id == product.id()));
+    }
+
+    #[test]
+    fn new_item_with_repeated_extra() {
+        let cheddar = extra::Extra::new(
+            extra::Name::new("Cheddar").expect("Valid extra name"),
+            Money::from_minor(200, Currency::BRL),
+        );
+        let product = mocked_product(vec![cheddar.clone()]);
+        let quantity = Quantity::new(1).expect("Valid quantity");
+
+        let result = Item::new(&product, &[cheddar.id(), cheddar.id()], quantity);
+        assert!(matches!(
+            result,
+            Err(Error::Conflict(ConflictKind::ExtraRepeated(id))) if id == cheddar.id()
+        ));
+    }
+
+    #[test]
+    fn new_item_with_selection_conflict() {
+        use crate::modifier;
+
+        let ketchup = extra::Extra::new(
+            extra::Name::new("Ketchup").expect("Valid extra name"),
+            Money::from_minor(0, Currency::BRL),
+        );
+        let mut product = mocked_product(vec![ketchup.clone()]);
+        let sauces = modifier::Group::new(
+            product.id(),
+            modifier::Name::new("Sauces").expect("Valid modifier group name"),
+            modifier::Limits::new(1, 1).expect("Valid limits"),
+            modifier::Options::new(vec![ketchup.id()]).expect("Valid modifier group options"),
+        );
+        product.modifiers = modifier::Groups::new(vec![sauces]).expect("Valid modifier groups");
+        let quantity = Quantity::new(1).expect("Valid quantity");
+
+        let result = Item::new(&product, &[], quantity);
+        assert!(matches!(
+            result,
+            Err(Error::Conflict(ConflictKind::Selection(
+                modifier::SelectionError::Missing { min: 1, .. }
+            )))
+        ));
+    }
+
+    fn mocked_product(extras: Vec<extra::Extra>) -> product::Product {
+        product::Product::new(
+            catalog::Id::new(),
+            product::Name::new("Cheese Burger").expect("Valid product name"),
+            Money::from_minor(2000, Currency::BRL),
+            product::Kind::Burger,
+            product::Extras::new(extras).expect("Valid product extras"),
+        )
+    }
+}
@@ -0,0 +1,107 @@
+use thiserror::Error;
+
+use super::Id;
+use crate::core::money::{Currency, MoneyError};
+use crate::extra;
+use crate::modifier;
+use crate::product;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error(transparent)]
+    Conflict(ConflictKind),
+    #[error(transparent)]
+    Internal(Box<dyn std::error::Error>),
+    #[error(transparent)]
+    NotFound(NotFoundKind),
+}
+
+impl Error {
+    /// Utility function to create [`Error::Internal`] without manually
+    /// boxing the error
+    #[must_use]
+    pub fn any(err: impl Into<Box<dyn std::error::Error>>) -> Self {
+        Self::Internal(err.into())
+    }
+
+    #[must_use]
+    pub fn extra_not_found(extra_id: extra::Id, product_id: product::Id) -> Self {
+        Self::NotFound(NotFoundKind::Extra {
+            extra_id,
+            product_id,
+        })
+    }
+
+    #[must_use]
+    pub fn extra_repeated(extra_id: extra::Id) -> Self {
+        Self::Conflict(ConflictKind::ExtraRepeated(extra_id))
+    }
+
+    #[must_use]
+    pub fn id_conflict(id: Id) -> Self {
+        Self::Conflict(ConflictKind::Id(id))
+    }
+
+    #[must_use]
+    pub fn id_not_found(id: Id) -> Self {
+        Self::NotFound(NotFoundKind::Id(id))
+    }
+
+    /// Maps a failed [`Money`](crate::core::money::Money) operation, where
+    /// mixing currencies is a conflict and overflowing is unexpected
+    #[must_use]
+    pub fn money(err: MoneyError) -> Self {
+        match err {
+            MoneyError::CurrencyMismatch { expected, found } => {
+                Self::Conflict(ConflictKind::Currency { expected, found })
+            }
+            err @ MoneyError::Overflow => Self::any(err),
+        }
+    }
+
+    /// Maps a selection of extras breaking the rules of a modifier group to
+    /// a conflict
+    #[must_use]
+    pub fn selection(err: modifier::SelectionError) -> Self {
+        Self::Conflict(ConflictKind::Selection(err))
+    }
+
+    /// Maps a failed [`product::Product::snapshot`], where extras not bound
+    /// to product are not found and any other failure is a conflict
+    #[must_use]
+    pub fn snapshot(err: product::SnapshotError) -> Self {
+        match err {
+            product::SnapshotError::Extra {
+                extra_id,
+                product_id,
+            } => Self::extra_not_found(extra_id, product_id),
+            product::SnapshotError::Repeated(extra_id) => Self::extra_repeated(extra_id),
+            product::SnapshotError::Selection(err) => Self::selection(err),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum ConflictKind {
+    #[error("Order with id `{0}` already exists")]
+    Id(Id),
+    #[error("Order priced in `{expected}` cannot be combined with prices in `{found}`")]
+    Currency { expected: Currency, found: Currency },
+    #[error("Product extra `{0}` cannot be selected more than once")]
+    ExtraRepeated(extra::Id),
+    #[error(transparent)]
+    Selection(modifier::SelectionError),
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum NotFoundKind {
+    #[error("Order with id `{0}` not found")]
+    Id(Id),
+    #[error("Product extra with id `{extra_id}` not available for product {product_id}")]
+    Extra {
+        extra_id: extra::Id,
+        product_id: product::Id,
+    },
+    #[error(transparent)]
+    Product(product::NotFoundKind),
+}
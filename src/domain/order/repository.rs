@@ -0,0 +1,26 @@
+use std::num::{NonZeroU32, NonZeroU8};
+
+use super::{Error, Id, Order};
+
+// TODO: remove when stabilized
+#[allow(async_fn_in_trait)]
+pub trait Repository: Send + Clone {
+    async fn create(&mut self, order: &Order) -> Result<(), Error>;
+    async fn delete(&mut self, id: Id) -> Result<Order, Error>;
+    async fn find(&self, id: Id) -> Result<Order, Error>;
+    async fn list(&self, query: ListQuery) -> Result<Pagination, Error>;
+}
+
+#[derive(Clone, Debug)]
+pub struct ListQuery {
+    pub page: NonZeroU32,
+    pub limit: NonZeroU8,
+}
+
+#[derive(Clone, Debug)]
+pub struct Pagination {
+    pub count: u64,
+    pub page: NonZeroU32,
+    pub limit: NonZeroU8,
+    pub items: Vec<Order>,
+}
@@ -0,0 +1,167 @@
+use std::fmt;
+
+use thiserror::Error;
+use uuid::Uuid;
+
+use super::Item;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Id(Uuid);
+
+impl Id {
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::now_v7())
+    }
+
+    /// Try parsing `value` into [`Id`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `value` is not a valid [`Id`]
+    pub fn parse_str(value: &str) -> Result<Self, ParseIdError> {
+        match Uuid::parse_str(value) {
+            Ok(uuid) => Ok(Self(uuid)),
+            Err(_) => Err(ParseIdError(Box::from(value))),
+        }
+    }
+}
+
+impl Id {
+    #[must_use]
+    pub fn uuid(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for Id {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for Id {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<Uuid> for Id {
+    fn from(value: Uuid) -> Self {
+        Self(value)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ItemId(Uuid);
+
+impl ItemId {
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::now_v7())
+    }
+}
+
+impl ItemId {
+    #[must_use]
+    pub fn uuid(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for ItemId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for ItemId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<Uuid> for ItemId {
+    fn from(value: Uuid) -> Self {
+        Self(value)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Items(Vec<Item>);
+
+impl Items {
+    pub const MAX_LEN: usize = 64;
+
+    /// Try parsing `items` into [`Items`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `items` is empty or has more items than allowed
+    pub fn new(items: Vec<Item>) -> Result<Self, ItemsError> {
+        if items.is_empty() {
+            return Err(ItemsError::Empty);
+        }
+
+        if items.len() > Self::MAX_LEN {
+            return Err(ItemsError::Length);
+        }
+
+        Ok(Self(items))
+    }
+}
+
+impl Items {
+    #[must_use]
+    pub fn as_slice(&self) -> &[Item] {
+        &self.0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Item> {
+        self.0.iter()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[error("Provided string `{0}` is not a valid order id")]
+pub struct ParseIdError(pub Box<str>);
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum ItemsError {
+    #[error("Order must have at least one item")]
+    Empty,
+    #[error("Order cannot have more than {len} items", len = Items::MAX_LEN)]
+    Length,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_id_works() {
+        let valid = Id::new().to_string();
+        assert!(Id::parse_str(&valid).is_ok());
+
+        let invalid = "invalid-id";
+        assert_eq!(
+            Id::parse_str(invalid),
+            Err(ParseIdError(Box::from(invalid)))
+        );
+    }
+
+    #[test]
+    fn new_items_with_empty() {
+        assert_eq!(Items::new(vec![]).err(), Some(ItemsError::Empty));
+    }
+}
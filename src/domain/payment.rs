@@ -0,0 +1,11 @@
+mod entity;
+mod error;
+mod provider;
+mod repository;
+mod vo;
+
+pub use entity::{Payment, PaymentConfig};
+pub use error::{ConflictKind, Error, NotFoundKind};
+pub use provider::{PaymentProvider, ProviderError};
+pub use repository::Repository;
+pub use vo::{Id, ParseIdError, ParseStatusError, Reference, ReferenceError, Status};
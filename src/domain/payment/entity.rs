@@ -0,0 +1,256 @@
+use super::{Error, Id, Reference, Status};
+use crate::core::metadata;
+use crate::core::money::Money;
+
+/// Attempt of charging `amount` through a payment provider, recorded with
+/// the provider reference so it can be reconciled later
+#[derive(Clone, Debug)]
+pub struct Payment {
+    pub(super) id: Id,
+    amount: Money,
+    provider: String,
+    status: Status,
+    reference: Option<Reference>,
+    failure: Option<String>,
+    pub metadata: metadata::Metadata,
+}
+
+impl Payment {
+    /// Create a pending [`Payment`] of `amount` to be charged through `provider`
+    #[must_use]
+    pub fn new(amount: Money, provider: impl Into<String>) -> Self {
+        Self {
+            id: Id::new(),
+            amount,
+            provider: provider.into(),
+            status: Status::Pending,
+            reference: None,
+            failure: None,
+            metadata: metadata::Metadata::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn config(config: PaymentConfig) -> Self {
+        Self {
+            id: config.id,
+            amount: config.amount,
+            provider: config.provider,
+            status: config.status,
+            reference: config.reference,
+            failure: config.failure,
+            metadata: config.metadata,
+        }
+    }
+}
+
+impl Payment {
+    #[must_use]
+    pub fn id(&self) -> Id {
+        self.id
+    }
+
+    #[must_use]
+    pub fn amount(&self) -> Money {
+        self.amount
+    }
+
+    #[must_use]
+    pub fn provider(&self) -> &str {
+        &self.provider
+    }
+
+    #[must_use]
+    pub fn status(&self) -> Status {
+        self.status
+    }
+
+    #[must_use]
+    pub fn reference(&self) -> Option<&Reference> {
+        self.reference.as_ref()
+    }
+
+    /// Why provider did not accept the payment, when it failed
+    #[must_use]
+    pub fn failure(&self) -> Option<&str> {
+        self.failure.as_deref()
+    }
+
+    /// Register provider authorized payment under `reference`
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if payment is not pending
+    pub fn authorize(&mut self, reference: Reference) -> Result<(), Error> {
+        self.transition(Status::Authorized)?;
+        self.reference = Some(reference);
+        Ok(())
+    }
+
+    /// Register provider collected the authorized amount
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if payment is not authorized
+    pub fn capture(&mut self) -> Result<(), Error> {
+        self.transition(Status::Captured)
+    }
+
+    /// Register provider was asked to release the authorized amount
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if payment is not authorized
+    pub fn start_void(&mut self) -> Result<(), Error> {
+        self.transition(Status::Voiding)
+    }
+
+    /// Register provider released the authorized amount
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if payment is not voiding
+    pub fn void(&mut self) -> Result<(), Error> {
+        self.transition(Status::Voided)
+    }
+
+    /// Register provider declined releasing the authorized amount
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if payment is not voiding
+    pub fn revert_void(&mut self) -> Result<(), Error> {
+        self.revert(Status::Voiding, Status::Authorized)
+    }
+
+    /// Register provider was asked to give back the captured amount
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if payment is not captured
+    pub fn start_refund(&mut self) -> Result<(), Error> {
+        self.transition(Status::Refunding)
+    }
+
+    /// Register provider gave back the captured amount
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if payment is not refunding
+    pub fn refund(&mut self) -> Result<(), Error> {
+        self.transition(Status::Refunded)
+    }
+
+    /// Register provider declined giving back the captured amount
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if payment is not refunding
+    pub fn revert_refund(&mut self) -> Result<(), Error> {
+        self.revert(Status::Refunding, Status::Captured)
+    }
+
+    /// Register provider did not accept payment because of `reason`
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if payment is already captured or finished
+    pub fn fail(&mut self, reason: impl Into<String>) -> Result<(), Error> {
+        self.transition(Status::Failed)?;
+        self.failure = Some(reason.into());
+        Ok(())
+    }
+
+    fn transition(&mut self, next: Status) -> Result<(), Error> {
+        if !self.status.can_transition_to(next) {
+            return Err(Error::transition_conflict(self.status, next));
+        }
+
+        self.status = next;
+        self.metadata.update();
+        Ok(())
+    }
+
+    /// Move back to `previous` status, as long as payment is still `from`
+    fn revert(&mut self, from: Status, previous: Status) -> Result<(), Error> {
+        if self.status != from {
+            return Err(Error::transition_conflict(self.status, previous));
+        }
+
+        self.status = previous;
+        self.metadata.update();
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PaymentConfig {
+    pub id: Id,
+    pub amount: Money,
+    pub provider: String,
+    pub status: Status,
+    pub reference: Option<Reference>,
+    pub failure: Option<String>,
+    pub metadata: metadata::Metadata,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::money::Currency;
+    use crate::payment::ConflictKind;
+
+    #[test]
+    fn authorize_and_capture_works() {
+        let mut payment = Payment::new(Money::from_minor(4590, Currency::BRL), "fake");
+        let reference = Reference::new("fake_123").expect("Valid reference");
+
+        assert!(payment.authorize(reference.clone()).is_ok());
+        assert!(payment.capture().is_ok());
+        assert_eq!(payment.status(), Status::Captured);
+        assert_eq!(payment.reference(), Some(&reference));
+    }
+
+    #[test]
+    fn refund_and_revert_works() {
+        let mut payment = Payment::new(Money::from_minor(4590, Currency::BRL), "fake");
+        let reference = Reference::new("fake_123").expect("Valid reference");
+        payment.authorize(reference).expect("Authorized payment");
+        payment.capture().expect("Captured payment");
+
+        assert!(matches!(
+            payment.refund(),
+            Err(Error::Conflict(ConflictKind::Transition {
+                from: Status::Captured,
+                to: Status::Refunded
+            }))
+        ));
+
+        assert!(payment.start_refund().is_ok());
+        assert!(payment.revert_refund().is_ok());
+        assert_eq!(payment.status(), Status::Captured);
+
+        assert!(payment.start_refund().is_ok());
+        assert!(payment.refund().is_ok());
+        assert_eq!(payment.status(), Status::Refunded);
+        assert!(payment.revert_refund().is_err());
+    }
+
+    #[test]
+    fn fail_keeps_reason() {
+        let mut payment = Payment::new(Money::from_minor(4590, Currency::BRL), "fake");
+
+        assert!(payment.fail("Insufficient funds").is_ok());
+        assert_eq!(payment.status(), Status::Failed);
+        assert_eq!(payment.failure(), Some("Insufficient funds"));
+
+        let result = payment.capture();
+        assert!(matches!(
+            result,
+            Err(Error::Conflict(ConflictKind::Transition {
+                from: Status::Failed,
+                to: Status::Captured
+            }))
+        ));
+    }
+}
@@ -0,0 +1,88 @@
+use thiserror::Error;
+
+use super::{Id, ProviderError, Status};
+use crate::core::money::{Currency, MoneyError};
+use crate::modifier;
+use crate::product;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error(transparent)]
+    Conflict(ConflictKind),
+    #[error(transparent)]
+    Internal(Box<dyn std::error::Error>),
+    #[error(transparent)]
+    NotFound(NotFoundKind),
+    #[error(transparent)]
+    Provider(ProviderError),
+}
+
+impl Error {
+    /// Utility function to create [`Error::Internal`] without manually
+    /// boxing the error
+    #[must_use]
+    pub fn any(err: impl Into<Box<dyn std::error::Error>>) -> Self {
+        Self::Internal(err.into())
+    }
+
+    #[must_use]
+    pub fn id_conflict(id: Id) -> Self {
+        Self::Conflict(ConflictKind::Id(id))
+    }
+
+    #[must_use]
+    pub fn id_not_found(id: Id) -> Self {
+        Self::NotFound(NotFoundKind::Id(id))
+    }
+
+    /// Maps a failed [`Money`](crate::core::money::Money) operation, where
+    /// mixing currencies is a conflict and overflowing is unexpected
+    #[must_use]
+    pub fn money(err: MoneyError) -> Self {
+        match err {
+            MoneyError::CurrencyMismatch { expected, found } => {
+                Self::Conflict(ConflictKind::Currency { expected, found })
+            }
+            err @ MoneyError::Overflow => Self::any(err),
+        }
+    }
+
+    /// Maps a selection of extras breaking the rules of a modifier group to
+    /// a conflict
+    #[must_use]
+    pub fn selection(err: modifier::SelectionError) -> Self {
+        Self::Conflict(ConflictKind::Selection(err))
+    }
+
+    #[must_use]
+    pub fn status_conflict(id: Id, expected: Status) -> Self {
+        Self::Conflict(ConflictKind::Status { id, expected })
+    }
+
+    #[must_use]
+    pub fn transition_conflict(from: Status, to: Status) -> Self {
+        Self::Conflict(ConflictKind::Transition { from, to })
+    }
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum ConflictKind {
+    #[error("Payment cannot combine prices in `{expected}` with prices in `{found}`")]
+    Currency { expected: Currency, found: Currency },
+    #[error("Payment with id `{0}` already exists")]
+    Id(Id),
+    #[error(transparent)]
+    Selection(modifier::SelectionError),
+    #[error("Payment with id `{id}` is no longer `{expected}`")]
+    Status { id: Id, expected: Status },
+    #[error("Payment cannot move from `{from}` to `{to}`")]
+    Transition { from: Status, to: Status },
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum NotFoundKind {
+    #[error("Payment with id `{0}` not found")]
+    Id(Id),
+    #[error(transparent)]
+    Product(product::NotFoundKind),
+}
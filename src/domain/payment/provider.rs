@@ -0,0 +1,32 @@
+use thiserror::Error;
+
+use super::{Id, Reference};
+use crate::core::money::Money;
+
+/// Seam between payments and the gateway actually moving money, so gateways
+/// can be swapped without touching the checkout flow
+// TODO: remove when stabilized
+#[allow(async_fn_in_trait)]
+pub trait PaymentProvider: Send + Clone {
+    /// Name recorded along with every payment attempt, e.g. `fake`
+    fn name(&self) -> &'static str;
+
+    /// Reserve `amount` for payment `id`, returning the provider reference
+    async fn authorize(&mut self, id: Id, amount: Money) -> Result<Reference, ProviderError>;
+    /// Collect `amount` previously authorized under `reference`
+    async fn capture(&mut self, reference: &Reference, amount: Money) -> Result<(), ProviderError>;
+    /// Give back `amount` previously captured under `reference`
+    async fn refund(&mut self, reference: &Reference, amount: Money) -> Result<(), ProviderError>;
+    /// Release an authorization that was not captured
+    async fn void(&mut self, reference: &Reference) -> Result<(), ProviderError>;
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum ProviderError {
+    /// Provider refused the operation, such as for insufficient funds
+    #[error("Payment declined: {0}")]
+    Declined(Box<str>),
+    /// Provider could not be reached or failed unexpectedly
+    #[error("Payment provider unavailable: {0}")]
+    Unavailable(Box<str>),
+}
@@ -0,0 +1,12 @@
+use super::error::Error;
+use super::{Id, Payment, Status};
+
+// TODO: remove when stabilized
+#[allow(async_fn_in_trait)]
+pub trait Repository: Send + Clone {
+    async fn create(&mut self, payment: &Payment) -> Result<(), Error>;
+    async fn find(&self, id: Id) -> Result<Payment, Error>;
+    /// Update `payment` as long as it is still stored with `status`, so
+    /// concurrent refunds or voids cannot both reach the provider
+    async fn update(&mut self, payment: &Payment, status: Status) -> Result<(), Error>;
+}
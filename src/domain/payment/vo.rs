@@ -0,0 +1,231 @@
+use std::fmt;
+
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::core::string::trim_in_place;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Id(Uuid);
+
+impl Id {
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::now_v7())
+    }
+
+    /// Try parsing `value` into [`Id`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `value` is not a valid [`Id`]
+    pub fn parse_str(value: &str) -> Result<Self, ParseIdError> {
+        Uuid::parse_str(value)
+            .map(Self)
+            .map_err(|_| ParseIdError(Box::from(value)))
+    }
+}
+
+impl Id {
+    #[must_use]
+    pub fn uuid(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for Id {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for Id {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<Uuid> for Id {
+    fn from(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+}
+
+/// Stage of a payment attempt with its provider
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Status {
+    Pending,
+    Authorized,
+    Captured,
+    Voiding,
+    Voided,
+    Refunding,
+    Refunded,
+    Failed,
+}
+
+impl Status {
+    /// Try parsing `value` into [`Status`]
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseStatusError`] when `value` cannot be parsed to [`Status`]
+    pub fn parse_str(value: &str) -> Result<Self, ParseStatusError> {
+        match value {
+            "pending" => Ok(Self::Pending),
+            "authorized" => Ok(Self::Authorized),
+            "captured" => Ok(Self::Captured),
+            "voiding" => Ok(Self::Voiding),
+            "voided" => Ok(Self::Voided),
+            "refunding" => Ok(Self::Refunding),
+            "refunded" => Ok(Self::Refunded),
+            "failed" => Ok(Self::Failed),
+            other => Err(ParseStatusError(Box::from(other))),
+        }
+    }
+}
+
+impl Status {
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Authorized => "authorized",
+            Self::Captured => "captured",
+            Self::Voiding => "voiding",
+            Self::Voided => "voided",
+            Self::Refunding => "refunding",
+            Self::Refunded => "refunded",
+            Self::Failed => "failed",
+        }
+    }
+
+    /// Check if payment workflow allows moving from `self` to `next`, where
+    /// voiding and refunding are recorded while waiting for provider
+    #[must_use]
+    pub fn can_transition_to(self, next: Self) -> bool {
+        matches!(
+            (self, next),
+            (Self::Pending, Self::Authorized | Self::Failed)
+                | (
+                    Self::Authorized,
+                    Self::Captured | Self::Voiding | Self::Failed
+                )
+                | (Self::Voiding, Self::Voided)
+                | (Self::Captured, Self::Refunding)
+                | (Self::Refunding, Self::Refunded)
+        )
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// Identifier a payment provider gave to a payment, used to reconcile
+/// payment attempts with the provider records
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Reference(String);
+
+impl Reference {
+    pub const MAX_LEN: usize = 128;
+
+    /// Try parsing `reference` into [`Reference`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `reference` is empty or longer than [`Reference::MAX_LEN`]
+    pub fn new(reference: impl Into<String>) -> Result<Self, ReferenceError> {
+        let mut reference: String = reference.into();
+        trim_in_place(&mut reference);
+        if reference.is_empty() {
+            return Err(ReferenceError::Empty);
+        }
+        if reference.len() > Self::MAX_LEN {
+            return Err(ReferenceError::Length);
+        }
+
+        Ok(Self(reference))
+    }
+}
+
+impl Reference {
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Reference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[error("Provided string `{0}` is not a valid payment id")]
+pub struct ParseIdError(pub Box<str>);
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[error("Provided string `{0}` is not a valid payment status")]
+pub struct ParseStatusError(pub Box<str>);
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum ReferenceError {
+    #[error("Payment reference cannot be empty")]
+    Empty,
+    #[error("Payment reference cannot have more than {len} characters", len = Reference::MAX_LEN)]
+    Length,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STATUSES: [Status; 8] = [
+        Status::Pending,
+        Status::Authorized,
+        Status::Captured,
+        Status::Voiding,
+        Status::Voided,
+        Status::Refunding,
+        Status::Refunded,
+        Status::Failed,
+    ];
+
+    #[test]
+    fn status_back_and_forth_str() {
+        for status in STATUSES {
+            assert_eq!(Status::parse_str(status.as_str()), Ok(status));
+        }
+
+        let invalid = "lost";
+        assert_eq!(
+            Status::parse_str(invalid),
+            Err(ParseStatusError(Box::from(invalid)))
+        );
+    }
+
+    #[test]
+    fn status_transitions() {
+        let allowed = [
+            (Status::Pending, Status::Authorized),
+            (Status::Pending, Status::Failed),
+            (Status::Authorized, Status::Captured),
+            (Status::Authorized, Status::Voiding),
+            (Status::Authorized, Status::Failed),
+            (Status::Voiding, Status::Voided),
+            (Status::Captured, Status::Refunding),
+            (Status::Refunding, Status::Refunded),
+        ];
+
+        for from in STATUSES {
+            for to in STATUSES {
+                let expected = allowed.contains(&(from, to));
+                assert_eq!(from.can_transition_to(to), expected, "{from} -> {to}");
+            }
+        }
+    }
+}
@@ -1,9 +1,16 @@
 mod entity;
 mod error;
+mod quote;
 mod repository;
+mod snapshot;
 mod vo;
 
 pub use entity::{ProductConfig, Product};
 pub use error::{ConflictKind, Error, NotFoundKind};
-pub use repository::Repository;
-pub use vo::{Extras, ExtrasError, Id, Kind, Name, NameError, ParseIdError, ParseKindError, Price};
+pub use quote::{ExtraSelection, Quote, QuoteExtra};
+pub use repository::{ListQuery, Pagination, Repository};
+pub use snapshot::{ExtraSnapshot, Snapshot, SnapshotError};
+pub use vo::{
+    Extras, ExtrasError, Id, Kind, Name, NameError, ParseIdError, ParseKindError, Schedule,
+    ScheduleError,
+};
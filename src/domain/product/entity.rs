@@ -1,15 +1,28 @@
-use super::{Extras, Id, Kind, Name, Price};
+use time::OffsetDateTime;
+
+use super::{Extras, Id, Kind, Name, Schedule};
 use crate::catalog;
 use crate::core::metadata;
+use crate::core::money::Money;
+use crate::modifier;
+use crate::variant;
 
 #[derive(Clone, Debug)]
 pub struct Product {
     pub(super) id: Id,
     pub(super) catalog_id: catalog::Id,
     pub name: Name,
-    pub price: Price,
+    pub price: Money,
     pub kind: Kind,
     pub extras: Extras,
+    /// Ways product is sold, such as sizes, each priced on its own
+    pub variants: variant::Variants,
+    /// Groups of extras, each with rules on how many of them are selected
+    pub modifiers: modifier::Groups,
+    /// Whether product is offered at all, regardless of its schedule
+    pub available: bool,
+    /// When product is offered, where [`None`] means any time
+    pub schedule: Option<Schedule>,
     pub metadata: metadata::Metadata,
 }
 
@@ -18,7 +31,7 @@ impl Product {
     pub fn new(
         catalog_id: catalog::Id,
         name: Name,
-        price: Price,
+        price: Money,
         kind: Kind,
         extras: Extras,
     ) -> Self {
@@ -29,6 +42,10 @@ impl Product {
             price,
             kind,
             extras,
+            variants: variant::Variants::default(),
+            modifiers: modifier::Groups::default(),
+            available: true,
+            schedule: None,
             metadata: metadata::Metadata::new(),
         }
     }
@@ -42,6 +59,10 @@ impl Product {
             price: config.price,
             kind: config.kind,
             extras: config.extras.unwrap_or_default(),
+            variants: config.variants.unwrap_or_default(),
+            modifiers: config.modifiers.unwrap_or_default(),
+            available: config.available,
+            schedule: config.schedule,
             metadata: config.metadata,
         }
     }
@@ -57,6 +78,17 @@ impl Product {
     pub fn catalog_id(&self) -> catalog::Id {
         self.catalog_id
     }
+
+    /// Whether product is offered `at`, according to its availability and
+    /// schedule
+    #[must_use]
+    pub fn is_available_at(&self, at: OffsetDateTime) -> bool {
+        self.available
+            && self
+                .schedule
+                .as_ref()
+                .is_none_or(|schedule| schedule.contains(at))
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -64,8 +96,12 @@ pub struct ProductConfig {
     pub id: Id,
     pub catalog_id: catalog::Id,
     pub name: Name,
-    pub price: Price,
+    pub price: Money,
     pub kind: Kind,
     pub extras: Option<Extras>,
+    pub variants: Option<variant::Variants>,
+    pub modifiers: Option<modifier::Groups>,
+    pub available: bool,
+    pub schedule: Option<Schedule>,
     pub metadata: metadata::Metadata,
 }
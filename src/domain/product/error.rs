@@ -2,7 +2,11 @@ use thiserror::Error;
 
 use super::{Id, Name};
 use crate::catalog;
+use crate::core::money::{Currency, MoneyError};
+use crate::core::quantity::Quantity;
 use crate::extra;
+use crate::modifier;
+use crate::variant;
 
 #[derive(Debug, Error)]
 pub enum Error {
@@ -32,11 +36,53 @@ impl Error {
         Self::NotFound(NotFoundKind::ExtraId(extra_id))
     }
 
+    #[must_use]
+    pub fn extra_out_of_scope(extra_id: extra::Id, catalog_id: catalog::Id) -> Self {
+        Self::Conflict(ConflictKind::ExtraScope {
+            id: extra_id,
+            catalog_id,
+        })
+    }
+
+    #[must_use]
+    pub fn extra_quantity_exceeded(extra_id: extra::Id, max: Quantity) -> Self {
+        Self::Conflict(ConflictKind::ExtraQuantity { id: extra_id, max })
+    }
+
+    #[must_use]
+    pub fn extra_repeated(extra_id: extra::Id) -> Self {
+        Self::Conflict(ConflictKind::ExtraRepeated(extra_id))
+    }
+
     #[must_use]
     pub fn id_conflict(id: Id) -> Self {
         Self::Conflict(ConflictKind::Id(id))
     }
 
+    /// Maps a failed [`Money`](crate::core::money::Money) operation, where
+    /// mixing currencies is a conflict and overflowing is unexpected
+    #[must_use]
+    pub fn money(err: MoneyError) -> Self {
+        match err {
+            MoneyError::CurrencyMismatch { expected, found } => {
+                Self::Conflict(ConflictKind::Currency { expected, found })
+            }
+            err @ MoneyError::Overflow => Self::any(err),
+        }
+    }
+
+    /// Maps an invalid set of [`modifier::Groups`], such as two groups
+    /// offering the same extra, to a conflict
+    #[must_use]
+    pub fn modifiers(err: modifier::GroupsError) -> Self {
+        Self::Conflict(ConflictKind::Modifiers(err))
+    }
+
+    #[must_use]
+    pub fn modifier_not_found(id: modifier::Id) -> Self {
+        Self::NotFound(NotFoundKind::ModifierId(id))
+    }
+
     #[must_use]
     pub fn name_conflict(name: Name) -> Self {
         Self::Conflict(ConflictKind::Name(name))
@@ -46,6 +92,25 @@ impl Error {
     pub fn id_not_found(id: Id, catalog_id: catalog::Id) -> Self {
         Self::NotFound(NotFoundKind::Id { id, catalog_id })
     }
+
+    /// Maps a selection of extras breaking the rules of a modifier group to
+    /// a conflict
+    #[must_use]
+    pub fn selection(err: modifier::SelectionError) -> Self {
+        Self::Conflict(ConflictKind::Selection(err))
+    }
+
+    /// Maps an invalid set of [`variant::Variants`], such as two variants
+    /// sharing a name, to a conflict
+    #[must_use]
+    pub fn variants(err: variant::VariantsError) -> Self {
+        Self::Conflict(ConflictKind::Variants(err))
+    }
+
+    #[must_use]
+    pub fn variant_not_found(id: variant::Id) -> Self {
+        Self::NotFound(NotFoundKind::VariantId(id))
+    }
 }
 
 #[derive(Clone, Debug, Eq, Error, PartialEq)]
@@ -54,6 +119,23 @@ pub enum ConflictKind {
     Id(Id),
     #[error("Product with name `{0}` already exists")]
     Name(Name),
+    #[error("Product priced in `{expected}` cannot be combined with prices in `{found}`")]
+    Currency { expected: Currency, found: Currency },
+    #[error("Product allows at most {max} of extra `{id}` per unit")]
+    ExtraQuantity { id: extra::Id, max: Quantity },
+    #[error("Product extra `{0}` cannot be selected more than once")]
+    ExtraRepeated(extra::Id),
+    #[error("Product extra `{id}` is not available to catalog `{catalog_id}`")]
+    ExtraScope {
+        id: extra::Id,
+        catalog_id: catalog::Id,
+    },
+    #[error(transparent)]
+    Modifiers(modifier::GroupsError),
+    #[error(transparent)]
+    Selection(modifier::SelectionError),
+    #[error(transparent)]
+    Variants(variant::VariantsError),
 }
 
 #[derive(Clone, Debug, Eq, Error, PartialEq)]
@@ -64,4 +146,8 @@ pub enum NotFoundKind {
     CatalogId(catalog::Id),
     #[error("Product extra with id `{0}` not found")]
     ExtraId(extra::Id),
+    #[error("Product modifier group with id `{0}` not found")]
+    ModifierId(modifier::Id),
+    #[error("Product variant with id `{0}` not found")]
+    VariantId(variant::Id),
 }
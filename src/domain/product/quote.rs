@@ -0,0 +1,329 @@
+use std::collections::HashSet;
+
+use super::{Error, Id, Product};
+use crate::core::money::Money;
+use crate::core::quantity::Quantity;
+use crate::extra;
+
+/// Extra chosen to be quoted along with a [`Product`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ExtraSelection {
+    pub id: extra::Id,
+    pub quantity: Quantity,
+}
+
+impl ExtraSelection {
+    /// Selection of a single unit of extra with `id`
+    #[must_use]
+    pub fn single(id: extra::Id) -> Self {
+        Self {
+            id,
+            quantity: Quantity::one(),
+        }
+    }
+}
+
+/// Price breakdown of a [`Product`] with its selected extras, computed from
+/// current prices so every client gets the same numbers
+#[derive(Clone, Debug)]
+pub struct Quote {
+    product_id: Id,
+    price: Money,
+    quantity: Quantity,
+    extras: Vec<QuoteExtra>,
+    subtotal: Money,
+    total: Money,
+}
+
+impl Quote {
+    #[must_use]
+    pub fn product_id(&self) -> Id {
+        self.product_id
+    }
+
+    /// Base price of a single unit of product, without extras
+    #[must_use]
+    pub fn price(&self) -> Money {
+        self.price
+    }
+
+    #[must_use]
+    pub fn quantity(&self) -> Quantity {
+        self.quantity
+    }
+
+    #[must_use]
+    pub fn extras(&self) -> &[QuoteExtra] {
+        &self.extras
+    }
+
+    /// Price of a single unit of product including its extras
+    #[must_use]
+    pub fn subtotal(&self) -> Money {
+        self.subtotal
+    }
+
+    #[must_use]
+    pub fn total(&self) -> Money {
+        self.total
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct QuoteExtra {
+    pub id: extra::Id,
+    pub name: extra::Name,
+    pub price: Money,
+    pub quantity: Quantity,
+    /// Price of extra for a single unit of product
+    pub total: Money,
+}
+
+impl Product {
+    /// Price `selection` of an extra for a single unit of product, checking
+    /// it does not exceed the maximum quantity of extra
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if selected extra is not bound to product or if
+    /// more of it is selected than allowed
+    pub fn price_extra(&self, selection: &ExtraSelection) -> Result<QuoteExtra, Error> {
+        let extra = self
+            .extras
+            .find(selection.id)
+            .ok_or_else(|| Error::extra_not_found(selection.id))?;
+
+        let max = self.extras.max_quantity(selection.id);
+        if selection.quantity > max {
+            return Err(Error::extra_quantity_exceeded(selection.id, max));
+        }
+
+        let total = extra
+            .price
+            .checked_mul(selection.quantity.value())
+            .map_err(Error::money)?;
+
+        Ok(QuoteExtra {
+            id: extra.id(),
+            name: extra.name.clone(),
+            price: extra.price,
+            quantity: selection.quantity,
+            total,
+        })
+    }
+
+    /// Quote `quantity` units of product, each one with the `selections` of extras
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if any selected extra is not bound to product, is
+    /// repeated among `selections`, is selected more than allowed or is
+    /// priced in another currency, or if `selections` break the rules of any
+    /// modifier group of product
+    pub fn quote(&self, quantity: Quantity, selections: &[ExtraSelection]) -> Result<Quote, Error> {
+        let mut selected_ids = HashSet::with_capacity(selections.len());
+        if let Some(repeated) = selections
+            .iter()
+            .find(|selection| !selected_ids.insert(selection.id))
+        {
+            return Err(Error::extra_repeated(repeated.id));
+        }
+
+        let extras = selections
+            .iter()
+            .map(|selection| self.price_extra(selection))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.modifiers
+            .validate(selections)
+            .map_err(Error::selection)?;
+
+        let currency = self.price.currency();
+        let extras_totals = extras.iter().map(|extra| extra.total);
+        let subtotal = Money::checked_sum(currency, extras_totals)
+            .and_then(|extras_total| extras_total.checked_add(self.price))
+            .map_err(Error::money)?;
+
+        let total = subtotal
+            .checked_mul(quantity.value())
+            .map_err(Error::money)?;
+
+        Ok(Quote {
+            product_id: self.id,
+            price: self.price,
+            quantity,
+            extras,
+            subtotal,
+            total,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog;
+    use crate::core::money::Currency;
+    use crate::product::{ConflictKind, Extras, Kind, Name, NotFoundKind};
+
+    #[test]
+    fn quote_works() {
+        let cheddar = mocked_extra("Cheddar", 200);
+        let bacon = mocked_extra("Bacon", 350);
+        let product = mocked_product(1990, vec![cheddar.clone(), bacon.clone()]);
+
+        let selections = [
+            ExtraSelection {
+                id: cheddar.id(),
+                quantity: Quantity::new(2).expect("Valid quantity"),
+            },
+            ExtraSelection {
+                id: bacon.id(),
+                quantity: Quantity::new(1).expect("Valid quantity"),
+            },
+        ];
+
+        let quantity = Quantity::new(3).expect("Valid quantity");
+        let quote = product.quote(quantity, &selections).expect("Valid quote");
+
+        assert_eq!(quote.price(), brl(1990));
+        assert_eq!(quote.extras().len(), 2);
+        assert_eq!(quote.extras()[0].total, brl(400));
+        // 19.90 + 2 * 2.00 + 3.50
+        assert_eq!(quote.subtotal(), brl(2740));
+        assert_eq!(quote.total(), brl(8220));
+    }
+
+    #[test]
+    fn quote_with_extra_not_found() {
+        let product = mocked_product(2000, vec![mocked_extra("Cheddar", 200)]);
+        let extra_id = extra::Id::new();
+        let selections = [ExtraSelection {
+            id: extra_id,
+            quantity: Quantity::new(1).expect("Valid quantity"),
+        }];
+
+        let quantity = Quantity::new(1).expect("Valid quantity");
+        let result = product.quote(quantity, &selections);
+        assert!(matches!(
+            result,
+            Err(Error::NotFound(NotFoundKind::ExtraId(id))) if id == extra_id
+        ));
+    }
+
+    #[test]
+    fn quote_with_currency_conflict() {
+        let cheddar = extra::Extra::new(
+            extra::Name::new("Cheddar").expect("Valid extra name"),
+            Money::from_minor(200, Currency::USD),
+        );
+        let product = mocked_product(2000, vec![cheddar.clone()]);
+        let selections = [ExtraSelection {
+            id: cheddar.id(),
+            quantity: Quantity::new(1).expect("Valid quantity"),
+        }];
+
+        let quantity = Quantity::new(1).expect("Valid quantity");
+        let result = product.quote(quantity, &selections);
+        assert!(matches!(
+            result,
+            Err(Error::Conflict(ConflictKind::Currency {
+                expected: Currency::BRL,
+                found: Currency::USD
+            }))
+        ));
+    }
+
+    #[test]
+    fn quote_with_repeated_extra() {
+        let cheddar = mocked_extra("Cheddar", 200);
+        let product = mocked_product(2000, vec![cheddar.clone()]);
+        let one = Quantity::new(1).expect("Valid quantity");
+        let selection = ExtraSelection {
+            id: cheddar.id(),
+            quantity: one,
+        };
+
+        let result = product.quote(one, &[selection, selection]);
+        assert!(matches!(
+            result,
+            Err(Error::Conflict(ConflictKind::ExtraRepeated(id))) if id == cheddar.id()
+        ));
+    }
+
+    #[test]
+    fn quote_with_selection_conflict() {
+        use crate::modifier;
+
+        let ketchup = mocked_extra("Ketchup", 0);
+        let mut product = mocked_product(2000, vec![ketchup.clone()]);
+        let sauces = modifier::Group::new(
+            product.id(),
+            modifier::Name::new("Sauces").expect("Valid modifier group name"),
+            modifier::Limits::new(1, 1).expect("Valid limits"),
+            modifier::Options::new(vec![ketchup.id()]).expect("Valid modifier group options"),
+        );
+        product.modifiers = modifier::Groups::new(vec![sauces]).expect("Valid modifier groups");
+
+        let one = Quantity::new(1).expect("Valid quantity");
+        assert!(product
+            .quote(one, &[ExtraSelection::single(ketchup.id())])
+            .is_ok());
+        assert!(matches!(
+            product.quote(one, &[]),
+            Err(Error::Conflict(ConflictKind::Selection(
+                modifier::SelectionError::Missing { min: 1, .. }
+            )))
+        ));
+    }
+
+    #[test]
+    fn price_extra_with_quantity_exceeded() {
+        let cheddar = mocked_extra("Cheddar", 200);
+        let mut product = mocked_product(2000, vec![cheddar.clone()]);
+        let double = Quantity::new(2).expect("Valid quantity");
+        product
+            .extras
+            .set_max_quantity(cheddar.id(), double)
+            .expect("Cheddar bound to product");
+
+        let selection = ExtraSelection {
+            id: cheddar.id(),
+            quantity: double,
+        };
+        let priced = product
+            .price_extra(&selection)
+            .expect("Valid extra selection");
+        assert_eq!(priced.total, brl(400));
+
+        let selection = ExtraSelection {
+            id: cheddar.id(),
+            quantity: Quantity::new(3).expect("Valid quantity"),
+        };
+        assert!(matches!(
+            product.price_extra(&selection),
+            Err(Error::Conflict(ConflictKind::ExtraQuantity { id, max }))
+                if id == cheddar.id() && max == double
+        ));
+    }
+
+    fn brl(cents: i64) -> Money {
+        Money::from_minor(cents, Currency::BRL)
+    }
+
+    fn mocked_extra(name: &str, cents: i64) -> extra::Extra {
+        extra::Extra::new(
+            extra::Name::new(name).expect("Valid extra name"),
+            brl(cents),
+        )
+    }
+
+    fn mocked_product(cents: i64, extras: Vec<extra::Extra>) -> Product {
+        Product::new(
+            catalog::Id::new(),
+            Name::new("Cheese Burger").expect("Valid product name"),
+            brl(cents),
+            Kind::Burger,
+            Extras::new(extras).expect("Valid product extras"),
+        )
+    }
+}
@@ -1,6 +1,13 @@
+use std::num::{NonZeroU32, NonZeroU8};
+
 use super::error::Error;
-use super::{Id, Product};
+use super::{Id, Kind, Product};
 use crate::catalog;
+use crate::core::money::Money;
+use crate::core::sort::Sort;
+use crate::extra;
+use crate::modifier;
+use crate::variant;
 
 // TODO: remove when stabilized
 #[allow(async_fn_in_trait)]
@@ -8,5 +15,49 @@ pub trait Repository: Send + Clone {
     async fn create(&mut self, product: &Product) -> Result<(), Error>;
     async fn delete(&mut self, id: Id, catalog_id: catalog::Id) -> Result<Product, Error>;
     async fn find(&self, id: Id, catalog_id: catalog::Id) -> Result<Product, Error>;
+    async fn list(&self, query: ListQuery) -> Result<Pagination, Error>;
     async fn update(&mut self, product: &Product) -> Result<(), Error>;
+    async fn create_variant(&mut self, variant: &variant::Variant) -> Result<(), Error>;
+    async fn delete_variant(
+        &mut self,
+        id: variant::Id,
+        product_id: Id,
+    ) -> Result<variant::Variant, Error>;
+    async fn update_variant(&mut self, variant: &variant::Variant) -> Result<(), Error>;
+    async fn create_modifier(&mut self, group: &modifier::Group) -> Result<(), Error>;
+    async fn delete_modifier(
+        &mut self,
+        id: modifier::Id,
+        product_id: Id,
+    ) -> Result<modifier::Group, Error>;
+    async fn update_modifier(&mut self, group: &modifier::Group) -> Result<(), Error>;
+}
+
+#[derive(Clone, Debug)]
+pub struct ListQuery {
+    pub page: NonZeroU32,
+    pub limit: NonZeroU8,
+    /// Only products of the catalog, if set
+    pub catalog_id: Option<catalog::Id>,
+    pub kind: Option<Kind>,
+    /// Only products whose name contains it, ignoring case
+    pub name: Option<String>,
+    /// Only products priced in the same currency for at least this amount
+    pub min_price: Option<Money>,
+    /// Only products priced in the same currency for at most this amount
+    pub max_price: Option<Money>,
+    /// Only products offering the extra, if set
+    pub extra_id: Option<extra::Id>,
+    /// Only products enabled or disabled, regardless of their schedule,
+    /// ingredients or stock
+    pub enabled: Option<bool>,
+    pub sort: Sort,
+}
+
+#[derive(Clone, Debug)]
+pub struct Pagination {
+    pub count: u64,
+    pub page: NonZeroU32,
+    pub limit: NonZeroU8,
+    pub items: Vec<Product>,
 }
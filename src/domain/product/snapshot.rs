@@ -0,0 +1,152 @@
+use std::collections::HashSet;
+
+use thiserror::Error;
+
+use super::{ExtraSelection, Id, Name, Product};
+use crate::catalog;
+use crate::core::money::{Money, MoneyError};
+use crate::extra;
+use crate::modifier;
+
+/// Copy of a [`Product`] with some of its extras at a given moment, such as
+/// when it is ordered or sold, so later changes to the product do not
+/// rewrite history
+#[derive(Clone, Debug)]
+pub struct Snapshot {
+    pub product_id: Id,
+    pub catalog_id: catalog::Id,
+    pub name: Name,
+    pub price: Money,
+    pub extras: Vec<ExtraSnapshot>,
+}
+
+impl Snapshot {
+    /// Price of a single unit of product, including its extras
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if extras are priced in another currency or if the
+    /// sum overflows
+    pub fn unit_price(&self) -> Result<Money, MoneyError> {
+        let extras_prices = self.extras.iter().map(|extra| extra.price);
+        Money::checked_sum(self.price.currency(), extras_prices)
+            .and_then(|extras_price| self.price.checked_add(extras_price))
+    }
+}
+
+/// Copy of an [`extra::Extra`] chosen for a [`Snapshot`]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExtraSnapshot {
+    pub id: extra::Id,
+    pub name: extra::Name,
+    pub price: Money,
+}
+
+impl From<&extra::Extra> for ExtraSnapshot {
+    fn from(extra: &extra::Extra) -> Self {
+        Self {
+            id: extra.id(),
+            name: extra.name.clone(),
+            price: extra.price,
+        }
+    }
+}
+
+impl Product {
+    /// Snapshot product with the chosen `extras_ids`, each one being a single
+    /// unit of extra, as orders and sales take them
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if any of `extras_ids` is not one of product extras
+    /// or is repeated, or if they break the rules of any modifier group of
+    /// product
+    pub fn snapshot(&self, extras_ids: &[extra::Id]) -> Result<Snapshot, SnapshotError> {
+        let mut selected_ids = HashSet::with_capacity(extras_ids.len());
+        if let Some(repeated) = extras_ids.iter().find(|id| !selected_ids.insert(**id)) {
+            return Err(SnapshotError::Repeated(*repeated));
+        }
+
+        let extras = extras_ids
+            .iter()
+            .map(|extra_id| {
+                self.extras
+                    .iter()
+                    .find(|extra| extra.id() == *extra_id)
+                    .map(ExtraSnapshot::from)
+                    .ok_or(SnapshotError::Extra {
+                        extra_id: *extra_id,
+                        product_id: self.id,
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let selections = extras_ids
+            .iter()
+            .map(|id| ExtraSelection::single(*id))
+            .collect::<Vec<_>>();
+        self.modifiers
+            .validate(&selections)
+            .map_err(SnapshotError::Selection)?;
+
+        Ok(Snapshot {
+            product_id: self.id,
+            catalog_id: self.catalog_id,
+            name: self.name.clone(),
+            price: self.price,
+            extras,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum SnapshotError {
+    #[error("Product extra with id `{extra_id}` not available for product {product_id}")]
+    Extra { extra_id: extra::Id, product_id: Id },
+    #[error("Product extra `{0}` cannot be selected more than once")]
+    Repeated(extra::Id),
+    #[error(transparent)]
+    Selection(modifier::SelectionError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::money::Currency;
+    use crate::product::{Extras, Kind};
+
+    #[test]
+    fn snapshot_works() {
+        let cheddar = extra::Extra::new(
+            extra::Name::new("Cheddar").expect("Valid extra name"),
+            Money::from_minor(200, Currency::BRL),
+        );
+        let product = Product::new(
+            catalog::Id::new(),
+            Name::new("Cheese Burger").expect("Valid product name"),
+            Money::from_minor(2000, Currency::BRL),
+            Kind::Burger,
+            Extras::new(vec![cheddar.clone()]).expect("Valid product extras"),
+        );
+
+        let snapshot = product.snapshot(&[cheddar.id()]).expect("Valid snapshot");
+        assert_eq!(snapshot.extras, [ExtraSnapshot::from(&cheddar)]);
+        assert_eq!(
+            snapshot.unit_price(),
+            Ok(Money::from_minor(2200, Currency::BRL))
+        );
+
+        let extra_id = extra::Id::new();
+        assert_eq!(
+            product.snapshot(&[extra_id]).err(),
+            Some(SnapshotError::Extra {
+                extra_id,
+                product_id: product.id(),
+            })
+        );
+        assert_eq!(
+            product.snapshot(&[cheddar.id(), cheddar.id()]).err(),
+            Some(SnapshotError::Repeated(cheddar.id()))
+        );
+    }
+}
@@ -1,9 +1,13 @@
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 
-use rust_decimal::Decimal;
 use thiserror::Error;
+use time::{Date, OffsetDateTime};
 use uuid::Uuid;
 
+use crate::core::money::Money;
+use crate::core::quantity::Quantity;
+use crate::core::schedule::{Window, Zone};
 use crate::core::string::trim_in_place;
 use crate::extra;
 
@@ -95,8 +99,17 @@ impl fmt::Display for Name {
     }
 }
 
+/// Extras bound to a product, each one selectable up to a maximum quantity
+/// and possibly priced differently than elsewhere
 #[derive(Clone, Debug, Default)]
-pub struct Extras(Vec<extra::Extra>);
+pub struct Extras {
+    items: Vec<extra::Extra>,
+    /// Maximum quantity of each extra, where a missing one means up to
+    /// [`Quantity::MAX`]
+    max_quantities: HashMap<extra::Id, Quantity>,
+    /// Extras whose price was overridden for product
+    overridden: HashSet<extra::Id>,
+}
 
 impl Extras {
     pub const MAX_LEN: usize = 32;
@@ -111,70 +124,95 @@ impl Extras {
             return Err(ExtrasError::Length);
         }
 
-        Ok(Self(extras))
+        Ok(Self {
+            items: extras,
+            max_quantities: HashMap::new(),
+            overridden: HashSet::new(),
+        })
     }
 }
 
 impl Extras {
     #[must_use]
     pub fn as_slice(&self) -> &[extra::Extra] {
-        &self.0
+        &self.items
+    }
+
+    #[must_use]
+    pub fn find(&self, id: extra::Id) -> Option<&extra::Extra> {
+        self.items.iter().find(|extra| extra.id() == id)
     }
 
     #[must_use]
     pub fn is_empty(&self) -> bool {
-        self.0.is_empty()
+        self.items.is_empty()
     }
 
     pub fn iter(&self) -> impl Iterator<Item = &extra::Extra> {
-        self.0.iter()
+        self.items.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut extra::Extra> {
+        self.items.iter_mut()
     }
 
     #[must_use]
     pub fn len(&self) -> usize {
-        self.0.len()
+        self.items.len()
     }
 
+    /// Maximum quantity of extra with `id` selectable for a single unit of
+    /// product
     #[must_use]
-    pub fn take(self) -> Vec<extra::Extra> {
-        self.0
+    pub fn max_quantity(&self, id: extra::Id) -> Quantity {
+        self.max_quantities
+            .get(&id)
+            .copied()
+            .unwrap_or_else(Quantity::max)
     }
-}
 
-#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct Price(Decimal);
+    /// Set the maximum quantity of extra with `id` selectable for a single
+    /// unit of product
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if extra with `id` is not among extras
+    pub fn set_max_quantity(&mut self, id: extra::Id, max: Quantity) -> Result<(), ExtrasError> {
+        if self.find(id).is_none() {
+            return Err(ExtrasError::Unbound(id));
+        }
 
-impl Price {
-    #[must_use]
-    pub fn new(value: Decimal) -> Self {
-        Self(value.trunc_with_scale(2))
+        self.max_quantities.insert(id, max);
+        Ok(())
     }
 
+    /// Whether price of extra with `id` was overridden for product
     #[must_use]
-    pub fn from_cents(value: u64) -> Self {
-        let num = i64::try_from(value).unwrap_or(i64::MAX);
-        Self(Decimal::new(num, 2))
+    pub fn is_overridden(&self, id: extra::Id) -> bool {
+        self.overridden.contains(&id)
     }
-}
 
-impl Price {
-    #[must_use]
-    pub fn decimal(&self) -> Decimal {
-        self.0
+    /// Price extra with `id` as `price` whenever it is bound to product,
+    /// instead of its own price
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if extra with `id` is not among extras
+    pub fn override_price(&mut self, id: extra::Id, price: Money) -> Result<(), ExtrasError> {
+        let extra = self
+            .items
+            .iter_mut()
+            .find(|extra| extra.id() == id)
+            .ok_or(ExtrasError::Unbound(id))?;
+
+        extra.price = price;
+        self.overridden.insert(id);
+        Ok(())
     }
 
-    // TODO: maybe i should return an error here and improve performance
     #[must_use]
-    pub fn to_cents(&self) -> u64 {
-        use rust_decimal::prelude::ToPrimitive;
-        let cents = self.0.saturating_mul(Decimal::new(100, 0));
-        cents.to_u64().unwrap_or_default()
-    }
-}
-
-impl From<Decimal> for Price {
-    fn from(value: Decimal) -> Self {
-        Self::new(value)
+    pub fn take(self) -> Vec<extra::Extra> {
+        self.items
     }
 }
 
@@ -244,6 +282,89 @@ impl TryFrom<&str> for Kind {
     }
 }
 
+/// When a product is offered: within the season from `starts_on` to
+/// `ends_on`, both inclusive and optional, and during any of the weekly
+/// `windows`, if there are some. Dates are local to `zone`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Schedule {
+    windows: Vec<Window>,
+    starts_on: Option<Date>,
+    ends_on: Option<Date>,
+    zone: Zone,
+}
+
+impl Schedule {
+    pub const MAX_WINDOWS: usize = 16;
+
+    /// Try creating a [`Schedule`] out of weekly `windows` and season dates
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if there are neither windows nor dates, if there
+    /// are more than [`Schedule::MAX_WINDOWS`] windows, if some window is
+    /// not in `zone` or if the season ends before it starts
+    pub fn new(
+        windows: Vec<Window>,
+        starts_on: Option<Date>,
+        ends_on: Option<Date>,
+        zone: Zone,
+    ) -> Result<Self, ScheduleError> {
+        if windows.is_empty() && starts_on.is_none() && ends_on.is_none() {
+            return Err(ScheduleError::Empty);
+        }
+        if windows.len() > Self::MAX_WINDOWS {
+            return Err(ScheduleError::Length);
+        }
+        if windows.iter().any(|window| window.zone() != zone) {
+            return Err(ScheduleError::Zone);
+        }
+        if let (Some(starts_on), Some(ends_on)) = (starts_on, ends_on) {
+            if starts_on > ends_on {
+                return Err(ScheduleError::Season);
+            }
+        }
+
+        Ok(Self {
+            windows,
+            starts_on,
+            ends_on,
+            zone,
+        })
+    }
+}
+
+impl Schedule {
+    #[must_use]
+    pub fn windows(&self) -> &[Window] {
+        &self.windows
+    }
+
+    #[must_use]
+    pub fn starts_on(&self) -> Option<Date> {
+        self.starts_on
+    }
+
+    #[must_use]
+    pub fn ends_on(&self) -> Option<Date> {
+        self.ends_on
+    }
+
+    #[must_use]
+    pub fn zone(&self) -> Zone {
+        self.zone
+    }
+
+    #[must_use]
+    pub fn contains(&self, at: OffsetDateTime) -> bool {
+        let date = self.zone.local(at).date();
+        let in_season = self.starts_on.is_none_or(|starts_on| starts_on <= date)
+            && self.ends_on.is_none_or(|ends_on| date <= ends_on);
+
+        in_season
+            && (self.windows.is_empty() || self.windows.iter().any(|window| window.contains(at)))
+    }
+}
+
 #[derive(Clone, Debug, Eq, Error, PartialEq)]
 #[error("Provided string `{0} is not a valid product id`")]
 pub struct ParseIdError(pub Box<str>);
@@ -258,22 +379,37 @@ pub enum NameError {
     Length,
 }
 
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum ScheduleError {
+    #[error("Product schedule must have weekly windows or season dates")]
+    Empty,
+    #[error("Product schedule cannot have more than {len} windows", len = Schedule::MAX_WINDOWS)]
+    Length,
+    #[error("Product season cannot end before it starts")]
+    Season,
+    #[error("Product schedule windows must be in the same time zone as the schedule")]
+    Zone,
+}
+
 #[derive(Clone, Debug, Eq, Error, PartialEq)]
 pub enum ExtrasError {
     #[error("Product cannot have more than {len} extras", len = Extras::MAX_LEN)]
     Length,
+    #[error("Extra with id `{0}` is not bound to product")]
+    Unbound(extra::Id),
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::money::{Currency, Money};
 
     #[test]
     fn parse_name() {
         let single = vec!["Hironha", "Carlos", "John", "Elon", "Marx"];
         let composed = vec!["João Vitor", "José Bonifácio"];
 
-        for name in single.into_iter().chain(composed.into_iter()) {
+        for name in single.into_iter().chain(composed) {
             assert!(Name::new(name).is_ok());
         }
 
@@ -299,11 +435,51 @@ mod tests {
         assert!(Extras::new(small_extras).is_ok());
 
         let name = extra::Name::new("Test").unwrap();
-        let extra = extra::Extra::new(name, extra::Price::from_cents(20));
+        let extra = extra::Extra::new(name, Money::from_minor(20, Currency::BRL));
         let big_extras: Vec<extra::Extra> = vec![extra; Extras::MAX_LEN + 1];
         assert_eq!(Extras::new(big_extras).err(), Some(ExtrasError::Length));
     }
 
+    #[test]
+    fn set_extras_max_quantity() {
+        let name = extra::Name::new("Cheddar").unwrap();
+        let cheddar = extra::Extra::new(name, Money::from_minor(200, Currency::BRL));
+        let mut extras = Extras::new(vec![cheddar.clone()]).unwrap();
+        assert_eq!(extras.max_quantity(cheddar.id()).value(), Quantity::MAX);
+
+        let double = Quantity::new(2).unwrap();
+        assert!(extras.set_max_quantity(cheddar.id(), double).is_ok());
+        assert_eq!(extras.max_quantity(cheddar.id()), double);
+
+        let unbound = extra::Id::new();
+        assert_eq!(
+            extras.set_max_quantity(unbound, double),
+            Err(ExtrasError::Unbound(unbound))
+        );
+    }
+
+    #[test]
+    fn override_extras_price() {
+        let name = extra::Name::new("Bacon").unwrap();
+        let bacon = extra::Extra::new(name, Money::from_minor(350, Currency::BRL));
+        let mut extras = Extras::new(vec![bacon.clone()]).unwrap();
+        assert!(!extras.is_overridden(bacon.id()));
+
+        let price = Money::from_minor(500, Currency::BRL);
+        assert!(extras.override_price(bacon.id(), price).is_ok());
+        assert!(extras.is_overridden(bacon.id()));
+        assert_eq!(
+            extras.find(bacon.id()).map(|extra| extra.price),
+            Some(price)
+        );
+
+        let unbound = extra::Id::new();
+        assert_eq!(
+            extras.override_price(unbound, price),
+            Err(ExtrasError::Unbound(unbound))
+        );
+    }
+
     #[test]
     fn kind_back_and_forth_str() {
         let kinds = [
@@ -324,4 +500,42 @@ mod tests {
             assert_eq!(parsed.unwrap().as_str(), kind.as_str());
         }
     }
+
+    #[test]
+    fn schedule_contains_in_season_and_windows() {
+        use time::{Month, PrimitiveDateTime, Time, Weekday};
+
+        use crate::core::schedule::Weekdays;
+
+        let weekdays = Weekdays::new(&[Weekday::Saturday, Weekday::Sunday]).unwrap();
+        let starts_at = Time::from_hms(18, 0, 0).unwrap();
+        let ends_at = Time::from_hms(23, 0, 0).unwrap();
+        let window = Window::new(weekdays, starts_at, ends_at, Zone::UTC).unwrap();
+        let starts_on = Date::from_calendar_date(2024, Month::June, 1).unwrap();
+        let ends_on = Date::from_calendar_date(2024, Month::August, 31).unwrap();
+        let schedule =
+            Schedule::new(vec![window], Some(starts_on), Some(ends_on), Zone::UTC).unwrap();
+
+        let at = |month, day, hour| {
+            let date = Date::from_calendar_date(2024, month, day).unwrap();
+            let time = Time::from_hms(hour, 0, 0).unwrap();
+            PrimitiveDateTime::new(date, time).assume_utc()
+        };
+
+        // 2024-08-17 is a saturday, 2024-08-19 a monday and 2024-09-07 a
+        // saturday out of season
+        assert!(schedule.contains(at(Month::August, 17, 19)));
+        assert!(!schedule.contains(at(Month::August, 17, 12)));
+        assert!(!schedule.contains(at(Month::August, 19, 19)));
+        assert!(!schedule.contains(at(Month::September, 7, 19)));
+
+        assert_eq!(
+            Schedule::new(vec![], None, None, Zone::UTC),
+            Err(ScheduleError::Empty)
+        );
+        assert_eq!(
+            Schedule::new(vec![], Some(ends_on), Some(starts_on), Zone::UTC),
+            Err(ScheduleError::Season)
+        );
+    }
 }
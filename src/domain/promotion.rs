@@ -0,0 +1,14 @@
+mod entity;
+mod error;
+mod pricing;
+mod repository;
+mod vo;
+
+pub use entity::{Promotion, PromotionConfig};
+pub use error::{ConflictKind, Error, NotFoundKind};
+pub use pricing::{Applied, Promotions};
+pub use repository::Repository;
+pub use vo::{
+    Discount, DiscountError, Id, Name, NameError, ParseIdError, Percentage, PercentageError,
+    Target, Targets, TargetsError, Validity, ValidityError,
+};
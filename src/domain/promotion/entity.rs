@@ -0,0 +1,159 @@
+use time::OffsetDateTime;
+
+use super::{Discount, Error, Id, Name, Targets, Validity};
+use crate::core::metadata;
+use crate::core::money::{Money, Rounding};
+use crate::exchange;
+
+/// Discount over the prices of its targets while it is valid. When many
+/// promotions apply to the same price the one with highest `priority` wins
+#[derive(Clone, Debug)]
+pub struct Promotion {
+    pub(super) id: Id,
+    pub name: Name,
+    pub discount: Discount,
+    pub targets: Targets,
+    pub validity: Validity,
+    pub priority: u8,
+    pub metadata: metadata::Metadata,
+}
+
+impl Promotion {
+    #[must_use]
+    pub fn new(
+        name: Name,
+        discount: Discount,
+        targets: Targets,
+        validity: Validity,
+        priority: u8,
+    ) -> Self {
+        Self {
+            id: Id::new(),
+            name,
+            discount,
+            targets,
+            validity,
+            priority,
+            metadata: metadata::Metadata::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn config(config: PromotionConfig) -> Self {
+        Self {
+            id: config.id,
+            name: config.name,
+            discount: config.discount,
+            targets: config.targets,
+            validity: config.validity,
+            priority: config.priority,
+            metadata: config.metadata,
+        }
+    }
+}
+
+impl Promotion {
+    #[must_use]
+    pub fn id(&self) -> Id {
+        self.id
+    }
+
+    #[must_use]
+    pub fn is_active_at(&self, at: OffsetDateTime) -> bool {
+        self.validity.contains(at)
+    }
+
+    /// Amount taken off `price`, never greater than `price` itself. Fixed
+    /// discounts in another currency do not apply, returning [`None`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if the computation overflows
+    pub fn discount_for(&self, price: Money) -> Result<Option<Money>, Error> {
+        let discount = match self.discount {
+            Discount::Percentage(percentage) => price
+                .checked_percentage(percentage.value())
+                .map_err(Error::money)?
+                .round(Rounding::HalfUp),
+            Discount::Fixed(amount) if amount.currency() == price.currency() => amount,
+            Discount::Fixed(_) => return Ok(None),
+        };
+
+        if discount.amount() > price.amount() {
+            return Ok(Some(price));
+        }
+
+        Ok(Some(discount))
+    }
+
+    /// Convert fixed discount with `rate`, so it applies to prices converted
+    /// with the same rate
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if the conversion overflows
+    pub fn exchange(&mut self, rate: &exchange::ExchangeRate) -> Result<(), exchange::Error> {
+        if let Discount::Fixed(amount) = self.discount {
+            if amount.currency() == rate.pair().from() {
+                self.discount = Discount::Fixed(rate.convert(amount)?);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PromotionConfig {
+    pub id: Id,
+    pub name: Name,
+    pub discount: Discount,
+    pub targets: Targets,
+    pub validity: Validity,
+    pub priority: u8,
+    pub metadata: metadata::Metadata,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::money::Currency;
+    use crate::product;
+    use crate::promotion::{Percentage, Target};
+
+    #[test]
+    fn discount_for_percentage() {
+        let promotion = mocked_promotion(Discount::Percentage(
+            Percentage::parse_str("15").expect("Valid percentage"),
+        ));
+
+        // 15% of 19.90 is 2.985
+        let discount = promotion.discount_for(brl(1990)).expect("Valid discount");
+        assert_eq!(discount, Some(brl(299)));
+    }
+
+    #[test]
+    fn discount_for_fixed() {
+        let promotion = mocked_promotion(Discount::fixed(brl(500)).expect("Valid fixed discount"));
+
+        assert_eq!(promotion.discount_for(brl(2000)).ok(), Some(Some(brl(500))));
+        assert_eq!(promotion.discount_for(brl(300)).ok(), Some(Some(brl(300))));
+
+        let usd = Money::from_minor(2000, Currency::USD);
+        assert_eq!(promotion.discount_for(usd).ok(), Some(None));
+    }
+
+    fn brl(cents: i64) -> Money {
+        Money::from_minor(cents, Currency::BRL)
+    }
+
+    fn mocked_promotion(discount: Discount) -> Promotion {
+        Promotion::new(
+            Name::new("Burger week").expect("Valid promotion name"),
+            discount,
+            Targets::new(vec![Target::Kind(product::Kind::Burger)]).expect("Valid targets"),
+            Validity::default(),
+            0,
+        )
+    }
+}
@@ -0,0 +1,66 @@
+use thiserror::Error;
+
+use super::Id;
+use crate::core::money::{Currency, MoneyError};
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error(transparent)]
+    Conflict(ConflictKind),
+    #[error(transparent)]
+    Internal(Box<dyn std::error::Error>),
+    #[error(transparent)]
+    NotFound(NotFoundKind),
+}
+
+impl Error {
+    /// Utility function to create [`Error::Internal`] without manually
+    /// boxing the error
+    #[must_use]
+    pub fn any(err: impl Into<Box<dyn std::error::Error>>) -> Self {
+        Self::Internal(err.into())
+    }
+
+    #[must_use]
+    pub fn id_conflict(id: Id) -> Self {
+        Self::Conflict(ConflictKind::Id(id))
+    }
+
+    #[must_use]
+    pub fn id_not_found(id: Id) -> Self {
+        Self::NotFound(NotFoundKind::Id(id))
+    }
+
+    #[must_use]
+    pub fn targets_not_found() -> Self {
+        Self::NotFound(NotFoundKind::Targets)
+    }
+
+    /// Maps a failed [`Money`](crate::core::money::Money) operation, where
+    /// mixing currencies is a conflict and overflowing is unexpected
+    #[must_use]
+    pub fn money(err: MoneyError) -> Self {
+        match err {
+            MoneyError::CurrencyMismatch { expected, found } => {
+                Self::Conflict(ConflictKind::Currency { expected, found })
+            }
+            err @ MoneyError::Overflow => Self::any(err),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum ConflictKind {
+    #[error("Discount in `{expected}` cannot be applied to prices in `{found}`")]
+    Currency { expected: Currency, found: Currency },
+    #[error("Promotion with id `{0}` already exists")]
+    Id(Id),
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum NotFoundKind {
+    #[error("Promotion with id `{0}` not found")]
+    Id(Id),
+    #[error("Some of promotion targets do not exist")]
+    Targets,
+}
@@ -0,0 +1,199 @@
+use time::OffsetDateTime;
+
+use super::{Error, Id, Name, Promotion};
+use crate::core::money::Money;
+use crate::exchange;
+use crate::extra;
+use crate::product;
+
+/// Promotion winning over a price, with the resulting promoted price
+#[derive(Clone, Debug)]
+pub struct Applied {
+    pub promotion_id: Id,
+    pub name: Name,
+    pub discount: Money,
+    pub price: Money,
+}
+
+/// Set of promotions, resolving which one wins over each price
+#[derive(Clone, Debug, Default)]
+pub struct Promotions(Vec<Promotion>);
+
+impl Promotions {
+    #[must_use]
+    pub fn new(promotions: Vec<Promotion>) -> Self {
+        Self(promotions)
+    }
+}
+
+impl Promotions {
+    #[must_use]
+    pub fn as_slice(&self) -> &[Promotion] {
+        &self.0
+    }
+
+    /// Convert fixed discounts of every promotion with `rate`
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if any conversion overflows
+    pub fn exchange(&mut self, rate: &exchange::ExchangeRate) -> Result<(), exchange::Error> {
+        self.0
+            .iter_mut()
+            .try_for_each(|promotion| promotion.exchange(rate))
+    }
+
+    /// Best promotion for `product` price active `at`
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if computing any discount overflows
+    pub fn best_for_product(
+        &self,
+        product: &product::Product,
+        at: OffsetDateTime,
+    ) -> Result<Option<Applied>, Error> {
+        self.best(product.price, at, |promotion| {
+            promotion
+                .targets
+                .iter()
+                .any(|target| target.matches_product(product))
+        })
+    }
+
+    /// Best promotion for `extra` price active `at`
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if computing any discount overflows
+    pub fn best_for_extra(
+        &self,
+        extra: &extra::Extra,
+        at: OffsetDateTime,
+    ) -> Result<Option<Applied>, Error> {
+        self.best(extra.price, at, |promotion| {
+            promotion
+                .targets
+                .iter()
+                .any(|target| target.matches_extra(extra))
+        })
+    }
+
+    /// Highest priority promotion matching and applicable to `price`, where
+    /// ties are broken by the biggest discount and then by the oldest promotion
+    fn best(
+        &self,
+        price: Money,
+        at: OffsetDateTime,
+        matches: impl Fn(&Promotion) -> bool,
+    ) -> Result<Option<Applied>, Error> {
+        let mut best: Option<(&Promotion, Money)> = None;
+        for promotion in &self.0 {
+            if !promotion.is_active_at(at) || !matches(promotion) {
+                continue;
+            }
+            let Some(discount) = promotion.discount_for(price)? else {
+                continue;
+            };
+
+            let wins = best.is_none_or(|(current, current_discount)| {
+                (promotion.priority, discount.amount(), current.id())
+                    > (current.priority, current_discount.amount(), promotion.id())
+            });
+            if wins {
+                best = Some((promotion, discount));
+            }
+        }
+
+        best.map(|(promotion, discount)| {
+            let price = price.checked_sub(discount).map_err(Error::money)?;
+            Ok(Applied {
+                promotion_id: promotion.id(),
+                name: promotion.name.clone(),
+                discount,
+                price,
+            })
+        })
+        .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Duration;
+
+    use super::*;
+    use crate::catalog;
+    use crate::core::money::Currency;
+    use crate::promotion::{Discount, Percentage, Target, Targets, Validity};
+
+    #[test]
+    fn best_for_product_prefers_priority() {
+        let product = mocked_product();
+        let cheap = mocked_promotion(Target::Kind(product::Kind::Burger), "10", 1);
+        let generous = mocked_promotion(Target::Product(product.id()), "50", 0);
+        let promotions = Promotions::new(vec![generous, cheap.clone()]);
+
+        let applied = promotions
+            .best_for_product(&product, OffsetDateTime::now_utc())
+            .expect("Valid pricing")
+            .expect("Applied promotion");
+        assert_eq!(applied.promotion_id, cheap.id());
+        assert_eq!(applied.discount, brl(200));
+        assert_eq!(applied.price, brl(1800));
+    }
+
+    #[test]
+    fn best_for_product_breaks_ties_by_discount() {
+        let product = mocked_product();
+        let small = mocked_promotion(Target::Catalog(product.catalog_id()), "10", 0);
+        let big = mocked_promotion(Target::Product(product.id()), "25", 0);
+        let promotions = Promotions::new(vec![small, big.clone()]);
+
+        let applied = promotions
+            .best_for_product(&product, OffsetDateTime::now_utc())
+            .expect("Valid pricing");
+        assert_eq!(applied.map(|a| a.promotion_id), Some(big.id()));
+    }
+
+    #[test]
+    fn best_for_product_ignores_inactive_and_unmatched() {
+        let product = mocked_product();
+        let now = OffsetDateTime::now_utc();
+        let mut expired = mocked_promotion(Target::Product(product.id()), "50", 9);
+        expired.validity =
+            Validity::new(None, Some(now - Duration::days(1))).expect("Valid validity");
+        let other_kind = mocked_promotion(Target::Kind(product::Kind::Vegan), "50", 9);
+        let extra_only = mocked_promotion(Target::Extra(extra::Id::new()), "50", 9);
+        let promotions = Promotions::new(vec![expired, other_kind, extra_only]);
+
+        let applied = promotions
+            .best_for_product(&product, now)
+            .expect("Valid pricing");
+        assert!(applied.is_none());
+    }
+
+    fn brl(cents: i64) -> Money {
+        Money::from_minor(cents, Currency::BRL)
+    }
+
+    fn mocked_product() -> product::Product {
+        product::Product::new(
+            catalog::Id::new(),
+            product::Name::new("Cheese Burger").expect("Valid product name"),
+            brl(2000),
+            product::Kind::Burger,
+            product::Extras::default(),
+        )
+    }
+
+    fn mocked_promotion(target: Target, percentage: &str, priority: u8) -> Promotion {
+        Promotion::new(
+            Name::new("Promotion").expect("Valid promotion name"),
+            Discount::Percentage(Percentage::parse_str(percentage).expect("Valid percentage")),
+            Targets::new(vec![target]).expect("Valid targets"),
+            Validity::default(),
+            priority,
+        )
+    }
+}
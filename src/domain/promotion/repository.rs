@@ -0,0 +1,16 @@
+use time::OffsetDateTime;
+
+use super::error::Error;
+use super::{Id, Promotion, Promotions};
+
+// TODO: remove when stabilized
+#[allow(async_fn_in_trait)]
+pub trait Repository: Send + Clone {
+    /// Promotions whose validity contains `at`
+    async fn active(&self, at: OffsetDateTime) -> Result<Promotions, Error>;
+    async fn all(&self) -> Result<Vec<Promotion>, Error>;
+    async fn create(&mut self, promotion: &Promotion) -> Result<(), Error>;
+    async fn delete(&mut self, id: Id) -> Result<Promotion, Error>;
+    async fn find(&self, id: Id) -> Result<Promotion, Error>;
+    async fn update(&mut self, promotion: &Promotion) -> Result<(), Error>;
+}
@@ -0,0 +1,377 @@
+use std::fmt;
+use std::str::FromStr;
+
+use rust_decimal::Decimal;
+use thiserror::Error;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::catalog;
+use crate::core::money::Money;
+use crate::core::string::trim_in_place;
+use crate::extra;
+use crate::product;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Id(Uuid);
+
+impl Id {
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::now_v7())
+    }
+
+    /// Try parsing `value` into [`Id`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `value` is not a valid [`Id`]
+    pub fn parse_str(value: &str) -> Result<Self, ParseIdError> {
+        Uuid::parse_str(value)
+            .map(Self)
+            .map_err(|_| ParseIdError(Box::from(value)))
+    }
+}
+
+impl Id {
+    #[must_use]
+    pub fn uuid(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for Id {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for Id {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<Uuid> for Id {
+    fn from(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Name(String);
+
+impl Name {
+    pub const MAX_LEN: usize = 64;
+
+    /// Try parsing `name` into [`Name`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `name` is empty or longer than [`Name::MAX_LEN`]
+    pub fn new(name: impl Into<String>) -> Result<Self, NameError> {
+        let mut name: String = name.into();
+        trim_in_place(&mut name);
+        if name.is_empty() {
+            return Err(NameError::Empty);
+        }
+        if name.len() > Self::MAX_LEN {
+            return Err(NameError::Length);
+        }
+
+        Ok(Self(name))
+    }
+}
+
+impl Name {
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Percentage taken off a price, greater than 0% and up to 100%
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Percentage(Decimal);
+
+impl Percentage {
+    pub const MAX_SCALE: u32 = 2;
+
+    /// Try creating a [`Percentage`] from `value`
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `value` is not greater than 0 and up to 100 or
+    /// has more than [`Percentage::MAX_SCALE`] decimal places
+    pub fn new(value: Decimal) -> Result<Self, PercentageError> {
+        let value = value.normalize();
+        if value <= Decimal::ZERO || value > Decimal::ONE_HUNDRED {
+            return Err(PercentageError::Range);
+        }
+        if value.scale() > Self::MAX_SCALE {
+            return Err(PercentageError::Precision);
+        }
+
+        Ok(Self(value))
+    }
+
+    /// Try parsing `value` into [`Percentage`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `value` is not a valid [`Percentage`]
+    pub fn parse_str(value: &str) -> Result<Self, PercentageError> {
+        let decimal =
+            Decimal::from_str(value).map_err(|_| PercentageError::Parse(Box::from(value)))?;
+        Self::new(decimal)
+    }
+}
+
+impl Percentage {
+    #[must_use]
+    pub fn value(&self) -> Decimal {
+        self.0
+    }
+}
+
+impl fmt::Display for Percentage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// How much a promotion takes off a price
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Discount {
+    Percentage(Percentage),
+    /// Fixed amount, only applicable to prices in the same currency
+    Fixed(Money),
+}
+
+impl Discount {
+    /// Try creating a [`Discount::Fixed`] of `amount`
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `amount` is not greater than zero
+    pub fn fixed(amount: Money) -> Result<Self, DiscountError> {
+        if amount.is_negative() || amount.amount().is_zero() {
+            return Err(DiscountError::NotPositive);
+        }
+
+        Ok(Self::Fixed(amount))
+    }
+}
+
+/// Products and extras a promotion is restricted to
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Target {
+    /// Every product of a catalog
+    Catalog(catalog::Id),
+    /// Every product of a kind
+    Kind(product::Kind),
+    Product(product::Id),
+    /// An extra, in every product it is added to
+    Extra(extra::Id),
+}
+
+impl Target {
+    #[must_use]
+    pub fn matches_product(&self, product: &product::Product) -> bool {
+        match self {
+            Self::Catalog(catalog_id) => product.catalog_id() == *catalog_id,
+            Self::Kind(kind) => product.kind == *kind,
+            Self::Product(product_id) => product.id() == *product_id,
+            Self::Extra(_) => false,
+        }
+    }
+
+    #[must_use]
+    pub fn matches_extra(&self, extra: &extra::Extra) -> bool {
+        matches!(self, Self::Extra(extra_id) if extra.id() == *extra_id)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Targets(Vec<Target>);
+
+impl Targets {
+    pub const MAX_LEN: usize = 32;
+
+    /// Try parsing `targets` into [`Targets`], ignoring duplicates
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `targets` is empty or has more items than allowed
+    pub fn new(targets: Vec<Target>) -> Result<Self, TargetsError> {
+        let mut unique_targets: Vec<Target> = Vec::with_capacity(targets.len());
+        for target in targets {
+            if !unique_targets.contains(&target) {
+                unique_targets.push(target);
+            }
+        }
+
+        if unique_targets.is_empty() {
+            return Err(TargetsError::Empty);
+        }
+        if unique_targets.len() > Self::MAX_LEN {
+            return Err(TargetsError::Length);
+        }
+
+        Ok(Self(unique_targets))
+    }
+}
+
+impl Targets {
+    #[must_use]
+    pub fn as_slice(&self) -> &[Target] {
+        &self.0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Target> {
+        self.0.iter()
+    }
+}
+
+/// Period a promotion is active, where a missing bound means it is unbounded
+/// on that side. `starts_at` is inclusive and `ends_at` is exclusive
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Validity {
+    starts_at: Option<OffsetDateTime>,
+    ends_at: Option<OffsetDateTime>,
+}
+
+impl Validity {
+    /// Try creating a [`Validity`] between `starts_at` and `ends_at`
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `ends_at` is not after `starts_at`
+    pub fn new(
+        starts_at: Option<OffsetDateTime>,
+        ends_at: Option<OffsetDateTime>,
+    ) -> Result<Self, ValidityError> {
+        if let (Some(starts_at), Some(ends_at)) = (starts_at, ends_at) {
+            if ends_at <= starts_at {
+                return Err(ValidityError::Range);
+            }
+        }
+
+        Ok(Self { starts_at, ends_at })
+    }
+}
+
+impl Validity {
+    #[must_use]
+    pub fn starts_at(&self) -> Option<OffsetDateTime> {
+        self.starts_at
+    }
+
+    #[must_use]
+    pub fn ends_at(&self) -> Option<OffsetDateTime> {
+        self.ends_at
+    }
+
+    #[must_use]
+    pub fn contains(&self, at: OffsetDateTime) -> bool {
+        self.starts_at.is_none_or(|starts_at| starts_at <= at)
+            && self.ends_at.is_none_or(|ends_at| at < ends_at)
+    }
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[error("Provided string `{0}` is not a valid promotion id")]
+pub struct ParseIdError(pub Box<str>);
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum NameError {
+    #[error("Promotion name cannot be empty")]
+    Empty,
+    #[error("Promotion name cannot have more than {len} characters", len = Name::MAX_LEN)]
+    Length,
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum PercentageError {
+    #[error("Provided string `{0}` is not a valid discount percentage")]
+    Parse(Box<str>),
+    #[error("Discount percentage cannot have more than {scale} decimal places", scale = Percentage::MAX_SCALE)]
+    Precision,
+    #[error("Discount percentage must be greater than 0 and up to 100")]
+    Range,
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum DiscountError {
+    #[error("Fixed discount must be greater than zero")]
+    NotPositive,
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum TargetsError {
+    #[error("Promotion must target at least one catalog, kind, product or extra")]
+    Empty,
+    #[error("Promotion cannot have more than {len} targets", len = Targets::MAX_LEN)]
+    Length,
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum ValidityError {
+    #[error("Promotion must end after it starts")]
+    Range,
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn parse_percentage() {
+        assert_eq!(
+            Percentage::parse_str("15.5").map(|p| p.value()),
+            Ok(Decimal::new(155, 1))
+        );
+        assert!(Percentage::parse_str("100").is_ok());
+        assert_eq!(Percentage::parse_str("0"), Err(PercentageError::Range));
+        assert_eq!(Percentage::parse_str("100.5"), Err(PercentageError::Range));
+        assert_eq!(
+            Percentage::parse_str("1.005"),
+            Err(PercentageError::Precision)
+        );
+    }
+
+    #[test]
+    fn new_targets() {
+        let extra_id = extra::Id::new();
+        let targets = Targets::new(vec![Target::Extra(extra_id), Target::Extra(extra_id)])
+            .expect("Valid targets");
+        assert_eq!(targets.as_slice(), &[Target::Extra(extra_id)]);
+
+        assert_eq!(Targets::new(vec![]), Err(TargetsError::Empty));
+    }
+
+    #[test]
+    fn validity_contains() {
+        let now = OffsetDateTime::now_utc();
+        let validity =
+            Validity::new(Some(now), Some(now + Duration::hours(1))).expect("Valid validity");
+
+        assert!(validity.contains(now));
+        assert!(!validity.contains(now - Duration::seconds(1)));
+        assert!(!validity.contains(now + Duration::hours(1)));
+        assert!(Validity::default().contains(now));
+
+        assert_eq!(
+            Validity::new(Some(now), Some(now)),
+            Err(ValidityError::Range)
+        );
+    }
+}
@@ -0,0 +1,12 @@
+mod entity;
+mod error;
+mod repository;
+mod vo;
+
+pub use entity::{Line, LineConfig, LineExtra, Refund, RefundConfig, Sale, SaleConfig};
+pub use error::{ConflictKind, Error, NotFoundKind};
+pub use repository::{ListQuery, Pagination, Repository};
+pub use vo::{
+    Id, LineId, Lines, LinesError, ParseIdError, ParseLineIdError, Reason, ReasonError, RefundId,
+    RefundLine, RefundLines, RefundLinesError,
+};
@@ -0,0 +1,531 @@
+use time::OffsetDateTime;
+
+use super::{ConflictKind, Error, Id, LineId, Lines, Reason, RefundId, RefundLine, RefundLines};
+use crate::catalog;
+use crate::core::metadata;
+use crate::core::money::Money;
+use crate::core::quantity::Quantity;
+use crate::extra;
+use crate::product;
+
+/// Ledger entry of products sold together, along with every refund given
+/// back from it
+#[derive(Clone, Debug)]
+pub struct Sale {
+    pub(super) id: Id,
+    lines: Lines,
+    refunds: Vec<Refund>,
+    pub metadata: metadata::Metadata,
+}
+
+impl Sale {
+    #[must_use]
+    pub fn new(lines: Lines) -> Self {
+        Self {
+            id: Id::new(),
+            lines,
+            refunds: Vec::new(),
+            metadata: metadata::Metadata::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn config(config: SaleConfig) -> Self {
+        Self {
+            id: config.id,
+            lines: config.lines,
+            refunds: config.refunds,
+            metadata: config.metadata,
+        }
+    }
+}
+
+impl Sale {
+    #[must_use]
+    pub fn id(&self) -> Id {
+        self.id
+    }
+
+    #[must_use]
+    pub fn lines(&self) -> &Lines {
+        &self.lines
+    }
+
+    #[must_use]
+    pub fn refunds(&self) -> &[Refund] {
+        &self.refunds
+    }
+
+    /// Sum of every line total, already considering quantities
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if lines are priced in different currencies or if
+    /// the sum overflows
+    pub fn total(&self) -> Result<Money, Error> {
+        let totals = self
+            .lines
+            .iter()
+            .map(Line::total)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Money::checked_sum(self.currency_line().price().currency(), totals).map_err(Error::money)
+    }
+
+    /// Sum of every refund amount given back so far
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if refunds are in another currency or if the sum
+    /// overflows
+    pub fn refunded(&self) -> Result<Money, Error> {
+        let amounts = self.refunds.iter().map(|refund| refund.amount);
+        Money::checked_sum(self.currency_line().price().currency(), amounts).map_err(Error::money)
+    }
+
+    /// Units of line `line_id` still available to be refunded, or [`None`] if
+    /// the line is not part of this sale
+    #[must_use]
+    pub fn refundable_quantity(&self, line_id: LineId) -> Option<u16> {
+        let line = self.lines.iter().find(|line| line.id == line_id)?;
+        let refunded = self
+            .refunds
+            .iter()
+            .flat_map(|refund| refund.lines.iter())
+            .filter(|refund_line| refund_line.line_id == line_id)
+            .map(|refund_line| refund_line.quantity.value())
+            .sum::<u16>();
+
+        Some(line.quantity.value().saturating_sub(refunded))
+    }
+
+    /// Refund every unit not refunded yet
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if sale is already fully refunded
+    pub fn refund_all(&mut self, reason: Reason) -> Result<Refund, Error> {
+        let lines = self
+            .lines
+            .iter()
+            .filter_map(|line| {
+                let refundable = self.refundable_quantity(line.id)?;
+                let quantity = Quantity::new(refundable).ok()?;
+                Some(RefundLine {
+                    line_id: line.id,
+                    quantity,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        if lines.is_empty() {
+            return Err(Error::Conflict(ConflictKind::Refunded(self.id)));
+        }
+
+        let lines = RefundLines::new(lines).map_err(Error::any)?;
+        self.refund_lines(reason, lines)
+    }
+
+    /// Refund only the given units of each sale line in `lines`
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if any line is not part of this sale or if more
+    /// units than still refundable are requested
+    pub fn refund_lines(&mut self, reason: Reason, lines: RefundLines) -> Result<Refund, Error> {
+        let currency = self.currency_line().price().currency();
+        let mut amounts = Vec::with_capacity(lines.as_slice().len());
+        for refund_line in lines.iter() {
+            let line = self
+                .lines
+                .iter()
+                .find(|line| line.id == refund_line.line_id)
+                .ok_or_else(|| Error::line_not_found(refund_line.line_id, self.id))?;
+
+            let amount = line
+                .unit_price()?
+                .checked_mul(refund_line.quantity.value())
+                .map_err(Error::money)?;
+
+            amounts.push(amount);
+        }
+
+        let refund = Refund {
+            id: RefundId::new(),
+            reason,
+            lines,
+            amount: Money::checked_sum(currency, amounts).map_err(Error::money)?,
+            created_at: OffsetDateTime::now_utc(),
+        };
+
+        self.add_refund(refund.clone())?;
+        Ok(refund)
+    }
+
+    /// Record an already priced `refund`, such as one loaded from storage
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if any line is not part of this sale or if more
+    /// units than still refundable are refunded
+    pub fn add_refund(&mut self, refund: Refund) -> Result<(), Error> {
+        for refund_line in refund.lines.iter() {
+            let refundable = self
+                .refundable_quantity(refund_line.line_id)
+                .ok_or_else(|| Error::line_not_found(refund_line.line_id, self.id))?;
+
+            let requested = refund_line.quantity.value();
+            if requested > refundable {
+                return Err(Error::refund_conflict(
+                    refund_line.line_id,
+                    requested,
+                    refundable,
+                ));
+            }
+        }
+
+        self.refunds.push(refund);
+        self.metadata.update();
+        Ok(())
+    }
+
+    fn currency_line(&self) -> &Line {
+        &self.lines.as_slice()[0]
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SaleConfig {
+    pub id: Id,
+    pub lines: Lines,
+    pub refunds: Vec<Refund>,
+    pub metadata: metadata::Metadata,
+}
+
+/// Snapshot of a [`product::Product`] at the moment it was sold, so later
+/// changes to the product do not rewrite the sales ledger
+#[derive(Clone, Debug)]
+pub struct Line {
+    id: LineId,
+    snapshot: product::Snapshot,
+    quantity: Quantity,
+}
+
+impl Line {
+    /// Try creating a [`Line`] from `product` with the chosen `extras_ids`
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if any of `extras_ids` is not one of `product`
+    /// extras or is repeated, or if, taking a single unit of each, they break
+    /// the rules of any modifier group of `product`
+    pub fn new(
+        product: &product::Product,
+        extras_ids: &[extra::Id],
+        quantity: Quantity,
+    ) -> Result<Self, Error> {
+        let snapshot = product.snapshot(extras_ids).map_err(Error::snapshot)?;
+
+        Ok(Self {
+            id: LineId::new(),
+            snapshot,
+            quantity,
+        })
+    }
+
+    #[must_use]
+    pub fn config(config: LineConfig) -> Self {
+        Self {
+            id: config.id,
+            snapshot: product::Snapshot {
+                product_id: config.product_id,
+                catalog_id: config.catalog_id,
+                name: config.name,
+                price: config.price,
+                extras: config.extras,
+            },
+            quantity: config.quantity,
+        }
+    }
+}
+
+impl Line {
+    #[must_use]
+    pub fn id(&self) -> LineId {
+        self.id
+    }
+
+    #[must_use]
+    pub fn product_id(&self) -> product::Id {
+        self.snapshot.product_id
+    }
+
+    #[must_use]
+    pub fn catalog_id(&self) -> catalog::Id {
+        self.snapshot.catalog_id
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &product::Name {
+        &self.snapshot.name
+    }
+
+    #[must_use]
+    pub fn price(&self) -> Money {
+        self.snapshot.price
+    }
+
+    #[must_use]
+    pub fn quantity(&self) -> Quantity {
+        self.quantity
+    }
+
+    #[must_use]
+    pub fn extras(&self) -> &[LineExtra] {
+        &self.snapshot.extras
+    }
+
+    /// Price of a single unit of this line, including its extras
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if extras are priced in another currency or if the
+    /// sum overflows
+    pub fn unit_price(&self) -> Result<Money, Error> {
+        self.snapshot.unit_price().map_err(Error::money)
+    }
+
+    /// Price of this line considering its quantity
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if [`Line::unit_price`] fails or if the
+    /// multiplication overflows
+    pub fn total(&self) -> Result<Money, Error> {
+        self.unit_price()?
+            .checked_mul(self.quantity.value())
+            .map_err(Error::money)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct LineConfig {
+    pub id: LineId,
+    pub product_id: product::Id,
+    pub catalog_id: catalog::Id,
+    pub name: product::Name,
+    pub price: Money,
+    pub quantity: Quantity,
+    pub extras: Vec<LineExtra>,
+}
+
+/// Snapshot of an [`extra::Extra`] chosen for a [`Line`]
+pub type LineExtra = product::ExtraSnapshot;
+
+/// Money given back from a [`Sale`], priced from the snapshotted lines
+#[derive(Clone, Debug)]
+pub struct Refund {
+    id: RefundId,
+    reason: Reason,
+    lines: RefundLines,
+    amount: Money,
+    created_at: OffsetDateTime,
+}
+
+impl Refund {
+    #[must_use]
+    pub fn config(config: RefundConfig) -> Self {
+        Self {
+            id: config.id,
+            reason: config.reason,
+            lines: config.lines,
+            amount: config.amount,
+            created_at: config.created_at,
+        }
+    }
+}
+
+impl Refund {
+    #[must_use]
+    pub fn id(&self) -> RefundId {
+        self.id
+    }
+
+    #[must_use]
+    pub fn reason(&self) -> &Reason {
+        &self.reason
+    }
+
+    #[must_use]
+    pub fn lines(&self) -> &RefundLines {
+        &self.lines
+    }
+
+    #[must_use]
+    pub fn amount(&self) -> Money {
+        self.amount
+    }
+
+    #[must_use]
+    pub fn created_at(&self) -> OffsetDateTime {
+        self.created_at
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RefundConfig {
+    pub id: RefundId,
+    pub reason: Reason,
+    pub lines: RefundLines,
+    pub amount: Money,
+    pub created_at: OffsetDateTime,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::money::Currency;
+    use crate::sale::NotFoundKind;
+
+    #[test]
+    fn refund_lines_works() {
+        let mut sale = mocked_sale(3);
+        let line_id = sale.lines().as_slice()[0].id();
+        let lines = refund_lines(line_id, 2);
+
+        let refund = sale
+            .refund_lines(mocked_reason(), lines)
+            .expect("Valid partial refund");
+
+        // 2 * (20.00 + 2.00)
+        assert_eq!(refund.amount(), brl(4400));
+        assert_eq!(sale.refundable_quantity(line_id), Some(1));
+        assert_eq!(sale.refunded().ok(), Some(brl(4400)));
+    }
+
+    #[test]
+    fn refund_lines_exceeding_sold_quantity() {
+        let mut sale = mocked_sale(3);
+        let line_id = sale.lines().as_slice()[0].id();
+        sale.refund_lines(mocked_reason(), refund_lines(line_id, 2))
+            .expect("Valid partial refund");
+
+        let result = sale.refund_lines(mocked_reason(), refund_lines(line_id, 2));
+        assert!(matches!(
+            result,
+            Err(Error::Conflict(ConflictKind::Refund {
+                line_id: err_line_id,
+                requested: 2,
+                refundable: 1
+            })) if err_line_id == line_id
+        ));
+        assert_eq!(sale.refunds().len(), 1);
+    }
+
+    #[test]
+    fn refund_lines_with_line_not_found() {
+        let mut sale = mocked_sale(1);
+        let line_id = LineId::new();
+
+        let result = sale.refund_lines(mocked_reason(), refund_lines(line_id, 1));
+        assert!(matches!(
+            result,
+            Err(Error::NotFound(NotFoundKind::Line { line_id: err_line_id, sale_id }))
+                if err_line_id == line_id && sale_id == sale.id()
+        ));
+    }
+
+    #[test]
+    fn refund_all_refunds_what_is_left() {
+        let mut sale = mocked_sale(3);
+        let line_id = sale.lines().as_slice()[0].id();
+        sale.refund_lines(mocked_reason(), refund_lines(line_id, 1))
+            .expect("Valid partial refund");
+
+        let refund = sale.refund_all(mocked_reason()).expect("Valid full refund");
+        assert_eq!(refund.amount(), brl(4400));
+        assert_eq!(sale.refunded().ok(), sale.total().ok());
+
+        let result = sale.refund_all(mocked_reason());
+        assert!(matches!(
+            result,
+            Err(Error::Conflict(ConflictKind::Refunded(id))) if id == sale.id()
+        ));
+    }
+
+    #[test]
+    fn new_line_with_repeated_extra() {
+        let cheddar = mocked_extra("Cheddar");
+        let product = mocked_product(vec![cheddar.clone()]);
+        let one = Quantity::new(1).expect("Valid quantity");
+
+        let result = Line::new(&product, &[cheddar.id(), cheddar.id()], one);
+        assert!(matches!(
+            result,
+            Err(Error::Conflict(ConflictKind::ExtraRepeated(id))) if id == cheddar.id()
+        ));
+    }
+
+    #[test]
+    fn new_line_with_selection_conflict() {
+        use crate::modifier;
+
+        let ketchup = mocked_extra("Ketchup");
+        let mut product = mocked_product(vec![ketchup.clone()]);
+        let sauces = modifier::Group::new(
+            product.id(),
+            modifier::Name::new("Sauces").expect("Valid modifier group name"),
+            modifier::Limits::new(1, 1).expect("Valid limits"),
+            modifier::Options::new(vec![ketchup.id()]).expect("Valid modifier group options"),
+        );
+        product.modifiers = modifier::Groups::new(vec![sauces]).expect("Valid modifier groups");
+        let one = Quantity::new(1).expect("Valid quantity");
+
+        let result = Line::new(&product, &[], one);
+        assert!(matches!(
+            result,
+            Err(Error::Conflict(ConflictKind::Selection(
+                modifier::SelectionError::Missing { min: 1, .. }
+            )))
+        ));
+    }
+
+    fn brl(cents: i64) -> Money {
+        Money::from_minor(cents, Currency::BRL)
+    }
+
+    fn refund_lines(line_id: LineId, quantity: u16) -> RefundLines {
+        RefundLines::new(vec![RefundLine {
+            line_id,
+            quantity: Quantity::new(quantity).expect("Valid quantity"),
+        }])
+        .expect("Valid refund lines")
+    }
+
+    fn mocked_reason() -> Reason {
+        Reason::new("Customer changed their mind").expect("Valid reason")
+    }
+
+    fn mocked_extra(name: &str) -> extra::Extra {
+        extra::Extra::new(extra::Name::new(name).expect("Valid extra name"), brl(200))
+    }
+
+    fn mocked_product(extras: Vec<extra::Extra>) -> product::Product {
+        product::Product::new(
+            catalog::Id::new(),
+            product::Name::new("Cheese Burger").expect("Valid product name"),
+            brl(2000),
+            product::Kind::Burger,
+            product::Extras::new(extras).expect("Valid product extras"),
+        )
+    }
+
+    fn mocked_sale(quantity: u16) -> Sale {
+        let cheddar = mocked_extra("Cheddar");
+        let product = mocked_product(vec![cheddar.clone()]);
+        let quantity = Quantity::new(quantity).expect("Valid quantity");
+        let line = Line::new(&product, &[cheddar.id()], quantity).expect("Valid sale line");
+
+        Sale::new(Lines::new(vec![line]).expect("Valid sale lines"))
+    }
+}
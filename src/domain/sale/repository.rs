@@ -0,0 +1,28 @@
+use std::num::{NonZeroU32, NonZeroU8};
+
+use super::{Error, Id, Refund, Sale};
+
+// TODO: remove when stabilized
+#[allow(async_fn_in_trait)]
+pub trait Repository: Send + Clone {
+    async fn create(&mut self, sale: &Sale) -> Result<(), Error>;
+    async fn find(&self, id: Id) -> Result<Sale, Error>;
+    async fn list(&self, query: ListQuery) -> Result<Pagination, Error>;
+    /// Record `refund` for sale `id`, failing instead of refunding more units
+    /// than were sold even when other refunds are recorded concurrently
+    async fn refund(&mut self, id: Id, refund: &Refund) -> Result<(), Error>;
+}
+
+#[derive(Clone, Debug)]
+pub struct ListQuery {
+    pub page: NonZeroU32,
+    pub limit: NonZeroU8,
+}
+
+#[derive(Clone, Debug)]
+pub struct Pagination {
+    pub count: u64,
+    pub page: NonZeroU32,
+    pub limit: NonZeroU8,
+    pub items: Vec<Sale>,
+}
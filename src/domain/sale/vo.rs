@@ -0,0 +1,352 @@
+use std::fmt;
+
+use thiserror::Error;
+use uuid::Uuid;
+
+use super::Line;
+use crate::core::quantity::Quantity;
+use crate::core::string::trim_in_place;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Id(Uuid);
+
+impl Id {
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::now_v7())
+    }
+
+    /// Try parsing `value` into [`Id`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `value` is not a valid [`Id`]
+    pub fn parse_str(value: &str) -> Result<Self, ParseIdError> {
+        match Uuid::parse_str(value) {
+            Ok(uuid) => Ok(Self(uuid)),
+            Err(_) => Err(ParseIdError(Box::from(value))),
+        }
+    }
+}
+
+impl Id {
+    #[must_use]
+    pub fn uuid(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for Id {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for Id {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<Uuid> for Id {
+    fn from(value: Uuid) -> Self {
+        Self(value)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct LineId(Uuid);
+
+impl LineId {
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::now_v7())
+    }
+
+    /// Try parsing `value` into [`LineId`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `value` is not a valid [`LineId`]
+    pub fn parse_str(value: &str) -> Result<Self, ParseLineIdError> {
+        match Uuid::parse_str(value) {
+            Ok(uuid) => Ok(Self(uuid)),
+            Err(_) => Err(ParseLineIdError(Box::from(value))),
+        }
+    }
+}
+
+impl LineId {
+    #[must_use]
+    pub fn uuid(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for LineId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for LineId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<Uuid> for LineId {
+    fn from(value: Uuid) -> Self {
+        Self(value)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct RefundId(Uuid);
+
+impl RefundId {
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::now_v7())
+    }
+}
+
+impl RefundId {
+    #[must_use]
+    pub fn uuid(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for RefundId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for RefundId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<Uuid> for RefundId {
+    fn from(value: Uuid) -> Self {
+        Self(value)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Lines(Vec<Line>);
+
+impl Lines {
+    pub const MAX_LEN: usize = 64;
+
+    /// Try parsing `lines` into [`Lines`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `lines` is empty or has more lines than allowed
+    pub fn new(lines: Vec<Line>) -> Result<Self, LinesError> {
+        if lines.is_empty() {
+            return Err(LinesError::Empty);
+        }
+
+        if lines.len() > Self::MAX_LEN {
+            return Err(LinesError::Length);
+        }
+
+        Ok(Self(lines))
+    }
+}
+
+impl Lines {
+    #[must_use]
+    pub fn as_slice(&self) -> &[Line] {
+        &self.0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Line> {
+        self.0.iter()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Why a sale, or part of it, was given back to the customer
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Reason(String);
+
+impl Reason {
+    pub const MAX_LEN: usize = 256;
+
+    /// Try parsing `reason` into [`Reason`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `reason` is empty or longer than [`Reason::MAX_LEN`]
+    pub fn new(reason: impl Into<String>) -> Result<Self, ReasonError> {
+        let mut reason: String = reason.into();
+        trim_in_place(&mut reason);
+        if reason.is_empty() {
+            return Err(ReasonError::Empty);
+        }
+        if reason.chars().count() > Self::MAX_LEN {
+            return Err(ReasonError::Length);
+        }
+
+        Ok(Self(reason))
+    }
+}
+
+impl Reason {
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Units of a single sale line being refunded
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RefundLine {
+    pub line_id: LineId,
+    pub quantity: Quantity,
+}
+
+/// Lines of a partial refund, each sale line appearing at most once
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundLines(Vec<RefundLine>);
+
+impl RefundLines {
+    /// Try parsing `lines` into [`RefundLines`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `lines` is empty, has more lines than a sale can
+    /// have or refers to the same sale line more than once
+    pub fn new(lines: Vec<RefundLine>) -> Result<Self, RefundLinesError> {
+        if lines.is_empty() {
+            return Err(RefundLinesError::Empty);
+        }
+        if lines.len() > Lines::MAX_LEN {
+            return Err(RefundLinesError::Length);
+        }
+
+        for (idx, line) in lines.iter().enumerate() {
+            if lines[..idx].iter().any(|l| l.line_id == line.line_id) {
+                return Err(RefundLinesError::Duplicated(line.line_id));
+            }
+        }
+
+        Ok(Self(lines))
+    }
+}
+
+impl RefundLines {
+    #[must_use]
+    pub fn as_slice(&self) -> &[RefundLine] {
+        &self.0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &RefundLine> {
+        self.0.iter()
+    }
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[error("Provided string `{0}` is not a valid sale id")]
+pub struct ParseIdError(pub Box<str>);
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[error("Provided string `{0}` is not a valid sale line id")]
+pub struct ParseLineIdError(pub Box<str>);
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum LinesError {
+    #[error("Sale must have at least one line")]
+    Empty,
+    #[error("Sale cannot have more than {len} lines", len = Lines::MAX_LEN)]
+    Length,
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum ReasonError {
+    #[error("Refund reason cannot be empty")]
+    Empty,
+    #[error("Refund reason cannot have more than {len} characters", len = Reason::MAX_LEN)]
+    Length,
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum RefundLinesError {
+    #[error("Partial refund must have at least one line")]
+    Empty,
+    #[error("Partial refund cannot have more than {len} lines", len = Lines::MAX_LEN)]
+    Length,
+    #[error("Sale line `{0}` cannot be refunded twice in the same refund")]
+    Duplicated(LineId),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_id_works() {
+        let valid = Id::new().to_string();
+        assert!(Id::parse_str(&valid).is_ok());
+
+        let invalid = "invalid-id";
+        assert_eq!(
+            Id::parse_str(invalid),
+            Err(ParseIdError(Box::from(invalid)))
+        );
+    }
+
+    #[test]
+    fn reason_works() {
+        assert_eq!(
+            Reason::new("  Burger arrived cold ").map(|reason| reason.0),
+            Ok(String::from("Burger arrived cold"))
+        );
+        assert_eq!(Reason::new("   "), Err(ReasonError::Empty));
+        assert_eq!(
+            Reason::new("a".repeat(Reason::MAX_LEN + 1)),
+            Err(ReasonError::Length)
+        );
+    }
+
+    #[test]
+    fn refund_lines_with_duplicated_line() {
+        let line_id = LineId::new();
+        let quantity = Quantity::new(1).expect("Valid quantity");
+        let lines = vec![
+            RefundLine { line_id, quantity },
+            RefundLine {
+                line_id: LineId::new(),
+                quantity,
+            },
+            RefundLine { line_id, quantity },
+        ];
+
+        assert_eq!(
+            RefundLines::new(lines),
+            Err(RefundLinesError::Duplicated(line_id))
+        );
+        assert_eq!(RefundLines::new(vec![]), Err(RefundLinesError::Empty));
+    }
+}
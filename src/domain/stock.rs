@@ -0,0 +1,9 @@
+mod entity;
+mod error;
+mod repository;
+mod vo;
+
+pub use entity::{Stock, StockConfig, Stocks};
+pub use error::{ConflictKind, Error, NotFoundKind};
+pub use repository::Repository;
+pub use vo::{Adjustment, Level, LevelError, Quantity, QuantityError};
@@ -0,0 +1,227 @@
+use super::{Adjustment, ConflictKind, Error, Level, Quantity};
+use crate::core::metadata;
+use crate::product;
+
+/// Units on hand of a single product. Products without a [`Stock`] are not
+/// tracked, being always available
+#[derive(Clone, Debug)]
+pub struct Stock {
+    product_id: product::Id,
+    level: Level,
+    held: u32,
+    pub metadata: metadata::Metadata,
+}
+
+impl Stock {
+    #[must_use]
+    pub fn new(product_id: product::Id, level: Level) -> Self {
+        Self {
+            product_id,
+            level,
+            held: 0,
+            metadata: metadata::Metadata::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn config(config: StockConfig) -> Self {
+        Self {
+            product_id: config.product_id,
+            level: config.level,
+            held: config.held,
+            metadata: config.metadata,
+        }
+    }
+}
+
+impl Stock {
+    #[must_use]
+    pub fn product_id(&self) -> product::Id {
+        self.product_id
+    }
+
+    #[must_use]
+    pub fn level(&self) -> Level {
+        self.level
+    }
+
+    /// Units reserved by holds not released yet
+    #[must_use]
+    pub fn held(&self) -> u32 {
+        self.held
+    }
+
+    /// Units on hand not reserved by any hold
+    #[must_use]
+    pub fn available(&self) -> u32 {
+        self.level.value().saturating_sub(self.held)
+    }
+
+    #[must_use]
+    pub fn is_out_of_stock(&self) -> bool {
+        self.available() == 0
+    }
+
+    /// Reserve `quantity` units, keeping them from being consumed or held by
+    /// anyone else
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if holding more units than available
+    pub fn hold(&mut self, quantity: Quantity) -> Result<(), Error> {
+        self.ensure_available(quantity)?;
+        self.held += quantity.value();
+        Ok(())
+    }
+
+    /// Change stock level according to `adjustment`
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if decrementing more units than available or if
+    /// incrementing above [`Level::MAX`]
+    pub fn apply(&mut self, adjustment: Adjustment) -> Result<(), Error> {
+        let level = match adjustment {
+            Adjustment::Increment(quantity) => self
+                .level
+                .value()
+                .checked_add(quantity.value())
+                .and_then(|value| Level::new(value).ok())
+                .ok_or(Error::Conflict(ConflictKind::Level(self.product_id)))?,
+            Adjustment::Decrement(quantity) => {
+                // held units are reserved, so they cannot be consumed
+                self.ensure_available(quantity)?;
+                Level::new(self.level.value() - quantity.value()).map_err(Error::any)?
+            }
+            Adjustment::Set(level) => level,
+        };
+
+        self.level = level;
+        self.metadata.update();
+        Ok(())
+    }
+
+    fn ensure_available(&self, quantity: Quantity) -> Result<(), Error> {
+        if quantity.value() > self.available() {
+            return Err(Error::Conflict(ConflictKind::Insufficient {
+                product_id: self.product_id,
+                requested: quantity.value(),
+                available: self.available(),
+            }));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct StockConfig {
+    pub product_id: product::Id,
+    pub level: Level,
+    pub held: u32,
+    pub metadata: metadata::Metadata,
+}
+
+/// Stocks of many products, where missing ones are not tracked
+#[derive(Clone, Debug, Default)]
+pub struct Stocks(Vec<Stock>);
+
+impl Stocks {
+    #[must_use]
+    pub fn new(stocks: Vec<Stock>) -> Self {
+        Self(stocks)
+    }
+}
+
+impl Stocks {
+    #[must_use]
+    pub fn as_slice(&self) -> &[Stock] {
+        &self.0
+    }
+
+    #[must_use]
+    pub fn find(&self, product_id: product::Id) -> Option<&Stock> {
+        self.0.iter().find(|stock| stock.product_id == product_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_works() {
+        let mut stock = Stock::new(product::Id::new(), level(5));
+
+        let increment = Adjustment::Increment(quantity(3));
+        assert!(stock.apply(increment).is_ok());
+        assert_eq!(stock.level(), level(8));
+
+        let decrement = Adjustment::Decrement(quantity(8));
+        assert!(stock.apply(decrement).is_ok());
+        assert!(stock.is_out_of_stock());
+
+        assert!(stock.apply(Adjustment::Set(level(2))).is_ok());
+        assert_eq!(stock.level(), level(2));
+    }
+
+    #[test]
+    fn apply_with_insufficient_stock() {
+        let product_id = product::Id::new();
+        let mut stock = Stock::new(product_id, level(2));
+
+        let result = stock.apply(Adjustment::Decrement(quantity(3)));
+        assert!(matches!(
+            result,
+            Err(Error::Conflict(ConflictKind::Insufficient {
+                product_id: err_product_id,
+                requested: 3,
+                available: 2
+            })) if err_product_id == product_id
+        ));
+        assert_eq!(stock.level(), level(2));
+    }
+
+    #[test]
+    fn apply_above_max_level() {
+        let product_id = product::Id::new();
+        let mut stock = Stock::new(product_id, level(Level::MAX));
+
+        let result = stock.apply(Adjustment::Increment(quantity(1)));
+        assert!(matches!(
+            result,
+            Err(Error::Conflict(ConflictKind::Level(id))) if id == product_id
+        ));
+    }
+
+    #[test]
+    fn hold_keeps_units_from_being_consumed() {
+        let product_id = product::Id::new();
+        let mut stock = Stock::new(product_id, level(3));
+
+        assert!(stock.hold(quantity(2)).is_ok());
+        assert_eq!(stock.available(), 1);
+
+        let result = stock.hold(quantity(2));
+        assert!(matches!(
+            result,
+            Err(Error::Conflict(ConflictKind::Insufficient {
+                requested: 2,
+                available: 1,
+                ..
+            }))
+        ));
+
+        assert!(stock.apply(Adjustment::Decrement(quantity(1))).is_ok());
+        assert!(stock.is_out_of_stock());
+        assert_eq!(stock.level(), level(2));
+    }
+
+    fn level(value: u32) -> Level {
+        Level::new(value).expect("Valid stock level")
+    }
+
+    fn quantity(value: u32) -> Quantity {
+        Quantity::new(value).expect("Valid stock quantity")
+    }
+}
@@ -0,0 +1,48 @@
+use thiserror::Error;
+
+use super::Level;
+use crate::product;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error(transparent)]
+    Conflict(ConflictKind),
+    #[error(transparent)]
+    Internal(Box<dyn std::error::Error>),
+    #[error(transparent)]
+    NotFound(NotFoundKind),
+}
+
+impl Error {
+    /// Utility function to create [`Error::Internal`] without manually
+    /// boxing the error
+    #[must_use]
+    pub fn any(err: impl Into<Box<dyn std::error::Error>>) -> Self {
+        Self::Internal(err.into())
+    }
+
+    #[must_use]
+    pub fn untracked(product_id: product::Id) -> Self {
+        Self::NotFound(NotFoundKind::Untracked(product_id))
+    }
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum ConflictKind {
+    #[error("Insufficient stock for product `{product_id}`: requested {requested}, available {available}")]
+    Insufficient {
+        product_id: product::Id,
+        requested: u32,
+        available: u32,
+    },
+    #[error("Stock of product `{0}` cannot be bigger than {max}", max = Level::MAX)]
+    Level(product::Id),
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum NotFoundKind {
+    #[error(transparent)]
+    Product(product::NotFoundKind),
+    #[error("Stock of product `{0}` is not tracked")]
+    Untracked(product::Id),
+}
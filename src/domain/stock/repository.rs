@@ -0,0 +1,17 @@
+use super::{Adjustment, Error, Stock, Stocks};
+use crate::product;
+
+// TODO: remove when stabilized
+#[allow(async_fn_in_trait)]
+pub trait Repository: Send + Clone {
+    async fn all(&self, products_ids: &[product::Id]) -> Result<Stocks, Error>;
+    async fn find(&self, product_id: product::Id) -> Result<Stock, Error>;
+    /// Apply `adjustment` to stock of product `product_id` atomically, so
+    /// concurrent adjustments never lose updates nor take stock below zero.
+    /// Incrementing or setting an untracked stock starts tracking it
+    async fn adjust(
+        &mut self,
+        product_id: product::Id,
+        adjustment: Adjustment,
+    ) -> Result<Stock, Error>;
+}
@@ -0,0 +1,90 @@
+use std::fmt;
+
+use thiserror::Error;
+
+/// Units of a product on hand
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Level(u32);
+
+impl Level {
+    pub const MAX: u32 = 1_000_000;
+    pub const ZERO: Self = Self(0);
+
+    /// Try parsing `value` into [`Level`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `value` is bigger than [`Level::MAX`]
+    pub fn new(value: u32) -> Result<Self, LevelError> {
+        if value > Self::MAX {
+            return Err(LevelError);
+        }
+
+        Ok(Self(value))
+    }
+}
+
+impl Level {
+    #[must_use]
+    pub fn value(&self) -> u32 {
+        self.0
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Units moved in or out of stock at once
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Quantity(u32);
+
+impl Quantity {
+    /// Try parsing `value` into [`Quantity`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `value` is zero or bigger than [`Level::MAX`]
+    pub fn new(value: u32) -> Result<Self, QuantityError> {
+        match value {
+            0 => Err(QuantityError::Zero),
+            v if v > Level::MAX => Err(QuantityError::Max),
+            v => Ok(Self(v)),
+        }
+    }
+}
+
+impl Quantity {
+    #[must_use]
+    pub fn value(&self) -> u32 {
+        self.0
+    }
+}
+
+impl fmt::Display for Quantity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Change applied to a stock level
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Adjustment {
+    Increment(Quantity),
+    Decrement(Quantity),
+    Set(Level),
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[error("Stock level cannot be bigger than {max}", max = Level::MAX)]
+pub struct LevelError;
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum QuantityError {
+    #[error("Stock quantity cannot be zero")]
+    Zero,
+    #[error("Stock quantity cannot be bigger than {max}", max = Level::MAX)]
+    Max,
+}
@@ -0,0 +1,11 @@
+mod entity;
+mod error;
+mod repository;
+mod rules;
+mod vo;
+
+pub use entity::{Breakdown, TaxRule, TaxRuleConfig};
+pub use error::{ConflictKind, Error, NotFoundKind};
+pub use repository::Repository;
+pub use rules::Rules;
+pub use vo::{Id, Mode, Name, NameError, ParseIdError, ParseModeError, Rate, RateError, Target};
@@ -0,0 +1,170 @@
+use rust_decimal::Decimal;
+
+use super::{Error, Id, Mode, Name, Rate, Target};
+use crate::core::metadata;
+use crate::core::money::{Money, Rounding};
+
+#[derive(Clone, Debug)]
+pub struct TaxRule {
+    pub(super) id: Id,
+    pub name: Name,
+    pub target: Target,
+    pub rate: Rate,
+    pub mode: Mode,
+    pub metadata: metadata::Metadata,
+}
+
+impl TaxRule {
+    #[must_use]
+    pub fn new(name: Name, target: Target, rate: Rate, mode: Mode) -> Self {
+        Self {
+            id: Id::new(),
+            name,
+            target,
+            rate,
+            mode,
+            metadata: metadata::Metadata::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn config(config: TaxRuleConfig) -> Self {
+        Self {
+            id: config.id,
+            name: config.name,
+            target: config.target,
+            rate: config.rate,
+            mode: config.mode,
+            metadata: config.metadata,
+        }
+    }
+}
+
+impl TaxRule {
+    #[must_use]
+    pub fn id(&self) -> Id {
+        self.id
+    }
+
+    /// Split `price` into net, tax and gross amounts according to rule mode.
+    /// Tax is rounded half up to the currency minor unit
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if the computation overflows
+    pub fn apply(&self, price: Money) -> Result<Breakdown, Error> {
+        let rate = self.rate.value();
+        match self.mode {
+            Mode::Exclusive => {
+                let tax = price
+                    .checked_percentage(rate)
+                    .map_err(Error::money)?
+                    .round(Rounding::HalfUp);
+                let gross = price.checked_add(tax).map_err(Error::money)?;
+
+                Ok(Breakdown {
+                    net: price,
+                    tax,
+                    gross,
+                })
+            }
+            Mode::Inclusive => {
+                let divisor = Decimal::ONE_HUNDRED + rate;
+                let net_amount = price
+                    .amount()
+                    .checked_mul(Decimal::ONE_HUNDRED)
+                    .and_then(|amount| amount.checked_div(divisor))
+                    .ok_or_else(|| Error::any("Tax computation overflowed"))?;
+                let net = Money::new(net_amount, price.currency()).round(Rounding::HalfUp);
+                let tax = price.checked_sub(net).map_err(Error::money)?;
+
+                Ok(Breakdown {
+                    net,
+                    tax,
+                    gross: price,
+                })
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TaxRuleConfig {
+    pub id: Id,
+    pub name: Name,
+    pub target: Target,
+    pub rate: Rate,
+    pub mode: Mode,
+    pub metadata: metadata::Metadata,
+}
+
+/// Price split into its amount before taxes, its taxes and its total
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Breakdown {
+    pub net: Money,
+    pub tax: Money,
+    pub gross: Money,
+}
+
+impl Breakdown {
+    /// Breakdown of a `price` no tax applies to
+    #[must_use]
+    pub fn untaxed(price: Money) -> Self {
+        Self {
+            net: price,
+            tax: Money::zero(price.currency()),
+            gross: price,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::money::Currency;
+    use crate::product;
+
+    #[test]
+    fn apply_exclusive() {
+        let rule = mocked_rule("12.5", Mode::Exclusive);
+
+        let breakdown = rule.apply(brl(1999)).expect("Valid breakdown");
+        // 12.5% of 19.99 is 2.49875
+        assert_eq!(breakdown.net, brl(1999));
+        assert_eq!(breakdown.tax, brl(250));
+        assert_eq!(breakdown.gross, brl(2249));
+    }
+
+    #[test]
+    fn apply_inclusive() {
+        let rule = mocked_rule("10", Mode::Inclusive);
+
+        let breakdown = rule.apply(brl(2000)).expect("Valid breakdown");
+        // 20.00 / 1.10 is 18.1818...
+        assert_eq!(breakdown.net, brl(1818));
+        assert_eq!(breakdown.tax, brl(182));
+        assert_eq!(breakdown.gross, brl(2000));
+    }
+
+    #[test]
+    fn apply_zero_rate() {
+        for mode in [Mode::Inclusive, Mode::Exclusive] {
+            let rule = mocked_rule("0", mode);
+            let breakdown = rule.apply(brl(2000)).expect("Valid breakdown");
+            assert_eq!(breakdown, Breakdown::untaxed(brl(2000)));
+        }
+    }
+
+    fn brl(cents: i64) -> Money {
+        Money::from_minor(cents, Currency::BRL)
+    }
+
+    fn mocked_rule(rate: &str, mode: Mode) -> TaxRule {
+        TaxRule::new(
+            Name::new("VAT").expect("Valid tax name"),
+            Target::Kind(product::Kind::Burger),
+            Rate::parse_str(rate).expect("Valid tax rate"),
+            mode,
+        )
+    }
+}
@@ -0,0 +1,69 @@
+use thiserror::Error;
+
+use super::{Id, Target};
+use crate::catalog;
+use crate::core::money::{Currency, MoneyError};
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error(transparent)]
+    Conflict(ConflictKind),
+    #[error(transparent)]
+    Internal(Box<dyn std::error::Error>),
+    #[error(transparent)]
+    NotFound(NotFoundKind),
+}
+
+impl Error {
+    /// Utility function to create [`Error::Internal`] without manually
+    /// boxing the error
+    #[must_use]
+    pub fn any(err: impl Into<Box<dyn std::error::Error>>) -> Self {
+        Self::Internal(err.into())
+    }
+
+    #[must_use]
+    pub fn id_conflict(id: Id) -> Self {
+        Self::Conflict(ConflictKind::Id(id))
+    }
+
+    #[must_use]
+    pub fn id_not_found(id: Id) -> Self {
+        Self::NotFound(NotFoundKind::Id(id))
+    }
+
+    #[must_use]
+    pub fn target_conflict(target: Target) -> Self {
+        Self::Conflict(ConflictKind::Target(target))
+    }
+
+    /// Maps a failed [`Money`](crate::core::money::Money) operation, where
+    /// mixing currencies is a conflict and overflowing is unexpected
+    #[must_use]
+    pub fn money(err: MoneyError) -> Self {
+        match err {
+            MoneyError::CurrencyMismatch { expected, found } => {
+                Self::Conflict(ConflictKind::Currency { expected, found })
+            }
+            err @ MoneyError::Overflow => Self::any(err),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum ConflictKind {
+    #[error("Tax priced in `{expected}` cannot be combined with prices in `{found}`")]
+    Currency { expected: Currency, found: Currency },
+    #[error("Tax rule with id `{0}` already exists")]
+    Id(Id),
+    #[error("Tax rule for {0} already exists")]
+    Target(Target),
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum NotFoundKind {
+    #[error("Product catalog with id `{0}` not found")]
+    Catalog(catalog::Id),
+    #[error("Tax rule with id `{0}` not found")]
+    Id(Id),
+}
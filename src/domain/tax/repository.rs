@@ -0,0 +1,12 @@
+use super::error::Error;
+use super::{Id, Rules, TaxRule};
+
+// TODO: remove when stabilized
+#[allow(async_fn_in_trait)]
+pub trait Repository: Send + Clone {
+    async fn all(&self) -> Result<Rules, Error>;
+    async fn create(&mut self, rule: &TaxRule) -> Result<(), Error>;
+    async fn delete(&mut self, id: Id) -> Result<TaxRule, Error>;
+    async fn find(&self, id: Id) -> Result<TaxRule, Error>;
+    async fn update(&mut self, rule: &TaxRule) -> Result<(), Error>;
+}
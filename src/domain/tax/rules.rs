@@ -0,0 +1,104 @@
+use super::{Breakdown, Error, Target, TaxRule};
+use crate::core::money::Money;
+use crate::product;
+
+/// Set of tax rules, resolving which one applies to each product
+#[derive(Clone, Debug, Default)]
+pub struct Rules(Vec<TaxRule>);
+
+impl Rules {
+    #[must_use]
+    pub fn new(rules: Vec<TaxRule>) -> Self {
+        Self(rules)
+    }
+}
+
+impl Rules {
+    #[must_use]
+    pub fn as_slice(&self) -> &[TaxRule] {
+        &self.0
+    }
+
+    /// Rule applying to `product`, preferring a rule targeting its catalog
+    /// over a rule targeting its kind
+    #[must_use]
+    pub fn rule_for(&self, product: &product::Product) -> Option<&TaxRule> {
+        let matching = |rule: &&TaxRule| rule.target.matches(product);
+        self.0
+            .iter()
+            .filter(matching)
+            .find(|rule| matches!(rule.target, Target::Catalog(_)))
+            .or_else(|| self.0.iter().find(matching))
+    }
+
+    /// Breakdown of `price` of `product`, or of one of its extras, which are
+    /// taxed like the product they are added to
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if the computation overflows
+    pub fn breakdown(&self, product: &product::Product, price: Money) -> Result<Breakdown, Error> {
+        match self.rule_for(product) {
+            Some(rule) => rule.apply(price),
+            None => Ok(Breakdown::untaxed(price)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog;
+    use crate::core::money::Currency;
+    use crate::tax::{Mode, Name, Rate};
+
+    #[test]
+    fn rule_for_prefers_catalog() {
+        let product = mocked_product(product::Kind::Burger);
+        let kind_rule = mocked_rule(Target::Kind(product::Kind::Burger));
+        let catalog_rule = mocked_rule(Target::Catalog(product.catalog_id()));
+        let other_rule = mocked_rule(Target::Kind(product::Kind::Vegan));
+
+        let rules = Rules::new(vec![other_rule.clone(), kind_rule.clone()]);
+        assert_eq!(
+            rules.rule_for(&product).map(TaxRule::id),
+            Some(kind_rule.id())
+        );
+
+        let rules = Rules::new(vec![kind_rule, catalog_rule.clone(), other_rule]);
+        assert_eq!(
+            rules.rule_for(&product).map(TaxRule::id),
+            Some(catalog_rule.id())
+        );
+    }
+
+    #[test]
+    fn breakdown_without_rule() {
+        let product = mocked_product(product::Kind::Vegan);
+        let rules = Rules::new(vec![mocked_rule(Target::Kind(product::Kind::Burger))]);
+
+        let breakdown = rules
+            .breakdown(&product, product.price)
+            .expect("Valid breakdown");
+        assert_eq!(breakdown, Breakdown::untaxed(product.price));
+    }
+
+    fn mocked_product(kind: product::Kind) -> product::Product {
+        product::Product::new(
+            catalog::Id::new(),
+            product::Name::new("Cheese Burger").expect("Valid product name"),
+            Money::from_minor(2000, Currency::BRL),
+            kind,
+            product::Extras::default(),
+        )
+    }
+
+    fn mocked_rule(target: Target) -> TaxRule {
+        TaxRule::new(
+            Name::new("VAT").expect("Valid tax name"),
+            target,
+            Rate::parse_str("10").expect("Valid tax rate"),
+            Mode::Exclusive,
+        )
+    }
+}
@@ -0,0 +1,272 @@
+use std::fmt;
+use std::str::FromStr;
+
+use rust_decimal::Decimal;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::catalog;
+use crate::core::string::trim_in_place;
+use crate::product;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Id(Uuid);
+
+impl Id {
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::now_v7())
+    }
+
+    /// Try parsing `value` into [`Id`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `value` is not a valid [`Id`]
+    pub fn parse_str(value: &str) -> Result<Self, ParseIdError> {
+        Uuid::parse_str(value)
+            .map(Self)
+            .map_err(|_| ParseIdError(Box::from(value)))
+    }
+}
+
+impl Id {
+    #[must_use]
+    pub fn uuid(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for Id {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for Id {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<Uuid> for Id {
+    fn from(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+}
+
+/// Name printed on invoices for the tax, e.g. `ICMS` or `VAT`
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Name(String);
+
+impl Name {
+    pub const MAX_LEN: usize = 64;
+
+    /// Try parsing `name` into [`Name`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `name` is empty or longer than [`Name::MAX_LEN`]
+    pub fn new(name: impl Into<String>) -> Result<Self, NameError> {
+        let mut name: String = name.into();
+        trim_in_place(&mut name);
+        if name.is_empty() {
+            return Err(NameError::Empty);
+        }
+        if name.len() > Self::MAX_LEN {
+            return Err(NameError::Length);
+        }
+
+        Ok(Self(name))
+    }
+}
+
+impl Name {
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Tax rate as a percentage of the net price, from 0% to 100%
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Rate(Decimal);
+
+impl Rate {
+    pub const MAX_SCALE: u32 = 4;
+
+    /// Try creating a [`Rate`] from a percentage `value`
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `value` is not between 0 and 100 or has more
+    /// than [`Rate::MAX_SCALE`] decimal places
+    pub fn new(value: Decimal) -> Result<Self, RateError> {
+        let value = value.normalize();
+        if value.is_sign_negative() || value > Decimal::ONE_HUNDRED {
+            return Err(RateError::Range);
+        }
+        if value.scale() > Self::MAX_SCALE {
+            return Err(RateError::Precision);
+        }
+
+        Ok(Self(value))
+    }
+
+    /// Try parsing `value` into [`Rate`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `value` is not a valid percentage
+    pub fn parse_str(value: &str) -> Result<Self, RateError> {
+        let decimal = Decimal::from_str(value).map_err(|_| RateError::Parse(Box::from(value)))?;
+        Self::new(decimal)
+    }
+}
+
+impl Rate {
+    #[must_use]
+    pub fn value(&self) -> Decimal {
+        self.0
+    }
+}
+
+impl fmt::Display for Rate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Whether registered prices already include the tax or have it added on top
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Mode {
+    /// Registered price is the gross amount, tax is already included in it
+    Inclusive,
+    /// Registered price is the net amount, tax is added on top of it
+    Exclusive,
+}
+
+impl Mode {
+    /// Try parsing `value` into [`Mode`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `value` is not a valid [`Mode`]
+    pub fn parse_str(value: &str) -> Result<Self, ParseModeError> {
+        match value {
+            "inclusive" => Ok(Self::Inclusive),
+            "exclusive" => Ok(Self::Exclusive),
+            other => Err(ParseModeError(Box::from(other))),
+        }
+    }
+}
+
+impl Mode {
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Inclusive => "inclusive",
+            Self::Exclusive => "exclusive",
+        }
+    }
+}
+
+impl fmt::Display for Mode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl TryFrom<&str> for Mode {
+    type Error = ParseModeError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::parse_str(value)
+    }
+}
+
+/// Products a tax rule applies to. Rules targeting a catalog take precedence
+/// over rules targeting a product kind
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Target {
+    Catalog(catalog::Id),
+    Kind(product::Kind),
+}
+
+impl Target {
+    #[must_use]
+    pub fn matches(&self, product: &product::Product) -> bool {
+        match self {
+            Self::Catalog(catalog_id) => product.catalog_id() == *catalog_id,
+            Self::Kind(kind) => product.kind == *kind,
+        }
+    }
+}
+
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Catalog(catalog_id) => write!(f, "product catalog `{catalog_id}`"),
+            Self::Kind(kind) => write!(f, "product kind `{kind}`"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[error("Provided string `{0}` is not a valid tax rule id")]
+pub struct ParseIdError(pub Box<str>);
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum NameError {
+    #[error("Tax name cannot be empty")]
+    Empty,
+    #[error("Tax name cannot have more than {len} characters", len = Name::MAX_LEN)]
+    Length,
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum RateError {
+    #[error("Provided string `{0}` is not a valid tax rate")]
+    Parse(Box<str>),
+    #[error("Tax rate cannot have more than {scale} decimal places", scale = Rate::MAX_SCALE)]
+    Precision,
+    #[error("Tax rate must be a percentage between 0 and 100")]
+    Range,
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[error("Provided string `{0}` is not a valid tax mode")]
+pub struct ParseModeError(pub Box<str>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_rate() {
+        let rate = Rate::parse_str("12.50").expect("Valid rate");
+        assert_eq!(rate.value(), Decimal::new(125, 1));
+
+        assert!(Rate::parse_str("0").is_ok());
+        assert!(Rate::parse_str("100").is_ok());
+        assert_eq!(Rate::parse_str("100.01"), Err(RateError::Range));
+        assert_eq!(Rate::parse_str("-1"), Err(RateError::Range));
+        assert_eq!(Rate::parse_str("1.23456"), Err(RateError::Precision));
+        assert!(matches!(Rate::parse_str("ten"), Err(RateError::Parse(_))));
+    }
+
+    #[test]
+    fn parse_mode() {
+        for mode in [Mode::Inclusive, Mode::Exclusive] {
+            assert_eq!(Mode::parse_str(mode.as_str()), Ok(mode));
+        }
+
+        assert!(Mode::parse_str("Inclusive").is_err());
+    }
+}
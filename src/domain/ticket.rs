@@ -0,0 +1,9 @@
+mod entity;
+mod error;
+mod repository;
+mod vo;
+
+pub use entity::{Ticket, TicketConfig};
+pub use error::{ConflictKind, Error, NotFoundKind};
+pub use repository::{ListQuery, Pagination, Repository};
+pub use vo::{Id, ParseIdError, ParseStatusError, Status, Timeline};
@@ -0,0 +1,253 @@
+use super::{ConflictKind, Error, Id, Status, Timeline};
+use crate::core::metadata;
+use crate::extra;
+use crate::product;
+
+/// Kitchen ticket for preparing a single [`product::Product`], moving through
+/// [`Status`] according to the kitchen workflow
+#[derive(Clone, Debug)]
+pub struct Ticket {
+    pub(super) id: Id,
+    product: product::Product,
+    extras_ids: Vec<extra::Id>,
+    status: Status,
+    timeline: Timeline,
+    pub metadata: metadata::Metadata,
+}
+
+impl Ticket {
+    /// Try creating a received [`Ticket`] for `product` with the selected `extras_ids`
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if any of `extras_ids` is not bound to `product`
+    pub fn new(product: product::Product, extras_ids: &[extra::Id]) -> Result<Self, Error> {
+        let extras_ids = select_extras(&product, extras_ids)?;
+        let metadata = metadata::Metadata::new();
+
+        Ok(Self {
+            id: Id::new(),
+            product,
+            extras_ids,
+            status: Status::Received,
+            timeline: Timeline::new(metadata.created_at()),
+            metadata,
+        })
+    }
+
+    #[must_use]
+    pub fn config(config: TicketConfig) -> Self {
+        Self {
+            id: config.id,
+            product: config.product,
+            extras_ids: config.extras_ids,
+            status: config.status,
+            timeline: config.timeline,
+            metadata: config.metadata,
+        }
+    }
+}
+
+impl Ticket {
+    #[must_use]
+    pub fn id(&self) -> Id {
+        self.id
+    }
+
+    #[must_use]
+    pub fn product(&self) -> &product::Product {
+        &self.product
+    }
+
+    #[must_use]
+    pub fn extras_ids(&self) -> &[extra::Id] {
+        &self.extras_ids
+    }
+
+    #[must_use]
+    pub fn status(&self) -> Status {
+        self.status
+    }
+
+    #[must_use]
+    pub fn timeline(&self) -> &Timeline {
+        &self.timeline
+    }
+
+    /// Selected extras with their current data
+    pub fn extras(&self) -> impl Iterator<Item = &extra::Extra> {
+        self.product
+            .extras
+            .iter()
+            .filter(|extra| self.extras_ids.contains(&extra.id()))
+    }
+
+    /// Replace selected extras of ticket
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if kitchen already started working on ticket or any
+    /// of `extras_ids` is not bound to ticket product
+    pub fn set_extras(&mut self, extras_ids: &[extra::Id]) -> Result<(), Error> {
+        if self.status != Status::Received {
+            return Err(Error::Conflict(ConflictKind::Locked(self.status)));
+        }
+
+        self.extras_ids = select_extras(&self.product, extras_ids)?;
+        Ok(())
+    }
+
+    /// Move ticket to `next` status, registering when it happened
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if kitchen workflow does not allow moving from
+    /// current status to `next`
+    pub fn transition(&mut self, next: Status) -> Result<(), Error> {
+        if !self.status.can_transition_to(next) {
+            return Err(Error::transition_conflict(self.status, next));
+        }
+
+        self.metadata.update();
+        self.timeline.stamp(next, self.metadata.updated_at());
+        self.status = next;
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TicketConfig {
+    pub id: Id,
+    pub product: product::Product,
+    pub extras_ids: Vec<extra::Id>,
+    pub status: Status,
+    pub timeline: Timeline,
+    pub metadata: metadata::Metadata,
+}
+
+fn select_extras(
+    product: &product::Product,
+    extras_ids: &[extra::Id],
+) -> Result<Vec<extra::Id>, Error> {
+    let mut selected_extras_ids: Vec<extra::Id> = Vec::with_capacity(extras_ids.len());
+    for extra_id in extras_ids {
+        if !product.extras.iter().any(|extra| extra.id() == *extra_id) {
+            return Err(Error::extra_not_found(*extra_id, product.id()));
+        }
+        if !selected_extras_ids.contains(extra_id) {
+            selected_extras_ids.push(*extra_id);
+        }
+    }
+
+    Ok(selected_extras_ids)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog;
+    use crate::core::money::{Currency, Money};
+
+    #[test]
+    fn new_works() {
+        let cheddar = mocked_extra("Cheddar", 200);
+        let product = mocked_product(vec![cheddar.clone(), mocked_extra("Bacon", 350)]);
+
+        let ticket = Ticket::new(product, &[cheddar.id(), cheddar.id()]).expect("Valid ticket");
+        assert_eq!(ticket.status(), Status::Received);
+        assert_eq!(ticket.extras_ids(), &[cheddar.id()]);
+        assert_eq!(ticket.timeline().received_at, ticket.metadata.created_at());
+    }
+
+    #[test]
+    fn new_with_extra_not_found() {
+        use crate::ticket::NotFoundKind;
+
+        let product = mocked_product(vec![mocked_extra("Cheddar", 200)]);
+        let product_id = product.id();
+        let extra_id = extra::Id::new();
+
+        let result = Ticket::new(product, &[extra_id]);
+        assert!(matches!(result, Err(Error::NotFound(NotFoundKind::Extra {
+            extra_id: err_extra_id,
+            product_id: err_product_id
+        })) if err_extra_id == extra_id && err_product_id == product_id));
+    }
+
+    #[test]
+    fn transition_works() {
+        let mut ticket = Ticket::new(mocked_product(vec![]), &[]).expect("Valid ticket");
+
+        for status in [Status::Preparing, Status::Ready, Status::HandedOver] {
+            assert!(ticket.transition(status).is_ok());
+            assert_eq!(ticket.status(), status);
+            assert_eq!(
+                ticket.timeline().at(status),
+                Some(ticket.metadata.updated_at())
+            );
+        }
+
+        assert_eq!(ticket.timeline().cancelled_at, None);
+    }
+
+    #[test]
+    fn transition_with_conflict() {
+        let mut ticket = Ticket::new(mocked_product(vec![]), &[]).expect("Valid ticket");
+
+        let result = ticket.transition(Status::Ready);
+        assert!(matches!(
+            result,
+            Err(Error::Conflict(ConflictKind::Transition {
+                from: Status::Received,
+                to: Status::Ready
+            }))
+        ));
+        assert_eq!(ticket.status(), Status::Received);
+        assert_eq!(ticket.timeline().ready_at, None);
+
+        ticket
+            .transition(Status::Cancelled)
+            .expect("Cancelled ticket");
+        let result = ticket.transition(Status::Preparing);
+        assert!(matches!(
+            result,
+            Err(Error::Conflict(ConflictKind::Transition { .. }))
+        ));
+    }
+
+    #[test]
+    fn set_extras_locked_after_received() {
+        let cheddar = mocked_extra("Cheddar", 200);
+        let mut ticket =
+            Ticket::new(mocked_product(vec![cheddar.clone()]), &[]).expect("Valid ticket");
+
+        assert!(ticket.set_extras(&[cheddar.id()]).is_ok());
+        assert_eq!(ticket.extras().count(), 1);
+
+        ticket
+            .transition(Status::Preparing)
+            .expect("Preparing ticket");
+        let result = ticket.set_extras(&[]);
+        assert!(matches!(
+            result,
+            Err(Error::Conflict(ConflictKind::Locked(Status::Preparing)))
+        ));
+    }
+
+    fn mocked_extra(name: &str, cents: i64) -> extra::Extra {
+        extra::Extra::new(
+            extra::Name::new(name).expect("Valid extra name"),
+            Money::from_minor(cents, Currency::BRL),
+        )
+    }
+
+    fn mocked_product(extras: Vec<extra::Extra>) -> product::Product {
+        product::Product::new(
+            catalog::Id::new(),
+            product::Name::new("Cheese Burger").expect("Valid product name"),
+            Money::from_minor(2000, Currency::BRL),
+            product::Kind::Burger,
+            product::Extras::new(extras).expect("Valid product extras"),
+        )
+    }
+}
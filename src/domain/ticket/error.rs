@@ -0,0 +1,77 @@
+use thiserror::Error;
+
+use super::{Id, Status};
+use crate::extra;
+use crate::product;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error(transparent)]
+    Conflict(ConflictKind),
+    #[error(transparent)]
+    Internal(Box<dyn std::error::Error>),
+    #[error(transparent)]
+    NotFound(NotFoundKind),
+}
+
+impl Error {
+    /// Utility function to create [`Error::Internal`] without manually
+    /// boxing the error
+    #[must_use]
+    pub fn any(err: impl Into<Box<dyn std::error::Error>>) -> Self {
+        Self::Internal(err.into())
+    }
+
+    #[must_use]
+    pub fn extra_not_found(extra_id: extra::Id, product_id: product::Id) -> Self {
+        Self::NotFound(NotFoundKind::Extra {
+            extra_id,
+            product_id,
+        })
+    }
+
+    #[must_use]
+    pub fn id_conflict(id: Id) -> Self {
+        Self::Conflict(ConflictKind::Id(id))
+    }
+
+    #[must_use]
+    pub fn id_not_found(id: Id) -> Self {
+        Self::NotFound(NotFoundKind::Id(id))
+    }
+
+    #[must_use]
+    pub fn status_conflict(id: Id, expected: Status) -> Self {
+        Self::Conflict(ConflictKind::Status { id, expected })
+    }
+
+    #[must_use]
+    pub fn transition_conflict(from: Status, to: Status) -> Self {
+        Self::Conflict(ConflictKind::Transition { from, to })
+    }
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum ConflictKind {
+    #[error("Ticket with id `{0}` already exists")]
+    Id(Id),
+    #[error("Ticket cannot be changed once it is `{0}`")]
+    Locked(Status),
+    #[error("Ticket with id `{id}` is no longer `{expected}`")]
+    Status { id: Id, expected: Status },
+    #[error("Ticket cannot move from `{from}` to `{to}`")]
+    Transition { from: Status, to: Status },
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum NotFoundKind {
+    #[error("Ticket with id `{0}` not found")]
+    Id(Id),
+    #[error("Product extra with id `{extra_id}` not available for product {product_id}")]
+    Extra {
+        extra_id: extra::Id,
+        product_id: product::Id,
+    },
+    #[error(transparent)]
+    Product(product::NotFoundKind),
+}
@@ -0,0 +1,30 @@
+use std::num::{NonZeroU32, NonZeroU8};
+
+use super::{Error, Id, Status, Ticket};
+
+// TODO: remove when stabilized
+#[allow(async_fn_in_trait)]
+pub trait Repository: Send + Clone {
+    async fn create(&mut self, ticket: &Ticket) -> Result<(), Error>;
+    async fn delete(&mut self, id: Id) -> Result<Ticket, Error>;
+    async fn find(&self, id: Id) -> Result<Ticket, Error>;
+    async fn list(&self, query: ListQuery) -> Result<Pagination, Error>;
+    /// Update `ticket` as long as it is still stored with `status`, so
+    /// concurrent transitions cannot overwrite each other
+    async fn update(&mut self, ticket: &Ticket, status: Status) -> Result<(), Error>;
+}
+
+#[derive(Clone, Debug)]
+pub struct ListQuery {
+    pub page: NonZeroU32,
+    pub limit: NonZeroU8,
+    pub status: Option<Status>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Pagination {
+    pub count: u64,
+    pub page: NonZeroU32,
+    pub limit: NonZeroU8,
+    pub items: Vec<Ticket>,
+}
@@ -0,0 +1,239 @@
+use std::fmt;
+
+use thiserror::Error;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Id(Uuid);
+
+impl Id {
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::now_v7())
+    }
+
+    /// Try parsing `value` into [`Id`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `value` is not a valid [`Id`]
+    pub fn parse_str(value: &str) -> Result<Self, ParseIdError> {
+        match Uuid::parse_str(value) {
+            Ok(uuid) => Ok(Self(uuid)),
+            Err(_) => Err(ParseIdError(Box::from(value))),
+        }
+    }
+}
+
+impl Id {
+    #[must_use]
+    pub fn uuid(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for Id {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for Id {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<Uuid> for Id {
+    fn from(value: Uuid) -> Self {
+        Self(value)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Status {
+    Received,
+    Preparing,
+    Ready,
+    HandedOver,
+    Cancelled,
+}
+
+impl Status {
+    /// Try parsing `value` into [`Status`]
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseStatusError`] when `value` cannot be parsed to [`Status`]
+    pub fn parse_str(value: &str) -> Result<Self, ParseStatusError> {
+        match value {
+            "received" => Ok(Self::Received),
+            "preparing" => Ok(Self::Preparing),
+            "ready" => Ok(Self::Ready),
+            "handed_over" => Ok(Self::HandedOver),
+            "cancelled" => Ok(Self::Cancelled),
+            other => Err(ParseStatusError(Box::from(other))),
+        }
+    }
+}
+
+impl Status {
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Received => "received",
+            Self::Preparing => "preparing",
+            Self::Ready => "ready",
+            Self::HandedOver => "handed_over",
+            Self::Cancelled => "cancelled",
+        }
+    }
+
+    /// Check if kitchen workflow allows moving from `self` to `next`
+    #[must_use]
+    pub fn can_transition_to(self, next: Self) -> bool {
+        matches!(
+            (self, next),
+            (Self::Received, Self::Preparing | Self::Cancelled)
+                | (Self::Preparing, Self::Ready | Self::Cancelled)
+                | (Self::Ready, Self::HandedOver | Self::Cancelled)
+        )
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl TryFrom<&str> for Status {
+    type Error = ParseStatusError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::parse_str(value)
+    }
+}
+
+/// Moment a ticket reached each [`Status`]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Timeline {
+    pub received_at: OffsetDateTime,
+    pub preparing_at: Option<OffsetDateTime>,
+    pub ready_at: Option<OffsetDateTime>,
+    pub handed_over_at: Option<OffsetDateTime>,
+    pub cancelled_at: Option<OffsetDateTime>,
+}
+
+impl Timeline {
+    #[must_use]
+    pub fn new(received_at: OffsetDateTime) -> Self {
+        Self {
+            received_at,
+            preparing_at: None,
+            ready_at: None,
+            handed_over_at: None,
+            cancelled_at: None,
+        }
+    }
+}
+
+impl Timeline {
+    #[must_use]
+    pub fn at(&self, status: Status) -> Option<OffsetDateTime> {
+        match status {
+            Status::Received => Some(self.received_at),
+            Status::Preparing => self.preparing_at,
+            Status::Ready => self.ready_at,
+            Status::HandedOver => self.handed_over_at,
+            Status::Cancelled => self.cancelled_at,
+        }
+    }
+
+    pub fn stamp(&mut self, status: Status, at: OffsetDateTime) {
+        match status {
+            Status::Received => self.received_at = at,
+            Status::Preparing => self.preparing_at = Some(at),
+            Status::Ready => self.ready_at = Some(at),
+            Status::HandedOver => self.handed_over_at = Some(at),
+            Status::Cancelled => self.cancelled_at = Some(at),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[error("Provided string `{0}` is not a valid ticket id")]
+pub struct ParseIdError(pub Box<str>);
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[error("Provided string `{0}` is not a valid ticket status")]
+pub struct ParseStatusError(pub Box<str>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STATUSES: [Status; 5] = [
+        Status::Received,
+        Status::Preparing,
+        Status::Ready,
+        Status::HandedOver,
+        Status::Cancelled,
+    ];
+
+    #[test]
+    fn parse_id_works() {
+        let valid = Id::new().to_string();
+        assert!(Id::parse_str(&valid).is_ok());
+
+        let invalid = "invalid-id";
+        assert_eq!(
+            Id::parse_str(invalid),
+            Err(ParseIdError(Box::from(invalid)))
+        );
+    }
+
+    #[test]
+    fn status_back_and_forth_str() {
+        for status in STATUSES {
+            assert_eq!(Status::parse_str(status.as_str()), Ok(status));
+        }
+
+        let invalid = "burnt";
+        assert_eq!(
+            Status::parse_str(invalid),
+            Err(ParseStatusError(Box::from(invalid)))
+        );
+    }
+
+    #[test]
+    fn status_transitions() {
+        let allowed = [
+            (Status::Received, Status::Preparing),
+            (Status::Received, Status::Cancelled),
+            (Status::Preparing, Status::Ready),
+            (Status::Preparing, Status::Cancelled),
+            (Status::Ready, Status::HandedOver),
+            (Status::Ready, Status::Cancelled),
+        ];
+
+        for from in STATUSES {
+            for to in STATUSES {
+                let expected = allowed.contains(&(from, to));
+                assert_eq!(from.can_transition_to(to), expected, "{from} -> {to}");
+            }
+        }
+    }
+
+    #[test]
+    fn timeline_stamp_works() {
+        let now = OffsetDateTime::now_utc();
+        let mut timeline = Timeline::new(now);
+        assert_eq!(timeline.at(Status::Ready), None);
+
+        timeline.stamp(Status::Ready, now);
+        assert_eq!(timeline.at(Status::Ready), Some(now));
+        assert_eq!(timeline.at(Status::Received), Some(now));
+    }
+}
@@ -0,0 +1,7 @@
+mod entity;
+mod vo;
+
+pub use entity::{Variant, VariantConfig};
+pub use vo::{
+    Id, Name, NameError, ParseIdError, ParsePricingError, Pricing, Variants, VariantsError,
+};
@@ -0,0 +1,113 @@
+use super::{Id, Name, Pricing};
+use crate::core::metadata;
+use crate::core::money::{Money, MoneyError};
+use crate::product;
+
+/// Named way a product is sold, such as a size, with a price of its own
+#[derive(Clone, Debug)]
+pub struct Variant {
+    pub(super) id: Id,
+    pub(super) product_id: product::Id,
+    pub name: Name,
+    pub pricing: Pricing,
+    pub price: Money,
+    pub metadata: metadata::Metadata,
+}
+
+impl Variant {
+    #[must_use]
+    pub fn new(product_id: product::Id, name: Name, pricing: Pricing, price: Money) -> Self {
+        Self {
+            id: Id::new(),
+            product_id,
+            name,
+            pricing,
+            price,
+            metadata: metadata::Metadata::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn config(config: VariantConfig) -> Self {
+        Self {
+            id: config.id,
+            product_id: config.product_id,
+            name: config.name,
+            pricing: config.pricing,
+            price: config.price,
+            metadata: config.metadata,
+        }
+    }
+}
+
+impl Variant {
+    #[must_use]
+    pub fn id(&self) -> Id {
+        self.id
+    }
+
+    #[must_use]
+    pub fn product_id(&self) -> product::Id {
+        self.product_id
+    }
+
+    /// Price of variant given the `base` price of its product
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if a delta is not in the currency of `base` or if
+    /// adding it overflows
+    pub fn price_for(&self, base: Money) -> Result<Money, MoneyError> {
+        match self.pricing {
+            Pricing::Fixed => Ok(self.price),
+            Pricing::Delta => base.checked_add(self.price),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct VariantConfig {
+    pub id: Id,
+    pub product_id: product::Id,
+    pub name: Name,
+    pub pricing: Pricing,
+    pub price: Money,
+    pub metadata: metadata::Metadata,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::money::Currency;
+
+    #[test]
+    fn price_for_works() {
+        let base = Money::from_minor(3000, Currency::BRL);
+        let product_id = product::Id::new();
+
+        let small = Variant::new(
+            product_id,
+            Name::new("Small").expect("Valid variant name"),
+            Pricing::Fixed,
+            Money::from_minor(2500, Currency::BRL),
+        );
+        assert_eq!(
+            small.price_for(base),
+            Ok(Money::from_minor(2500, Currency::BRL))
+        );
+
+        let large = Variant::new(
+            product_id,
+            Name::new("Large").expect("Valid variant name"),
+            Pricing::Delta,
+            Money::from_minor(800, Currency::BRL),
+        );
+        assert_eq!(
+            large.price_for(base),
+            Ok(Money::from_minor(3800, Currency::BRL))
+        );
+        assert!(large
+            .price_for(Money::from_minor(3000, Currency::USD))
+            .is_err());
+    }
+}
@@ -0,0 +1,253 @@
+use std::fmt;
+
+use thiserror::Error;
+use uuid::Uuid;
+
+use super::Variant;
+use crate::core::string::trim_in_place;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Id(Uuid);
+
+impl Id {
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::now_v7())
+    }
+
+    /// Try parsing `value` into [`Id`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `value` cannot be parsed into [`Id`]
+    pub fn parse_str(value: &str) -> Result<Self, ParseIdError> {
+        match Uuid::parse_str(value) {
+            Ok(uuid) => Ok(Self(uuid)),
+            Err(_) => Err(ParseIdError(Box::from(value))),
+        }
+    }
+}
+
+impl Id {
+    #[must_use]
+    pub fn uuid(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for Id {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for Id {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<Uuid> for Id {
+    fn from(value: Uuid) -> Self {
+        Self(value)
+    }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Name(String);
+
+impl Name {
+    pub const MAX_LEN: usize = 32;
+
+    /// Try parsing `name` into [`Name`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `name` is empty or longer than [`Name::MAX_LEN`]
+    pub fn new(name: impl Into<String>) -> Result<Self, NameError> {
+        let mut name: String = name.into();
+        trim_in_place(&mut name);
+
+        if name.is_empty() {
+            return Err(NameError::Empty);
+        }
+        if name.len() > Self::MAX_LEN {
+            return Err(NameError::Length);
+        }
+
+        Ok(Self(name))
+    }
+}
+
+impl Name {
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// How the price of a variant relates to the price of its product
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Pricing {
+    /// Variant price replaces the product price
+    Fixed,
+    /// Variant price is added to the product price
+    Delta,
+}
+
+impl Pricing {
+    /// Try parsing `value` into [`Pricing`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `value` is neither `fixed` nor `delta`
+    pub fn parse_str(value: &str) -> Result<Self, ParsePricingError> {
+        match value {
+            "fixed" => Ok(Self::Fixed),
+            "delta" => Ok(Self::Delta),
+            other => Err(ParsePricingError(Box::from(other))),
+        }
+    }
+}
+
+impl Pricing {
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Fixed => "fixed",
+            Self::Delta => "delta",
+        }
+    }
+}
+
+/// Variants of a product, which never share a name
+#[derive(Clone, Debug, Default)]
+pub struct Variants(Vec<Variant>);
+
+impl Variants {
+    pub const MAX_LEN: usize = 8;
+
+    /// Try parsing `variants` into [`Variants`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Err`] if `variants` has more items than allowed or if two
+    /// of them share a name
+    pub fn new(variants: Vec<Variant>) -> Result<Self, VariantsError> {
+        if variants.len() > Self::MAX_LEN {
+            return Err(VariantsError::Length);
+        }
+        for (i, variant) in variants.iter().enumerate() {
+            if variants[..i].iter().any(|other| other.name == variant.name) {
+                return Err(VariantsError::Name(variant.name.clone()));
+            }
+        }
+
+        Ok(Self(variants))
+    }
+}
+
+impl Variants {
+    #[must_use]
+    pub fn as_slice(&self) -> &[Variant] {
+        &self.0
+    }
+
+    #[must_use]
+    pub fn find(&self, id: Id) -> Option<&Variant> {
+        self.0.iter().find(|variant| variant.id() == id)
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Variant> {
+        self.0.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Variant> {
+        self.0.iter_mut()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[error("Provided string `{0}` is not a valid product variant id")]
+pub struct ParseIdError(pub Box<str>);
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum NameError {
+    #[error("Product variant name cannot be empty")]
+    Empty,
+    #[error("Product variant name cannot have more than {len} characters", len = Name::MAX_LEN)]
+    Length,
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[error("Provided string `{0}` is not a valid variant pricing, expected `fixed` or `delta`")]
+pub struct ParsePricingError(pub Box<str>);
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum VariantsError {
+    #[error("Product cannot have more than {len} variants", len = Variants::MAX_LEN)]
+    Length,
+    #[error("Product already has a variant named `{0}`")]
+    Name(Name),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::money::{Currency, Money};
+    use crate::product;
+
+    #[test]
+    fn new_name_works() {
+        assert_eq!(Name::new(" Large ").as_ref().map(Name::as_str), Ok("Large"));
+        assert_eq!(Name::new("  "), Err(NameError::Empty));
+
+        let big = ["a"; Name::MAX_LEN + 1].join("");
+        assert_eq!(Name::new(big), Err(NameError::Length));
+    }
+
+    #[test]
+    fn new_variants_with_repeated_name() {
+        let product_id = product::Id::new();
+        let variant = |name: &str| {
+            Variant::new(
+                product_id,
+                Name::new(name).expect("Valid variant name"),
+                Pricing::Fixed,
+                Money::from_minor(2500, Currency::BRL),
+            )
+        };
+
+        assert!(Variants::new(vec![variant("Small"), variant("Large")]).is_ok());
+        assert_eq!(
+            Variants::new(vec![variant("Small"), variant("Large"), variant("Small")]).err(),
+            Some(VariantsError::Name(
+                Name::new("Small").expect("Valid variant name")
+            ))
+        );
+    }
+
+    #[test]
+    fn pricing_back_and_forth_str() {
+        for pricing in [Pricing::Fixed, Pricing::Delta] {
+            assert_eq!(Pricing::parse_str(pricing.as_str()), Ok(pricing));
+        }
+        assert!(Pricing::parse_str("percentage").is_err());
+    }
+}
@@ -1,7 +1,31 @@
+mod cart;
 mod catalog;
+mod exchange;
 mod extra;
+mod happy_hour;
+mod hold;
+mod ingredient;
+mod order;
+mod payment;
 mod product;
+mod promotion;
+mod sale;
+mod stock;
+mod tax;
+mod ticket;
 
+pub use cart::PgCarts;
 pub use catalog::PgCatalogs;
+pub use exchange::PgExchangeRates;
 pub use extra::PgExtras;
+pub use happy_hour::PgHappyHours;
+pub use hold::PgHolds;
+pub use ingredient::PgIngredients;
+pub use order::PgOrders;
+pub use payment::{AnyPaymentProvider, PgPayments};
 pub use product::PgProducts;
+pub use promotion::PgPromotions;
+pub use sale::PgSales;
+pub use stock::PgStocks;
+pub use tax::PgTaxRules;
+pub use ticket::PgTickets;
@@ -0,0 +1,5 @@
+mod db;
+mod model;
+
+pub use db::PgCarts;
+pub(super) use model::CartWithItemsModel;
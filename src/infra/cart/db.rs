@@ -0,0 +1,261 @@
+mod queries;
+
+use sqlx::PgPool;
+
+use domain::cart;
+
+#[derive(Clone, Debug)]
+pub struct PgCarts {
+    pool: PgPool,
+}
+
+impl PgCarts {
+    const PK: &'static str = "pk_cart";
+
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn is_pk_error(err: &sqlx::Error) -> bool {
+        err.as_database_error()
+            .is_some_and(|db_err| db_err.constraint() == Some(Self::PK))
+    }
+}
+
+impl cart::Repository for PgCarts {
+    async fn create(&mut self, cart: &cart::Cart) -> Result<(), cart::Error> {
+        let mut trx = self.pool.begin().await.map_err(cart::Error::any)?;
+
+        let create_query = queries::CreateQuery { cart };
+        create_query.exec(trx.as_mut()).await.map_err(|err| {
+            if Self::is_pk_error(&err) {
+                cart::Error::id_conflict(cart.id())
+            } else {
+                cart::Error::any(err)
+            }
+        })?;
+
+        let create_items_query = queries::CreateItemsQuery {
+            id: cart.id(),
+            items: cart.items(),
+        };
+
+        create_items_query
+            .exec(trx.as_mut())
+            .await
+            .map_err(cart::Error::any)?;
+
+        let create_extras_query = queries::CreateExtrasQuery {
+            items: cart.items(),
+        };
+
+        create_extras_query
+            .exec(trx.as_mut())
+            .await
+            .map_err(cart::Error::any)?;
+
+        trx.commit().await.map_err(cart::Error::any)
+    }
+
+    async fn delete(&mut self, id: cart::Id) -> Result<cart::Cart, cart::Error> {
+        let query = queries::DeleteQuery { id };
+        let model = query.exec(&self.pool).await.map_err(|err| match err {
+            sqlx::Error::RowNotFound => cart::Error::id_not_found(id),
+            _ => cart::Error::any(err),
+        })?;
+
+        model.try_into_entity().map_err(cart::Error::any)
+    }
+
+    async fn find(&self, id: cart::Id) -> Result<cart::Cart, cart::Error> {
+        let query = queries::FindQuery { id };
+        let model = query.exec(&self.pool).await.map_err(|err| match err {
+            sqlx::Error::RowNotFound => cart::Error::id_not_found(id),
+            _ => cart::Error::any(err),
+        })?;
+
+        model.try_into_entity().map_err(cart::Error::any)
+    }
+
+    async fn update(&mut self, cart: &cart::Cart) -> Result<(), cart::Error> {
+        let mut trx = self.pool.begin().await.map_err(cart::Error::any)?;
+
+        let update_query = queries::UpdateQuery { cart };
+        update_query
+            .exec(trx.as_mut())
+            .await
+            .map_err(|err| match err {
+                sqlx::Error::RowNotFound => cart::Error::id_not_found(cart.id()),
+                _ => cart::Error::any(err),
+            })?;
+
+        // items are few and cheap to rewrite, so they are replaced as a whole
+        let delete_items_query = queries::DeleteItemsQuery { id: cart.id() };
+        delete_items_query
+            .exec(trx.as_mut())
+            .await
+            .map_err(cart::Error::any)?;
+
+        let create_items_query = queries::CreateItemsQuery {
+            id: cart.id(),
+            items: cart.items(),
+        };
+
+        create_items_query
+            .exec(trx.as_mut())
+            .await
+            .map_err(cart::Error::any)?;
+
+        let create_extras_query = queries::CreateExtrasQuery {
+            items: cart.items(),
+        };
+
+        create_extras_query
+            .exec(trx.as_mut())
+            .await
+            .map_err(cart::Error::any)?;
+
+        trx.commit().await.map_err(cart::Error::any)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+
+    use domain::cart::Repository;
+    use domain::catalog;
+    use domain::core::money::{Currency, Money};
+    use domain::core::quantity::Quantity;
+    use domain::extra;
+    use domain::product;
+
+    use super::*;
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn create_method_works(pool: PgPool) {
+        let result = PgCarts::new(pool)
+            .create(&cart::Cart::new(Currency::BRL))
+            .await;
+        assert!(result.is_ok());
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn delete_method_works(pool: PgPool) {
+        let id = cart::Id::parse_str("0190f6c1-3b7d-7e21-a4f8-6d2c9e0b1a35")
+            .expect("Valid cart id from fixtures");
+
+        let result = PgCarts::new(pool).delete(id).await;
+        assert!(matches!(result, Ok(cart) if cart.id() == id));
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn delete_with_not_found(pool: PgPool) {
+        use cart::{Error, NotFoundKind};
+
+        let id = cart::Id::parse_str("0190f6d0-7a2b-7c3d-8e4f-9a0b1c2d3e4f")
+            .expect("Valid cart id not in fixtures");
+
+        let result = PgCarts::new(pool).delete(id).await;
+        assert!(matches!(result, Err(Error::NotFound(NotFoundKind::Id(err_id))) if err_id == id));
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn find_method_works(pool: PgPool) {
+        let id = cart::Id::parse_str("0190f6c1-3b7d-7e21-a4f8-6d2c9e0b1a35")
+            .expect("Valid cart id from fixtures");
+
+        let cart = PgCarts::new(pool).find(id).await.expect("Found cart");
+        assert_eq!(cart.id(), id);
+        assert_eq!(cart.items().len(), 1);
+        // 2 * (20.00 + 2.00 of Cheddar)
+        assert_eq!(
+            cart.total().ok(),
+            Some(Money::from_minor(4400, Currency::BRL))
+        );
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn find_uses_current_prices(pool: PgPool) {
+        let id = cart::Id::parse_str("0190f6c1-3b7d-7e21-a4f8-6d2c9e0b1a35")
+            .expect("Valid cart id from fixtures");
+
+        sqlx::query("update extra set price = 3.00 where name = 'Cheddar'")
+            .execute(&pool)
+            .await
+            .expect("Updated Cheddar price");
+
+        let cart = PgCarts::new(pool).find(id).await.expect("Found cart");
+        assert_eq!(
+            cart.total().ok(),
+            Some(Money::from_minor(4600, Currency::BRL))
+        );
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn find_with_not_found(pool: PgPool) {
+        use cart::{Error, NotFoundKind};
+
+        let id = cart::Id::parse_str("0190f6d0-7a2b-7c3d-8e4f-9a0b1c2d3e4f")
+            .expect("Valid cart id not in fixtures");
+
+        let result = PgCarts::new(pool).find(id).await;
+        assert!(matches!(result, Err(Error::NotFound(NotFoundKind::Id(err_id))) if err_id == id));
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn update_method_works(pool: PgPool) {
+        let id = cart::Id::parse_str("0190f6c1-3b7d-7e21-a4f8-6d2c9e0b1a35")
+            .expect("Valid cart id from fixtures");
+
+        let mut repository = PgCarts::new(pool);
+        let mut cart = repository.find(id).await.expect("Found cart");
+        let product = cart.items()[0].product().clone();
+        let quantity = Quantity::new(1).expect("Valid quantity");
+        cart.add_item(product, &[], quantity)
+            .expect("Valid cart item");
+        cart.metadata.update();
+
+        let result = repository.update(&cart).await;
+        assert!(result.is_ok());
+
+        let updated = repository.find(id).await.expect("Updated cart");
+        assert_eq!(updated.items().len(), 2);
+        assert_eq!(
+            updated.total().ok(),
+            Some(Money::from_minor(6400, Currency::BRL))
+        );
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn create_with_unbound_extra(pool: PgPool) {
+        let hot_sauce = extra::Extra::new(
+            extra::Name::new("Hot Sauce").expect("Valid extra name"),
+            Money::from_minor(150, Currency::BRL),
+        );
+        let product = product::Product::config(product::ProductConfig {
+            id: product::Id::parse_str("0190ec14-0af8-71d1-9554-f1e5249ae3a2")
+                .expect("Valid product id from fixtures"),
+            catalog_id: catalog::Id::parse_str("0190ec30-286b-7211-aadb-003fc0449734")
+                .expect("Valid catalog id from fixtures"),
+            name: product::Name::new("Cheese Burger").expect("Valid product name"),
+            price: Money::from_minor(2000, Currency::BRL),
+            kind: product::Kind::Burger,
+            // stale product data claiming an extra that is not in product_extras
+            extras: Some(product::Extras::new(vec![hot_sauce.clone()]).expect("Valid extras")),
+            variants: None,
+            modifiers: None,
+            available: true,
+            schedule: None,
+            metadata: domain::core::metadata::Metadata::new(),
+        });
+
+        let mut cart = cart::Cart::new(Currency::BRL);
+        let quantity = Quantity::new(1).expect("Valid quantity");
+        cart.add_item(product, &[hot_sauce.id()], quantity)
+            .expect("Valid cart item");
+
+        let result = PgCarts::new(pool).create(&cart).await;
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,29 @@
+insert into catalog (id, name, description, created_at, updated_at)
+values
+    ('0190ec30-286b-7211-aadb-003fc0449734', 'Burgers', null, now(), now());
+
+insert into product (id, catalog_id, name, price, kind, created_at, updated_at)
+values
+    ('0190ec14-0af8-71d1-9554-f1e5249ae3a2', '0190ec30-286b-7211-aadb-003fc0449734', 'Cheese Burger', 20.00, 'burger', now(), now());
+
+insert into extra (id, name, price, created_at, updated_at)
+values
+    ('0190ec10-4aa7-7552-ba8f-df997d9f8a8e', 'Hot Sauce', 1.50, now(), now()),
+    ('0190ec13-15cc-7f53-bc0f-d60f0beea824', 'Cheddar', 2.00, now(), now());
+
+insert into product_extras (product_id, extra_id)
+values
+    -- only Cheddar is bound to Cheese Burger
+    ('0190ec14-0af8-71d1-9554-f1e5249ae3a2', '0190ec13-15cc-7f53-bc0f-d60f0beea824');
+
+insert into cart (id, created_at, updated_at)
+values
+    ('0190f6c1-3b7d-7e21-a4f8-6d2c9e0b1a35', now(), now());
+
+insert into cart_item (id, cart_id, product_id, quantity)
+values
+    ('0190f6c2-8e4a-7b13-9c5d-0f1e2a3b4c5d', '0190f6c1-3b7d-7e21-a4f8-6d2c9e0b1a35', '0190ec14-0af8-71d1-9554-f1e5249ae3a2', 2);
+
+insert into cart_item_extra (cart_item_id, product_id, extra_id)
+values
+    ('0190f6c2-8e4a-7b13-9c5d-0f1e2a3b4c5d', '0190ec14-0af8-71d1-9554-f1e5249ae3a2', '0190ec13-15cc-7f53-bc0f-d60f0beea824');
@@ -0,0 +1,203 @@
+use sqlx::PgExecutor;
+
+use domain::cart;
+
+use crate::infra::cart::CartWithItemsModel;
+
+#[derive(Clone, Debug)]
+pub(super) struct CreateQuery<'a> {
+    pub(super) cart: &'a cart::Cart,
+}
+
+impl<'a> CreateQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
+        let sql = include_str!("./sql/create.sql");
+        sqlx::query(sql)
+            .bind(self.cart.id().uuid())
+            .bind(self.cart.currency().as_str())
+            .bind(self.cart.metadata.created_at())
+            .bind(self.cart.metadata.updated_at())
+            .execute(exec)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct CreateItemsQuery<'a> {
+    pub(super) id: cart::Id,
+    pub(super) items: &'a [cart::Item],
+}
+
+impl<'a> CreateItemsQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
+        let len = self.items.len();
+        let mut ids = Vec::with_capacity(len);
+        let mut products_ids = Vec::with_capacity(len);
+        let mut quantities = Vec::with_capacity(len);
+
+        for item in self.items {
+            ids.push(item.id().uuid());
+            products_ids.push(item.product().id().uuid());
+            quantities.push(i16::try_from(item.quantity().value()).unwrap_or(i16::MAX));
+        }
+
+        let sql = include_str!("./sql/items_create.sql");
+        sqlx::query(sql)
+            .bind(self.id.uuid())
+            .bind(ids)
+            .bind(products_ids)
+            .bind(quantities)
+            .execute(exec)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct CreateExtrasQuery<'a> {
+    pub(super) items: &'a [cart::Item],
+}
+
+impl<'a> CreateExtrasQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
+        let mut items_ids = Vec::new();
+        let mut products_ids = Vec::new();
+        let mut extras_ids = Vec::new();
+
+        for item in self.items {
+            for extra_id in item.extras_ids() {
+                items_ids.push(item.id().uuid());
+                products_ids.push(item.product().id().uuid());
+                extras_ids.push(extra_id.uuid());
+            }
+        }
+
+        let sql = include_str!("./sql/extras_create.sql");
+        sqlx::query(sql)
+            .bind(items_ids)
+            .bind(products_ids)
+            .bind(extras_ids)
+            .execute(exec)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct DeleteQuery {
+    pub(super) id: cart::Id,
+}
+
+impl DeleteQuery {
+    pub(super) async fn exec(
+        self,
+        exec: impl PgExecutor<'_>,
+    ) -> Result<CartWithItemsModel, sqlx::Error> {
+        let sql = include_str!("./sql/delete.sql");
+        sqlx::query_as(sql)
+            .bind(self.id.uuid())
+            .fetch_one(exec)
+            .await
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct DeleteItemsQuery {
+    pub(super) id: cart::Id,
+}
+
+impl DeleteItemsQuery {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'_>) -> Result<(), sqlx::Error> {
+        let sql = include_str!("./sql/items_delete.sql");
+        sqlx::query(sql).bind(self.id.uuid()).execute(exec).await?;
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct FindQuery {
+    pub(super) id: cart::Id,
+}
+
+impl FindQuery {
+    pub(super) async fn exec(
+        self,
+        exec: impl PgExecutor<'_>,
+    ) -> Result<CartWithItemsModel, sqlx::Error> {
+        let sql = include_str!("./sql/find.sql");
+        sqlx::query_as(sql)
+            .bind(self.id.uuid())
+            .fetch_one(exec)
+            .await
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct UpdateQuery<'a> {
+    pub(super) cart: &'a cart::Cart,
+}
+
+impl<'a> UpdateQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
+        let sql = include_str!("./sql/update.sql");
+        let result = sqlx::query(sql)
+            .bind(self.cart.metadata.updated_at())
+            .bind(self.cart.id().uuid())
+            .execute(exec)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(sqlx::Error::RowNotFound);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+
+    use super::*;
+    use domain::core::money::Currency;
+
+    #[sqlx::test(fixtures("seed"))]
+    async fn create_query_works(pool: PgPool) {
+        let cart = cart::Cart::new(Currency::BRL);
+        let result = CreateQuery { cart: &cart }.exec(&pool).await;
+        assert!(result.is_ok());
+    }
+
+    #[sqlx::test(fixtures("seed"))]
+    async fn delete_items_query_works(pool: PgPool) {
+        let id = cart::Id::parse_str("0190f6c1-3b7d-7e21-a4f8-6d2c9e0b1a35")
+            .expect("Valid cart id from fixtures");
+
+        let result = DeleteItemsQuery { id }.exec(&pool).await;
+        assert!(result.is_ok());
+
+        let found = FindQuery { id }
+            .exec(&pool)
+            .await
+            .expect("Found cart model");
+        assert!(found.items.is_empty());
+    }
+
+    #[sqlx::test(fixtures("seed"))]
+    async fn find_query_works(pool: PgPool) {
+        let id = cart::Id::parse_str("0190f6c1-3b7d-7e21-a4f8-6d2c9e0b1a35")
+            .expect("Valid cart id from fixtures");
+
+        let found = FindQuery { id }
+            .exec(&pool)
+            .await
+            .expect("Found cart model");
+        assert_eq!(found.id, id.uuid());
+        assert_eq!(found.items.len(), 1);
+        assert_eq!(found.items[0].extras_ids.len(), 1);
+    }
+}
@@ -0,0 +1,2 @@
+insert into cart (id, currency, created_at, updated_at)
+values ($1, $2, $3, $4)
\ No newline at end of file
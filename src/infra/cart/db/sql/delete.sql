@@ -0,0 +1,3 @@
+delete from cart_with_items as cart
+where cart.id = $1
+returning cart.*
\ No newline at end of file
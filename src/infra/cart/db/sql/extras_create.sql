@@ -0,0 +1,2 @@
+insert into cart_item_extra (cart_item_id, product_id, extra_id)
+select * from unnest($1::uuid[], $2::uuid[], $3::uuid[])
\ No newline at end of file
@@ -0,0 +1,3 @@
+select cart.*
+from cart_with_items as cart
+where cart.id = $1
\ No newline at end of file
@@ -0,0 +1,2 @@
+insert into cart_item (cart_id, id, product_id, quantity)
+select $1, * from unnest($2::uuid[], $3::uuid[], $4::smallint[])
\ No newline at end of file
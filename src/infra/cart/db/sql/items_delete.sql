@@ -0,0 +1,2 @@
+delete from cart_item as item
+where item.cart_id = $1
\ No newline at end of file
@@ -0,0 +1,3 @@
+update cart
+set updated_at = $1
+where cart.id = $2
\ No newline at end of file
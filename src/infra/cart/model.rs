@@ -0,0 +1,68 @@
+use serde::Deserialize;
+use sqlx::types::{Json, Uuid};
+use sqlx::FromRow;
+use time::OffsetDateTime;
+
+use domain::cart;
+use domain::core::metadata;
+use domain::core::money::Currency;
+use domain::core::quantity::Quantity;
+use domain::extra;
+
+use crate::infra::product::ProductModel;
+
+#[derive(Clone, Debug, Deserialize, FromRow)]
+pub struct CartWithItemsModel {
+    pub id: Uuid,
+    pub currency: String,
+    pub items: Json<Vec<CartItemModel>>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime,
+}
+
+impl CartWithItemsModel {
+    pub fn try_into_entity(self) -> Result<cart::Cart, Box<dyn std::error::Error>> {
+        let items = self
+            .items
+            .0
+            .into_iter()
+            .map(CartItemModel::try_into_entity)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let currency = Currency::parse_str(&self.currency)?;
+        let metadata = metadata::Metadata::configured(self.created_at, self.updated_at)?;
+        let cart = cart::Cart::config(cart::CartConfig {
+            id: cart::Id::from(self.id),
+            currency,
+            items,
+            metadata,
+        });
+
+        Ok(cart)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CartItemModel {
+    pub id: Uuid,
+    pub quantity: i16,
+    pub product: ProductModel,
+    pub extras_ids: Vec<Uuid>,
+}
+
+impl CartItemModel {
+    pub fn try_into_entity(self) -> Result<cart::Item, Box<dyn std::error::Error>> {
+        let quantity = Quantity::new(u16::try_from(self.quantity)?)?;
+        let product = self.product.try_into_entity()?;
+        let extras_ids = self.extras_ids.into_iter().map(extra::Id::from).collect();
+
+        Ok(cart::Item::config(cart::ItemConfig {
+            id: cart::ItemId::from(self.id),
+            product,
+            extras_ids,
+            quantity,
+        }))
+    }
+}
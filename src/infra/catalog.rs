@@ -2,4 +2,4 @@ mod db;
 mod model;
 
 pub use db::PgCatalogs;
-pub(super) use model::CatalogWithProductsModel;
+pub(super) use model::{CatalogWithProductsModel, DaypartModel, OpeningHoursModel};
@@ -68,7 +68,10 @@ impl catalog::Repository for PgCatalogs {
     }
 
     async fn list(&self, query: catalog::ListQuery) -> Result<catalog::Pagination, catalog::Error> {
-        let count = queries::CountQuery
+        let count_query = queries::CountQuery {
+            active_at: query.active_at,
+        };
+        let count = count_query
             .exec(&self.pool)
             .await
             .map_err(catalog::Error::any)?;
@@ -118,6 +121,57 @@ mod tests {
 
     use super::*;
 
+    #[sqlx::test]
+    async fn catalog_is_active_matches_daypart(pool: PgPool) {
+        use domain::core::schedule::{Weekdays, Window, Zone};
+        use sqlx::types::Json;
+        use time::macros::{datetime, time};
+        use time::{Duration, Weekday};
+
+        use crate::infra::catalog::DaypartModel;
+
+        // overnight windows spanning both ends of the week, where the sql
+        // function has to wrap weekday bits around
+        let windows = [
+            (Weekday::Sunday, time!(22:00), time!(2:00)),
+            (Weekday::Saturday, time!(23:00), time!(1:30)),
+        ];
+        let zones = ["UTC", "America/Sao_Paulo", "America/New_York"];
+        // both ranges span a weekend, and new york changes its clocks on
+        // 2024-03-10 and 2024-11-03
+        let ranges = [
+            datetime!(2024-03-08 12:00 UTC),
+            datetime!(2024-11-01 12:00 UTC),
+        ];
+
+        for (day, starts_at, ends_at) in windows {
+            for zone in zones {
+                let zone = Zone::parse_str(zone).expect("Valid zone");
+                let weekdays = Weekdays::new(&[day]).expect("Valid weekdays");
+                let window = Window::new(weekdays, starts_at, ends_at, zone).expect("Valid window");
+                let daypart = catalog::Daypart::new(vec![window], zone).expect("Valid daypart");
+                let model = Json(DaypartModel::from_daypart(&daypart));
+
+                let mut active = 0;
+                for start in ranges {
+                    for step in 0..(4 * 24 * 4) {
+                        let at = start + Duration::minutes(15 * step);
+                        let is_active: bool =
+                            sqlx::query_scalar("select catalog_is_active($1, $2)")
+                                .bind(&model)
+                                .bind(at)
+                                .fetch_one(&pool)
+                                .await
+                                .expect("Active check");
+                        assert_eq!(is_active, daypart.contains(at), "{day} in {zone} at {at}");
+                        active += usize::from(is_active);
+                    }
+                }
+                assert!(active > 0);
+            }
+        }
+    }
+
     #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
     async fn create_method_works(pool: PgPool) {
         let catalog = catalog::Catalog::new(
@@ -129,6 +183,38 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn create_with_hours(pool: PgPool) {
+        use domain::core::schedule::{Weekdays, Window, Zone};
+        use time::macros::{date, time};
+        use time::Weekday;
+
+        let zone = Zone::parse_str("America/Sao_Paulo").expect("Valid zone");
+        let weekdays =
+            Weekdays::new(&[Weekday::Friday, Weekday::Saturday]).expect("Valid weekdays");
+        let window = Window::new(weekdays, time!(18:00), time!(2:00), zone).expect("Valid window");
+        let hours = catalog::OpeningHours::new(vec![window], vec![date!(2024 - 12 - 25)], zone)
+            .expect("Valid opening hours");
+
+        let mut catalog = catalog::Catalog::new(
+            catalog::Name::new("Vegetarian").expect("Valid catalog name not in fixtures"),
+            None,
+        );
+        catalog.hours = Some(hours);
+
+        let mut pg_catalogs = PgCatalogs::new(pool);
+        pg_catalogs
+            .create(&catalog)
+            .await
+            .expect("Catalog should be created");
+
+        let found_product_catalog = pg_catalogs
+            .find(catalog.id())
+            .await
+            .expect("Created catalog should be found");
+        assert_eq!(found_product_catalog.catalog.hours, catalog.hours);
+    }
+
     #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
     async fn create_with_id_conflict(pool: PgPool) {
         use catalog::{ConflictKind, Error};
@@ -138,6 +224,8 @@ mod tests {
                 .expect("Valid catalog id from fixtures"),
             name: catalog::Name::new("Vegetarian").expect("Valid catalog name not in fixtures"),
             description: None,
+            hours: None,
+            daypart: None,
             metadata: metadata::Metadata::new(),
         });
 
@@ -156,6 +244,8 @@ mod tests {
                 .expect("Valid catalog id not in fixtures"),
             name: catalog::Name::new("Burgers").expect("Valid catalog name from fixtures"),
             description: None,
+            hours: None,
+            daypart: None,
             metadata: metadata::Metadata::new(),
         });
 
@@ -213,6 +303,7 @@ mod tests {
         let query = catalog::ListQuery {
             limit: NonZeroU8::new(10).unwrap(),
             page: NonZeroU32::new(1).unwrap(),
+            active_at: None,
         };
 
         let result = PgCatalogs::new(pool).list(query.clone()).await;
@@ -225,6 +316,77 @@ mod tests {
         assert_eq!(pagination.limit, query.limit);
     }
 
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn list_with_active_at(pool: PgPool) {
+        use std::num::{NonZeroU32, NonZeroU8};
+
+        use domain::core::schedule::{Weekdays, Window, Zone};
+        use time::macros::{datetime, time};
+        use time::{OffsetDateTime, Weekday};
+
+        let mut pg_catalogs = PgCatalogs::new(pool);
+        let dayparts = [
+            (
+                "Breakfast",
+                Weekday::Monday,
+                time!(6:00),
+                time!(10:30),
+                Zone::parse_str("America/Sao_Paulo").expect("Valid zone"),
+            ),
+            (
+                "Late Night",
+                Weekday::Friday,
+                time!(22:00),
+                time!(2:00),
+                Zone::UTC,
+            ),
+        ];
+        for (name, day, starts_at, ends_at, zone) in dayparts {
+            let weekdays = Weekdays::new(&[day]).expect("Valid weekdays");
+            let window = Window::new(weekdays, starts_at, ends_at, zone).expect("Valid window");
+            let mut catalog = catalog::Catalog::new(
+                catalog::Name::new(name).expect("Valid catalog name not in fixtures"),
+                None,
+            );
+            catalog.daypart =
+                Some(catalog::Daypart::new(vec![window], zone).expect("Valid daypart"));
+            pg_catalogs
+                .create(&catalog)
+                .await
+                .expect("Catalog should be created");
+        }
+
+        let list_active_at = |active_at: OffsetDateTime| catalog::ListQuery {
+            page: NonZeroU32::new(1).unwrap(),
+            limit: NonZeroU8::new(10).unwrap(),
+            active_at: Some(active_at),
+        };
+
+        // 2024-08-26 is a monday and 2024-08-31 is a saturday, while fixtures
+        // catalogs have no daypart and so are always active
+        let cases = [
+            (datetime!(2024-08-26 07:00 -3), Some("Breakfast")),
+            (datetime!(2024-08-26 12:00 -3), None),
+            (datetime!(2024-08-31 01:00 UTC), Some("Late Night")),
+            (datetime!(2024-08-31 03:00 UTC), None),
+        ];
+        for (active_at, daypart_name) in cases {
+            let pagination = pg_catalogs
+                .list(list_active_at(active_at))
+                .await
+                .expect("Paginated catalog list");
+
+            let names = pagination
+                .items
+                .iter()
+                .filter(|product_catalog| product_catalog.catalog.daypart.is_some())
+                .map(|product_catalog| product_catalog.catalog.name.as_str())
+                .collect::<Vec<_>>();
+            assert_eq!(names, daypart_name.into_iter().collect::<Vec<_>>());
+            assert_eq!(pagination.count, 2 + names.len() as u64);
+        }
+    }
+
     #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
     async fn update_method_works(pool: PgPool) {
         let catalog = catalog::Catalog::config(catalog::CatalogConfig {
@@ -235,6 +397,8 @@ mod tests {
                 catalog::Description::new("Delicous vegetarian meals")
                     .expect("Valid catalog description"),
             ),
+            hours: None,
+            daypart: None,
             metadata: metadata::Metadata::new(),
         });
 
@@ -265,6 +429,8 @@ mod tests {
                 catalog::Description::new("Delicous vegetarian meals")
                     .expect("Valid catalog description"),
             ),
+            hours: None,
+            daypart: None,
             metadata: metadata::Metadata::new(),
         });
 
@@ -281,6 +447,8 @@ mod tests {
                 .expect("Valid catalog id from fixtures"),
             name: catalog::Name::new("Burgers").expect("Valid catalog name from fixtures"),
             description: None,
+            hours: None,
+            daypart: None,
             metadata: metadata::Metadata::new(),
         });
 
@@ -1,8 +1,10 @@
+use sqlx::types::Json;
 use sqlx::PgExecutor;
+use time::OffsetDateTime;
 
 use domain::catalog;
 
-use crate::infra::catalog::CatalogWithProductsModel;
+use crate::infra::catalog::{CatalogWithProductsModel, DaypartModel, OpeningHoursModel};
 
 #[derive(Clone, Debug)]
 pub(super) struct CreateQuery<'a> {
@@ -10,6 +12,20 @@ pub(super) struct CreateQuery<'a> {
 }
 
 impl<'a> CreateQuery<'a> {
+    fn hours(&self) -> Option<Json<OpeningHoursModel>> {
+        self.catalog
+            .hours
+            .as_ref()
+            .map(|hours| Json(OpeningHoursModel::from_opening_hours(hours)))
+    }
+
+    fn daypart(&self) -> Option<Json<DaypartModel>> {
+        self.catalog
+            .daypart
+            .as_ref()
+            .map(|daypart| Json(DaypartModel::from_daypart(daypart)))
+    }
+
     pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
         let sql = include_str!("./sql/create.sql");
         sqlx::query(sql)
@@ -21,6 +37,8 @@ impl<'a> CreateQuery<'a> {
                     .as_ref()
                     .map(catalog::Description::as_str),
             )
+            .bind(self.hours())
+            .bind(self.daypart())
             .bind(self.catalog.metadata.created_at())
             .bind(self.catalog.metadata.updated_at())
             .execute(exec)
@@ -31,12 +49,17 @@ impl<'a> CreateQuery<'a> {
 }
 
 #[derive(Clone, Debug)]
-pub(super) struct CountQuery;
+pub(super) struct CountQuery {
+    pub(super) active_at: Option<OffsetDateTime>,
+}
 
 impl CountQuery {
     pub(super) async fn exec(self, exec: impl PgExecutor<'_>) -> Result<u64, sqlx::Error> {
         let sql = include_str!("./sql/count.sql");
-        let count: i64 = sqlx::query_scalar(sql).fetch_one(exec).await?;
+        let count: i64 = sqlx::query_scalar(sql)
+            .bind(self.active_at)
+            .fetch_one(exec)
+            .await?;
         Ok(u64::try_from(count).unwrap_or_default())
     }
 }
@@ -93,6 +116,7 @@ impl ListQuery {
         sqlx::query_as(sql)
             .bind(i64::from(limit))
             .bind(i64::from(offset))
+            .bind(self.0.active_at)
             .fetch_all(exec)
             .await
     }
@@ -104,6 +128,20 @@ pub(super) struct UpdateQuery<'a> {
 }
 
 impl<'a> UpdateQuery<'a> {
+    fn hours(&self) -> Option<Json<OpeningHoursModel>> {
+        self.catalog
+            .hours
+            .as_ref()
+            .map(|hours| Json(OpeningHoursModel::from_opening_hours(hours)))
+    }
+
+    fn daypart(&self) -> Option<Json<DaypartModel>> {
+        self.catalog
+            .daypart
+            .as_ref()
+            .map(|daypart| Json(DaypartModel::from_daypart(daypart)))
+    }
+
     pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
         let sql = include_str!("./sql/update.sql");
         let result = sqlx::query(sql)
@@ -114,6 +152,8 @@ impl<'a> UpdateQuery<'a> {
                     .as_ref()
                     .map(catalog::Description::as_str),
             )
+            .bind(self.hours())
+            .bind(self.daypart())
             .bind(self.catalog.metadata.updated_at())
             .bind(self.catalog.id().uuid())
             .execute(exec)
@@ -148,7 +188,7 @@ mod tests {
 
     #[sqlx::test(fixtures("seed"))]
     async fn count_query_works(pool: PgPool) {
-        let result = CountQuery.exec(&pool).await;
+        let result = CountQuery { active_at: None }.exec(&pool).await;
         assert_eq!(result.ok(), Some(2u64));
     }
 
@@ -185,6 +225,7 @@ mod tests {
         let query = catalog::ListQuery {
             page: NonZeroU32::new(1).unwrap(),
             limit: NonZeroU8::new(10).unwrap(),
+            active_at: None,
         };
 
         let result = ListQuery(query).exec(&pool).await;
@@ -201,6 +242,8 @@ mod tests {
                 .expect("Valid catalog id from fixtures"),
             name: catalog::Name::new("Burgers Updated").expect("Valid catalog name"),
             description: None,
+            hours: None,
+            daypart: None,
             metadata: metadata::Metadata::new(),
         });
 
@@ -1 +1,2 @@
-select count(id) from catalog
\ No newline at end of file
+select count(id) from catalog
+where $1::timestamptz is null or catalog_is_active(daypart, $1)
\ No newline at end of file
@@ -1,2 +1,2 @@
-insert into catalog (id, name, description, created_at, updated_at)
-values ($1, $2, $3, $4, $5)
\ No newline at end of file
+insert into catalog (id, name, description, hours, daypart, created_at, updated_at)
+values ($1, $2, $3, $4, $5, $6, $7)
\ No newline at end of file
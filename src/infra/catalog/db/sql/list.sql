@@ -1,4 +1,5 @@
 select catalog.*
 from catalog_with_products as catalog
+where $3::timestamptz is null or catalog_is_active(catalog.daypart, $3)
 order by catalog.created_at desc
 limit $1 offset $2
\ No newline at end of file
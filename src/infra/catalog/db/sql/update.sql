@@ -1,3 +1,3 @@
 update catalog
-set name = $1, description = $2, updated_at = $3
-where catalog.id = $4
\ No newline at end of file
+set name = $1, description = $2, hours = $3, daypart = $4, updated_at = $5
+where catalog.id = $6
\ No newline at end of file
@@ -1,18 +1,21 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use sqlx::types::{Json, Uuid};
 use sqlx::FromRow;
-use time::OffsetDateTime;
+use time::{Date, OffsetDateTime};
 
 use domain::catalog;
 use domain::core::metadata;
+use domain::core::schedule;
 
-use crate::infra::product::ProductModel;
+use crate::infra::product::{ProductModel, ScheduleWindowModel};
 
 #[derive(Clone, Debug, FromRow, Deserialize)]
 pub struct CatalogWithProductsModel {
     pub id: Uuid,
     pub name: String,
     pub description: Option<String>,
+    pub hours: Option<Json<OpeningHoursModel>>,
+    pub daypart: Option<Json<DaypartModel>>,
     pub products: Json<Vec<ProductModel>>,
     #[serde(with = "time::serde::rfc3339")]
     pub created_at: OffsetDateTime,
@@ -36,15 +39,100 @@ impl CatalogWithProductsModel {
             .collect::<Result<Vec<_>, _>>()?;
 
         let products = catalog::Products::new(products)?;
+        let hours = self
+            .hours
+            .map(|hours| hours.0.try_into_opening_hours())
+            .transpose()?;
+        let daypart = self
+            .daypart
+            .map(|daypart| daypart.0.try_into_daypart())
+            .transpose()?;
 
         let metadata = metadata::Metadata::configured(self.created_at, self.updated_at)?;
         let catalog = catalog::Catalog::config(catalog::CatalogConfig {
             id: catalog::Id::from(self.id),
             name,
             description,
+            hours,
+            daypart,
             metadata,
         });
 
         Ok(catalog::ProductCatalog::new(catalog, products))
     }
 }
+
+time::serde::format_description!(date_format, Date, "[year]-[month]-[day]");
+
+/// Opening hours as stored in its jsonb column, with windows in the same
+/// format as product schedules
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct OpeningHoursModel {
+    pub windows: Vec<ScheduleWindowModel>,
+    pub closures: Vec<ClosureModel>,
+    pub time_zone: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct ClosureModel(#[serde(with = "date_format")] pub Date);
+
+impl OpeningHoursModel {
+    pub fn from_opening_hours(hours: &catalog::OpeningHours) -> Self {
+        Self {
+            windows: hours
+                .windows()
+                .iter()
+                .map(ScheduleWindowModel::from_window)
+                .collect(),
+            closures: hours.closures().iter().copied().map(ClosureModel).collect(),
+            time_zone: hours.zone().as_str().to_owned(),
+        }
+    }
+
+    pub fn try_into_opening_hours(
+        self,
+    ) -> Result<catalog::OpeningHours, Box<dyn std::error::Error>> {
+        let zone = schedule::Zone::parse_str(&self.time_zone)?;
+        let windows = self
+            .windows
+            .into_iter()
+            .map(|window| window.try_into_window(zone))
+            .collect::<Result<Vec<_>, _>>()?;
+        let closures = self.closures.into_iter().map(|closure| closure.0).collect();
+
+        Ok(catalog::OpeningHours::new(windows, closures, zone)?)
+    }
+}
+
+/// Daypart as stored in its jsonb column, with windows in the same format as
+/// product schedules
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DaypartModel {
+    pub windows: Vec<ScheduleWindowModel>,
+    pub time_zone: String,
+}
+
+impl DaypartModel {
+    pub fn from_daypart(daypart: &catalog::Daypart) -> Self {
+        Self {
+            windows: daypart
+                .windows()
+                .iter()
+                .map(ScheduleWindowModel::from_window)
+                .collect(),
+            time_zone: daypart.zone().as_str().to_owned(),
+        }
+    }
+
+    pub fn try_into_daypart(self) -> Result<catalog::Daypart, Box<dyn std::error::Error>> {
+        let zone = schedule::Zone::parse_str(&self.time_zone)?;
+        let windows = self
+            .windows
+            .into_iter()
+            .map(|window| window.try_into_window(zone))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(catalog::Daypart::new(windows, zone)?)
+    }
+}
@@ -0,0 +1,5 @@
+mod db;
+mod model;
+
+pub use db::PgExchangeRates;
+pub(super) use model::ExchangeRateModel;
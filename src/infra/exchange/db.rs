@@ -0,0 +1,98 @@
+mod queries;
+
+use sqlx::PgPool;
+
+use domain::exchange;
+
+use super::model::ExchangeRateModel;
+
+#[derive(Clone, Debug)]
+pub struct PgExchangeRates {
+    pool: PgPool,
+}
+
+impl PgExchangeRates {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl exchange::Repository for PgExchangeRates {
+    async fn find(&self, pair: exchange::Pair) -> Result<exchange::ExchangeRate, exchange::Error> {
+        let query = queries::FindQuery { pair };
+        let model = query.exec(&self.pool).await.map_err(|err| match &err {
+            sqlx::Error::RowNotFound => exchange::Error::NotFound(pair),
+            _ => exchange::Error::any(err),
+        })?;
+
+        model.try_into_entity().map_err(exchange::Error::any)
+    }
+
+    async fn list(&self) -> Result<Vec<exchange::ExchangeRate>, exchange::Error> {
+        let models = queries::ListQuery
+            .exec(&self.pool)
+            .await
+            .map_err(exchange::Error::any)?;
+
+        models
+            .into_iter()
+            .map(ExchangeRateModel::try_into_entity)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(exchange::Error::any)
+    }
+
+    async fn save_many(&mut self, rates: &[exchange::ExchangeRate]) -> Result<(), exchange::Error> {
+        let query = queries::SaveManyQuery { rates };
+        query.exec(&self.pool).await.map_err(exchange::Error::any)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+
+    use domain::core::money::{Currency, Money};
+    use domain::exchange::Repository;
+
+    use super::*;
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn find_method_works(pool: PgPool) {
+        let pair = exchange::Pair::new(Currency::BRL, Currency::USD).expect("Valid pair");
+
+        let result = PgExchangeRates::new(pool).find(pair).await;
+        let rate = result.expect("Exchange rate from fixtures");
+        assert_eq!(
+            rate.convert(Money::from_minor(1000, Currency::BRL)).ok(),
+            Some(Money::from_minor(180, Currency::USD))
+        );
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn find_with_not_found(pool: PgPool) {
+        use exchange::Error;
+
+        let pair = exchange::Pair::new(Currency::USD, Currency::EUR).expect("Valid pair");
+
+        let result = PgExchangeRates::new(pool).find(pair).await;
+        assert!(matches!(result, Err(Error::NotFound(err_pair)) if err_pair == pair));
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn save_many_method_works(pool: PgPool) {
+        let mut repository = PgExchangeRates::new(pool);
+        let pair = exchange::Pair::new(Currency::BRL, Currency::USD).expect("Valid pair");
+        let mut rate = repository
+            .find(pair)
+            .await
+            .expect("Exchange rate from fixtures");
+        rate.set_rate(exchange::Rate::parse_str("0.2").expect("Valid rate"));
+
+        let result = repository.save_many(&[rate.clone()]).await;
+        assert!(result.is_ok());
+
+        let saved = repository.find(pair).await.expect("Saved exchange rate");
+        assert_eq!(saved.rate, rate.rate);
+        assert_eq!(saved.metadata.created_at(), rate.metadata.created_at());
+    }
+}
@@ -0,0 +1,4 @@
+insert into exchange_rate (from_currency, to_currency, rate, created_at, updated_at)
+values
+    ('BRL', 'USD', 0.18, now(), now()),
+    ('BRL', 'EUR', 0.165, now(), now());
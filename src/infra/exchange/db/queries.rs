@@ -0,0 +1,126 @@
+use sqlx::PgExecutor;
+
+use domain::exchange;
+
+use crate::infra::exchange::ExchangeRateModel;
+
+#[derive(Clone, Debug)]
+pub(super) struct FindQuery {
+    pub(super) pair: exchange::Pair,
+}
+
+impl FindQuery {
+    pub(super) async fn exec(
+        self,
+        exec: impl PgExecutor<'_>,
+    ) -> Result<ExchangeRateModel, sqlx::Error> {
+        let sql = include_str!("./sql/find.sql");
+        sqlx::query_as(sql)
+            .bind(self.pair.from().as_str())
+            .bind(self.pair.to().as_str())
+            .fetch_one(exec)
+            .await
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct ListQuery;
+
+impl ListQuery {
+    pub(super) async fn exec(
+        self,
+        exec: impl PgExecutor<'_>,
+    ) -> Result<Vec<ExchangeRateModel>, sqlx::Error> {
+        let sql = include_str!("./sql/list.sql");
+        sqlx::query_as(sql).fetch_all(exec).await
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct SaveManyQuery<'a> {
+    pub(super) rates: &'a [exchange::ExchangeRate],
+}
+
+impl<'a> SaveManyQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
+        let len = self.rates.len();
+        let mut from_currencies = Vec::with_capacity(len);
+        let mut to_currencies = Vec::with_capacity(len);
+        let mut rates = Vec::with_capacity(len);
+        let mut created_ats = Vec::with_capacity(len);
+        let mut updated_ats = Vec::with_capacity(len);
+
+        for rate in self.rates {
+            from_currencies.push(rate.pair().from().to_string());
+            to_currencies.push(rate.pair().to().to_string());
+            rates.push(rate.rate.value());
+            created_ats.push(rate.metadata.created_at());
+            updated_ats.push(rate.metadata.updated_at());
+        }
+
+        let sql = include_str!("./sql/save_many.sql");
+        sqlx::query(sql)
+            .bind(from_currencies)
+            .bind(to_currencies)
+            .bind(rates)
+            .bind(created_ats)
+            .bind(updated_ats)
+            .execute(exec)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+
+    use domain::core::money::Currency;
+
+    use super::*;
+
+    #[sqlx::test(fixtures("seed"))]
+    async fn find_query_works(pool: PgPool) {
+        let pair = exchange::Pair::new(Currency::BRL, Currency::USD).expect("Valid pair");
+
+        let result = FindQuery { pair }.exec(&pool).await;
+        let model = result.expect("Exchange rate from fixtures");
+        assert_eq!(model.from_currency, "BRL");
+        assert_eq!(model.to_currency, "USD");
+    }
+
+    #[sqlx::test(fixtures("seed"))]
+    async fn list_query_works(pool: PgPool) {
+        let result = ListQuery.exec(&pool).await;
+        let models = result.expect("Exchange rates from fixtures");
+        assert_eq!(models.len(), 2);
+    }
+
+    #[sqlx::test(fixtures("seed"))]
+    async fn save_many_query_works(pool: PgPool) {
+        let rates = [
+            exchange::ExchangeRate::new(
+                exchange::Pair::new(Currency::BRL, Currency::USD).expect("Valid pair"),
+                exchange::Rate::parse_str("0.2").expect("Valid rate"),
+            ),
+            exchange::ExchangeRate::new(
+                exchange::Pair::new(Currency::USD, Currency::BRL).expect("Valid pair"),
+                exchange::Rate::parse_str("5").expect("Valid rate"),
+            ),
+        ];
+
+        let result = SaveManyQuery { rates: &rates }.exec(&pool).await;
+        assert!(result.is_ok());
+
+        let models = ListQuery.exec(&pool).await.expect("Saved exchange rates");
+        assert_eq!(models.len(), 3);
+
+        let pair = rates[0].pair();
+        let model = FindQuery { pair }
+            .exec(&pool)
+            .await
+            .expect("Saved exchange rate");
+        assert_eq!(model.rate, rates[0].rate.value());
+    }
+}
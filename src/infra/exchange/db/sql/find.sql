@@ -0,0 +1,2 @@
+select exchange_rate.* from exchange_rate
+where from_currency = $1 and to_currency = $2
\ No newline at end of file
@@ -0,0 +1,2 @@
+select exchange_rate.* from exchange_rate
+order by from_currency, to_currency
\ No newline at end of file
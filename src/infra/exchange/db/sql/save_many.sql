@@ -0,0 +1,10 @@
+insert into exchange_rate (from_currency, to_currency, rate, created_at, updated_at)
+select * from unnest(
+    $1::char(3)[],
+    $2::char(3)[],
+    $3::decimal[],
+    $4::timestamptz[],
+    $5::timestamptz[]
+)
+on conflict (from_currency, to_currency) do update
+set rate = excluded.rate, updated_at = excluded.updated_at
\ No newline at end of file
@@ -0,0 +1,31 @@
+use sqlx::types::Decimal;
+use sqlx::FromRow;
+use time::OffsetDateTime;
+
+use domain::core::metadata;
+use domain::core::money::Currency;
+use domain::exchange;
+
+#[derive(Clone, Debug, FromRow)]
+pub struct ExchangeRateModel {
+    pub from_currency: String,
+    pub to_currency: String,
+    pub rate: Decimal,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+impl ExchangeRateModel {
+    pub fn try_into_entity(self) -> Result<exchange::ExchangeRate, Box<dyn std::error::Error>> {
+        let from = Currency::parse_str(&self.from_currency)?;
+        let to = Currency::parse_str(&self.to_currency)?;
+        let metadata = metadata::Metadata::configured(self.created_at, self.updated_at)?;
+        let exchange_rate = exchange::ExchangeRate::config(exchange::ExchangeRateConfig {
+            pair: exchange::Pair::new(from, to)?,
+            rate: exchange::Rate::new(self.rate)?,
+            metadata,
+        });
+
+        Ok(exchange_rate)
+    }
+}
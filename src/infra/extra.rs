@@ -2,4 +2,4 @@ mod db;
 mod model;
 
 pub use db::PgExtras;
-pub(super) use model::ExtraModel;
+pub(super) use model::{ExtraModel, UsageModel};
@@ -4,7 +4,7 @@ use sqlx::PgPool;
 
 use domain::extra;
 
-use super::model::ExtraModel;
+use super::model::{ExtraModel, UsageModel};
 
 #[derive(Clone, Debug)]
 pub struct PgExtras {
@@ -13,7 +13,9 @@ pub struct PgExtras {
 
 impl PgExtras {
     const PK: &'static str = "pk_extra";
-    const AK_NAME: &'static str = "ak_extra_name";
+    const AK_NAME: &'static str = "ak_extra_scope_name";
+    const FK_CATALOG: &'static str = "fk_extra_catalog_id";
+    const FK_PRODUCT_EXTRAS: &'static str = "fk_product_extras_extra_id";
 
     pub fn new(pool: PgPool) -> Self {
         Self { pool }
@@ -28,22 +30,19 @@ impl PgExtras {
         err.as_database_error()
             .is_some_and(|db_err| db_err.constraint() == Some(Self::AK_NAME))
     }
-}
 
-impl extra::Repository for PgExtras {
-    async fn all(&self) -> Result<Vec<extra::Extra>, extra::Error> {
-        let models = queries::AllQuery
-            .exec(&self.pool)
-            .await
-            .map_err(extra::Error::any)?;
+    fn is_fk_catalog_error(err: &sqlx::Error) -> bool {
+        err.as_database_error()
+            .is_some_and(|db_err| db_err.constraint() == Some(Self::FK_CATALOG))
+    }
 
-        models
-            .into_iter()
-            .map(ExtraModel::try_into_entity)
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(extra::Error::any)
+    fn is_fk_product_extras_error(err: &sqlx::Error) -> bool {
+        err.as_database_error()
+            .is_some_and(|db_err| db_err.constraint() == Some(Self::FK_PRODUCT_EXTRAS))
     }
+}
 
+impl extra::Repository for PgExtras {
     async fn create(&mut self, extra: &extra::Extra) -> Result<(), extra::Error> {
         let query = queries::CreateQuery { extra };
         query.exec(&self.pool).await.map_err(|err| {
@@ -51,6 +50,10 @@ impl extra::Repository for PgExtras {
                 extra::Error::id_conflict(extra.id())
             } else if Self::is_ak_name_error(&err) {
                 extra::Error::name_conflict(extra.name.clone())
+            } else if let Some(catalog_id) =
+                extra.catalog_id.filter(|_| Self::is_fk_catalog_error(&err))
+            {
+                extra::Error::CatalogNotFound(catalog_id)
             } else {
                 extra::Error::any(err)
             }
@@ -59,12 +62,32 @@ impl extra::Repository for PgExtras {
         Ok(())
     }
 
-    async fn delete(&mut self, id: extra::Id) -> Result<extra::Extra, extra::Error> {
-        let query = queries::DeleteQuery { id };
-        let model = query.exec(&self.pool).await.map_err(|err| match &err {
-            sqlx::Error::RowNotFound => extra::Error::NotFound(id),
-            _ => extra::Error::any(err),
-        })?;
+    async fn delete(&mut self, id: extra::Id, force: bool) -> Result<extra::Extra, extra::Error> {
+        let mut trx = self.pool.begin().await.map_err(extra::Error::any)?;
+
+        if force {
+            let unbind_query = queries::UnbindQuery { id };
+            unbind_query
+                .exec(trx.as_mut())
+                .await
+                .map_err(extra::Error::any)?;
+        }
+
+        // products binding extra meanwhile still make the delete fail, since
+        // their foreign key restricts it
+        let delete_query = queries::DeleteQuery { id };
+        let model = match delete_query.exec(trx.as_mut()).await {
+            Ok(model) => model,
+            Err(sqlx::Error::RowNotFound) => return Err(extra::Error::NotFound(id)),
+            Err(err) if Self::is_fk_product_extras_error(&err) => {
+                drop(trx);
+                let usages = self.usages(id).await?;
+                return Err(extra::Error::in_use(id, usages));
+            }
+            Err(err) => return Err(extra::Error::any(err)),
+        };
+
+        trx.commit().await.map_err(extra::Error::any)?;
 
         model.try_into_entity().map_err(extra::Error::any)
     }
@@ -99,6 +122,31 @@ impl extra::Repository for PgExtras {
         Ok(extras)
     }
 
+    async fn list(&self, query: extra::ListQuery) -> Result<extra::Pagination, extra::Error> {
+        let count = queries::CountQuery(&query)
+            .exec(&self.pool)
+            .await
+            .map_err(extra::Error::any)?;
+
+        let models = queries::ListQuery(&query)
+            .exec(&self.pool)
+            .await
+            .map_err(extra::Error::any)?;
+
+        let extras = models
+            .into_iter()
+            .map(ExtraModel::try_into_entity)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(extra::Error::any)?;
+
+        Ok(extra::Pagination {
+            count,
+            page: query.page,
+            limit: query.limit,
+            items: extras,
+        })
+    }
+
     async fn update(&mut self, extra: &extra::Extra) -> Result<(), extra::Error> {
         let query = queries::UpdateQuery { extra };
         query.exec(&self.pool).await.map_err(|err| {
@@ -113,29 +161,138 @@ impl extra::Repository for PgExtras {
 
         Ok(())
     }
+
+    async fn usages(&self, id: extra::Id) -> Result<Vec<extra::Usage>, extra::Error> {
+        let query = queries::UsagesQuery { id };
+        let models = query.exec(&self.pool).await.map_err(extra::Error::any)?;
+
+        models
+            .into_iter()
+            .map(UsageModel::try_into_usage)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(extra::Error::any)
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::num::{NonZeroU32, NonZeroU8};
+
     use sqlx::PgPool;
 
+    use domain::catalog;
     use domain::core::metadata;
+    use domain::core::money::{Currency, Money};
+    use domain::core::sort::Sort;
     use domain::extra::Repository;
+    use domain::product;
 
     use super::*;
 
+    fn list_query() -> extra::ListQuery {
+        extra::ListQuery {
+            page: NonZeroU32::new(1).unwrap(),
+            limit: NonZeroU8::new(10).unwrap(),
+            catalog_id: None,
+            category: None,
+            name: None,
+            min_price: None,
+            max_price: None,
+            sort: Sort::default(),
+        }
+    }
+
     #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
-    async fn all_method_works(pool: PgPool) {
-        let result = PgExtras::new(pool).all().await;
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap().len(), 2);
+    async fn list_method_works(pool: PgPool) {
+        let query = extra::ListQuery {
+            limit: NonZeroU8::new(2).unwrap(),
+            ..list_query()
+        };
+
+        let result = PgExtras::new(pool).list(query).await;
+        let pagination = result.expect("Paginated extras list");
+        assert_eq!(pagination.count, 3);
+        assert_eq!(pagination.items.len(), 2);
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn list_with_scope_filters(pool: PgPool) {
+        let burgers_id = catalog::Id::parse_str("0190ec30-286b-7211-aadb-003fc0449734")
+            .expect("Valid catalog id from fixtures");
+        let vegan_id = catalog::Id::parse_str("0190ec30-7e38-75c0-a207-13c52449957d")
+            .expect("Valid catalog id from fixtures");
+        let extras = PgExtras::new(pool);
+
+        let query = extra::ListQuery {
+            catalog_id: Some(burgers_id),
+            ..list_query()
+        };
+        let burgers_extras = extras.list(query).await.expect("Burgers extras");
+        assert_eq!(burgers_extras.count, 3);
+
+        let query = extra::ListQuery {
+            catalog_id: Some(vegan_id),
+            ..list_query()
+        };
+        let vegan_extras = extras.list(query).await.expect("Vegan extras");
+        assert!(vegan_extras.items.iter().all(|e| e.catalog_id.is_none()));
+        assert_eq!(vegan_extras.count, 2);
+
+        let query = extra::ListQuery {
+            category: Some(extra::Category::Sauce),
+            ..list_query()
+        };
+        let sauces = extras.list(query).await.expect("Sauce extras");
+        assert_eq!(sauces.count, 1);
+        assert_eq!(sauces.items[0].catalog_id, Some(burgers_id));
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn list_with_name_and_price_filters(pool: PgPool) {
+        let extras = PgExtras::new(pool);
+
+        let query = extra::ListQuery {
+            name: Some(String::from("sa")),
+            sort: Sort::Price,
+            ..list_query()
+        };
+        let sauces = extras.list(query).await.expect("Extras named after sauce");
+        let prices = sauces.items.iter().map(|e| e.price).collect::<Vec<_>>();
+        assert_eq!(
+            prices,
+            [
+                Money::from_minor(500, Currency::BRL),
+                Money::from_minor(800, Currency::BRL)
+            ]
+        );
+
+        let query = extra::ListQuery {
+            min_price: Some(Money::from_minor(600, Currency::BRL)),
+            max_price: Some(Money::from_minor(1000, Currency::BRL)),
+            sort: Sort::Name,
+            ..list_query()
+        };
+        let priced = extras.list(query).await.expect("Extras within price range");
+        let names = priced
+            .items
+            .iter()
+            .map(|e| e.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["Cheese", "Sauce"]);
+
+        let query = extra::ListQuery {
+            min_price: Some(Money::from_minor(100, Currency::USD)),
+            ..list_query()
+        };
+        let priced = extras.list(query).await.expect("Extras priced in dollars");
+        assert_eq!(priced.count, 0);
     }
 
     #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
     async fn create_method_works(pool: PgPool) {
         let extra = extra::Extra::new(
             extra::Name::new("Fork").expect("Valid extra name"),
-            extra::Price::from_cents(150),
+            Money::from_minor(150, Currency::BRL),
         );
 
         let result = PgExtras::new(pool).create(&extra).await;
@@ -152,7 +309,9 @@ mod tests {
         let extra = extra::Extra::config(extra::ExtraConfig {
             id,
             name: extra::Name::new("Fork").expect("Valid extra name"),
-            price: extra::Price::from_cents(150),
+            price: Money::from_minor(150, Currency::BRL),
+            catalog_id: None,
+            category: None,
             metadata: metadata::Metadata::new(),
         });
 
@@ -165,7 +324,7 @@ mod tests {
         use extra::{ConflictKind, Error};
 
         let name = extra::Name::new("Cheese").expect("Valid extra name from fixtures");
-        let extra = extra::Extra::new(name.clone(), extra::Price::from_cents(800));
+        let extra = extra::Extra::new(name.clone(), Money::from_minor(800, Currency::BRL));
 
         let result = PgExtras::new(pool).create(&extra).await;
         assert!(
@@ -173,13 +332,72 @@ mod tests {
         );
     }
 
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn create_with_name_in_other_scope(pool: PgPool) {
+        let vegan_id = catalog::Id::parse_str("0190ec30-7e38-75c0-a207-13c52449957d")
+            .expect("Valid catalog id from fixtures");
+
+        let name = extra::Name::new("Sauce").expect("Valid extra name from fixtures");
+        let mut extra = extra::Extra::new(name, Money::from_minor(300, Currency::BRL));
+        extra.catalog_id = Some(vegan_id);
+        extra.category = Some(extra::Category::Sauce);
+
+        let result = PgExtras::new(pool).create(&extra).await;
+        assert!(result.is_ok());
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn create_with_catalog_not_found(pool: PgPool) {
+        use extra::Error;
+
+        let catalog_id = catalog::Id::parse_str("0191b3b0-2d4f-7a11-9c3e-5f8a7b6c4d21")
+            .expect("Valid catalog id not in fixtures");
+
+        let name = extra::Name::new("Mustard").expect("Valid extra name");
+        let mut extra = extra::Extra::new(name, Money::from_minor(300, Currency::BRL));
+        extra.catalog_id = Some(catalog_id);
+
+        let result = PgExtras::new(pool).create(&extra).await;
+        assert!(matches!(result, Err(Error::CatalogNotFound(err_id)) if err_id == catalog_id));
+    }
+
     #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
     async fn delete_method_works(pool: PgPool) {
+        let id = extra::Id::parse_str("0190eb06-f512-7302-a037-a223a9deb4e0")
+            .expect("Valid extra id from fixtures");
+
+        let result = PgExtras::new(pool).delete(id, false).await;
+        assert!(matches!(result, Ok(extra) if extra.id() == id));
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn delete_with_force(pool: PgPool) {
         let id = extra::Id::parse_str("0190eaf5-c290-7443-b6a6-d22ce2a0fcb1")
             .expect("Valid extra id from fixtures");
 
-        let result = PgExtras::new(pool).delete(id).await;
+        let mut repository = PgExtras::new(pool);
+        let result = repository.delete(id, true).await;
         assert!(matches!(result, Ok(extra) if extra.id() == id));
+        assert!(matches!(repository.usages(id).await, Ok(usages) if usages.is_empty()));
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn delete_with_in_use(pool: PgPool) {
+        use extra::{ConflictKind, Error};
+
+        let id = extra::Id::parse_str("0190eaf5-c290-7443-b6a6-d22ce2a0fcb1")
+            .expect("Valid extra id from fixtures");
+        let product_id = product::Id::parse_str("0190ec14-0af8-71d1-9554-f1e5249ae3a2")
+            .expect("Valid product id from fixtures");
+
+        let mut repository = PgExtras::new(pool);
+        let result = repository.delete(id, false).await;
+        assert!(matches!(
+            result,
+            Err(Error::Conflict(ConflictKind::InUse { id: err_id, usages }))
+                if err_id == id && usages.iter().map(|u| u.product_id).eq([product_id])
+        ));
+        assert!(repository.find(id).await.is_ok());
     }
 
     #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
@@ -189,7 +407,7 @@ mod tests {
         let id = extra::Id::parse_str("0190f5d0-0209-7a43-9a57-e091e56493a4")
             .expect("Valid extra id not in fixtures");
 
-        let result = PgExtras::new(pool).delete(id).await;
+        let result = PgExtras::new(pool).delete(id, false).await;
         assert!(matches!(result, Err(Error::NotFound(err_id)) if err_id == id));
     }
 
@@ -253,7 +471,9 @@ mod tests {
             id: extra::Id::parse_str("0190eaf5-c290-7443-b6a6-d22ce2a0fcb1")
                 .expect("Valid extra id from fixtures"),
             name: extra::Name::new("Cheddar").expect("Valid extra name"),
-            price: extra::Price::from_cents(1200),
+            price: Money::from_minor(1200, Currency::BRL),
+            catalog_id: None,
+            category: None,
             metadata: metadata::Metadata::new(),
         });
 
@@ -269,7 +489,9 @@ mod tests {
             id: extra::Id::parse_str("0190f5e1-5495-7391-9984-8997dbe367c6")
                 .expect("Valid extra id not in fixtures"),
             name: extra::Name::new("Cheddar").expect("Valid extra name"),
-            price: extra::Price::from_cents(1200),
+            price: Money::from_minor(1200, Currency::BRL),
+            catalog_id: None,
+            category: None,
             metadata: metadata::Metadata::new(),
         });
 
@@ -285,7 +507,9 @@ mod tests {
             id: extra::Id::parse_str("0190eaf5-c290-7443-b6a6-d22ce2a0fcb1")
                 .expect("Valid extra id from fixtures"),
             name: extra::Name::new("Sauce").expect("Valid extra name from fixtures"),
-            price: extra::Price::from_cents(600),
+            price: Money::from_minor(600, Currency::BRL),
+            catalog_id: None,
+            category: None,
             metadata: metadata::Metadata::new(),
         });
 
@@ -294,4 +518,26 @@ mod tests {
             matches!(result, Err(Error::Conflict(ConflictKind::Name(err_name))) if err_name == extra.name)
         );
     }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn usages_method_works(pool: PgPool) {
+        let id = extra::Id::parse_str("0190eaf5-c290-7443-b6a6-d22ce2a0fcb1")
+            .expect("Valid extra id from fixtures");
+        let product_id = product::Id::parse_str("0190ec14-0af8-71d1-9554-f1e5249ae3a2")
+            .expect("Valid product id from fixtures");
+
+        let result = PgExtras::new(pool).usages(id).await;
+        let usages = result.expect("Usages from fixtures");
+        let products_ids = usages.iter().map(|u| u.product_id).collect::<Vec<_>>();
+        assert_eq!(products_ids, [product_id]);
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn usages_of_unused_extra(pool: PgPool) {
+        let id = extra::Id::parse_str("0190eb06-f512-7302-a037-a223a9deb4e0")
+            .expect("Valid extra id from fixtures");
+
+        let result = PgExtras::new(pool).usages(id).await;
+        assert!(matches!(result, Ok(usages) if usages.is_empty()));
+    }
 }
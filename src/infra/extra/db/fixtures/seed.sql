@@ -1,4 +1,24 @@
+insert into catalog(id, name, description, created_at, updated_at)
+values 
+    ('0190ec30-286b-7211-aadb-003fc0449734', 'Burgers', 'Delicious burgers', now(), now()),
+    ('0190ec30-7e38-75c0-a207-13c52449957d', 'Vegan', null, now(), now());
+
 insert into extra (id, name, price, created_at, updated_at)
 values 
     ('0190eaf5-c290-7443-b6a6-d22ce2a0fcb1', 'Cheese', 8.00, now(), now()),
-    ('0190eb06-f512-7302-a037-a223a9deb4e0', 'Sauce', 8.00, now(), now());
\ No newline at end of file
+    ('0190eb06-f512-7302-a037-a223a9deb4e0', 'Sauce', 8.00, now(), now());
+
+insert into extra (id, name, price, catalog_id, category, created_at, updated_at)
+values 
+    -- sauce only served along with Burgers catalog
+    ('0191b3a2-5c1e-7d40-8f2a-6b9e1c7d3a54', 'Sauce', 5.00, '0190ec30-286b-7211-aadb-003fc0449734', 'sauce', now(), now());
+
+insert into product(id, catalog_id, name, price, kind, created_at, updated_at)
+values 
+    -- add Cheese Burger to Burgers catalog
+    ('0190ec14-0af8-71d1-9554-f1e5249ae3a2', '0190ec30-286b-7211-aadb-003fc0449734', 'Cheese Burger', 2000, 'burger', now(), now());
+
+insert into product_extras(product_id, extra_id)
+values 
+    -- bind Cheese to Cheese Burger
+    ('0190ec14-0af8-71d1-9554-f1e5249ae3a2', '0190eaf5-c290-7443-b6a6-d22ce2a0fcb1');
\ No newline at end of file
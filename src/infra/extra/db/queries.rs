@@ -1,19 +1,21 @@
-use sqlx::PgExecutor;
+use sqlx::postgres::PgArguments;
+use sqlx::query::QueryAs;
+use sqlx::{PgExecutor, Postgres};
 
 use domain::extra;
 
-use crate::infra::extra::ExtraModel;
+use crate::infra::extra::{ExtraModel, UsageModel};
 
 #[derive(Clone, Debug)]
-pub(super) struct AllQuery;
+pub(super) struct CountQuery<'a>(pub(super) &'a extra::ListQuery);
 
-impl AllQuery {
-    pub(super) async fn exec(
-        self,
-        exec: impl PgExecutor<'_>,
-    ) -> Result<Vec<ExtraModel>, sqlx::Error> {
-        let sql = include_str!("./sql/all.sql");
-        sqlx::query_as(sql).fetch_all(exec).await
+impl<'a> CountQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<u64, sqlx::Error> {
+        let sql = include_str!("./sql/count.sql");
+        let (count,): (i64,) = bind_filters(sqlx::query_as(sql), self.0)
+            .fetch_one(exec)
+            .await?;
+        Ok(u64::try_from(count).unwrap_or_default())
     }
 }
 
@@ -28,7 +30,10 @@ impl<'a> CreateQuery<'a> {
         sqlx::query(sql)
             .bind(self.extra.id().uuid())
             .bind(self.extra.name.as_str())
-            .bind(self.extra.price.decimal())
+            .bind(self.extra.price.amount())
+            .bind(self.extra.price.currency().as_str())
+            .bind(self.extra.catalog_id.map(|id| id.uuid()))
+            .bind(self.extra.category.as_ref().map(extra::Category::as_str))
             .bind(self.extra.metadata.created_at())
             .bind(self.extra.metadata.updated_at())
             .execute(exec)
@@ -84,6 +89,43 @@ impl FindQuery {
     }
 }
 
+#[derive(Clone, Debug)]
+pub(super) struct ListQuery<'a>(pub(super) &'a extra::ListQuery);
+
+impl<'a> ListQuery<'a> {
+    pub(super) async fn exec(
+        self,
+        exec: impl PgExecutor<'a>,
+    ) -> Result<Vec<ExtraModel>, sqlx::Error> {
+        let limit = u8::from(self.0.limit);
+        let page = u32::from(self.0.page);
+        let offset = page.saturating_sub(1) * u32::from(limit);
+
+        let sql = include_str!("./sql/list.sql");
+        bind_filters(sqlx::query_as(sql), self.0)
+            .bind(self.0.sort.as_str())
+            .bind(i64::from(limit))
+            .bind(i64::from(offset))
+            .fetch_all(exec)
+            .await
+    }
+}
+
+/// Unbinds extra from every product offering it
+#[derive(Clone, Debug)]
+pub(super) struct UnbindQuery {
+    pub(super) id: extra::Id,
+}
+
+impl UnbindQuery {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'_>) -> Result<(), sqlx::Error> {
+        let sql = include_str!("./sql/unbind.sql");
+        sqlx::query(sql).bind(self.id.uuid()).execute(exec).await?;
+
+        Ok(())
+    }
+}
+
 #[derive(Clone, Debug)]
 pub(super) struct UpdateQuery<'a> {
     pub(super) extra: &'a extra::Extra,
@@ -94,7 +136,9 @@ impl<'a> UpdateQuery<'a> {
         let sql = include_str!("./sql/update.sql");
         let result = sqlx::query(sql)
             .bind(self.extra.name.as_str())
-            .bind(self.extra.price.decimal())
+            .bind(self.extra.price.amount())
+            .bind(self.extra.price.currency().as_str())
+            .bind(self.extra.category.as_ref().map(extra::Category::as_str))
             .bind(self.extra.metadata.updated_at())
             .bind(self.extra.id().uuid())
             .execute(exec)
@@ -108,26 +152,78 @@ impl<'a> UpdateQuery<'a> {
     }
 }
 
+#[derive(Clone, Debug)]
+pub(super) struct UsagesQuery {
+    pub(super) id: extra::Id,
+}
+
+impl UsagesQuery {
+    pub(super) async fn exec(
+        self,
+        exec: impl PgExecutor<'_>,
+    ) -> Result<Vec<UsageModel>, sqlx::Error> {
+        let sql = include_str!("./sql/usages.sql");
+        sqlx::query_as(sql)
+            .bind(self.id.uuid())
+            .fetch_all(exec)
+            .await
+    }
+}
+
+/// Bind filters of `list` query, shared by listing and counting extras
+fn bind_filters<'q, O>(
+    query: QueryAs<'q, Postgres, O, PgArguments>,
+    list: &'q extra::ListQuery,
+) -> QueryAs<'q, Postgres, O, PgArguments> {
+    query
+        .bind(list.catalog_id.map(|id| id.uuid()))
+        .bind(list.category.as_ref().map(extra::Category::as_str))
+        .bind(list.name.as_deref())
+        .bind(list.min_price.map(|price| price.amount()))
+        .bind(list.min_price.map(|price| price.currency().to_string()))
+        .bind(list.max_price.map(|price| price.amount()))
+        .bind(list.max_price.map(|price| price.currency().to_string()))
+}
+
 #[cfg(test)]
 mod tests {
+    use std::num::{NonZeroU32, NonZeroU8};
+
     use sqlx::PgPool;
 
     use domain::core::metadata;
+    use domain::core::money::{Currency, Money};
+    use domain::core::sort::Sort;
 
     use super::*;
 
     #[sqlx::test(fixtures("seed"))]
-    async fn all_query_works(pool: PgPool) {
-        let result = AllQuery.exec(&pool).await;
-        let extras = result.expect("All extras from fixtures");
+    async fn list_query_works(pool: PgPool) {
+        let query = extra::ListQuery {
+            page: NonZeroU32::new(1).unwrap(),
+            limit: NonZeroU8::new(2).unwrap(),
+            catalog_id: None,
+            category: None,
+            name: None,
+            min_price: None,
+            max_price: None,
+            sort: Sort::Name,
+        };
+
+        let count = CountQuery(&query).exec(&pool).await;
+        assert_eq!(count.expect("Extras count from fixtures"), 3);
+
+        let result = ListQuery(&query).exec(&pool).await;
+        let extras = result.expect("Listed extras from fixtures");
         assert_eq!(extras.len(), 2);
+        assert_eq!(extras[0].name.as_str(), "Cheese");
     }
 
     #[sqlx::test(fixtures("seed"))]
     async fn create_query_works(pool: PgPool) {
         let extra = extra::Extra::new(
             extra::Name::new("Salad").expect("Salad is a valid extra name"),
-            extra::Price::from_cents(250),
+            Money::from_minor(250, Currency::BRL),
         );
 
         let result = CreateQuery { extra: &extra }.exec(&pool).await;
@@ -136,7 +232,7 @@ mod tests {
 
     #[sqlx::test(fixtures("seed"))]
     async fn delete_query_works(pool: PgPool) {
-        let id = extra::Id::parse_str("0190eaf5-c290-7443-b6a6-d22ce2a0fcb1")
+        let id = extra::Id::parse_str("0190eb06-f512-7302-a037-a223a9deb4e0")
             .expect("Valid extra id from fixtures");
 
         let result = DeleteQuery { id }.exec(&pool).await;
@@ -176,7 +272,9 @@ mod tests {
             id: extra::Id::parse_str("0190eaf5-c290-7443-b6a6-d22ce2a0fcb1")
                 .expect("Valid extra id from fixtures"),
             name: extra::Name::new("Salad").expect("Salad is a valid extra name"),
-            price: extra::Price::from_cents(250),
+            price: Money::from_minor(250, Currency::BRL),
+            catalog_id: None,
+            category: Some(extra::Category::Side),
             metadata: metadata::Metadata::new(),
         });
 
@@ -190,6 +288,17 @@ mod tests {
 
         assert_eq!(updated_model.id, extra.id().uuid());
         assert_eq!(updated_model.name.as_str(), extra.name.as_str());
-        assert_eq!(updated_model.price, extra.price.decimal());
+        assert_eq!(updated_model.price, extra.price.amount());
+    }
+
+    #[sqlx::test(fixtures("seed"))]
+    async fn usages_query_works(pool: PgPool) {
+        let id = extra::Id::parse_str("0190eaf5-c290-7443-b6a6-d22ce2a0fcb1")
+            .expect("Valid extra id from fixtures");
+
+        let result = UsagesQuery { id }.exec(&pool).await;
+        let usages = result.expect("Usages from fixtures");
+        assert_eq!(usages.len(), 1);
+        assert_eq!(usages[0].product_name.as_str(), "Cheese Burger");
     }
 }
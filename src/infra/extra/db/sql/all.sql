@@ -1 +0,0 @@
-select extra.* from extra
\ No newline at end of file
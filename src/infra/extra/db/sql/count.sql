@@ -0,0 +1,6 @@
+select count(id) from extra
+where ($1::uuid is null or extra.catalog_id is null or extra.catalog_id = $1)
+    and ($2::varchar is null or extra.category = $2)
+    and ($3::varchar is null or starts_with(lower(extra.name), lower($3)))
+    and ($4::decimal is null or (extra.price >= $4 and extra.currency = $5))
+    and ($6::decimal is null or (extra.price <= $6 and extra.currency = $7))
\ No newline at end of file
@@ -1,3 +1,3 @@
-insert into extra (id, name, price, created_at, updated_at)
-values ($1, $2, $3, $4, $5)
+insert into extra (id, name, price, currency, catalog_id, category, created_at, updated_at)
+values ($1, $2, $3, $4, $5, $6, $7, $8)
 returning id
\ No newline at end of file
@@ -0,0 +1 @@
+delete from product_extras where product_extras.extra_id = $1
\ No newline at end of file
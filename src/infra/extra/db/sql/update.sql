@@ -1,3 +1,3 @@
 update extra
-set name = $1, price = $2, updated_at = $3
-where id = $4
\ No newline at end of file
+set name = $1, price = $2, currency = $3, category = $4, updated_at = $5
+where id = $6
\ No newline at end of file
@@ -0,0 +1,5 @@
+select product.id as product_id, product.catalog_id, product.name as product_name
+from product
+inner join product_extras as pe on pe.product_id = product.id
+where pe.extra_id = $1
+order by product.name
\ No newline at end of file
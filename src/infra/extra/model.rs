@@ -3,14 +3,20 @@ use sqlx::types::{Decimal, Uuid};
 use sqlx::FromRow;
 use time::OffsetDateTime;
 
+use domain::catalog;
 use domain::core::metadata;
+use domain::core::money::{Currency, Money};
 use domain::extra;
+use domain::product;
 
 #[derive(Clone, Debug, Deserialize, FromRow)]
 pub struct ExtraModel {
     pub id: Uuid,
     pub name: String,
     pub price: Decimal,
+    pub currency: String,
+    pub catalog_id: Option<Uuid>,
+    pub category: Option<String>,
     #[serde(with = "time::serde::rfc3339")]
     pub created_at: OffsetDateTime,
     #[serde(with = "time::serde::rfc3339")]
@@ -20,14 +26,42 @@ pub struct ExtraModel {
 impl ExtraModel {
     pub fn try_into_entity(self) -> Result<extra::Extra, Box<dyn std::error::Error>> {
         let name = extra::Name::new(self.name)?;
+        let currency = Currency::parse_str(&self.currency)?;
+        let category = self
+            .category
+            .as_deref()
+            .map(extra::Category::parse_str)
+            .transpose()?;
         let metadata = metadata::Metadata::configured(self.created_at, self.updated_at)?;
         let product_extra = extra::Extra::config(extra::ExtraConfig {
             id: extra::Id::from(self.id),
             name,
-            price: extra::Price::new(self.price),
+            price: Money::new(self.price, currency),
+            catalog_id: self.catalog_id.map(catalog::Id::from),
+            category,
             metadata,
         });
 
         Ok(product_extra)
     }
 }
+
+#[derive(Clone, Debug, FromRow)]
+pub struct UsageModel {
+    pub product_id: Uuid,
+    pub catalog_id: Uuid,
+    pub product_name: String,
+}
+
+impl UsageModel {
+    pub fn try_into_usage(self) -> Result<extra::Usage, Box<dyn std::error::Error>> {
+        let product_name = product::Name::new(self.product_name)?;
+        let usage = extra::Usage {
+            product_id: product::Id::from(self.product_id),
+            catalog_id: catalog::Id::from(self.catalog_id),
+            product_name,
+        };
+
+        Ok(usage)
+    }
+}
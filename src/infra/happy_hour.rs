@@ -0,0 +1,5 @@
+mod db;
+mod model;
+
+pub use db::PgHappyHours;
+pub(super) use model::HappyHourModel;
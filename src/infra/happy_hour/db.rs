@@ -0,0 +1,208 @@
+mod queries;
+
+use sqlx::PgPool;
+
+use domain::catalog;
+use domain::happy_hour;
+
+use super::model::HappyHourModel;
+
+#[derive(Clone, Debug)]
+pub struct PgHappyHours {
+    pool: PgPool,
+}
+
+impl PgHappyHours {
+    const PK: &'static str = "pk_happy_hour";
+    const FK_CATALOG_ID: &'static str = "fk_happy_hour_catalog_id";
+
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn is_pk_error(err: &sqlx::Error) -> bool {
+        err.as_database_error()
+            .is_some_and(|db_err| db_err.constraint() == Some(Self::PK))
+    }
+
+    fn is_fk_catalog_id_error(err: &sqlx::Error) -> bool {
+        err.as_database_error()
+            .is_some_and(|db_err| db_err.constraint() == Some(Self::FK_CATALOG_ID))
+    }
+}
+
+impl happy_hour::Repository for PgHappyHours {
+    async fn all(
+        &self,
+        catalog_id: catalog::Id,
+    ) -> Result<happy_hour::HappyHours, happy_hour::Error> {
+        let models = queries::AllQuery { catalog_id }
+            .exec(&self.pool)
+            .await
+            .map_err(happy_hour::Error::any)?;
+
+        models
+            .into_iter()
+            .map(HappyHourModel::try_into_entity)
+            .collect::<Result<Vec<_>, _>>()
+            .map(happy_hour::HappyHours::new)
+            .map_err(happy_hour::Error::any)
+    }
+
+    async fn create(
+        &mut self,
+        happy_hour: &happy_hour::HappyHour,
+    ) -> Result<(), happy_hour::Error> {
+        let query = queries::CreateQuery { happy_hour };
+        query.exec(&self.pool).await.map_err(|err| {
+            if Self::is_pk_error(&err) {
+                happy_hour::Error::id_conflict(happy_hour.id())
+            } else if Self::is_fk_catalog_id_error(&err) {
+                happy_hour::Error::catalog_not_found(happy_hour.catalog_id())
+            } else {
+                happy_hour::Error::any(err)
+            }
+        })
+    }
+
+    async fn delete(
+        &mut self,
+        id: happy_hour::Id,
+        catalog_id: catalog::Id,
+    ) -> Result<happy_hour::HappyHour, happy_hour::Error> {
+        let query = queries::DeleteQuery { id, catalog_id };
+        let model = query.exec(&self.pool).await.map_err(|err| match &err {
+            sqlx::Error::RowNotFound => happy_hour::Error::id_not_found(id, catalog_id),
+            _ => happy_hour::Error::any(err),
+        })?;
+
+        model.try_into_entity().map_err(happy_hour::Error::any)
+    }
+
+    async fn find(
+        &self,
+        id: happy_hour::Id,
+        catalog_id: catalog::Id,
+    ) -> Result<happy_hour::HappyHour, happy_hour::Error> {
+        let query = queries::FindQuery { id, catalog_id };
+        let model = query.exec(&self.pool).await.map_err(|err| match &err {
+            sqlx::Error::RowNotFound => happy_hour::Error::id_not_found(id, catalog_id),
+            _ => happy_hour::Error::any(err),
+        })?;
+
+        model.try_into_entity().map_err(happy_hour::Error::any)
+    }
+
+    async fn update(
+        &mut self,
+        happy_hour: &happy_hour::HappyHour,
+    ) -> Result<(), happy_hour::Error> {
+        let query = queries::UpdateQuery { happy_hour };
+        query.exec(&self.pool).await.map_err(|err| match &err {
+            sqlx::Error::RowNotFound => {
+                happy_hour::Error::id_not_found(happy_hour.id(), happy_hour.catalog_id())
+            }
+            _ => happy_hour::Error::any(err),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+    use time::{Time, Weekday};
+
+    use domain::happy_hour::Repository;
+    use domain::product;
+    use domain::promotion;
+
+    use super::*;
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn all_method_works(pool: PgPool) {
+        let catalog_id = catalog::Id::parse_str("0190ec30-7e38-75c0-a207-13c52449957d")
+            .expect("Valid catalog id from fixtures");
+
+        let result = PgHappyHours::new(pool).all(catalog_id).await;
+        let happy_hours = result.expect("All happy hours from fixtures");
+        assert_eq!(happy_hours.as_slice().len(), 2);
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn create_method_works(pool: PgPool) {
+        let catalog_id = catalog::Id::parse_str("0190ec30-286b-7211-aadb-003fc0449734")
+            .expect("Valid catalog id from fixtures");
+        let happy_hour = mocked_happy_hour(catalog_id);
+
+        let mut repository = PgHappyHours::new(pool);
+        let result = repository.create(&happy_hour).await;
+        assert!(result.is_ok());
+
+        let found = repository
+            .find(happy_hour.id(), catalog_id)
+            .await
+            .expect("Created happy hour");
+        assert_eq!(found.window, happy_hour.window);
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn create_with_catalog_not_found(pool: PgPool) {
+        use happy_hour::{Error, NotFoundKind};
+
+        let catalog_id = catalog::Id::parse_str("01914594-8c3d-7e4f-8a5b-6c7d8e9f0a23")
+            .expect("Valid catalog id not in fixtures");
+        let happy_hour = mocked_happy_hour(catalog_id);
+
+        let result = PgHappyHours::new(pool).create(&happy_hour).await;
+        assert!(
+            matches!(result, Err(Error::NotFound(NotFoundKind::Catalog(err_id))) if err_id == catalog_id)
+        );
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn find_with_other_catalog(pool: PgPool) {
+        use happy_hour::{Error, NotFoundKind};
+
+        let id = happy_hour::Id::parse_str("01914592-6a1b-7c2d-8e3f-4a5b6c7d8e01")
+            .expect("Valid happy hour id from fixtures");
+        let catalog_id = catalog::Id::parse_str("0190ec30-286b-7211-aadb-003fc0449734")
+            .expect("Valid catalog id from fixtures");
+
+        let result = PgHappyHours::new(pool).find(id, catalog_id).await;
+        assert!(matches!(
+            result,
+            Err(Error::NotFound(NotFoundKind::Id { .. }))
+        ));
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn delete_method_works(pool: PgPool) {
+        let id = happy_hour::Id::parse_str("01914593-7b2c-7d3e-9f4a-5b6c7d8e9f12")
+            .expect("Valid happy hour id from fixtures");
+        let catalog_id = catalog::Id::parse_str("0190ec30-7e38-75c0-a207-13c52449957d")
+            .expect("Valid catalog id from fixtures");
+
+        let result = PgHappyHours::new(pool).delete(id, catalog_id).await;
+        assert!(matches!(result, Ok(happy_hour) if happy_hour.id() == id));
+    }
+
+    fn mocked_happy_hour(catalog_id: catalog::Id) -> happy_hour::HappyHour {
+        let weekdays = happy_hour::Weekdays::new(&[Weekday::Saturday, Weekday::Sunday])
+            .expect("Valid weekdays");
+        let window = happy_hour::Window::new(
+            weekdays,
+            Time::from_hms(15, 30, 0).expect("Valid time"),
+            Time::from_hms(18, 0, 0).expect("Valid time"),
+            happy_hour::Zone::parse_str("America/Sao_Paulo").expect("Valid zone"),
+        )
+        .expect("Valid window");
+
+        happy_hour::HappyHour::new(
+            catalog_id,
+            happy_hour::Name::new("Weekend burgers").expect("Valid happy hour name"),
+            Some(product::Kind::Burger),
+            promotion::Percentage::parse_str("15").expect("Valid percentage"),
+            window,
+        )
+    }
+}
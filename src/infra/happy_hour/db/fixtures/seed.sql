@@ -0,0 +1,11 @@
+insert into catalog (id, name, description, created_at, updated_at)
+values
+    ('0190ec30-286b-7211-aadb-003fc0449734', 'Burgers', null, now(), now()),
+    ('0190ec30-7e38-75c0-a207-13c52449957d', 'Japanese', null, now(), now());
+
+insert into happy_hour (id, catalog_id, name, kind, percentage, weekdays, starts_at, ends_at, time_zone, created_at, updated_at)
+values
+    -- weekdays from 17:00 to 19:00 in Sao Paulo
+    ('01914592-6a1b-7c2d-8e3f-4a5b6c7d8e01', '0190ec30-7e38-75c0-a207-13c52449957d', 'Sushi hour', 'japanese', 20, 31, '17:00', '19:00', 'America/Sao_Paulo', now(), now()),
+    -- every day past midnight
+    ('01914593-7b2c-7d3e-9f4a-5b6c7d8e9f12', '0190ec30-7e38-75c0-a207-13c52449957d', 'Late night', null, 10, 127, '23:00', '02:00', 'America/Sao_Paulo', now(), now());
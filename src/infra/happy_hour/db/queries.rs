@@ -0,0 +1,170 @@
+use sqlx::PgExecutor;
+
+use domain::catalog;
+use domain::happy_hour;
+use domain::product;
+
+use crate::infra::happy_hour::HappyHourModel;
+
+#[derive(Clone, Debug)]
+pub(super) struct AllQuery {
+    pub(super) catalog_id: catalog::Id,
+}
+
+impl AllQuery {
+    pub(super) async fn exec(
+        self,
+        exec: impl PgExecutor<'_>,
+    ) -> Result<Vec<HappyHourModel>, sqlx::Error> {
+        let sql = include_str!("./sql/all.sql");
+        sqlx::query_as(sql)
+            .bind(self.catalog_id.uuid())
+            .fetch_all(exec)
+            .await
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct CreateQuery<'a> {
+    pub(super) happy_hour: &'a happy_hour::HappyHour,
+}
+
+impl<'a> CreateQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
+        let window = self.happy_hour.window;
+        let sql = include_str!("./sql/create.sql");
+        sqlx::query(sql)
+            .bind(self.happy_hour.id().uuid())
+            .bind(self.happy_hour.catalog_id().uuid())
+            .bind(self.happy_hour.name.as_str())
+            .bind(self.happy_hour.kind.as_ref().map(product::Kind::as_str))
+            .bind(self.happy_hour.percentage.value())
+            .bind(i16::from(window.weekdays().bits()))
+            .bind(window.starts_at())
+            .bind(window.ends_at())
+            .bind(window.zone().as_str())
+            .bind(self.happy_hour.metadata.created_at())
+            .bind(self.happy_hour.metadata.updated_at())
+            .execute(exec)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct DeleteQuery {
+    pub(super) id: happy_hour::Id,
+    pub(super) catalog_id: catalog::Id,
+}
+
+impl DeleteQuery {
+    pub(super) async fn exec(
+        self,
+        exec: impl PgExecutor<'_>,
+    ) -> Result<HappyHourModel, sqlx::Error> {
+        let sql = include_str!("./sql/delete.sql");
+        sqlx::query_as(sql)
+            .bind(self.id.uuid())
+            .bind(self.catalog_id.uuid())
+            .fetch_one(exec)
+            .await
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct FindQuery {
+    pub(super) id: happy_hour::Id,
+    pub(super) catalog_id: catalog::Id,
+}
+
+impl FindQuery {
+    pub(super) async fn exec(
+        self,
+        exec: impl PgExecutor<'_>,
+    ) -> Result<HappyHourModel, sqlx::Error> {
+        let sql = include_str!("./sql/find.sql");
+        sqlx::query_as(sql)
+            .bind(self.id.uuid())
+            .bind(self.catalog_id.uuid())
+            .fetch_one(exec)
+            .await
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct UpdateQuery<'a> {
+    pub(super) happy_hour: &'a happy_hour::HappyHour,
+}
+
+impl<'a> UpdateQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
+        let window = self.happy_hour.window;
+        let sql = include_str!("./sql/update.sql");
+        let result = sqlx::query(sql)
+            .bind(self.happy_hour.name.as_str())
+            .bind(self.happy_hour.kind.as_ref().map(product::Kind::as_str))
+            .bind(self.happy_hour.percentage.value())
+            .bind(i16::from(window.weekdays().bits()))
+            .bind(window.starts_at())
+            .bind(window.ends_at())
+            .bind(window.zone().as_str())
+            .bind(self.happy_hour.metadata.updated_at())
+            .bind(self.happy_hour.id().uuid())
+            .bind(self.happy_hour.catalog_id().uuid())
+            .execute(exec)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(sqlx::Error::RowNotFound);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+
+    use super::*;
+
+    #[sqlx::test(fixtures("seed"))]
+    async fn all_query_works(pool: PgPool) {
+        let catalog_id = catalog::Id::parse_str("0190ec30-7e38-75c0-a207-13c52449957d")
+            .expect("Valid catalog id from fixtures");
+
+        let result = AllQuery { catalog_id }.exec(&pool).await;
+        let models = result.expect("All happy hours from fixtures");
+        assert_eq!(models.len(), 2);
+    }
+
+    #[sqlx::test(fixtures("seed"))]
+    async fn update_query_works(pool: PgPool) {
+        let id = happy_hour::Id::parse_str("01914592-6a1b-7c2d-8e3f-4a5b6c7d8e01")
+            .expect("Valid happy hour id from fixtures");
+        let catalog_id = catalog::Id::parse_str("0190ec30-7e38-75c0-a207-13c52449957d")
+            .expect("Valid catalog id from fixtures");
+        let mut happy_hour = FindQuery { id, catalog_id }
+            .exec(&pool)
+            .await
+            .expect("Happy hour from fixtures")
+            .try_into_entity()
+            .expect("Valid happy hour from fixtures");
+        happy_hour.kind = None;
+
+        let result = UpdateQuery {
+            happy_hour: &happy_hour,
+        }
+        .exec(&pool)
+        .await;
+        assert!(result.is_ok());
+
+        let model = FindQuery { id, catalog_id }
+            .exec(&pool)
+            .await
+            .expect("Updated happy hour");
+        assert_eq!(model.kind, None);
+        assert_eq!(model.time_zone, "America/Sao_Paulo");
+    }
+}
@@ -0,0 +1 @@
+select happy_hour.* from happy_hour where catalog_id = $1 order by id
\ No newline at end of file
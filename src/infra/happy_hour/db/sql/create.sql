@@ -0,0 +1,5 @@
+insert into happy_hour (
+    id, catalog_id, name, kind, percentage, weekdays,
+    starts_at, ends_at, time_zone, created_at, updated_at
+)
+values ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
\ No newline at end of file
@@ -0,0 +1,2 @@
+delete from happy_hour where id = $1 and catalog_id = $2
+returning *
\ No newline at end of file
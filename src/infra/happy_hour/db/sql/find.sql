@@ -0,0 +1 @@
+select happy_hour.* from happy_hour where id = $1 and catalog_id = $2
\ No newline at end of file
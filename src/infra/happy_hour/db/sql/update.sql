@@ -0,0 +1,4 @@
+update happy_hour
+set name = $1, kind = $2, percentage = $3, weekdays = $4,
+    starts_at = $5, ends_at = $6, time_zone = $7, updated_at = $8
+where id = $9 and catalog_id = $10
\ No newline at end of file
@@ -0,0 +1,49 @@
+use sqlx::types::{Decimal, Uuid};
+use sqlx::FromRow;
+use time::{OffsetDateTime, Time};
+
+use domain::catalog;
+use domain::core::metadata;
+use domain::happy_hour;
+use domain::product;
+use domain::promotion;
+
+#[derive(Clone, Debug, FromRow)]
+pub struct HappyHourModel {
+    pub id: Uuid,
+    pub catalog_id: Uuid,
+    pub name: String,
+    pub kind: Option<String>,
+    pub percentage: Decimal,
+    pub weekdays: i16,
+    pub starts_at: Time,
+    pub ends_at: Time,
+    pub time_zone: String,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+impl HappyHourModel {
+    pub fn try_into_entity(self) -> Result<happy_hour::HappyHour, Box<dyn std::error::Error>> {
+        let kind = self
+            .kind
+            .as_deref()
+            .map(product::Kind::parse_str)
+            .transpose()?;
+        let weekdays = happy_hour::Weekdays::from_bits(u8::try_from(self.weekdays)?)?;
+        let zone = happy_hour::Zone::parse_str(&self.time_zone)?;
+        let window = happy_hour::Window::new(weekdays, self.starts_at, self.ends_at, zone)?;
+        let metadata = metadata::Metadata::configured(self.created_at, self.updated_at)?;
+        let happy_hour = happy_hour::HappyHour::config(happy_hour::HappyHourConfig {
+            id: happy_hour::Id::from(self.id),
+            catalog_id: catalog::Id::from(self.catalog_id),
+            name: happy_hour::Name::new(self.name)?,
+            kind,
+            percentage: promotion::Percentage::new(self.percentage)?,
+            window,
+            metadata,
+        });
+
+        Ok(happy_hour)
+    }
+}
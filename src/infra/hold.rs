@@ -0,0 +1,5 @@
+mod db;
+mod model;
+
+pub use db::PgHolds;
+pub(super) use model::HoldModel;
@@ -0,0 +1,223 @@
+mod queries;
+
+use sqlx::PgPool;
+use time::OffsetDateTime;
+
+use domain::hold;
+use domain::stock;
+
+#[derive(Clone, Debug)]
+pub struct PgHolds {
+    pool: PgPool,
+}
+
+impl PgHolds {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl hold::Repository for PgHolds {
+    async fn create(&mut self, hold: &hold::Hold) -> Result<(), hold::Error> {
+        let mut trx = self.pool.begin().await.map_err(hold::Error::any)?;
+
+        // concurrent holds of the same product wait for the stock row lock,
+        // so the held units counted after it cannot go stale before inserting
+        let product_id = hold.product_id();
+        let lock_query = queries::LockStockQuery { product_id };
+        lock_query
+            .exec(trx.as_mut())
+            .await
+            .map_err(|err| match err {
+                sqlx::Error::RowNotFound => hold::Error::stock(stock::Error::untracked(product_id)),
+                _ => hold::Error::any(err),
+            })?;
+
+        // expiry is judged by the same clock that set `expires_at`, so holds
+        // of product expired by now are released before counting held units
+        let products_ids = [product_id.uuid()];
+        let release_query = queries::ReleaseExpiredQuery {
+            at: hold.metadata.created_at(),
+            products_ids: &products_ids,
+        };
+        release_query
+            .exec(trx.as_mut())
+            .await
+            .map_err(hold::Error::any)?;
+
+        let find_query = queries::FindStockQuery { product_id };
+        let mut stock = find_query
+            .exec(trx.as_mut())
+            .await
+            .map_err(hold::Error::any)?
+            .try_into_entity()
+            .map_err(hold::Error::any)?;
+
+        stock.hold(hold.quantity()).map_err(hold::Error::stock)?;
+
+        let create_query = queries::CreateQuery { hold };
+        create_query
+            .exec(trx.as_mut())
+            .await
+            .map_err(|err| match err {
+                sqlx::Error::Database(err) if err.is_unique_violation() => {
+                    hold::Error::id_conflict(hold.id())
+                }
+                _ => hold::Error::any(err),
+            })?;
+
+        trx.commit().await.map_err(hold::Error::any)
+    }
+
+    async fn delete(&mut self, id: hold::Id) -> Result<hold::Hold, hold::Error> {
+        let query = queries::DeleteQuery { id };
+        let model = query.exec(&self.pool).await.map_err(|err| match err {
+            sqlx::Error::RowNotFound => hold::Error::id_not_found(id),
+            _ => hold::Error::any(err),
+        })?;
+
+        model.try_into_entity().map_err(hold::Error::any)
+    }
+
+    async fn find(&self, id: hold::Id) -> Result<hold::Hold, hold::Error> {
+        let query = queries::FindQuery { id };
+        let model = query.exec(&self.pool).await.map_err(|err| match err {
+            sqlx::Error::RowNotFound => hold::Error::id_not_found(id),
+            _ => hold::Error::any(err),
+        })?;
+
+        model.try_into_entity().map_err(hold::Error::any)
+    }
+
+    async fn release_expired(&mut self, at: OffsetDateTime) -> Result<u64, hold::Error> {
+        let mut trx = self.pool.begin().await.map_err(hold::Error::any)?;
+
+        let lock_query = queries::LockExpiredQuery { at };
+        let products_ids = lock_query
+            .exec(trx.as_mut())
+            .await
+            .map_err(hold::Error::any)?;
+
+        let release_query = queries::ReleaseExpiredQuery {
+            at,
+            products_ids: &products_ids,
+        };
+        let released = release_query
+            .exec(trx.as_mut())
+            .await
+            .map_err(hold::Error::any)?;
+
+        trx.commit().await.map_err(hold::Error::any)?;
+
+        Ok(released)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+
+    use domain::hold::Repository;
+    use domain::product;
+
+    use super::*;
+
+    fn hold_of(product_id: &str, quantity: u32) -> hold::Hold {
+        let product_id = product::Id::parse_str(product_id).expect("Valid product id");
+        let quantity = stock::Quantity::new(quantity).expect("Valid stock quantity");
+        hold::Hold::new(product_id, quantity, hold::Window::default())
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn create_method_works(pool: PgPool) {
+        let mut repository = PgHolds::new(pool);
+        let hold = hold_of("0190ec14-0af8-71d1-9554-f1e5249ae3a2", 2);
+
+        assert!(repository.create(&hold).await.is_ok());
+
+        let found = repository.find(hold.id()).await.expect("Found hold");
+        assert_eq!(found.quantity(), hold.quantity());
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn create_with_insufficient_stock(pool: PgPool) {
+        use hold::{ConflictKind, Error};
+
+        // 3 units in stock, 1 already held
+        let hold = hold_of("0190ec14-0af8-71d1-9554-f1e5249ae3a2", 3);
+        let result = PgHolds::new(pool).create(&hold).await;
+
+        assert!(matches!(
+            result,
+            Err(Error::Conflict(ConflictKind::Stock(
+                stock::ConflictKind::Insufficient {
+                    requested: 3,
+                    available: 2,
+                    ..
+                }
+            )))
+        ));
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn create_with_untracked_stock(pool: PgPool) {
+        use hold::{Error, NotFoundKind};
+
+        let hold = hold_of("0190ec15-7985-7e62-aaca-d65c07e6d2e5", 1);
+        let result = PgHolds::new(pool).create(&hold).await;
+
+        assert!(matches!(
+            result,
+            Err(Error::NotFound(NotFoundKind::Stock(
+                stock::NotFoundKind::Untracked(_)
+            )))
+        ));
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn create_concurrently_holds_last_unit_once(pool: PgPool) {
+        let tasks = (0..4).map(|_| {
+            let mut repository = PgHolds::new(pool.clone());
+            let hold = hold_of("0190fe96-917c-7ec2-a1cf-831f117df95a", 1);
+            tokio::spawn(async move { repository.create(&hold).await.is_ok() })
+        });
+
+        let mut held = 0;
+        for task in tasks.collect::<Vec<_>>() {
+            if task.await.expect("Finished task") {
+                held += 1;
+            }
+        }
+
+        // only the single unit from fixtures can be held
+        assert_eq!(held, 1);
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn delete_method_works(pool: PgPool) {
+        use hold::{Error, NotFoundKind};
+
+        let mut repository = PgHolds::new(pool);
+        let id = hold::Id::parse_str("01916f4e-4c7a-7b1e-9d2a-5e3f0a1b2c3d")
+            .expect("Valid hold id from fixtures");
+
+        assert!(repository.delete(id).await.is_ok());
+        assert!(matches!(
+            repository.find(id).await,
+            Err(Error::NotFound(NotFoundKind::Id(_)))
+        ));
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn release_expired_frees_units(pool: PgPool) {
+        let mut repository = PgHolds::new(pool);
+        let released = repository
+            .release_expired(OffsetDateTime::now_utc())
+            .await
+            .expect("Released expired holds");
+
+        assert_eq!(released, 1);
+        let hold = hold_of("0190ec14-0af8-71d1-9554-f1e5249ae3a2", 2);
+        assert!(repository.create(&hold).await.is_ok());
+    }
+}
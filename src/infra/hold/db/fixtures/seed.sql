@@ -0,0 +1,21 @@
+insert into catalog (id, name, description, created_at, updated_at)
+values
+    ('0190ec30-286b-7211-aadb-003fc0449734', 'Burgers', null, now(), now());
+
+insert into product (id, catalog_id, name, price, kind, created_at, updated_at)
+values
+    ('0190ec14-0af8-71d1-9554-f1e5249ae3a2', '0190ec30-286b-7211-aadb-003fc0449734', 'Cheese Burger', 20.00, 'burger', now(), now()),
+    ('0190fe96-917c-7ec2-a1cf-831f117df95a', '0190ec30-286b-7211-aadb-003fc0449734', 'Cheese Salad', 20.00, 'burger', now(), now()),
+    -- stock is not tracked for this one
+    ('0190ec15-7985-7e62-aaca-d65c07e6d2e5', '0190ec30-286b-7211-aadb-003fc0449734', 'Caesar Salad', 16.30, 'burger', now(), now());
+
+insert into stock (product_id, level, created_at, updated_at)
+values
+    ('0190ec14-0af8-71d1-9554-f1e5249ae3a2', 3, now(), now()),
+    ('0190fe96-917c-7ec2-a1cf-831f117df95a', 1, now(), now());
+
+insert into hold (id, product_id, quantity, expires_at, created_at, updated_at)
+values
+    ('01916f4e-4c7a-7b1e-9d2a-5e3f0a1b2c3d', '0190ec14-0af8-71d1-9554-f1e5249ae3a2', 1, now() + interval '10 minutes', now(), now()),
+    -- already expired, though its units stay held until released
+    ('01916f4e-8d21-7f04-b6c5-2a9e8f7d6c5b', '0190ec14-0af8-71d1-9554-f1e5249ae3a2', 2, now() - interval '1 minute', now() - interval '11 minutes', now() - interval '11 minutes');
@@ -0,0 +1,177 @@
+use sqlx::types::Uuid;
+use sqlx::PgExecutor;
+use time::OffsetDateTime;
+
+use domain::hold;
+use domain::product;
+
+use crate::infra::hold::HoldModel;
+use crate::infra::stock::StockModel;
+
+#[derive(Clone, Debug)]
+pub(super) struct CreateQuery<'a> {
+    pub(super) hold: &'a hold::Hold,
+}
+
+impl<'a> CreateQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
+        let quantity = i32::try_from(self.hold.quantity().value()).unwrap_or(i32::MAX);
+        let sql = include_str!("./sql/create.sql");
+        sqlx::query(sql)
+            .bind(self.hold.id().uuid())
+            .bind(self.hold.product_id().uuid())
+            .bind(quantity)
+            .bind(self.hold.expires_at())
+            .bind(self.hold.metadata.created_at())
+            .bind(self.hold.metadata.updated_at())
+            .execute(exec)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct DeleteQuery {
+    pub(super) id: hold::Id,
+}
+
+impl DeleteQuery {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'_>) -> Result<HoldModel, sqlx::Error> {
+        let sql = include_str!("./sql/delete.sql");
+        sqlx::query_as(sql)
+            .bind(self.id.uuid())
+            .fetch_one(exec)
+            .await
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct FindQuery {
+    pub(super) id: hold::Id,
+}
+
+impl FindQuery {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'_>) -> Result<HoldModel, sqlx::Error> {
+        let sql = include_str!("./sql/find.sql");
+        sqlx::query_as(sql)
+            .bind(self.id.uuid())
+            .fetch_one(exec)
+            .await
+    }
+}
+
+/// Finds stock of product with its held units
+#[derive(Clone, Debug)]
+pub(super) struct FindStockQuery {
+    pub(super) product_id: product::Id,
+}
+
+impl FindStockQuery {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'_>) -> Result<StockModel, sqlx::Error> {
+        let sql = include_str!("./sql/find_stock.sql");
+        sqlx::query_as(sql)
+            .bind(self.product_id.uuid())
+            .fetch_one(exec)
+            .await
+    }
+}
+
+/// Locks stock rows of products with holds expired `at` until the transaction
+/// ends, returning their ids
+#[derive(Clone, Debug)]
+pub(super) struct LockExpiredQuery {
+    pub(super) at: OffsetDateTime,
+}
+
+impl LockExpiredQuery {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'_>) -> Result<Vec<Uuid>, sqlx::Error> {
+        let sql = include_str!("./sql/lock_expired.sql");
+        sqlx::query_scalar(sql).bind(self.at).fetch_all(exec).await
+    }
+}
+
+/// Locks stock row of product until the transaction ends, so its held units
+/// must be counted by a later statement to see holds committed meanwhile
+#[derive(Clone, Debug)]
+pub(super) struct LockStockQuery {
+    pub(super) product_id: product::Id,
+}
+
+impl LockStockQuery {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'_>) -> Result<(), sqlx::Error> {
+        let sql = include_str!("./sql/lock_stock.sql");
+        sqlx::query(sql)
+            .bind(self.product_id.uuid())
+            .fetch_one(exec)
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// Deletes holds of `products_ids` expired `at`
+#[derive(Clone, Debug)]
+pub(super) struct ReleaseExpiredQuery<'a> {
+    pub(super) at: OffsetDateTime,
+    pub(super) products_ids: &'a [Uuid],
+}
+
+impl<'a> ReleaseExpiredQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<u64, sqlx::Error> {
+        let sql = include_str!("./sql/release_expired.sql");
+        let result = sqlx::query(sql)
+            .bind(self.at)
+            .bind(self.products_ids)
+            .execute(exec)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+
+    use super::*;
+
+    #[sqlx::test(fixtures("seed"))]
+    async fn find_stock_query_counts_unreleased_holds(pool: PgPool) {
+        let product_id = product::Id::parse_str("0190ec14-0af8-71d1-9554-f1e5249ae3a2")
+            .expect("Valid product id from fixtures");
+
+        let model = FindStockQuery { product_id }
+            .exec(&pool)
+            .await
+            .expect("Found stock model");
+
+        // expired holds keep their units until released
+        assert_eq!(model.level, 3);
+        assert_eq!(model.held, 3);
+    }
+
+    #[sqlx::test(fixtures("seed"))]
+    async fn lock_expired_query_works(pool: PgPool) {
+        let query = LockExpiredQuery {
+            at: OffsetDateTime::now_utc(),
+        };
+
+        let products_ids = query.exec(&pool).await.expect("Locked products ids");
+        assert_eq!(
+            products_ids,
+            [Uuid::parse_str("0190ec14-0af8-71d1-9554-f1e5249ae3a2").unwrap()]
+        );
+    }
+
+    #[sqlx::test(fixtures("seed"))]
+    async fn release_expired_query_works(pool: PgPool) {
+        let products_ids = [Uuid::parse_str("0190ec14-0af8-71d1-9554-f1e5249ae3a2").unwrap()];
+        let query = ReleaseExpiredQuery {
+            at: OffsetDateTime::now_utc(),
+            products_ids: &products_ids,
+        };
+
+        assert_eq!(query.exec(&pool).await.ok(), Some(1));
+    }
+}
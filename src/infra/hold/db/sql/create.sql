@@ -0,0 +1,2 @@
+insert into hold (id, product_id, quantity, expires_at, created_at, updated_at)
+values ($1, $2, $3, $4, $5, $6)
\ No newline at end of file
@@ -0,0 +1 @@
+delete from hold where hold.id = $1 returning hold.*
\ No newline at end of file
@@ -0,0 +1 @@
+select hold.* from hold where hold.id = $1
\ No newline at end of file
@@ -0,0 +1,5 @@
+select stock.product_id
+from stock
+where exists (select 1 from hold where hold.product_id = stock.product_id and hold.expires_at <= $1)
+order by stock.product_id
+for update of stock
\ No newline at end of file
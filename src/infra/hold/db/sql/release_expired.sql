@@ -0,0 +1 @@
+delete from hold where hold.expires_at <= $1 and hold.product_id = any($2)
\ No newline at end of file
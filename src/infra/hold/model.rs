@@ -0,0 +1,34 @@
+use sqlx::types::Uuid;
+use sqlx::FromRow;
+use time::OffsetDateTime;
+
+use domain::core::metadata;
+use domain::hold;
+use domain::product;
+use domain::stock;
+
+#[derive(Clone, Debug, FromRow)]
+pub struct HoldModel {
+    pub id: Uuid,
+    pub product_id: Uuid,
+    pub quantity: i32,
+    pub expires_at: OffsetDateTime,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+impl HoldModel {
+    pub fn try_into_entity(self) -> Result<hold::Hold, Box<dyn std::error::Error>> {
+        let quantity = stock::Quantity::new(u32::try_from(self.quantity)?)?;
+        let metadata = metadata::Metadata::configured(self.created_at, self.updated_at)?;
+        let hold = hold::Hold::config(hold::HoldConfig {
+            id: hold::Id::from(self.id),
+            product_id: product::Id::from(self.product_id),
+            quantity,
+            expires_at: self.expires_at,
+            metadata,
+        });
+
+        Ok(hold)
+    }
+}
@@ -0,0 +1,5 @@
+mod db;
+mod model;
+
+pub use db::PgIngredients;
+pub(super) use model::{IngredientModel, PortionModel};
@@ -0,0 +1,409 @@
+mod queries;
+
+use sqlx::PgPool;
+
+use domain::extra;
+use domain::ingredient;
+use domain::product;
+
+use super::{IngredientModel, PortionModel};
+
+#[derive(Clone, Debug)]
+pub struct PgIngredients {
+    pool: PgPool,
+}
+
+impl PgIngredients {
+    const PK: &'static str = "pk_ingredient";
+    const AK_NAME: &'static str = "ak_ingredient_name";
+
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn is_pk_error(err: &sqlx::Error) -> bool {
+        err.as_database_error()
+            .is_some_and(|db_err| db_err.constraint() == Some(Self::PK))
+    }
+
+    fn is_ak_name_error(err: &sqlx::Error) -> bool {
+        err.as_database_error()
+            .is_some_and(|db_err| db_err.constraint() == Some(Self::AK_NAME))
+    }
+}
+
+impl ingredient::Repository for PgIngredients {
+    async fn all(&self) -> Result<Vec<ingredient::Ingredient>, ingredient::Error> {
+        let models = queries::AllQuery
+            .exec(&self.pool)
+            .await
+            .map_err(ingredient::Error::any)?;
+
+        models
+            .into_iter()
+            .map(IngredientModel::try_into_entity)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(ingredient::Error::any)
+    }
+
+    async fn create(
+        &mut self,
+        ingredient: &ingredient::Ingredient,
+    ) -> Result<(), ingredient::Error> {
+        let query = queries::CreateQuery { ingredient };
+        query.exec(&self.pool).await.map_err(|err| {
+            if Self::is_pk_error(&err) {
+                ingredient::Error::id_conflict(ingredient.id())
+            } else if Self::is_ak_name_error(&err) {
+                ingredient::Error::name_conflict(ingredient.name.clone())
+            } else {
+                ingredient::Error::any(err)
+            }
+        })
+    }
+
+    async fn delete(
+        &mut self,
+        id: ingredient::Id,
+    ) -> Result<ingredient::Ingredient, ingredient::Error> {
+        let query = queries::DeleteQuery { id };
+        let model = query.exec(&self.pool).await.map_err(|err| match err {
+            sqlx::Error::RowNotFound => ingredient::Error::id_not_found(id),
+            _ => ingredient::Error::any(err),
+        })?;
+
+        model.try_into_entity().map_err(ingredient::Error::any)
+    }
+
+    async fn find(&self, id: ingredient::Id) -> Result<ingredient::Ingredient, ingredient::Error> {
+        let query = queries::FindQuery { id };
+        let model = query.exec(&self.pool).await.map_err(|err| match err {
+            sqlx::Error::RowNotFound => ingredient::Error::id_not_found(id),
+            _ => ingredient::Error::any(err),
+        })?;
+
+        model.try_into_entity().map_err(ingredient::Error::any)
+    }
+
+    async fn find_many(
+        &self,
+        ids: &[ingredient::Id],
+    ) -> Result<Vec<ingredient::Ingredient>, ingredient::Error> {
+        let query = queries::FindManyQuery { ids };
+        let models = query
+            .exec(&self.pool)
+            .await
+            .map_err(ingredient::Error::any)?;
+
+        let ingredients = models
+            .into_iter()
+            .map(IngredientModel::try_into_entity)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(ingredient::Error::any)?;
+
+        if let Some(id_not_found) = ids
+            .iter()
+            .find(|id| !ingredients.iter().any(|i| i.id().eq(id)))
+        {
+            return Err(ingredient::Error::id_not_found(*id_not_found));
+        }
+
+        Ok(ingredients)
+    }
+
+    async fn update(
+        &mut self,
+        ingredient: &ingredient::Ingredient,
+    ) -> Result<(), ingredient::Error> {
+        let query = queries::UpdateQuery { ingredient };
+        query.exec(&self.pool).await.map_err(|err| {
+            if matches!(err, sqlx::Error::RowNotFound) {
+                ingredient::Error::id_not_found(ingredient.id())
+            } else if Self::is_ak_name_error(&err) {
+                ingredient::Error::name_conflict(ingredient.name.clone())
+            } else {
+                ingredient::Error::any(err)
+            }
+        })
+    }
+
+    async fn product_recipe(
+        &self,
+        product_id: product::Id,
+    ) -> Result<ingredient::Recipe, ingredient::Error> {
+        let query = queries::ProductRecipeQuery { product_id };
+        let models = query
+            .exec(&self.pool)
+            .await
+            .map_err(ingredient::Error::any)?;
+
+        PortionModel::try_into_recipe(models).map_err(ingredient::Error::any)
+    }
+
+    async fn set_product_recipe(
+        &mut self,
+        product_id: product::Id,
+        recipe: &ingredient::Recipe,
+    ) -> Result<(), ingredient::Error> {
+        let mut trx = self.pool.begin().await.map_err(ingredient::Error::any)?;
+
+        let delete_query = queries::DeleteProductRecipeQuery { product_id };
+        delete_query
+            .exec(trx.as_mut())
+            .await
+            .map_err(ingredient::Error::any)?;
+
+        if !recipe.is_empty() {
+            let create_query = queries::CreateProductRecipeQuery { product_id, recipe };
+            create_query
+                .exec(trx.as_mut())
+                .await
+                .map_err(ingredient::Error::any)?;
+        }
+
+        trx.commit().await.map_err(ingredient::Error::any)
+    }
+
+    async fn extra_recipe(
+        &self,
+        extra_id: extra::Id,
+    ) -> Result<ingredient::Recipe, ingredient::Error> {
+        let query = queries::ExtraRecipeQuery { extra_id };
+        let models = query
+            .exec(&self.pool)
+            .await
+            .map_err(ingredient::Error::any)?;
+
+        PortionModel::try_into_recipe(models).map_err(ingredient::Error::any)
+    }
+
+    async fn set_extra_recipe(
+        &mut self,
+        extra_id: extra::Id,
+        recipe: &ingredient::Recipe,
+    ) -> Result<(), ingredient::Error> {
+        let mut trx = self.pool.begin().await.map_err(ingredient::Error::any)?;
+
+        let delete_query = queries::DeleteExtraRecipeQuery { extra_id };
+        delete_query
+            .exec(trx.as_mut())
+            .await
+            .map_err(ingredient::Error::any)?;
+
+        if !recipe.is_empty() {
+            let create_query = queries::CreateExtraRecipeQuery { extra_id, recipe };
+            create_query
+                .exec(trx.as_mut())
+                .await
+                .map_err(ingredient::Error::any)?;
+        }
+
+        trx.commit().await.map_err(ingredient::Error::any)
+    }
+
+    async fn consume(
+        &mut self,
+        consumption: &ingredient::Consumption,
+    ) -> Result<Vec<ingredient::Ingredient>, ingredient::Error> {
+        let mut trx = self.pool.begin().await.map_err(ingredient::Error::any)?;
+
+        // rows are locked sorted by id, so concurrent consumptions sharing
+        // ingredients cannot deadlock
+        let ids = consumption.ingredients_ids();
+        let lock_query = queries::LockManyQuery { ids: &ids };
+        let mut ingredients = lock_query
+            .exec(trx.as_mut())
+            .await
+            .map_err(ingredient::Error::any)?
+            .into_iter()
+            .map(IngredientModel::try_into_entity)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(ingredient::Error::any)?;
+
+        for (ingredient_id, amount) in consumption.iter() {
+            let ingredient = ingredients
+                .iter_mut()
+                .find(|ingredient| ingredient.id() == ingredient_id)
+                .ok_or(ingredient::Error::id_not_found(ingredient_id))?;
+
+            ingredient.consume(amount)?;
+
+            let update_query = queries::UpdateQuery { ingredient };
+            update_query
+                .exec(trx.as_mut())
+                .await
+                .map_err(ingredient::Error::any)?;
+        }
+
+        trx.commit().await.map_err(ingredient::Error::any)?;
+
+        Ok(ingredients)
+    }
+
+    async fn exhausted(
+        &self,
+        products_ids: &[product::Id],
+    ) -> Result<ingredient::Exhausted, ingredient::Error> {
+        let query = queries::ExhaustedQuery { products_ids };
+        let products_ids = query
+            .exec(&self.pool)
+            .await
+            .map_err(ingredient::Error::any)?;
+
+        let products_ids = products_ids.into_iter().map(product::Id::from).collect();
+        Ok(ingredient::Exhausted::new(products_ids))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+
+    use domain::ingredient::Repository;
+
+    use super::*;
+
+    const BURGER_ID: &str = "0190ec14-0af8-71d1-9554-f1e5249ae3a2";
+    const CHEDDAR_ID: &str = "0190eaf5-c290-7443-b6a6-d22ce2a0fcb1";
+
+    fn consumption(recipes: &[(&ingredient::Recipe, u16)]) -> ingredient::Consumption {
+        let mut consumption = ingredient::Consumption::new();
+        for (recipe, times) in recipes {
+            consumption.add(recipe, *times);
+        }
+
+        consumption
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn create_method_works(pool: PgPool) {
+        let mut repository = PgIngredients::new(pool);
+        let name = ingredient::Name::new("Tomato").expect("Valid ingredient name");
+        let level = ingredient::Level::new(500).expect("Valid ingredient level");
+        let ingredient = ingredient::Ingredient::new(name, ingredient::Unit::Gram, level);
+
+        assert!(repository.create(&ingredient).await.is_ok());
+
+        let found = repository.find(ingredient.id()).await.expect("Found");
+        assert_eq!(found.level(), level);
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn create_with_name_conflict(pool: PgPool) {
+        use ingredient::{ConflictKind, Error};
+
+        let name = ingredient::Name::new("Beef").expect("Valid ingredient name");
+        let ingredient =
+            ingredient::Ingredient::new(name, ingredient::Unit::Gram, ingredient::Level::ZERO);
+
+        let result = PgIngredients::new(pool).create(&ingredient).await;
+        assert!(matches!(
+            result,
+            Err(Error::Conflict(ConflictKind::Name(_)))
+        ));
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn set_product_recipe_replaces_previous(pool: PgPool) {
+        let mut repository = PgIngredients::new(pool);
+        let product_id = product::Id::parse_str(BURGER_ID).expect("Valid product id");
+        let recipe = ingredient::Recipe::new(vec![ingredient::Portion {
+            ingredient_id: ingredient::Id::parse_str("01917a2b-1c3d-7e4f-8a9b-0c1d2e3f4a5b")
+                .expect("Valid ingredient id from fixtures"),
+            amount: ingredient::Amount::new(2).expect("Valid ingredient amount"),
+        }])
+        .expect("Valid recipe");
+
+        let result = repository.set_product_recipe(product_id, &recipe).await;
+        assert!(result.is_ok());
+
+        let found = repository.product_recipe(product_id).await;
+        assert_eq!(found.ok(), Some(recipe));
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn consume_method_works(pool: PgPool) {
+        let mut repository = PgIngredients::new(pool);
+        let product_id = product::Id::parse_str(BURGER_ID).expect("Valid product id");
+        let extra_id = extra::Id::parse_str(CHEDDAR_ID).expect("Valid extra id");
+        let product_recipe = repository.product_recipe(product_id).await.expect("Recipe");
+        let extra_recipe = repository.extra_recipe(extra_id).await.expect("Recipe");
+
+        let consumption = consumption(&[(&product_recipe, 1), (&extra_recipe, 1)]);
+        let ingredients = repository
+            .consume(&consumption)
+            .await
+            .expect("Consumed ingredients");
+
+        let mut levels = ingredients
+            .iter()
+            .map(|ingredient| (ingredient.name.as_str(), ingredient.level().value()))
+            .collect::<Vec<_>>();
+        levels.sort_unstable();
+        assert_eq!(levels, vec![("Beef", 850), ("Bun", 9), ("Cheddar", 20)]);
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn consume_with_insufficient_depletes_nothing(pool: PgPool) {
+        use ingredient::{ConflictKind, Error};
+
+        let mut repository = PgIngredients::new(pool);
+        let product_id = product::Id::parse_str(BURGER_ID).expect("Valid product id");
+        let extra_id = extra::Id::parse_str(CHEDDAR_ID).expect("Valid extra id");
+        let product_recipe = repository.product_recipe(product_id).await.expect("Recipe");
+        let extra_recipe = repository.extra_recipe(extra_id).await.expect("Recipe");
+
+        // 2 burgers are fine, but there is cheddar for a single one
+        let consumption = consumption(&[(&product_recipe, 2), (&extra_recipe, 2)]);
+        let result = repository.consume(&consumption).await;
+        assert!(matches!(
+            result,
+            Err(Error::Conflict(ConflictKind::Insufficient {
+                requested: 60,
+                available: 50,
+                ..
+            }))
+        ));
+
+        let ids = product_recipe.ingredients_ids();
+        let ingredients = repository.find_many(&ids).await.expect("Found");
+        assert!(ingredients
+            .iter()
+            .all(|ingredient| ingredient.level().value() >= 10));
+        let exhausted = repository
+            .exhausted(&[product_id])
+            .await
+            .expect("Exhausted");
+        assert!(!exhausted.contains(product_id));
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn consume_concurrently_never_overdraws(pool: PgPool) {
+        let product_id = product::Id::parse_str(BURGER_ID).expect("Valid product id");
+        let recipe = PgIngredients::new(pool.clone())
+            .product_recipe(product_id)
+            .await
+            .expect("Recipe");
+
+        let tasks = (0..10).map(|_| {
+            let mut repository = PgIngredients::new(pool.clone());
+            let consumption = consumption(&[(&recipe, 1)]);
+            tokio::spawn(async move { repository.consume(&consumption).await.is_ok() })
+        });
+
+        let mut consumed = 0;
+        for task in tasks.collect::<Vec<_>>() {
+            if task.await.expect("Finished task") {
+                consumed += 1;
+            }
+        }
+
+        // 1000g of beef from fixtures is enough for 6 burgers only
+        assert_eq!(consumed, 6);
+        let exhausted = PgIngredients::new(pool)
+            .exhausted(&[product_id])
+            .await
+            .expect("Exhausted");
+        assert!(exhausted.contains(product_id));
+    }
+}
@@ -0,0 +1,34 @@
+insert into catalog (id, name, description, created_at, updated_at)
+values
+    ('0190ec30-286b-7211-aadb-003fc0449734', 'Burgers', null, now(), now());
+
+insert into product (id, catalog_id, name, price, kind, created_at, updated_at)
+values
+    ('0190ec14-0af8-71d1-9554-f1e5249ae3a2', '0190ec30-286b-7211-aadb-003fc0449734', 'Cheese Burger', 20.00, 'burger', now(), now()),
+    ('0190ec15-7985-7e62-aaca-d65c07e6d2e5', '0190ec30-286b-7211-aadb-003fc0449734', 'Caesar Salad', 16.30, 'burger', now(), now());
+
+insert into extra (id, name, price, created_at, updated_at)
+values
+    ('0190eaf5-c290-7443-b6a6-d22ce2a0fcb1', 'Cheddar', 8.00, now(), now());
+
+insert into product_extras (product_id, extra_id)
+values
+    ('0190ec14-0af8-71d1-9554-f1e5249ae3a2', '0190eaf5-c290-7443-b6a6-d22ce2a0fcb1');
+
+insert into ingredient (id, name, unit, level, created_at, updated_at)
+values
+    ('01917a2b-1c3d-7e4f-8a9b-0c1d2e3f4a5b', 'Bun', 'piece', 10, now(), now()),
+    ('01917a2b-2d4e-7f50-9bac-1d2e3f4a5b6c', 'Beef', 'gram', 1000, now(), now()),
+    ('01917a2b-3e5f-7061-acbd-2e3f4a5b6c7d', 'Cheddar', 'gram', 50, now(), now()),
+    -- not enough for a single salad
+    ('01917a2b-4f60-7172-bdce-3f4a5b6c7d8e', 'Lettuce', 'gram', 20, now(), now());
+
+insert into product_ingredient (product_id, ingredient_id, amount)
+values
+    ('0190ec14-0af8-71d1-9554-f1e5249ae3a2', '01917a2b-1c3d-7e4f-8a9b-0c1d2e3f4a5b', 1),
+    ('0190ec14-0af8-71d1-9554-f1e5249ae3a2', '01917a2b-2d4e-7f50-9bac-1d2e3f4a5b6c', 150),
+    ('0190ec15-7985-7e62-aaca-d65c07e6d2e5', '01917a2b-4f60-7172-bdce-3f4a5b6c7d8e', 100);
+
+insert into extra_ingredient (extra_id, ingredient_id, amount)
+values
+    ('0190eaf5-c290-7443-b6a6-d22ce2a0fcb1', '01917a2b-3e5f-7061-acbd-2e3f4a5b6c7d', 30);
@@ -0,0 +1,324 @@
+use sqlx::types::Uuid;
+use sqlx::PgExecutor;
+
+use domain::extra;
+use domain::ingredient;
+use domain::product;
+
+use crate::infra::ingredient::{IngredientModel, PortionModel};
+
+#[derive(Clone, Debug)]
+pub(super) struct AllQuery;
+
+impl AllQuery {
+    pub(super) async fn exec(
+        self,
+        exec: impl PgExecutor<'_>,
+    ) -> Result<Vec<IngredientModel>, sqlx::Error> {
+        let sql = include_str!("./sql/all.sql");
+        sqlx::query_as(sql).fetch_all(exec).await
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct CreateQuery<'a> {
+    pub(super) ingredient: &'a ingredient::Ingredient,
+}
+
+impl<'a> CreateQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
+        let level = i32::try_from(self.ingredient.level().value()).unwrap_or(i32::MAX);
+        let sql = include_str!("./sql/create.sql");
+        sqlx::query(sql)
+            .bind(self.ingredient.id().uuid())
+            .bind(self.ingredient.name.as_str())
+            .bind(self.ingredient.unit.as_str())
+            .bind(level)
+            .bind(self.ingredient.metadata.created_at())
+            .bind(self.ingredient.metadata.updated_at())
+            .execute(exec)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct DeleteQuery {
+    pub(super) id: ingredient::Id,
+}
+
+impl DeleteQuery {
+    pub(super) async fn exec(
+        self,
+        exec: impl PgExecutor<'_>,
+    ) -> Result<IngredientModel, sqlx::Error> {
+        let sql = include_str!("./sql/delete.sql");
+        sqlx::query_as(sql)
+            .bind(self.id.uuid())
+            .fetch_one(exec)
+            .await
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct FindQuery {
+    pub(super) id: ingredient::Id,
+}
+
+impl FindQuery {
+    pub(super) async fn exec(
+        self,
+        exec: impl PgExecutor<'_>,
+    ) -> Result<IngredientModel, sqlx::Error> {
+        let sql = include_str!("./sql/find.sql");
+        sqlx::query_as(sql)
+            .bind(self.id.uuid())
+            .fetch_one(exec)
+            .await
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct FindManyQuery<'a> {
+    pub(super) ids: &'a [ingredient::Id],
+}
+
+impl<'a> FindManyQuery<'a> {
+    pub(super) async fn exec(
+        self,
+        exec: impl PgExecutor<'a>,
+    ) -> Result<Vec<IngredientModel>, sqlx::Error> {
+        let ids = self
+            .ids
+            .iter()
+            .map(ingredient::Id::uuid)
+            .collect::<Vec<_>>();
+        let sql = include_str!("./sql/find_many.sql");
+        sqlx::query_as(sql).bind(ids).fetch_all(exec).await
+    }
+}
+
+/// Finds many ingredients sorted by id, holding their row locks until the
+/// transaction ends
+#[derive(Clone, Debug)]
+pub(super) struct LockManyQuery<'a> {
+    pub(super) ids: &'a [ingredient::Id],
+}
+
+impl<'a> LockManyQuery<'a> {
+    pub(super) async fn exec(
+        self,
+        exec: impl PgExecutor<'a>,
+    ) -> Result<Vec<IngredientModel>, sqlx::Error> {
+        let ids = self
+            .ids
+            .iter()
+            .map(ingredient::Id::uuid)
+            .collect::<Vec<_>>();
+        let sql = include_str!("./sql/lock_many.sql");
+        sqlx::query_as(sql).bind(ids).fetch_all(exec).await
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct UpdateQuery<'a> {
+    pub(super) ingredient: &'a ingredient::Ingredient,
+}
+
+impl<'a> UpdateQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
+        let level = i32::try_from(self.ingredient.level().value()).unwrap_or(i32::MAX);
+        let sql = include_str!("./sql/update.sql");
+        let result = sqlx::query(sql)
+            .bind(self.ingredient.id().uuid())
+            .bind(self.ingredient.name.as_str())
+            .bind(self.ingredient.unit.as_str())
+            .bind(level)
+            .bind(self.ingredient.metadata.updated_at())
+            .execute(exec)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(sqlx::Error::RowNotFound);
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct ProductRecipeQuery {
+    pub(super) product_id: product::Id,
+}
+
+impl ProductRecipeQuery {
+    pub(super) async fn exec(
+        self,
+        exec: impl PgExecutor<'_>,
+    ) -> Result<Vec<PortionModel>, sqlx::Error> {
+        let sql = include_str!("./sql/product_recipe.sql");
+        sqlx::query_as(sql)
+            .bind(self.product_id.uuid())
+            .fetch_all(exec)
+            .await
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct DeleteProductRecipeQuery {
+    pub(super) product_id: product::Id,
+}
+
+impl DeleteProductRecipeQuery {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'_>) -> Result<(), sqlx::Error> {
+        let sql = include_str!("./sql/delete_product_recipe.sql");
+        sqlx::query(sql)
+            .bind(self.product_id.uuid())
+            .execute(exec)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct CreateProductRecipeQuery<'a> {
+    pub(super) product_id: product::Id,
+    pub(super) recipe: &'a ingredient::Recipe,
+}
+
+impl<'a> CreateProductRecipeQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
+        let (ingredients_ids, amounts) = unzip_recipe(self.recipe);
+        let sql = include_str!("./sql/create_product_recipe.sql");
+        sqlx::query(sql)
+            .bind(self.product_id.uuid())
+            .bind(ingredients_ids)
+            .bind(amounts)
+            .execute(exec)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct ExtraRecipeQuery {
+    pub(super) extra_id: extra::Id,
+}
+
+impl ExtraRecipeQuery {
+    pub(super) async fn exec(
+        self,
+        exec: impl PgExecutor<'_>,
+    ) -> Result<Vec<PortionModel>, sqlx::Error> {
+        let sql = include_str!("./sql/extra_recipe.sql");
+        sqlx::query_as(sql)
+            .bind(self.extra_id.uuid())
+            .fetch_all(exec)
+            .await
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct DeleteExtraRecipeQuery {
+    pub(super) extra_id: extra::Id,
+}
+
+impl DeleteExtraRecipeQuery {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'_>) -> Result<(), sqlx::Error> {
+        let sql = include_str!("./sql/delete_extra_recipe.sql");
+        sqlx::query(sql)
+            .bind(self.extra_id.uuid())
+            .execute(exec)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct CreateExtraRecipeQuery<'a> {
+    pub(super) extra_id: extra::Id,
+    pub(super) recipe: &'a ingredient::Recipe,
+}
+
+impl<'a> CreateExtraRecipeQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
+        let (ingredients_ids, amounts) = unzip_recipe(self.recipe);
+        let sql = include_str!("./sql/create_extra_recipe.sql");
+        sqlx::query(sql)
+            .bind(self.extra_id.uuid())
+            .bind(ingredients_ids)
+            .bind(amounts)
+            .execute(exec)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct ExhaustedQuery<'a> {
+    pub(super) products_ids: &'a [product::Id],
+}
+
+impl<'a> ExhaustedQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<Vec<Uuid>, sqlx::Error> {
+        let products_ids = self
+            .products_ids
+            .iter()
+            .map(product::Id::uuid)
+            .collect::<Vec<_>>();
+
+        let sql = include_str!("./sql/exhausted.sql");
+        sqlx::query_scalar(sql)
+            .bind(products_ids)
+            .fetch_all(exec)
+            .await
+    }
+}
+
+fn unzip_recipe(recipe: &ingredient::Recipe) -> (Vec<Uuid>, Vec<i32>) {
+    recipe
+        .iter()
+        .map(|portion| {
+            let amount = i32::try_from(portion.amount.value()).unwrap_or(i32::MAX);
+            (portion.ingredient_id.uuid(), amount)
+        })
+        .unzip()
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+
+    use super::*;
+
+    #[sqlx::test(fixtures("seed"))]
+    async fn product_recipe_query_works(pool: PgPool) {
+        let product_id = product::Id::parse_str("0190ec14-0af8-71d1-9554-f1e5249ae3a2")
+            .expect("Valid product id from fixtures");
+
+        let result = ProductRecipeQuery { product_id }.exec(&pool).await;
+        assert_eq!(result.map(|models| models.len()).ok(), Some(2));
+    }
+
+    #[sqlx::test(fixtures("seed"))]
+    async fn exhausted_query_works(pool: PgPool) {
+        let burger_id = product::Id::parse_str("0190ec14-0af8-71d1-9554-f1e5249ae3a2")
+            .expect("Valid product id from fixtures");
+        let salad_id = product::Id::parse_str("0190ec15-7985-7e62-aaca-d65c07e6d2e5")
+            .expect("Valid product id from fixtures");
+
+        let products_ids = [burger_id, salad_id];
+        let result = ExhaustedQuery {
+            products_ids: &products_ids,
+        }
+        .exec(&pool)
+        .await;
+
+        assert_eq!(result.ok(), Some(vec![salad_id.uuid()]));
+    }
+}
@@ -0,0 +1 @@
+select ingredient.* from ingredient order by ingredient.name
\ No newline at end of file
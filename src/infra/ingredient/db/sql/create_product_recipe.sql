@@ -0,0 +1,3 @@
+insert into product_ingredient (product_id, ingredient_id, amount)
+select $1, portion.ingredient_id, portion.amount
+from unnest($2::uuid[], $3::integer[]) as portion (ingredient_id, amount)
\ No newline at end of file
@@ -0,0 +1 @@
+delete from ingredient where ingredient.id = $1 returning ingredient.*
\ No newline at end of file
@@ -0,0 +1 @@
+delete from extra_ingredient where extra_ingredient.extra_id = $1
\ No newline at end of file
@@ -0,0 +1 @@
+delete from product_ingredient where product_ingredient.product_id = $1
\ No newline at end of file
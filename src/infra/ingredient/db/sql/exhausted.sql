@@ -0,0 +1,6 @@
+-- a product is exhausted when it cannot get a single portion of an ingredient
+select distinct product_ingredient.product_id
+from product_ingredient
+inner join ingredient on ingredient.id = product_ingredient.ingredient_id
+where product_ingredient.product_id = any($1)
+and ingredient.level < product_ingredient.amount
\ No newline at end of file
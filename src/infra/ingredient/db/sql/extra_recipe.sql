@@ -0,0 +1,4 @@
+select extra_ingredient.ingredient_id, extra_ingredient.amount
+from extra_ingredient
+where extra_ingredient.extra_id = $1
+order by extra_ingredient.ingredient_id
\ No newline at end of file
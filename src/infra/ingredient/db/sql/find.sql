@@ -0,0 +1 @@
+select ingredient.* from ingredient where ingredient.id = $1
\ No newline at end of file
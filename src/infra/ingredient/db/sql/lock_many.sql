@@ -0,0 +1,4 @@
+select ingredient.* from ingredient
+where ingredient.id = any($1)
+order by ingredient.id
+for update
\ No newline at end of file
@@ -0,0 +1,4 @@
+select product_ingredient.ingredient_id, product_ingredient.amount
+from product_ingredient
+where product_ingredient.product_id = $1
+order by product_ingredient.ingredient_id
\ No newline at end of file
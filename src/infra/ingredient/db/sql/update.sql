@@ -0,0 +1,3 @@
+update ingredient
+set name = $2, unit = $3, level = $4, updated_at = $5
+where ingredient.id = $1
\ No newline at end of file
@@ -0,0 +1,62 @@
+use sqlx::types::Uuid;
+use sqlx::FromRow;
+use time::OffsetDateTime;
+
+use domain::core::metadata;
+use domain::ingredient;
+
+#[derive(Clone, Debug, FromRow)]
+pub struct IngredientModel {
+    pub id: Uuid,
+    pub name: String,
+    pub unit: String,
+    pub level: i32,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+impl IngredientModel {
+    pub fn try_into_entity(self) -> Result<ingredient::Ingredient, Box<dyn std::error::Error>> {
+        let name = ingredient::Name::new(self.name)?;
+        let unit = ingredient::Unit::parse_str(&self.unit)?;
+        let level = ingredient::Level::new(u32::try_from(self.level)?)?;
+        let metadata = metadata::Metadata::configured(self.created_at, self.updated_at)?;
+        let ingredient = ingredient::Ingredient::config(ingredient::IngredientConfig {
+            id: ingredient::Id::from(self.id),
+            name,
+            unit,
+            level,
+            metadata,
+        });
+
+        Ok(ingredient)
+    }
+}
+
+#[derive(Clone, Debug, FromRow)]
+pub struct PortionModel {
+    pub ingredient_id: Uuid,
+    pub amount: i32,
+}
+
+impl PortionModel {
+    pub fn try_into_portion(self) -> Result<ingredient::Portion, Box<dyn std::error::Error>> {
+        let amount = ingredient::Amount::new(u32::try_from(self.amount)?)?;
+        Ok(ingredient::Portion {
+            ingredient_id: ingredient::Id::from(self.ingredient_id),
+            amount,
+        })
+    }
+
+    /// Try converting `models` into a [`ingredient::Recipe`]
+    pub fn try_into_recipe(
+        models: Vec<Self>,
+    ) -> Result<ingredient::Recipe, Box<dyn std::error::Error>> {
+        let portions = models
+            .into_iter()
+            .map(Self::try_into_portion)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(ingredient::Recipe::new(portions)?)
+    }
+}
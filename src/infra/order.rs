@@ -0,0 +1,5 @@
+mod db;
+mod model;
+
+pub use db::PgOrders;
+pub(super) use model::OrderWithItemsModel;
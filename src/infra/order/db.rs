@@ -0,0 +1,260 @@
+mod queries;
+
+use sqlx::PgPool;
+
+use domain::order;
+
+use super::OrderWithItemsModel;
+
+#[derive(Clone, Debug)]
+pub struct PgOrders {
+    pool: PgPool,
+}
+
+impl PgOrders {
+    const PK: &'static str = "pk_customer_order";
+
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn is_pk_error(err: &sqlx::Error) -> bool {
+        err.as_database_error()
+            .is_some_and(|db_err| db_err.constraint() == Some(Self::PK))
+    }
+}
+
+impl order::Repository for PgOrders {
+    async fn create(&mut self, order: &order::Order) -> Result<(), order::Error> {
+        let mut trx = self.pool.begin().await.map_err(order::Error::any)?;
+
+        let create_query = queries::CreateQuery { order };
+        create_query.exec(trx.as_mut()).await.map_err(|err| {
+            if Self::is_pk_error(&err) {
+                order::Error::id_conflict(order.id())
+            } else {
+                order::Error::any(err)
+            }
+        })?;
+
+        let create_items_query = queries::CreateItemsQuery {
+            id: order.id(),
+            items: order.items.as_slice(),
+        };
+
+        create_items_query
+            .exec(trx.as_mut())
+            .await
+            .map_err(order::Error::any)?;
+
+        let create_extras_query = queries::CreateExtrasQuery {
+            items: order.items.as_slice(),
+        };
+
+        create_extras_query
+            .exec(trx.as_mut())
+            .await
+            .map_err(order::Error::any)?;
+
+        trx.commit().await.map_err(order::Error::any)
+    }
+
+    async fn delete(&mut self, id: order::Id) -> Result<order::Order, order::Error> {
+        let query = queries::DeleteQuery { id };
+        let model = query.exec(&self.pool).await.map_err(|err| match err {
+            sqlx::Error::RowNotFound => order::Error::id_not_found(id),
+            _ => order::Error::any(err),
+        })?;
+
+        model.try_into_entity().map_err(order::Error::any)
+    }
+
+    async fn find(&self, id: order::Id) -> Result<order::Order, order::Error> {
+        let query = queries::FindQuery { id };
+        let model = query.exec(&self.pool).await.map_err(|err| match err {
+            sqlx::Error::RowNotFound => order::Error::id_not_found(id),
+            _ => order::Error::any(err),
+        })?;
+
+        model.try_into_entity().map_err(order::Error::any)
+    }
+
+    async fn list(&self, query: order::ListQuery) -> Result<order::Pagination, order::Error> {
+        let count = queries::CountQuery
+            .exec(&self.pool)
+            .await
+            .map_err(order::Error::any)?;
+
+        let list_query = queries::ListQuery(query.clone());
+        let models = list_query
+            .exec(&self.pool)
+            .await
+            .map_err(order::Error::any)?;
+
+        let orders = models
+            .into_iter()
+            .map(OrderWithItemsModel::try_into_entity)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(order::Error::any)?;
+
+        Ok(order::Pagination {
+            count,
+            page: query.page,
+            limit: query.limit,
+            items: orders,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+
+    use domain::catalog;
+    use domain::core::metadata;
+    use domain::core::money::{Currency, Money};
+    use domain::core::quantity::Quantity;
+    use domain::extra;
+    use domain::order::Repository;
+    use domain::product;
+
+    use super::*;
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn create_method_works(pool: PgPool) {
+        let order =
+            order::Order::new(order::Items::new(vec![mocked_item()]).expect("Valid order items"));
+
+        let mut repository = PgOrders::new(pool);
+        let result = repository.create(&order).await;
+        assert!(result.is_ok());
+
+        let created = repository.find(order.id()).await.expect("Created order");
+        assert_eq!(created.items.len(), 1);
+        assert_eq!(created.total().ok(), order.total().ok());
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn create_with_id_conflict(pool: PgPool) {
+        use order::{ConflictKind, Error};
+
+        let order = order::Order::config(order::OrderConfig {
+            id: order::Id::parse_str("0190f3a2-5c1e-7d40-9a8b-2e1f0c3d4b5a")
+                .expect("Valid order id from fixtures"),
+            items: order::Items::new(vec![mocked_item()]).expect("Valid order items"),
+            metadata: metadata::Metadata::new(),
+        });
+
+        let result = PgOrders::new(pool).create(&order).await;
+        assert!(matches!(result, Err(Error::Conflict(ConflictKind::Id(id))) if id == order.id()));
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn delete_method_works(pool: PgPool) {
+        let id = order::Id::parse_str("0190f3a2-5c1e-7d40-9a8b-2e1f0c3d4b5a")
+            .expect("Valid order id from fixtures");
+
+        let result = PgOrders::new(pool.clone()).delete(id).await;
+        assert!(matches!(result, Ok(order) if order.id() == id));
+
+        let related_items_count: i64 =
+            sqlx::query_scalar("select count(*) from order_item as oi where oi.order_id = $1")
+                .bind(id.uuid())
+                .fetch_one(&pool)
+                .await
+                .expect("Deleted order items count");
+
+        assert_eq!(related_items_count, 0);
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn delete_with_not_found(pool: PgPool) {
+        use order::{Error, NotFoundKind};
+
+        let id = order::Id::parse_str("0190f3b0-0d7e-7a61-b3c2-8f9e0a1b2c3d")
+            .expect("Valid order id not in fixtures");
+
+        let result = PgOrders::new(pool).delete(id).await;
+        assert!(matches!(result, Err(Error::NotFound(NotFoundKind::Id(err_id))) if err_id == id));
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn find_method_works(pool: PgPool) {
+        let id = order::Id::parse_str("0190f3a2-5c1e-7d40-9a8b-2e1f0c3d4b5a")
+            .expect("Valid order id from fixtures");
+
+        let order = PgOrders::new(pool).find(id).await.expect("Found order");
+        assert_eq!(order.id(), id);
+
+        // price snapshot from fixtures differs from the current product price
+        let item = &order.items.as_slice()[0];
+        assert_eq!(item.price(), Money::from_minor(1800, Currency::BRL));
+        assert_eq!(item.extras().len(), 1);
+        assert_eq!(
+            order.total().ok(),
+            Some(Money::from_minor(4000, Currency::BRL))
+        );
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn find_with_not_found(pool: PgPool) {
+        use order::{Error, NotFoundKind};
+
+        let id = order::Id::parse_str("0190f3b0-0d7e-7a61-b3c2-8f9e0a1b2c3d")
+            .expect("Valid order id not in fixtures");
+
+        let result = PgOrders::new(pool).find(id).await;
+        assert!(matches!(result, Err(Error::NotFound(NotFoundKind::Id(err_id))) if err_id == id));
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn list_method_works(pool: PgPool) {
+        use std::num::{NonZeroU32, NonZeroU8};
+
+        let query = order::ListQuery {
+            page: NonZeroU32::new(1).unwrap(),
+            limit: NonZeroU8::new(10).unwrap(),
+        };
+
+        let pagination = PgOrders::new(pool)
+            .list(query.clone())
+            .await
+            .expect("Paginated order list");
+
+        assert_eq!(pagination.count, 1);
+        assert_eq!(pagination.items.len(), 1);
+        assert_eq!(pagination.page, query.page);
+        assert_eq!(pagination.limit, query.limit);
+    }
+
+    fn mocked_item() -> order::Item {
+        let cheddar = extra::Extra::config(extra::ExtraConfig {
+            id: extra::Id::parse_str("0190ec13-15cc-7f53-bc0f-d60f0beea824")
+                .expect("Cheddar id from seed fixtures"),
+            name: extra::Name::new("Cheddar").expect("Valid extra name"),
+            price: Money::from_minor(200, Currency::BRL),
+            catalog_id: None,
+            category: None,
+            metadata: metadata::Metadata::new(),
+        });
+
+        let product = product::Product::config(product::ProductConfig {
+            id: product::Id::parse_str("0190ec14-0af8-71d1-9554-f1e5249ae3a2")
+                .expect("Valid product id from fixtures"),
+            catalog_id: catalog::Id::parse_str("0190ec30-286b-7211-aadb-003fc0449734")
+                .expect("Valid catalog id from fixtures"),
+            name: product::Name::new("Cheese Burger").expect("Valid product name"),
+            price: Money::from_minor(2000, Currency::BRL),
+            kind: product::Kind::Burger,
+            extras: Some(product::Extras::new(vec![cheddar.clone()]).expect("Valid extras")),
+            variants: None,
+            modifiers: None,
+            available: true,
+            schedule: None,
+            metadata: metadata::Metadata::new(),
+        });
+
+        let quantity = Quantity::new(2).expect("Valid quantity");
+        order::Item::new(&product, &[cheddar.id()], quantity).expect("Valid order item")
+    }
+}
@@ -0,0 +1,30 @@
+insert into catalog (id, name, description, created_at, updated_at)
+values
+    ('0190ec30-286b-7211-aadb-003fc0449734', 'Burgers', null, now(), now());
+
+insert into product (id, catalog_id, name, price, kind, created_at, updated_at)
+values
+    ('0190ec14-0af8-71d1-9554-f1e5249ae3a2', '0190ec30-286b-7211-aadb-003fc0449734', 'Cheese Burger', 20.00, 'burger', now(), now());
+
+insert into extra (id, name, price, created_at, updated_at)
+values
+    ('0190ec10-4aa7-7552-ba8f-df997d9f8a8e', 'Hot Sauce', 1.50, now(), now()),
+    ('0190ec13-15cc-7f53-bc0f-d60f0beea824', 'Cheddar', 2.00, now(), now());
+
+insert into product_extras (product_id, extra_id)
+values
+    ('0190ec14-0af8-71d1-9554-f1e5249ae3a2', '0190ec10-4aa7-7552-ba8f-df997d9f8a8e'),
+    ('0190ec14-0af8-71d1-9554-f1e5249ae3a2', '0190ec13-15cc-7f53-bc0f-d60f0beea824');
+
+insert into customer_order (id, created_at, updated_at)
+values
+    ('0190f3a2-5c1e-7d40-9a8b-2e1f0c3d4b5a', now(), now());
+
+insert into order_item (id, order_id, position, product_id, catalog_id, name, price, quantity)
+values
+    -- two Cheese Burgers with Cheddar, priced before any later change
+    ('0190f3a3-1a2b-7c3d-8e4f-5a6b7c8d9e0f', '0190f3a2-5c1e-7d40-9a8b-2e1f0c3d4b5a', 0, '0190ec14-0af8-71d1-9554-f1e5249ae3a2', '0190ec30-286b-7211-aadb-003fc0449734', 'Cheese Burger', 18.00, 2);
+
+insert into order_item_extra (order_item_id, extra_id, name, price)
+values
+    ('0190f3a3-1a2b-7c3d-8e4f-5a6b7c8d9e0f', '0190ec13-15cc-7f53-bc0f-d60f0beea824', 'Cheddar', 2.00);
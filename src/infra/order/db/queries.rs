@@ -0,0 +1,225 @@
+use sqlx::PgExecutor;
+
+use domain::core::money::Currency;
+use domain::order;
+
+use crate::infra::order::OrderWithItemsModel;
+
+#[derive(Clone, Debug)]
+pub(super) struct CountQuery;
+
+impl CountQuery {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'_>) -> Result<u64, sqlx::Error> {
+        let sql = include_str!("./sql/count.sql");
+        let count: i64 = sqlx::query_scalar(sql).fetch_one(exec).await?;
+        Ok(u64::try_from(count).unwrap_or_default())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct CreateQuery<'a> {
+    pub(super) order: &'a order::Order,
+}
+
+impl<'a> CreateQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
+        let sql = include_str!("./sql/create.sql");
+        sqlx::query(sql)
+            .bind(self.order.id().uuid())
+            .bind(self.order.metadata.created_at())
+            .bind(self.order.metadata.updated_at())
+            .execute(exec)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct CreateItemsQuery<'a> {
+    pub(super) id: order::Id,
+    pub(super) items: &'a [order::Item],
+}
+
+impl<'a> CreateItemsQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
+        let len = self.items.len();
+        let mut ids = Vec::with_capacity(len);
+        let mut positions = Vec::with_capacity(len);
+        let mut products_ids = Vec::with_capacity(len);
+        let mut catalogs_ids = Vec::with_capacity(len);
+        let mut names = Vec::with_capacity(len);
+        let mut prices = Vec::with_capacity(len);
+        let mut currencies = Vec::with_capacity(len);
+        let mut quantities = Vec::with_capacity(len);
+
+        for (position, item) in self.items.iter().enumerate() {
+            ids.push(item.id().uuid());
+            positions.push(i16::try_from(position).unwrap_or(i16::MAX));
+            products_ids.push(item.product_id().uuid());
+            catalogs_ids.push(item.catalog_id().uuid());
+            names.push(item.name().as_str());
+            prices.push(item.price().amount());
+            currencies.push(item.price().currency());
+            quantities.push(i16::try_from(item.quantity().value()).unwrap_or(i16::MAX));
+        }
+
+        let sql = include_str!("./sql/items_create.sql");
+        sqlx::query(sql)
+            .bind(self.id.uuid())
+            .bind(ids)
+            .bind(positions)
+            .bind(products_ids)
+            .bind(catalogs_ids)
+            .bind(names)
+            .bind(prices)
+            .bind(currencies.iter().map(Currency::as_str).collect::<Vec<_>>())
+            .bind(quantities)
+            .execute(exec)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct CreateExtrasQuery<'a> {
+    pub(super) items: &'a [order::Item],
+}
+
+impl<'a> CreateExtrasQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
+        let mut items_ids = Vec::new();
+        let mut extras_ids = Vec::new();
+        let mut names = Vec::new();
+        let mut prices = Vec::new();
+        let mut currencies = Vec::new();
+
+        for item in self.items {
+            for extra in item.extras() {
+                items_ids.push(item.id().uuid());
+                extras_ids.push(extra.id.uuid());
+                names.push(extra.name.as_str());
+                prices.push(extra.price.amount());
+                currencies.push(extra.price.currency());
+            }
+        }
+
+        let sql = include_str!("./sql/extras_create.sql");
+        sqlx::query(sql)
+            .bind(items_ids)
+            .bind(extras_ids)
+            .bind(names)
+            .bind(prices)
+            .bind(currencies.iter().map(Currency::as_str).collect::<Vec<_>>())
+            .execute(exec)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct DeleteQuery {
+    pub(super) id: order::Id,
+}
+
+impl DeleteQuery {
+    pub(super) async fn exec(
+        self,
+        exec: impl PgExecutor<'_>,
+    ) -> Result<OrderWithItemsModel, sqlx::Error> {
+        let sql = include_str!("./sql/delete.sql");
+        sqlx::query_as(sql)
+            .bind(self.id.uuid())
+            .fetch_one(exec)
+            .await
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct FindQuery {
+    pub(super) id: order::Id,
+}
+
+impl FindQuery {
+    pub(super) async fn exec(
+        self,
+        exec: impl PgExecutor<'_>,
+    ) -> Result<OrderWithItemsModel, sqlx::Error> {
+        let sql = include_str!("./sql/find.sql");
+        sqlx::query_as(sql)
+            .bind(self.id.uuid())
+            .fetch_one(exec)
+            .await
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct ListQuery(pub(super) order::ListQuery);
+
+impl ListQuery {
+    pub(super) async fn exec(
+        self,
+        exec: impl PgExecutor<'_>,
+    ) -> Result<Vec<OrderWithItemsModel>, sqlx::Error> {
+        let limit = u8::from(self.0.limit);
+        let page = u32::from(self.0.page);
+        let offset = page.saturating_sub(1) * u32::from(limit);
+
+        let sql = include_str!("./sql/list.sql");
+        sqlx::query_as(sql)
+            .bind(i64::from(limit))
+            .bind(i64::from(offset))
+            .fetch_all(exec)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+
+    use super::*;
+
+    #[sqlx::test(fixtures("seed"))]
+    async fn count_query_works(pool: PgPool) {
+        let result = CountQuery.exec(&pool).await;
+        assert_eq!(result.ok(), Some(1u64));
+    }
+
+    #[sqlx::test(fixtures("seed"))]
+    async fn delete_query_works(pool: PgPool) {
+        let id = order::Id::parse_str("0190f3a2-5c1e-7d40-9a8b-2e1f0c3d4b5a")
+            .expect("Valid order id from fixtures");
+
+        let result = DeleteQuery { id }.exec(&pool).await;
+        let deleted = result.expect("Deleted order model");
+        assert_eq!(deleted.id, id.uuid());
+    }
+
+    #[sqlx::test(fixtures("seed"))]
+    async fn find_query_works(pool: PgPool) {
+        let id = order::Id::parse_str("0190f3a2-5c1e-7d40-9a8b-2e1f0c3d4b5a")
+            .expect("Valid order id from fixtures");
+
+        let result = FindQuery { id }.exec(&pool).await;
+        let found = result.expect("Found order model");
+        assert_eq!(found.id, id.uuid());
+        assert_eq!(found.items.len(), 1);
+        assert_eq!(found.items[0].extras.len(), 1);
+    }
+
+    #[sqlx::test(fixtures("seed"))]
+    async fn list_query_works(pool: PgPool) {
+        use std::num::{NonZeroU32, NonZeroU8};
+
+        let query = order::ListQuery {
+            page: NonZeroU32::new(1).unwrap(),
+            limit: NonZeroU8::new(10).unwrap(),
+        };
+
+        let result = ListQuery(query).exec(&pool).await;
+        assert_eq!(result.map(|list| list.len()).ok(), Some(1));
+    }
+}
@@ -0,0 +1 @@
+select count(id) from customer_order
\ No newline at end of file
@@ -0,0 +1,2 @@
+insert into customer_order (id, created_at, updated_at)
+values ($1, $2, $3)
\ No newline at end of file
@@ -0,0 +1,3 @@
+delete from order_with_items as customer_order
+where customer_order.id = $1
+returning customer_order.*
\ No newline at end of file
@@ -0,0 +1,2 @@
+insert into order_item_extra (order_item_id, extra_id, name, price, currency)
+select * from unnest($1::uuid[], $2::uuid[], $3::varchar[], $4::decimal[], $5::char(3)[])
\ No newline at end of file
@@ -0,0 +1,3 @@
+select customer_order.*
+from order_with_items as customer_order
+where customer_order.id = $1
\ No newline at end of file
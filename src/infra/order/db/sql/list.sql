@@ -0,0 +1,4 @@
+select customer_order.*
+from order_with_items as customer_order
+order by customer_order.created_at desc
+limit $1 offset $2
\ No newline at end of file
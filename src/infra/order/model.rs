@@ -0,0 +1,97 @@
+use serde::Deserialize;
+use sqlx::types::{Decimal, Json, Uuid};
+use sqlx::FromRow;
+use time::OffsetDateTime;
+
+use domain::catalog;
+use domain::core::metadata;
+use domain::core::money::{Currency, Money};
+use domain::core::quantity::Quantity;
+use domain::extra;
+use domain::order;
+use domain::product;
+
+#[derive(Clone, Debug, Deserialize, FromRow)]
+pub struct OrderWithItemsModel {
+    pub id: Uuid,
+    pub items: Json<Vec<OrderItemModel>>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime,
+}
+
+impl OrderWithItemsModel {
+    pub fn try_into_entity(self) -> Result<order::Order, Box<dyn std::error::Error>> {
+        let items = self
+            .items
+            .0
+            .into_iter()
+            .map(OrderItemModel::try_into_entity)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let items = order::Items::new(items)?;
+        let metadata = metadata::Metadata::configured(self.created_at, self.updated_at)?;
+        let order = order::Order::config(order::OrderConfig {
+            id: order::Id::from(self.id),
+            items,
+            metadata,
+        });
+
+        Ok(order)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct OrderItemModel {
+    pub id: Uuid,
+    pub product_id: Uuid,
+    pub catalog_id: Uuid,
+    pub name: String,
+    pub price: Decimal,
+    pub currency: String,
+    pub quantity: i16,
+    pub extras: Vec<OrderItemExtraModel>,
+}
+
+impl OrderItemModel {
+    pub fn try_into_entity(self) -> Result<order::Item, Box<dyn std::error::Error>> {
+        let name = product::Name::new(self.name)?;
+        let quantity = Quantity::new(u16::try_from(self.quantity)?)?;
+        let currency = Currency::parse_str(&self.currency)?;
+        let extras = self
+            .extras
+            .into_iter()
+            .map(OrderItemExtraModel::try_into_entity)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(order::Item::config(order::ItemConfig {
+            id: order::ItemId::from(self.id),
+            product_id: product::Id::from(self.product_id),
+            catalog_id: catalog::Id::from(self.catalog_id),
+            name,
+            price: Money::new(self.price, currency),
+            quantity,
+            extras,
+        }))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct OrderItemExtraModel {
+    pub extra_id: Uuid,
+    pub name: String,
+    pub price: Decimal,
+    pub currency: String,
+}
+
+impl OrderItemExtraModel {
+    pub fn try_into_entity(self) -> Result<order::ItemExtra, Box<dyn std::error::Error>> {
+        let currency = Currency::parse_str(&self.currency)?;
+        Ok(order::ItemExtra {
+            id: extra::Id::from(self.extra_id),
+            name: extra::Name::new(self.name)?,
+            price: Money::new(self.price, currency),
+        })
+    }
+}
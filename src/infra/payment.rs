@@ -0,0 +1,9 @@
+mod any;
+mod db;
+mod fake;
+mod model;
+
+pub use any::AnyPaymentProvider;
+pub use db::PgPayments;
+pub use fake::FakePaymentProvider;
+pub(super) use model::PaymentModel;
@@ -0,0 +1,77 @@
+use domain::core::money::Money;
+use domain::payment::{self, PaymentProvider};
+
+use super::FakePaymentProvider;
+
+/// Whichever [`payment::PaymentProvider`] the server was configured with,
+/// so supporting another gateway only takes a new variant
+#[derive(Clone, Debug)]
+pub enum AnyPaymentProvider {
+    Fake(FakePaymentProvider),
+}
+
+impl AnyPaymentProvider {
+    /// Provider known by `name`, if it is supported
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "fake" => Some(Self::Fake(FakePaymentProvider::new())),
+            _ => None,
+        }
+    }
+}
+
+impl PaymentProvider for AnyPaymentProvider {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Fake(provider) => provider.name(),
+        }
+    }
+
+    async fn authorize(
+        &mut self,
+        id: payment::Id,
+        amount: Money,
+    ) -> Result<payment::Reference, payment::ProviderError> {
+        match self {
+            Self::Fake(provider) => provider.authorize(id, amount).await,
+        }
+    }
+
+    async fn capture(
+        &mut self,
+        reference: &payment::Reference,
+        amount: Money,
+    ) -> Result<(), payment::ProviderError> {
+        match self {
+            Self::Fake(provider) => provider.capture(reference, amount).await,
+        }
+    }
+
+    async fn refund(
+        &mut self,
+        reference: &payment::Reference,
+        amount: Money,
+    ) -> Result<(), payment::ProviderError> {
+        match self {
+            Self::Fake(provider) => provider.refund(reference, amount).await,
+        }
+    }
+
+    async fn void(&mut self, reference: &payment::Reference) -> Result<(), payment::ProviderError> {
+        match self {
+            Self::Fake(provider) => provider.void(reference).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_name_works() {
+        let provider = AnyPaymentProvider::from_name("fake").expect("Supported provider");
+        assert_eq!(provider.name(), "fake");
+        assert!(AnyPaymentProvider::from_name("stripe").is_none());
+    }
+}
@@ -0,0 +1,122 @@
+mod queries;
+
+use sqlx::PgPool;
+
+use domain::payment;
+
+#[derive(Clone, Debug)]
+pub struct PgPayments {
+    pool: PgPool,
+}
+
+impl PgPayments {
+    const PK: &'static str = "pk_payment";
+
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn is_pk_error(err: &sqlx::Error) -> bool {
+        err.as_database_error()
+            .is_some_and(|db_err| db_err.constraint() == Some(Self::PK))
+    }
+}
+
+impl payment::Repository for PgPayments {
+    async fn create(&mut self, payment: &payment::Payment) -> Result<(), payment::Error> {
+        let query = queries::CreateQuery { payment };
+        query.exec(&self.pool).await.map_err(|err| {
+            if Self::is_pk_error(&err) {
+                payment::Error::id_conflict(payment.id())
+            } else {
+                payment::Error::any(err)
+            }
+        })
+    }
+
+    async fn find(&self, id: payment::Id) -> Result<payment::Payment, payment::Error> {
+        let query = queries::FindQuery { id };
+        let model = query.exec(&self.pool).await.map_err(|err| match &err {
+            sqlx::Error::RowNotFound => payment::Error::id_not_found(id),
+            _ => payment::Error::any(err),
+        })?;
+
+        model.try_into_entity().map_err(payment::Error::any)
+    }
+
+    async fn update(
+        &mut self,
+        payment: &payment::Payment,
+        status: payment::Status,
+    ) -> Result<(), payment::Error> {
+        // no row means the payment is gone or left `status` since it was read
+        let query = queries::UpdateQuery { payment, status };
+        query.exec(&self.pool).await.map_err(|err| match &err {
+            sqlx::Error::RowNotFound => payment::Error::status_conflict(payment.id(), status),
+            _ => payment::Error::any(err),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+
+    use domain::payment::Repository;
+
+    use super::*;
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn find_method_works(pool: PgPool) {
+        let id = payment::Id::parse_str("01914b22-6d7e-7f8a-8b1c-2d3e4f5a6b72")
+            .expect("Valid payment id from fixtures");
+
+        let result = PgPayments::new(pool).find(id).await;
+        let payment = result.expect("Payment from fixtures");
+        assert_eq!(payment.status(), payment::Status::Failed);
+        assert_eq!(payment.failure(), Some("Amount above fake provider limit"));
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn update_with_unknown_payment(pool: PgPool) {
+        use domain::core::money::{Currency, Money};
+        use payment::{ConflictKind, Error};
+
+        let payment = payment::Payment::new(Money::from_minor(1000, Currency::BRL), "fake");
+
+        let result = PgPayments::new(pool)
+            .update(&payment, payment::Status::Pending)
+            .await;
+        assert!(matches!(
+            result,
+            Err(Error::Conflict(ConflictKind::Status { id: err_id, .. })) if err_id == payment.id()
+        ));
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn update_with_status_conflict(pool: PgPool) {
+        use payment::{ConflictKind, Error};
+
+        let id = payment::Id::parse_str("01914b21-5c6d-7e8f-9a0b-1c2d3e4f5a61")
+            .expect("Valid payment id from fixtures");
+
+        let mut repository = PgPayments::new(pool);
+        let mut payment = repository.find(id).await.expect("Found payment");
+        let mut stale = payment.clone();
+        payment.start_refund().expect("Refunding payment");
+        repository
+            .update(&payment, payment::Status::Captured)
+            .await
+            .expect("Updated payment");
+
+        stale.start_refund().expect("Refunding payment");
+        let result = repository.update(&stale, payment::Status::Captured).await;
+        assert!(matches!(
+            result,
+            Err(Error::Conflict(ConflictKind::Status {
+                id: err_id,
+                expected: payment::Status::Captured
+            })) if err_id == id
+        ));
+    }
+}
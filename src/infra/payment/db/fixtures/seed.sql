@@ -0,0 +1,4 @@
+insert into payment (id, amount, currency, provider, status, reference, failure, created_at, updated_at)
+values
+    ('01914b21-5c6d-7e8f-9a0b-1c2d3e4f5a61', 45.9, 'BRL', 'fake', 'captured', 'fake_01914b215c6d7e8f9a0b1c2d3e4f5a61', null, now(), now()),
+    ('01914b22-6d7e-7f8a-8b1c-2d3e4f5a6b72', 1200, 'BRL', 'fake', 'failed', null, 'Amount above fake provider limit', now(), now());
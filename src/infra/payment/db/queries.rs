@@ -0,0 +1,121 @@
+use sqlx::PgExecutor;
+
+use domain::payment;
+
+use crate::infra::payment::PaymentModel;
+
+#[derive(Clone, Debug)]
+pub(super) struct CreateQuery<'a> {
+    pub(super) payment: &'a payment::Payment,
+}
+
+impl<'a> CreateQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
+        let amount = self.payment.amount();
+        let sql = include_str!("./sql/create.sql");
+        sqlx::query(sql)
+            .bind(self.payment.id().uuid())
+            .bind(amount.amount())
+            .bind(amount.currency().as_str())
+            .bind(self.payment.provider())
+            .bind(self.payment.status().as_str())
+            .bind(self.payment.reference().map(payment::Reference::as_str))
+            .bind(self.payment.failure())
+            .bind(self.payment.metadata.created_at())
+            .bind(self.payment.metadata.updated_at())
+            .execute(exec)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct FindQuery {
+    pub(super) id: payment::Id,
+}
+
+impl FindQuery {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'_>) -> Result<PaymentModel, sqlx::Error> {
+        let sql = include_str!("./sql/find.sql");
+        sqlx::query_as(sql)
+            .bind(self.id.uuid())
+            .fetch_one(exec)
+            .await
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct UpdateQuery<'a> {
+    pub(super) payment: &'a payment::Payment,
+    pub(super) status: payment::Status,
+}
+
+impl<'a> UpdateQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
+        let sql = include_str!("./sql/update.sql");
+        let result = sqlx::query(sql)
+            .bind(self.payment.status().as_str())
+            .bind(self.payment.reference().map(payment::Reference::as_str))
+            .bind(self.payment.failure())
+            .bind(self.payment.metadata.updated_at())
+            .bind(self.payment.id().uuid())
+            .bind(self.status.as_str())
+            .execute(exec)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(sqlx::Error::RowNotFound);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+
+    use domain::core::money::{Currency, Money};
+
+    use super::*;
+
+    #[sqlx::test(fixtures("seed"))]
+    async fn create_query_works(pool: PgPool) {
+        let payment = payment::Payment::new(Money::from_minor(2590, Currency::USD), "fake");
+
+        let result = CreateQuery { payment: &payment }.exec(&pool).await;
+        assert!(result.is_ok());
+
+        let model = FindQuery { id: payment.id() }
+            .exec(&pool)
+            .await
+            .expect("Created payment");
+        assert_eq!(model.status, "pending");
+        assert_eq!(model.currency, "USD");
+    }
+
+    #[sqlx::test(fixtures("seed"))]
+    async fn update_query_works(pool: PgPool) {
+        let id = payment::Id::parse_str("01914b21-5c6d-7e8f-9a0b-1c2d3e4f5a61")
+            .expect("Valid payment id from fixtures");
+        let mut payment = FindQuery { id }
+            .exec(&pool)
+            .await
+            .expect("Payment from fixtures")
+            .try_into_entity()
+            .expect("Valid payment from fixtures");
+        payment.start_refund().expect("Refunding payment");
+
+        let result = UpdateQuery {
+            payment: &payment,
+            status: payment::Status::Captured,
+        }
+        .exec(&pool)
+        .await;
+        assert!(result.is_ok());
+
+        let model = FindQuery { id }.exec(&pool).await.expect("Updated payment");
+        assert_eq!(model.status, "refunding");
+    }
+}
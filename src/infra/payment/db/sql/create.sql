@@ -0,0 +1,2 @@
+insert into payment (id, amount, currency, provider, status, reference, failure, created_at, updated_at)
+values ($1, $2, $3, $4, $5, $6, $7, $8, $9)
\ No newline at end of file
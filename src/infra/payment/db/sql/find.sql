@@ -0,0 +1 @@
+select payment.* from payment where id = $1
\ No newline at end of file
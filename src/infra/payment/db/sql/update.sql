@@ -0,0 +1,4 @@
+update payment
+set status = $1, reference = $2, failure = $3, updated_at = $4
+where id = $5
+and status = $6
\ No newline at end of file
@@ -0,0 +1,215 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use rust_decimal::Decimal;
+
+use domain::core::money::Money;
+use domain::payment;
+
+/// Deterministic in-process [`payment::PaymentProvider`] for tests and local
+/// development. It declines any amount above its limit and keeps charges in
+/// memory, shared between its clones
+#[derive(Clone, Debug)]
+pub struct FakePaymentProvider {
+    limit: Decimal,
+    charges: Arc<Mutex<HashMap<payment::Reference, FakeCharge>>>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct FakeCharge {
+    authorized: Money,
+    captured: Option<Money>,
+    refunded: Option<Money>,
+    voided: bool,
+}
+
+impl FakePaymentProvider {
+    pub const DEFAULT_LIMIT: Decimal = Decimal::ONE_THOUSAND;
+
+    pub fn new() -> Self {
+        Self::with_limit(Self::DEFAULT_LIMIT)
+    }
+
+    /// Create a fake provider declining amounts above `limit`, regardless of
+    /// their currency
+    pub fn with_limit(limit: Decimal) -> Self {
+        Self {
+            limit,
+            charges: Arc::default(),
+        }
+    }
+
+    fn with_charge<T>(
+        &self,
+        reference: &payment::Reference,
+        f: impl FnOnce(&mut FakeCharge) -> Result<T, payment::ProviderError>,
+    ) -> Result<T, payment::ProviderError> {
+        let mut charges = self
+            .charges
+            .lock()
+            .map_err(|_| payment::ProviderError::Unavailable(Box::from("Poisoned fake charges")))?;
+
+        let charge = charges
+            .get_mut(reference)
+            .ok_or_else(|| declined(&format!("Unknown reference `{reference}`")))?;
+
+        f(charge)
+    }
+}
+
+impl Default for FakePaymentProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl payment::PaymentProvider for FakePaymentProvider {
+    fn name(&self) -> &'static str {
+        "fake"
+    }
+
+    async fn authorize(
+        &mut self,
+        id: payment::Id,
+        amount: Money,
+    ) -> Result<payment::Reference, payment::ProviderError> {
+        if amount.is_negative() || amount.amount().is_zero() {
+            return Err(declined("Amount must be greater than zero"));
+        }
+        if amount.amount() > self.limit {
+            return Err(declined("Amount above fake provider limit"));
+        }
+
+        let reference = payment::Reference::new(format!("fake_{}", id.uuid().simple()))
+            .map_err(|err| payment::ProviderError::Unavailable(Box::from(err.to_string())))?;
+
+        let mut charges = self
+            .charges
+            .lock()
+            .map_err(|_| payment::ProviderError::Unavailable(Box::from("Poisoned fake charges")))?;
+
+        charges.insert(
+            reference.clone(),
+            FakeCharge {
+                authorized: amount,
+                captured: None,
+                refunded: None,
+                voided: false,
+            },
+        );
+
+        Ok(reference)
+    }
+
+    async fn capture(
+        &mut self,
+        reference: &payment::Reference,
+        amount: Money,
+    ) -> Result<(), payment::ProviderError> {
+        self.with_charge(reference, |charge| {
+            if charge.voided || charge.captured.is_some() {
+                return Err(declined("Charge is not authorized"));
+            }
+            if amount != charge.authorized {
+                return Err(declined("Captured amount differs from authorized amount"));
+            }
+
+            charge.captured = Some(amount);
+            Ok(())
+        })
+    }
+
+    async fn refund(
+        &mut self,
+        reference: &payment::Reference,
+        amount: Money,
+    ) -> Result<(), payment::ProviderError> {
+        self.with_charge(reference, |charge| {
+            let Some(captured) = charge.captured else {
+                return Err(declined("Charge is not captured"));
+            };
+
+            let refunded = match charge.refunded {
+                Some(refunded) => refunded.checked_add(amount),
+                None => Ok(amount),
+            }
+            .map_err(|err| declined(&err.to_string()))?;
+
+            if refunded.amount() > captured.amount() {
+                return Err(declined("Refunded amount exceeds captured amount"));
+            }
+
+            charge.refunded = Some(refunded);
+            Ok(())
+        })
+    }
+
+    async fn void(&mut self, reference: &payment::Reference) -> Result<(), payment::ProviderError> {
+        self.with_charge(reference, |charge| {
+            if charge.voided || charge.captured.is_some() {
+                return Err(declined("Charge is not authorized"));
+            }
+
+            charge.voided = true;
+            Ok(())
+        })
+    }
+}
+
+fn declined(reason: &str) -> payment::ProviderError {
+    payment::ProviderError::Declined(Box::from(reason))
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::core::money::Currency;
+    use domain::payment::PaymentProvider;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn charge_lifecycle_works() {
+        let mut provider = FakePaymentProvider::new();
+        let amount = Money::from_minor(4590, Currency::BRL);
+
+        let reference = provider
+            .authorize(payment::Id::new(), amount)
+            .await
+            .expect("Authorized charge");
+        assert!(reference.as_str().starts_with("fake_"));
+
+        assert!(provider.capture(&reference, amount).await.is_ok());
+        assert!(provider
+            .refund(&reference, Money::from_minor(4000, Currency::BRL))
+            .await
+            .is_ok());
+
+        let result = provider
+            .refund(&reference, Money::from_minor(1000, Currency::BRL))
+            .await;
+        assert!(matches!(result, Err(payment::ProviderError::Declined(_))));
+    }
+
+    #[tokio::test]
+    async fn authorize_above_limit_declines() {
+        let mut provider = FakePaymentProvider::with_limit(Decimal::ONE_HUNDRED);
+        let amount = Money::from_minor(10001, Currency::BRL);
+
+        let result = provider.authorize(payment::Id::new(), amount).await;
+        assert!(matches!(result, Err(payment::ProviderError::Declined(_))));
+    }
+
+    #[tokio::test]
+    async fn capture_after_void_declines() {
+        let mut provider = FakePaymentProvider::new();
+        let amount = Money::from_minor(1000, Currency::BRL);
+        let reference = provider
+            .authorize(payment::Id::new(), amount)
+            .await
+            .expect("Authorized charge");
+
+        assert!(provider.void(&reference).await.is_ok());
+        let result = provider.capture(&reference, amount).await;
+        assert!(matches!(result, Err(payment::ProviderError::Declined(_))));
+    }
+}
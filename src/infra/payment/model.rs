@@ -0,0 +1,39 @@
+use sqlx::types::{Decimal, Uuid};
+use sqlx::FromRow;
+use time::OffsetDateTime;
+
+use domain::core::metadata;
+use domain::core::money::{Currency, Money};
+use domain::payment;
+
+#[derive(Clone, Debug, FromRow)]
+pub struct PaymentModel {
+    pub id: Uuid,
+    pub amount: Decimal,
+    pub currency: String,
+    pub provider: String,
+    pub status: String,
+    pub reference: Option<String>,
+    pub failure: Option<String>,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+impl PaymentModel {
+    pub fn try_into_entity(self) -> Result<payment::Payment, Box<dyn std::error::Error>> {
+        let currency = Currency::parse_str(&self.currency)?;
+        let reference = self.reference.map(payment::Reference::new).transpose()?;
+        let metadata = metadata::Metadata::configured(self.created_at, self.updated_at)?;
+        let payment = payment::Payment::config(payment::PaymentConfig {
+            id: payment::Id::from(self.id),
+            amount: Money::new(self.amount, currency),
+            provider: self.provider,
+            status: payment::Status::parse_str(&self.status)?,
+            reference,
+            failure: self.failure,
+            metadata,
+        });
+
+        Ok(payment)
+    }
+}
@@ -2,4 +2,6 @@ mod db;
 mod model;
 
 pub use db::PgProducts;
-pub(super) use model::ProductModel;
+pub(super) use model::{
+    ModifierModel, ProductModel, ScheduleModel, ScheduleWindowModel, VariantModel,
+};
@@ -3,7 +3,11 @@ mod queries;
 use sqlx::PgPool;
 
 use domain::catalog;
+use domain::modifier;
 use domain::product;
+use domain::variant;
+
+use super::ProductModel;
 
 #[derive(Clone, Debug)]
 pub struct PgProducts {
@@ -14,6 +18,8 @@ impl PgProducts {
     const PK: &'static str = "pk_product";
     const AK_NAME: &'static str = "ak_product_name";
     const FK_CATALOG_ID: &'static str = "fk_product_catalog_id";
+    const AK_VARIANT_NAME: &'static str = "ak_variant_product_id_name";
+    const AK_MODIFIER_NAME: &'static str = "ak_modifier_group_product_id_name";
 
     pub fn new(pool: PgPool) -> Self {
         Self { pool }
@@ -33,6 +39,16 @@ impl PgProducts {
         err.as_database_error()
             .is_some_and(|db_err| db_err.constraint() == Some(Self::FK_CATALOG_ID))
     }
+
+    fn is_ak_variant_name_error(err: &sqlx::Error) -> bool {
+        err.as_database_error()
+            .is_some_and(|db_err| db_err.constraint() == Some(Self::AK_VARIANT_NAME))
+    }
+
+    fn is_ak_modifier_name_error(err: &sqlx::Error) -> bool {
+        err.as_database_error()
+            .is_some_and(|db_err| db_err.constraint() == Some(Self::AK_MODIFIER_NAME))
+    }
 }
 
 impl product::Repository for PgProducts {
@@ -54,7 +70,7 @@ impl product::Repository for PgProducts {
 
         let bind_extras_query = queries::BindExtrasQuery {
             id: product.id(),
-            extras: product.extras.as_slice(),
+            extras: &product.extras,
         };
 
         bind_extras_query
@@ -93,6 +109,41 @@ impl product::Repository for PgProducts {
         model.try_into_entity().map_err(product::Error::any)
     }
 
+    async fn list(&self, query: product::ListQuery) -> Result<product::Pagination, product::Error> {
+        if let Some(catalog_id) = query.catalog_id {
+            let exists = queries::CatalogExistsQuery { catalog_id }
+                .exec(&self.pool)
+                .await
+                .map_err(product::Error::any)?;
+            if !exists {
+                return Err(product::Error::catalog_not_found(catalog_id));
+            }
+        }
+
+        let count = queries::CountQuery(&query)
+            .exec(&self.pool)
+            .await
+            .map_err(product::Error::any)?;
+
+        let models = queries::ListQuery(&query)
+            .exec(&self.pool)
+            .await
+            .map_err(product::Error::any)?;
+
+        let products = models
+            .into_iter()
+            .map(ProductModel::try_into_entity)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(product::Error::any)?;
+
+        Ok(product::Pagination {
+            count,
+            page: query.page,
+            limit: query.limit,
+            items: products,
+        })
+    }
+
     async fn update(&mut self, product: &product::Product) -> Result<(), product::Error> {
         let mut trx = self.pool.begin().await.map_err(product::Error::any)?;
 
@@ -109,7 +160,7 @@ impl product::Repository for PgProducts {
 
         let bind_extras_query = queries::BindExtrasQuery {
             id: product.id(),
-            extras: product.extras.as_slice(),
+            extras: &product.extras,
         };
 
         bind_extras_query
@@ -129,13 +180,121 @@ impl product::Repository for PgProducts {
 
         trx.commit().await.map_err(product::Error::any)
     }
+
+    async fn create_variant(&mut self, variant: &variant::Variant) -> Result<(), product::Error> {
+        let query = queries::CreateVariantQuery { variant };
+        query.exec(&self.pool).await.map_err(|err| {
+            if Self::is_ak_variant_name_error(&err) {
+                product::Error::variants(variant::VariantsError::Name(variant.name.clone()))
+            } else {
+                product::Error::any(err)
+            }
+        })
+    }
+
+    async fn delete_variant(
+        &mut self,
+        id: variant::Id,
+        product_id: product::Id,
+    ) -> Result<variant::Variant, product::Error> {
+        let query = queries::DeleteVariantQuery { id, product_id };
+        let model = query.exec(&self.pool).await.map_err(|err| match &err {
+            sqlx::Error::RowNotFound => product::Error::variant_not_found(id),
+            _ => product::Error::any(err),
+        })?;
+
+        model.try_into_entity().map_err(product::Error::any)
+    }
+
+    async fn update_variant(&mut self, variant: &variant::Variant) -> Result<(), product::Error> {
+        let query = queries::UpdateVariantQuery { variant };
+        query.exec(&self.pool).await.map_err(|err| {
+            if matches!(err, sqlx::Error::RowNotFound) {
+                product::Error::variant_not_found(variant.id())
+            } else if Self::is_ak_variant_name_error(&err) {
+                product::Error::variants(variant::VariantsError::Name(variant.name.clone()))
+            } else {
+                product::Error::any(err)
+            }
+        })
+    }
+
+    async fn create_modifier(&mut self, group: &modifier::Group) -> Result<(), product::Error> {
+        let mut trx = self.pool.begin().await.map_err(product::Error::any)?;
+
+        let create_query = queries::CreateModifierQuery { group };
+        create_query.exec(trx.as_mut()).await.map_err(|err| {
+            if Self::is_ak_modifier_name_error(&err) {
+                product::Error::modifiers(modifier::GroupsError::Name(group.name.clone()))
+            } else {
+                product::Error::any(err)
+            }
+        })?;
+
+        let bind_extras_query = queries::BindModifierExtrasQuery { group };
+        bind_extras_query
+            .exec(trx.as_mut())
+            .await
+            .map_err(product::Error::any)?;
+
+        trx.commit().await.map_err(product::Error::any)
+    }
+
+    async fn delete_modifier(
+        &mut self,
+        id: modifier::Id,
+        product_id: product::Id,
+    ) -> Result<modifier::Group, product::Error> {
+        let query = queries::DeleteModifierQuery { id, product_id };
+        let model = query.exec(&self.pool).await.map_err(|err| match &err {
+            sqlx::Error::RowNotFound => product::Error::modifier_not_found(id),
+            _ => product::Error::any(err),
+        })?;
+
+        model.try_into_entity().map_err(product::Error::any)
+    }
+
+    async fn update_modifier(&mut self, group: &modifier::Group) -> Result<(), product::Error> {
+        let mut trx = self.pool.begin().await.map_err(product::Error::any)?;
+
+        let update_query = queries::UpdateModifierQuery { group };
+        update_query.exec(trx.as_mut()).await.map_err(|err| {
+            if matches!(err, sqlx::Error::RowNotFound) {
+                product::Error::modifier_not_found(group.id())
+            } else if Self::is_ak_modifier_name_error(&err) {
+                product::Error::modifiers(modifier::GroupsError::Name(group.name.clone()))
+            } else {
+                product::Error::any(err)
+            }
+        })?;
+
+        let unbind_extras_query = queries::UnbindModifierExtrasQuery { id: group.id() };
+        unbind_extras_query
+            .exec(trx.as_mut())
+            .await
+            .map_err(product::Error::any)?;
+
+        let bind_extras_query = queries::BindModifierExtrasQuery { group };
+        bind_extras_query
+            .exec(trx.as_mut())
+            .await
+            .map_err(product::Error::any)?;
+
+        trx.commit().await.map_err(product::Error::any)
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::num::{NonZeroU32, NonZeroU8};
+
     use sqlx::PgPool;
 
     use domain::core::metadata;
+    use domain::core::money::{Currency, Money};
+    use domain::core::quantity::Quantity;
+    use domain::core::sort::Sort;
+    use domain::extra;
     use domain::product::Repository;
 
     use super::*;
@@ -146,7 +305,7 @@ mod tests {
             catalog::Id::parse_str("0190ec30-286b-7211-aadb-003fc0449734")
                 .expect("Valid catalog id from fixtures"),
             product::Name::new("Cheese Bacon").expect("Valid product name not in fixtures"),
-            product::Price::from_cents(2100),
+            Money::from_minor(2100, Currency::BRL),
             product::Kind::Burger,
             product::Extras::default(),
         );
@@ -155,6 +314,49 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn create_with_schedule(pool: PgPool) {
+        use domain::core::schedule::{Weekdays, Window, Zone};
+        use time::macros::{date, time};
+        use time::Weekday;
+
+        let zone = Zone::parse_str("America/Sao_Paulo").expect("Valid zone");
+        let weekdays =
+            Weekdays::new(&[Weekday::Saturday, Weekday::Sunday]).expect("Valid weekdays");
+        let window = Window::new(weekdays, time!(8:00), time!(11:30), zone).expect("Valid window");
+        let schedule = product::Schedule::new(
+            vec![window],
+            Some(date!(2024 - 12 - 01)),
+            Some(date!(2025 - 02 - 28)),
+            zone,
+        )
+        .expect("Valid schedule");
+
+        let mut product = product::Product::new(
+            catalog::Id::parse_str("0190ec30-286b-7211-aadb-003fc0449734")
+                .expect("Valid catalog id from fixtures"),
+            product::Name::new("Brunch Burger").expect("Valid product name not in fixtures"),
+            Money::from_minor(2100, Currency::BRL),
+            product::Kind::Burger,
+            product::Extras::default(),
+        );
+        product.available = false;
+        product.schedule = Some(schedule);
+
+        let mut pg_products = PgProducts::new(pool);
+        pg_products
+            .create(&product)
+            .await
+            .expect("Product should be created");
+
+        let found_product = pg_products
+            .find(product.id(), product.catalog_id())
+            .await
+            .expect("Created product should be found");
+        assert!(!found_product.available);
+        assert_eq!(found_product.schedule, product.schedule);
+    }
+
     #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
     async fn create_with_id_conflict(pool: PgPool) {
         use product::{ConflictKind, Error};
@@ -165,9 +367,13 @@ mod tests {
             catalog_id: catalog::Id::parse_str("0190ec30-286b-7211-aadb-003fc0449734")
                 .expect("Valid catalog id from fixtures"),
             name: product::Name::new("Cheese Bacon").expect("Valid product name not in fixtures"),
-            price: product::Price::from_cents(2100),
+            price: Money::from_minor(2100, Currency::BRL),
             kind: product::Kind::Burger,
             extras: None,
+            variants: None,
+            modifiers: None,
+            available: true,
+            schedule: None,
             metadata: metadata::Metadata::new(),
         });
 
@@ -183,7 +389,7 @@ mod tests {
             catalog::Id::parse_str("0190ec30-286b-7211-aadb-003fc0449734")
                 .expect("Valid catalog id from fixtures"),
             product::Name::new("Cheese Burger").expect("Valid product name from fixtures"),
-            product::Price::from_cents(2100),
+            Money::from_minor(2100, Currency::BRL),
             product::Kind::Burger,
             product::Extras::default(),
         );
@@ -202,7 +408,7 @@ mod tests {
             catalog::Id::parse_str("0190fbba-d10a-73f0-bc03-3d1a44592ccf")
                 .expect("Valid catalog id not in fixtures"),
             product::Name::new("Cheese Bacon").expect("Valid product name from fixtures"),
-            product::Price::from_cents(2100),
+            Money::from_minor(2100, Currency::BRL),
             product::Kind::Burger,
             product::Extras::default(),
         );
@@ -298,9 +504,13 @@ mod tests {
             catalog_id: catalog::Id::parse_str("0190ec30-286b-7211-aadb-003fc0449734")
                 .expect("Valid catalog id from fixtures"),
             name: product::Name::new("Cheese Bacon").expect("Valid product name not in fixtures"),
-            price: product::Price::from_cents(2325),
+            price: Money::from_minor(2325, Currency::BRL),
             kind: product::Kind::Burger,
             extras: Some(product::Extras::default()),
+            variants: None,
+            modifiers: None,
+            available: true,
+            schedule: None,
             metadata: metadata::Metadata::new(),
         });
 
@@ -330,9 +540,13 @@ mod tests {
             catalog_id: catalog::Id::parse_str("0190ec30-286b-7211-aadb-003fc0449734")
                 .expect("Valid catalog id from fixtures"),
             name: product::Name::new("Cheese Bacon").expect("Valid product name not in fixtures"),
-            price: product::Price::from_cents(2325),
+            price: Money::from_minor(2325, Currency::BRL),
             kind: product::Kind::Burger,
             extras: Some(product::Extras::default()),
+            variants: None,
+            modifiers: None,
+            available: true,
+            schedule: None,
             metadata: metadata::Metadata::new(),
         });
 
@@ -353,9 +567,13 @@ mod tests {
             catalog_id: catalog::Id::parse_str("0190ec30-286b-7211-aadb-003fc0449734")
                 .expect("Valid catalog id from fixtures"),
             name: product::Name::new("Cheese Salad").expect("Valid product name not in fixtures"),
-            price: product::Price::from_cents(2150),
+            price: Money::from_minor(2150, Currency::BRL),
             kind: product::Kind::Burger,
             extras: Some(product::Extras::default()),
+            variants: None,
+            modifiers: None,
+            available: true,
+            schedule: None,
             metadata: metadata::Metadata::new(),
         });
 
@@ -364,4 +582,313 @@ mod tests {
             matches!(result, Err(Error::Conflict(ConflictKind::Name(name))) if name == product.name)
         );
     }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn create_variant_method_works(pool: PgPool) {
+        let id = product::Id::parse_str("0190ec14-0af8-71d1-9554-f1e5249ae3a2")
+            .expect("Valid product id from fixtures");
+
+        let catalog_id = catalog::Id::parse_str("0190ec30-286b-7211-aadb-003fc0449734")
+            .expect("Valid catalog id from fixtures");
+
+        let variant = variant::Variant::new(
+            id,
+            variant::Name::new("Large").expect("Valid variant name"),
+            variant::Pricing::Delta,
+            Money::from_minor(450, Currency::BRL),
+        );
+
+        let mut products = PgProducts::new(pool);
+        let result = products.create_variant(&variant).await;
+        assert!(result.is_ok());
+
+        let found = products
+            .find(id, catalog_id)
+            .await
+            .expect("Product from fixtures");
+        let found_variant = found.variants.find(variant.id());
+        assert!(matches!(found_variant, Some(found_variant) if found_variant.name == variant.name));
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn create_variant_with_name_conflict(pool: PgPool) {
+        use product::{ConflictKind, Error};
+
+        let id = product::Id::parse_str("0190ec14-0af8-71d1-9554-f1e5249ae3a2")
+            .expect("Valid product id from fixtures");
+
+        let name = variant::Name::new("Large").expect("Valid variant name");
+        let price = Money::from_minor(2600, Currency::BRL);
+
+        let mut products = PgProducts::new(pool);
+        let variant = variant::Variant::new(id, name.clone(), variant::Pricing::Fixed, price);
+        products
+            .create_variant(&variant)
+            .await
+            .expect("Variant not in fixtures");
+
+        let duplicate = variant::Variant::new(id, name, variant::Pricing::Fixed, price);
+        let result = products.create_variant(&duplicate).await;
+        assert!(matches!(
+            result,
+            Err(Error::Conflict(ConflictKind::Variants(
+                variant::VariantsError::Name(name)
+            ))) if name == variant.name
+        ));
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn create_modifier_method_works(pool: PgPool) {
+        let id = product::Id::parse_str("0190ec14-0af8-71d1-9554-f1e5249ae3a2")
+            .expect("Valid product id from fixtures");
+
+        let catalog_id = catalog::Id::parse_str("0190ec30-286b-7211-aadb-003fc0449734")
+            .expect("Valid catalog id from fixtures");
+
+        let extra_id = extra::Id::parse_str("0190ec10-4aa7-7552-ba8f-df997d9f8a8e")
+            .expect("Valid extra id bound to product in fixtures");
+
+        let group = modifier::Group::new(
+            id,
+            modifier::Name::new("Sauces").expect("Valid modifier group name"),
+            modifier::Limits::new(1, 1).expect("Valid modifier group limits"),
+            modifier::Options::new(vec![extra_id]).expect("Valid modifier group options"),
+        );
+
+        let mut products = PgProducts::new(pool);
+        let result = products.create_modifier(&group).await;
+        assert!(result.is_ok());
+
+        let found = products
+            .find(id, catalog_id)
+            .await
+            .expect("Product from fixtures");
+        let found_group = found.modifiers.find(group.id());
+        assert!(
+            matches!(found_group, Some(found_group) if found_group.options.as_slice() == [extra_id])
+        );
+
+        let deleted = products
+            .delete_modifier(group.id(), id)
+            .await
+            .expect("Created modifier group");
+        assert_eq!(deleted.options.as_slice(), [extra_id]);
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn create_modifier_with_unbound_extra(pool: PgPool) {
+        use product::Error;
+
+        let id = product::Id::parse_str("0190ec14-0af8-71d1-9554-f1e5249ae3a2")
+            .expect("Valid product id from fixtures");
+
+        let extra_id = extra::Id::parse_str("0190ec13-15cc-7f53-bc0f-d60f0beea824")
+            .expect("Valid extra id not bound to product in fixtures");
+
+        let group = modifier::Group::new(
+            id,
+            modifier::Name::new("Toppings").expect("Valid modifier group name"),
+            modifier::Limits::new(0, 2).expect("Valid modifier group limits"),
+            modifier::Options::new(vec![extra_id]).expect("Valid modifier group options"),
+        );
+
+        let result = PgProducts::new(pool).create_modifier(&group).await;
+        assert!(matches!(result, Err(Error::Internal(_))));
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn update_with_extra_max_quantity(pool: PgPool) {
+        let id = product::Id::parse_str("0190ec14-0af8-71d1-9554-f1e5249ae3a2")
+            .expect("Valid product id from fixtures");
+
+        let catalog_id = catalog::Id::parse_str("0190ec30-286b-7211-aadb-003fc0449734")
+            .expect("Valid catalog id from fixtures");
+
+        let extra_id = extra::Id::parse_str("0190ec10-4aa7-7552-ba8f-df997d9f8a8e")
+            .expect("Valid extra id bound to product in fixtures");
+
+        let mut products = PgProducts::new(pool);
+        let mut product = products
+            .find(id, catalog_id)
+            .await
+            .expect("Product from fixtures");
+        assert_eq!(product.extras.max_quantity(extra_id).value(), Quantity::MAX);
+
+        let double = Quantity::new(2).expect("Valid quantity");
+        product
+            .extras
+            .set_max_quantity(extra_id, double)
+            .expect("Extra bound to product in fixtures");
+        products
+            .update(&product)
+            .await
+            .expect("Product from fixtures");
+
+        let found = products
+            .find(id, catalog_id)
+            .await
+            .expect("Product from fixtures");
+        assert_eq!(found.extras.max_quantity(extra_id), double);
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn update_with_extra_price_override(pool: PgPool) {
+        let id = product::Id::parse_str("0190ec14-0af8-71d1-9554-f1e5249ae3a2")
+            .expect("Valid product id from fixtures");
+
+        let catalog_id = catalog::Id::parse_str("0190ec30-286b-7211-aadb-003fc0449734")
+            .expect("Valid catalog id from fixtures");
+
+        let extra_id = extra::Id::parse_str("0190ec10-4aa7-7552-ba8f-df997d9f8a8e")
+            .expect("Valid extra id bound to product in fixtures");
+
+        let mut products = PgProducts::new(pool);
+        let mut product = products
+            .find(id, catalog_id)
+            .await
+            .expect("Product from fixtures");
+
+        let price = Money::from_minor(275, Currency::BRL);
+        product
+            .extras
+            .override_price(extra_id, price)
+            .expect("Extra bound to product in fixtures");
+        products
+            .update(&product)
+            .await
+            .expect("Product from fixtures");
+
+        // updating product again keeps the overridden price
+        let mut found = products
+            .find(id, catalog_id)
+            .await
+            .expect("Product from fixtures");
+        found.metadata.update();
+        products
+            .update(&found)
+            .await
+            .expect("Product from fixtures");
+
+        let found = products
+            .find(id, catalog_id)
+            .await
+            .expect("Product from fixtures");
+        assert!(found.extras.is_overridden(extra_id));
+        assert_eq!(
+            found.extras.find(extra_id).map(|extra| extra.price),
+            Some(price)
+        );
+    }
+
+    fn list_query() -> product::ListQuery {
+        product::ListQuery {
+            page: NonZeroU32::new(1).unwrap(),
+            limit: NonZeroU8::new(10).unwrap(),
+            catalog_id: None,
+            kind: None,
+            name: None,
+            min_price: None,
+            max_price: None,
+            extra_id: None,
+            enabled: None,
+            sort: Sort::default(),
+        }
+    }
+
+    async fn list_names(products: &PgProducts, query: product::ListQuery) -> Vec<String> {
+        let pagination = products.list(query).await.expect("Filtered products");
+        pagination
+            .items
+            .iter()
+            .map(|product| product.name.as_str().to_string())
+            .collect()
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn list_method_works(pool: PgPool) {
+        let query = product::ListQuery {
+            limit: NonZeroU8::new(2).unwrap(),
+            ..list_query()
+        };
+
+        let result = PgProducts::new(pool).list(query).await;
+        let pagination = result.expect("Paginated products list");
+        assert_eq!(pagination.count, 3);
+        assert_eq!(pagination.items.len(), 2);
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn list_with_filters(pool: PgPool) {
+        let products = PgProducts::new(pool);
+        let burgers_id = catalog::Id::parse_str("0190ec30-286b-7211-aadb-003fc0449734")
+            .expect("Valid catalog id from fixtures");
+        let query = product::ListQuery {
+            catalog_id: Some(burgers_id),
+            sort: Sort::Name,
+            ..list_query()
+        };
+        assert_eq!(
+            list_names(&products, query).await,
+            ["Cheese Burger", "Cheese Salad"]
+        );
+
+        let query = product::ListQuery {
+            kind: Some(product::Kind::Vegan),
+            ..list_query()
+        };
+        assert_eq!(list_names(&products, query).await, ["Caesar Salad"]);
+
+        let query = product::ListQuery {
+            name: Some(String::from("SALAD")),
+            sort: Sort::Price,
+            ..list_query()
+        };
+        assert_eq!(
+            list_names(&products, query).await,
+            ["Caesar Salad", "Cheese Salad"]
+        );
+
+        let hot_sauce_id = extra::Id::parse_str("0190ec10-4aa7-7552-ba8f-df997d9f8a8e")
+            .expect("Valid extra id from fixtures");
+        let query = product::ListQuery {
+            extra_id: Some(hot_sauce_id),
+            ..list_query()
+        };
+        assert_eq!(list_names(&products, query).await, ["Cheese Burger"]);
+
+        let query = product::ListQuery {
+            max_price: Some(Money::from_minor(170_000, Currency::BRL)),
+            ..list_query()
+        };
+        assert_eq!(list_names(&products, query).await, ["Caesar Salad"]);
+
+        let query = product::ListQuery {
+            min_price: Some(Money::from_minor(100, Currency::USD)),
+            ..list_query()
+        };
+        assert!(list_names(&products, query).await.is_empty());
+
+        let query = product::ListQuery {
+            enabled: Some(false),
+            ..list_query()
+        };
+        assert!(list_names(&products, query).await.is_empty());
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn list_with_catalog_not_found(pool: PgPool) {
+        use product::{Error, NotFoundKind};
+
+        let catalog_id = catalog::Id::parse_str("0190fbba-d10a-73f0-bc03-3d1a44592ccf")
+            .expect("Valid catalog id not in fixtures");
+        let query = product::ListQuery {
+            catalog_id: Some(catalog_id),
+            ..list_query()
+        };
+
+        let result = PgProducts::new(pool).list(query).await;
+        assert!(
+            matches!(result, Err(Error::NotFound(NotFoundKind::CatalogId(id))) if id == catalog_id)
+        );
+    }
 }
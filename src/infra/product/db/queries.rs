@@ -1,17 +1,22 @@
-use sqlx::PgExecutor;
+use sqlx::postgres::PgArguments;
+use sqlx::query::QueryAs;
+use sqlx::types::Json;
+use sqlx::{PgExecutor, Postgres};
 
 use domain::catalog;
 use domain::extra;
+use domain::modifier;
 use domain::product;
+use domain::variant;
 
-use crate::infra::product::ProductModel;
+use crate::infra::product::{ModifierModel, ProductModel, ScheduleModel, VariantModel};
 
 // TODO: improve code organization and reduce memory memory allocation
 
 #[derive(Clone, Debug)]
 pub(super) struct BindExtrasQuery<'a> {
     pub(super) id: product::Id,
-    pub(super) extras: &'a [extra::Extra],
+    pub(super) extras: &'a product::Extras,
 }
 
 impl<'a> BindExtrasQuery<'a> {
@@ -24,10 +29,33 @@ impl<'a> BindExtrasQuery<'a> {
 
         let product_ids = vec![self.id.uuid(); extras_ids.len()];
 
+        let max_quantities = self
+            .extras
+            .iter()
+            .map(|extra| self.extras.max_quantity(extra.id()).value())
+            .map(|max_quantity| i16::try_from(max_quantity).unwrap_or(i16::MAX))
+            .collect::<Vec<_>>();
+
+        let (override_prices, override_currencies): (Vec<_>, Vec<_>) = self
+            .extras
+            .iter()
+            .map(|extra| {
+                if self.extras.is_overridden(extra.id()) {
+                    let currency = extra.price.currency().as_str().to_string();
+                    (Some(extra.price.amount()), Some(currency))
+                } else {
+                    (None, None)
+                }
+            })
+            .unzip();
+
         let bind_extras_sql = include_str!("./sql/extras_bind.sql");
         sqlx::query(bind_extras_sql)
             .bind(product_ids)
             .bind(extras_ids)
+            .bind(max_quantities)
+            .bind(override_prices)
+            .bind(override_currencies)
             .execute(exec)
             .await?;
 
@@ -35,20 +63,45 @@ impl<'a> BindExtrasQuery<'a> {
     }
 }
 
+#[derive(Clone, Debug)]
+pub(super) struct CatalogExistsQuery {
+    pub(super) catalog_id: catalog::Id,
+}
+
+impl CatalogExistsQuery {
+    pub async fn exec(self, exec: impl PgExecutor<'_>) -> Result<bool, sqlx::Error> {
+        let sql = include_str!("./sql/catalog_exists.sql");
+        sqlx::query_scalar(sql)
+            .bind(self.catalog_id.uuid())
+            .fetch_one(exec)
+            .await
+    }
+}
+
 #[derive(Clone, Debug)]
 pub(super) struct CreateQuery<'a> {
     pub(super) product: &'a product::Product,
 }
 
 impl<'a> CreateQuery<'a> {
+    fn schedule(&self) -> Option<Json<ScheduleModel>> {
+        self.product
+            .schedule
+            .as_ref()
+            .map(|schedule| Json(ScheduleModel::from_schedule(schedule)))
+    }
+
     pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
         let sql = include_str!("./sql/create.sql");
         sqlx::query(sql)
             .bind(self.product.id().uuid())
             .bind(self.product.catalog_id().uuid())
             .bind(self.product.name.as_str())
-            .bind(self.product.price.decimal())
+            .bind(self.product.price.amount())
+            .bind(self.product.price.currency().as_str())
             .bind(self.product.kind.as_str())
+            .bind(self.product.available)
+            .bind(self.schedule())
             .bind(self.product.metadata.created_at())
             .bind(self.product.metadata.updated_at())
             .execute(exec)
@@ -58,6 +111,19 @@ impl<'a> CreateQuery<'a> {
     }
 }
 
+#[derive(Clone, Debug)]
+pub(super) struct CountQuery<'a>(pub(super) &'a product::ListQuery);
+
+impl<'a> CountQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<u64, sqlx::Error> {
+        let sql = include_str!("./sql/count.sql");
+        let (count,): (i64,) = bind_filters(sqlx::query_as(sql), self.0)
+            .fetch_one(exec)
+            .await?;
+        Ok(u64::try_from(count).unwrap_or_default())
+    }
+}
+
 #[derive(Clone, Debug)]
 pub(super) struct DeleteQuery {
     pub(super) id: product::Id,
@@ -92,6 +158,28 @@ impl FindQuery {
     }
 }
 
+#[derive(Clone, Debug)]
+pub(super) struct ListQuery<'a>(pub(super) &'a product::ListQuery);
+
+impl<'a> ListQuery<'a> {
+    pub(super) async fn exec(
+        self,
+        exec: impl PgExecutor<'a>,
+    ) -> Result<Vec<ProductModel>, sqlx::Error> {
+        let limit = u8::from(self.0.limit);
+        let page = u32::from(self.0.page);
+        let offset = page.saturating_sub(1) * u32::from(limit);
+
+        let sql = include_str!("./sql/list.sql");
+        bind_filters(sqlx::query_as(sql), self.0)
+            .bind(self.0.sort.as_str())
+            .bind(i64::from(limit))
+            .bind(i64::from(offset))
+            .fetch_all(exec)
+            .await
+    }
+}
+
 #[derive(Clone, Debug)]
 pub(super) struct UnbindExtrasQuery<'a> {
     pub(super) id: product::Id,
@@ -123,12 +211,22 @@ pub(super) struct UpdateQuery<'a> {
 }
 
 impl<'a> UpdateQuery<'a> {
+    fn schedule(&self) -> Option<Json<ScheduleModel>> {
+        self.product
+            .schedule
+            .as_ref()
+            .map(|schedule| Json(ScheduleModel::from_schedule(schedule)))
+    }
+
     pub async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
         let update_sql = include_str!("./sql/update.sql");
         let result = sqlx::query(update_sql)
             .bind(self.product.name.as_str())
-            .bind(self.product.price.decimal())
+            .bind(self.product.price.amount())
+            .bind(self.product.price.currency().as_str())
             .bind(self.product.kind.as_str())
+            .bind(self.product.available)
+            .bind(self.schedule())
             .bind(self.product.metadata.updated_at())
             .bind(self.product.id().uuid())
             .bind(self.product.catalog_id().uuid())
@@ -143,11 +241,203 @@ impl<'a> UpdateQuery<'a> {
     }
 }
 
+#[derive(Clone, Debug)]
+pub(super) struct CreateVariantQuery<'a> {
+    pub(super) variant: &'a variant::Variant,
+}
+
+impl<'a> CreateVariantQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
+        let sql = include_str!("./sql/variant_create.sql");
+        sqlx::query(sql)
+            .bind(self.variant.id().uuid())
+            .bind(self.variant.product_id().uuid())
+            .bind(self.variant.name.as_str())
+            .bind(self.variant.pricing.as_str())
+            .bind(self.variant.price.amount())
+            .bind(self.variant.price.currency().as_str())
+            .bind(self.variant.metadata.created_at())
+            .bind(self.variant.metadata.updated_at())
+            .execute(exec)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct DeleteVariantQuery {
+    pub(super) id: variant::Id,
+    pub(super) product_id: product::Id,
+}
+
+impl DeleteVariantQuery {
+    pub async fn exec(self, exec: impl PgExecutor<'_>) -> Result<VariantModel, sqlx::Error> {
+        let sql = include_str!("./sql/variant_delete.sql");
+        sqlx::query_as(sql)
+            .bind(self.id.uuid())
+            .bind(self.product_id.uuid())
+            .fetch_one(exec)
+            .await
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct UpdateVariantQuery<'a> {
+    pub(super) variant: &'a variant::Variant,
+}
+
+impl<'a> UpdateVariantQuery<'a> {
+    pub async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
+        let sql = include_str!("./sql/variant_update.sql");
+        let result = sqlx::query(sql)
+            .bind(self.variant.name.as_str())
+            .bind(self.variant.pricing.as_str())
+            .bind(self.variant.price.amount())
+            .bind(self.variant.price.currency().as_str())
+            .bind(self.variant.metadata.updated_at())
+            .bind(self.variant.id().uuid())
+            .bind(self.variant.product_id().uuid())
+            .execute(exec)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(sqlx::Error::RowNotFound);
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct BindModifierExtrasQuery<'a> {
+    pub(super) group: &'a modifier::Group,
+}
+
+impl<'a> BindModifierExtrasQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
+        let extras_ids = self
+            .group
+            .options
+            .iter()
+            .map(extra::Id::uuid)
+            .collect::<Vec<_>>();
+
+        let sql = include_str!("./sql/modifier_extras_bind.sql");
+        sqlx::query(sql)
+            .bind(self.group.id().uuid())
+            .bind(self.group.product_id().uuid())
+            .bind(extras_ids)
+            .execute(exec)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct CreateModifierQuery<'a> {
+    pub(super) group: &'a modifier::Group,
+}
+
+impl<'a> CreateModifierQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
+        let sql = include_str!("./sql/modifier_create.sql");
+        sqlx::query(sql)
+            .bind(self.group.id().uuid())
+            .bind(self.group.product_id().uuid())
+            .bind(self.group.name.as_str())
+            .bind(i16::from(self.group.limits.min()))
+            .bind(i16::from(self.group.limits.max()))
+            .bind(self.group.metadata.created_at())
+            .bind(self.group.metadata.updated_at())
+            .execute(exec)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct DeleteModifierQuery {
+    pub(super) id: modifier::Id,
+    pub(super) product_id: product::Id,
+}
+
+impl DeleteModifierQuery {
+    pub async fn exec(self, exec: impl PgExecutor<'_>) -> Result<ModifierModel, sqlx::Error> {
+        let sql = include_str!("./sql/modifier_delete.sql");
+        sqlx::query_as(sql)
+            .bind(self.id.uuid())
+            .bind(self.product_id.uuid())
+            .fetch_one(exec)
+            .await
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct UnbindModifierExtrasQuery {
+    pub(super) id: modifier::Id,
+}
+
+impl UnbindModifierExtrasQuery {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'_>) -> Result<(), sqlx::Error> {
+        let sql = include_str!("./sql/modifier_extras_unbind.sql");
+        sqlx::query(sql).bind(self.id.uuid()).execute(exec).await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct UpdateModifierQuery<'a> {
+    pub(super) group: &'a modifier::Group,
+}
+
+impl<'a> UpdateModifierQuery<'a> {
+    pub async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
+        let sql = include_str!("./sql/modifier_update.sql");
+        let result = sqlx::query(sql)
+            .bind(self.group.name.as_str())
+            .bind(i16::from(self.group.limits.min()))
+            .bind(i16::from(self.group.limits.max()))
+            .bind(self.group.metadata.updated_at())
+            .bind(self.group.id().uuid())
+            .bind(self.group.product_id().uuid())
+            .execute(exec)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(sqlx::Error::RowNotFound);
+        }
+
+        Ok(())
+    }
+}
+
+/// Bind filters of `list` query, shared by listing and counting products
+fn bind_filters<'q, O>(
+    query: QueryAs<'q, Postgres, O, PgArguments>,
+    list: &'q product::ListQuery,
+) -> QueryAs<'q, Postgres, O, PgArguments> {
+    query
+        .bind(list.catalog_id.map(|id| id.uuid()))
+        .bind(list.kind.as_ref().map(product::Kind::as_str))
+        .bind(list.name.as_deref())
+        .bind(list.min_price.map(|price| price.amount()))
+        .bind(list.min_price.map(|price| price.currency().to_string()))
+        .bind(list.max_price.map(|price| price.amount()))
+        .bind(list.max_price.map(|price| price.currency().to_string()))
+        .bind(list.extra_id.map(|id| id.uuid()))
+        .bind(list.enabled)
+}
+
 #[cfg(test)]
 mod tests {
     use sqlx::PgPool;
 
     use domain::core::metadata;
+    use domain::core::money::{Currency, Money};
 
     use super::*;
 
@@ -158,7 +448,8 @@ mod tests {
 
         let result = BindExtrasQuery {
             id: product_id,
-            extras: &[get_mocked_hot_sauce(), get_mocked_cheddar()],
+            extras: &product::Extras::new(vec![get_mocked_hot_sauce(), get_mocked_cheddar()])
+                .expect("Valid product extras"),
         }
         .exec(&pool)
         .await;
@@ -174,7 +465,7 @@ mod tests {
             catalog::Id::parse_str("0190ec30-286b-7211-aadb-003fc0449734")
                 .expect("Valid catalog id from fixtures"),
             product::Name::new("Cheese Bacon").expect("Valid product name"),
-            product::Price::from_cents(2450),
+            Money::from_minor(2450, Currency::BRL),
             product::Kind::Burger,
             product::Extras::new(extras).expect("Valid product extras"),
         );
@@ -232,11 +523,15 @@ mod tests {
             catalog_id: catalog::Id::parse_str("0190ec30-286b-7211-aadb-003fc0449734")
                 .expect("Valid catalog id from fixtures"),
             name: product::Name::new("Cheese Bacon").expect("Valid product name"),
-            price: product::Price::from_cents(2540),
+            price: Money::from_minor(2540, Currency::BRL),
             kind: product::Kind::Burger,
             extras: Some(
                 product::Extras::new(vec![get_mocked_cheddar()]).expect("Valid product extras"),
             ),
+            variants: None,
+            modifiers: None,
+            available: true,
+            schedule: None,
             metadata: metadata::Metadata::new(),
         });
 
@@ -254,7 +549,7 @@ mod tests {
         assert_eq!(updated_model.id, product.id().uuid());
         assert_eq!(updated_model.catalog_id, product.catalog_id().uuid());
         assert_eq!(updated_model.name.as_str(), product.name.as_str());
-        assert_eq!(updated_model.price, product.price.decimal());
+        assert_eq!(updated_model.price, product.price.amount());
         assert_eq!(updated_model.kind.as_str(), product.kind.as_str());
     }
 
@@ -263,7 +558,9 @@ mod tests {
             id: extra::Id::parse_str("0190ec13-15cc-7f53-bc0f-d60f0beea824")
                 .expect("Cheedar id from seed fixtures"),
             name: extra::Name::new("Cheddar").expect("Valid extra name"),
-            price: extra::Price::from_cents(200),
+            price: Money::from_minor(200, Currency::BRL),
+            catalog_id: None,
+            category: None,
             metadata: metadata::Metadata::new(),
         })
     }
@@ -273,7 +570,9 @@ mod tests {
             id: extra::Id::parse_str("0190ec10-4aa7-7552-ba8f-df997d9f8a8e")
                 .expect("Hot sauce id from seed fixtures"),
             name: extra::Name::new("Hot Sauce").expect("Valid extra name"),
-            price: extra::Price::from_cents(150),
+            price: Money::from_minor(150, Currency::BRL),
+            catalog_id: None,
+            category: None,
             metadata: metadata::Metadata::new(),
         })
     }
@@ -0,0 +1 @@
+select exists(select 1 from catalog where id = $1)
\ No newline at end of file
@@ -1,2 +1,2 @@
-insert into product (id, catalog_id, name, price, kind, created_at, updated_at)
-values ($1, $2, $3, $4, $5, $6, $7)
\ No newline at end of file
+insert into product (id, catalog_id, name, price, currency, kind, available, schedule, created_at, updated_at)
+values ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
\ No newline at end of file
@@ -1,4 +1,7 @@
-insert into product_extras (product_id, extra_id)
-select * from unnest($1::uuid[], $2::uuid[])
+insert into product_extras (product_id, extra_id, max_quantity, override_price, override_currency)
+select * from unnest($1::uuid[], $2::uuid[], $3::int2[], $4::decimal[], $5::char(3)[])
 on conflict (product_id, extra_id)
-do nothing
\ No newline at end of file
+do update set
+    max_quantity = excluded.max_quantity,
+    override_price = excluded.override_price,
+    override_currency = excluded.override_currency
\ No newline at end of file
@@ -0,0 +1,17 @@
+select product.*
+from product_with_extras as product
+where ($1::uuid is null or product.catalog_id = $1)
+    and ($2::varchar is null or product.kind = $2)
+    and ($3::varchar is null or position(lower($3) in lower(product.name)) > 0)
+    and ($4::decimal is null or (product.price >= $4 and product.currency = $5))
+    and ($6::decimal is null or (product.price <= $6 and product.currency = $7))
+    and ($8::uuid is null or exists (
+        select 1 from product_extras as pe
+        where pe.product_id = product.id and pe.extra_id = $8))
+    and ($9::boolean is null or product.available = $9)
+order by
+    case when $10 = 'name' then product.name end asc,
+    case when $10 = 'price' then product.price end asc,
+    product.created_at desc,
+    product.id
+limit $11 offset $12
\ No newline at end of file
@@ -0,0 +1,2 @@
+insert into modifier_group (id, product_id, name, min_selections, max_selections, created_at, updated_at)
+values ($1, $2, $3, $4, $5, $6, $7)
\ No newline at end of file
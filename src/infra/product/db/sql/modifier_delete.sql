@@ -0,0 +1,14 @@
+with deleted as (
+    delete from modifier_group
+    where id = $1 and product_id = $2
+    returning *
+)
+select
+    deleted.*,
+    coalesce(
+        (select jsonb_agg(mge.extra_id order by mge.position)
+        from modifier_group_extra as mge
+        where mge.group_id = deleted.id),
+        '[]'::jsonb
+    ) as extras_ids
+from deleted
\ No newline at end of file
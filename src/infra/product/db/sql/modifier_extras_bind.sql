@@ -0,0 +1,3 @@
+insert into modifier_group_extra (group_id, product_id, extra_id, position)
+select $1, $2, extra.id, extra.position - 1
+from unnest($3::uuid[]) with ordinality as extra (id, position)
\ No newline at end of file
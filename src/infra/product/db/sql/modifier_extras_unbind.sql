@@ -0,0 +1,2 @@
+delete from modifier_group_extra as mge
+where mge.group_id = $1
\ No newline at end of file
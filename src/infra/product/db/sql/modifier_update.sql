@@ -0,0 +1,3 @@
+update modifier_group
+set name = $1, min_selections = $2, max_selections = $3, updated_at = $4
+where id = $5 and product_id = $6
\ No newline at end of file
@@ -1,3 +1,3 @@
 update product
-set name = $1, price = $2, kind = $3, updated_at = $4
-where id = $5 and catalog_id = $6
+set name = $1, price = $2, currency = $3, kind = $4, available = $5, schedule = $6, updated_at = $7
+where id = $8 and catalog_id = $9
\ No newline at end of file
@@ -0,0 +1,2 @@
+insert into variant (id, product_id, name, pricing, price, currency, created_at, updated_at)
+values ($1, $2, $3, $4, $5, $6, $7, $8)
\ No newline at end of file
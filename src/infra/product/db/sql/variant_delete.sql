@@ -0,0 +1,3 @@
+delete from variant
+where id = $1 and product_id = $2
+returning *
\ No newline at end of file
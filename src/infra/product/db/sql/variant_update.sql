@@ -0,0 +1,3 @@
+update variant
+set name = $1, pricing = $2, price = $3, currency = $4, updated_at = $5
+where id = $6 and product_id = $7
\ No newline at end of file
@@ -1,11 +1,17 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use sqlx::types::{Decimal, Json, Uuid};
 use sqlx::FromRow;
-use time::OffsetDateTime;
+use time::{Date, OffsetDateTime, Time};
 
 use domain::catalog;
 use domain::core::metadata;
+use domain::core::money::{Currency, Money};
+use domain::core::quantity::Quantity;
+use domain::core::schedule;
+use domain::extra;
+use domain::modifier;
 use domain::product;
+use domain::variant;
 
 use crate::infra::extra::ExtraModel;
 
@@ -15,8 +21,13 @@ pub struct ProductModel {
     pub catalog_id: Uuid,
     pub name: String,
     pub price: Decimal,
+    pub currency: String,
     pub kind: String,
-    pub extras: Json<Vec<ExtraModel>>,
+    pub extras: Json<Vec<ProductExtraModel>>,
+    pub variants: Json<Vec<VariantModel>>,
+    pub modifiers: Json<Vec<ModifierModel>>,
+    pub available: bool,
+    pub schedule: Option<Json<ScheduleModel>>,
     #[serde(with = "time::serde::rfc3339")]
     pub created_at: OffsetDateTime,
     #[serde(with = "time::serde::rfc3339")]
@@ -26,26 +37,244 @@ pub struct ProductModel {
 impl ProductModel {
     pub fn try_into_entity(self) -> Result<product::Product, Box<dyn std::error::Error>> {
         let name = product::Name::new(self.name)?;
-        let extras_vec = self
-            .extras
+        let mut extras_vec = Vec::with_capacity(self.extras.0.len());
+        let mut max_quantities = Vec::with_capacity(self.extras.0.len());
+        let mut overrides = Vec::new();
+        for model in self.extras.0 {
+            let max_quantity = Quantity::new(u16::try_from(model.max_quantity)?)?;
+            let override_price = model.override_price()?;
+            let extra = model.extra.try_into_entity()?;
+            max_quantities.push((extra.id(), max_quantity));
+            if let Some(price) = override_price {
+                overrides.push((extra.id(), price));
+            }
+            extras_vec.push(extra);
+        }
+
+        let variants_vec = self
+            .variants
+            .0
+            .into_iter()
+            .map(VariantModel::try_into_entity)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // a group left without extras, e.g. after they were deleted, cannot
+        // be selected from anymore and so it is not loaded at all
+        let modifiers_vec = self
+            .modifiers
             .0
             .into_iter()
-            .map(ExtraModel::try_into_entity)
+            .filter(|modifier| !modifier.extras_ids.is_empty())
+            .map(ModifierModel::try_into_entity)
             .collect::<Result<Vec<_>, _>>()?;
 
         let kind = product::Kind::parse_str(&self.kind)?;
-        let extras = product::Extras::new(extras_vec)?;
+        let currency = Currency::parse_str(&self.currency)?;
+        let mut extras = product::Extras::new(extras_vec)?;
+        for (extra_id, max_quantity) in max_quantities {
+            extras.set_max_quantity(extra_id, max_quantity)?;
+        }
+        for (extra_id, price) in overrides {
+            extras.override_price(extra_id, price)?;
+        }
+        let variants = variant::Variants::new(variants_vec)?;
+        let modifiers = modifier::Groups::new(modifiers_vec)?;
+        let schedule = self
+            .schedule
+            .map(|schedule| schedule.0.try_into_schedule())
+            .transpose()?;
         let metadata = metadata::Metadata::configured(self.created_at, self.updated_at)?;
         let product = product::Product::config(product::ProductConfig {
             id: product::Id::from(self.id),
             catalog_id: catalog::Id::from(self.catalog_id),
             name,
-            price: product::Price::from(self.price),
+            price: Money::new(self.price, currency),
             kind,
             extras: Some(extras),
+            variants: Some(variants),
+            modifiers: Some(modifiers),
+            available: self.available,
+            schedule,
             metadata,
         });
 
         Ok(product)
     }
 }
+
+/// Extra as bound to a product, along with how much of it can be selected
+/// and the price it has there, if overridden
+#[derive(Clone, Debug, Deserialize)]
+pub struct ProductExtraModel {
+    #[serde(flatten)]
+    pub extra: ExtraModel,
+    pub max_quantity: i16,
+    pub override_price: Option<Decimal>,
+    pub override_currency: Option<String>,
+}
+
+impl ProductExtraModel {
+    fn override_price(&self) -> Result<Option<Money>, Box<dyn std::error::Error>> {
+        match (self.override_price, self.override_currency.as_deref()) {
+            (Some(amount), Some(currency)) => {
+                Ok(Some(Money::new(amount, Currency::parse_str(currency)?)))
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, FromRow)]
+pub struct VariantModel {
+    pub id: Uuid,
+    pub product_id: Uuid,
+    pub name: String,
+    pub pricing: String,
+    pub price: Decimal,
+    pub currency: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime,
+}
+
+impl VariantModel {
+    pub fn try_into_entity(self) -> Result<variant::Variant, Box<dyn std::error::Error>> {
+        let name = variant::Name::new(self.name)?;
+        let pricing = variant::Pricing::parse_str(&self.pricing)?;
+        let currency = Currency::parse_str(&self.currency)?;
+        let metadata = metadata::Metadata::configured(self.created_at, self.updated_at)?;
+        let variant = variant::Variant::config(variant::VariantConfig {
+            id: variant::Id::from(self.id),
+            product_id: product::Id::from(self.product_id),
+            name,
+            pricing,
+            price: Money::new(self.price, currency),
+            metadata,
+        });
+
+        Ok(variant)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, FromRow)]
+pub struct ModifierModel {
+    pub id: Uuid,
+    pub product_id: Uuid,
+    pub name: String,
+    pub min_selections: i16,
+    pub max_selections: i16,
+    pub extras_ids: Json<Vec<Uuid>>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime,
+}
+
+impl ModifierModel {
+    pub fn try_into_entity(self) -> Result<modifier::Group, Box<dyn std::error::Error>> {
+        let name = modifier::Name::new(self.name)?;
+        let limits = modifier::Limits::new(
+            u8::try_from(self.min_selections)?,
+            u8::try_from(self.max_selections)?,
+        )?;
+        let extras_ids = self.extras_ids.0.into_iter().map(extra::Id::from).collect();
+        let options = modifier::Options::new(extras_ids)?;
+        let metadata = metadata::Metadata::configured(self.created_at, self.updated_at)?;
+        let group = modifier::Group::config(modifier::GroupConfig {
+            id: modifier::Id::from(self.id),
+            product_id: product::Id::from(self.product_id),
+            name,
+            limits,
+            options,
+            metadata,
+        });
+
+        Ok(group)
+    }
+}
+
+time::serde::format_description!(date_format, Date, "[year]-[month]-[day]");
+
+/// Product schedule as stored in its jsonb column, with times in seconds from
+/// midnight and the name of its time zone
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ScheduleModel {
+    pub windows: Vec<ScheduleWindowModel>,
+    #[serde(with = "date_format::option")]
+    pub starts_on: Option<Date>,
+    #[serde(with = "date_format::option")]
+    pub ends_on: Option<Date>,
+    pub time_zone: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ScheduleWindowModel {
+    pub weekdays: u8,
+    pub starts_at: u32,
+    pub ends_at: u32,
+}
+
+impl ScheduleModel {
+    pub fn from_schedule(schedule: &product::Schedule) -> Self {
+        Self {
+            windows: schedule
+                .windows()
+                .iter()
+                .map(ScheduleWindowModel::from_window)
+                .collect(),
+            starts_on: schedule.starts_on(),
+            ends_on: schedule.ends_on(),
+            time_zone: schedule.zone().as_str().to_owned(),
+        }
+    }
+
+    pub fn try_into_schedule(self) -> Result<product::Schedule, Box<dyn std::error::Error>> {
+        let zone = schedule::Zone::parse_str(&self.time_zone)?;
+        let windows = self
+            .windows
+            .into_iter()
+            .map(|window| window.try_into_window(zone))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(product::Schedule::new(
+            windows,
+            self.starts_on,
+            self.ends_on,
+            zone,
+        )?)
+    }
+}
+
+impl ScheduleWindowModel {
+    pub fn from_window(window: &schedule::Window) -> Self {
+        let seconds = |time: Time| {
+            let (hour, minute, second) = time.as_hms();
+            u32::from(hour) * 3600 + u32::from(minute) * 60 + u32::from(second)
+        };
+
+        Self {
+            weekdays: window.weekdays().bits(),
+            starts_at: seconds(window.starts_at()),
+            ends_at: seconds(window.ends_at()),
+        }
+    }
+
+    pub fn try_into_window(
+        self,
+        zone: schedule::Zone,
+    ) -> Result<schedule::Window, Box<dyn std::error::Error>> {
+        let time = |seconds: u32| {
+            let hour = u8::try_from(seconds / 3600)?;
+            let minute = u8::try_from(seconds / 60 % 60)?;
+            let second = u8::try_from(seconds % 60)?;
+            Ok::<_, Box<dyn std::error::Error>>(Time::from_hms(hour, minute, second)?)
+        };
+
+        let weekdays = schedule::Weekdays::from_bits(self.weekdays)?;
+        let starts_at = time(self.starts_at)?;
+        let ends_at = time(self.ends_at)?;
+
+        Ok(schedule::Window::new(weekdays, starts_at, ends_at, zone)?)
+    }
+}
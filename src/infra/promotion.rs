@@ -0,0 +1,5 @@
+mod db;
+mod model;
+
+pub use db::PgPromotions;
+pub(super) use model::PromotionModel;
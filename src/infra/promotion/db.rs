@@ -0,0 +1,244 @@
+mod queries;
+
+use sqlx::PgPool;
+use time::OffsetDateTime;
+
+use domain::promotion;
+
+use super::model::PromotionModel;
+
+#[derive(Clone, Debug)]
+pub struct PgPromotions {
+    pool: PgPool,
+}
+
+impl PgPromotions {
+    const PK: &'static str = "pk_promotion";
+    const FK_CATALOG_ID: &'static str = "fk_promotion_target_catalog_id";
+    const FK_PRODUCT_ID: &'static str = "fk_promotion_target_product_id";
+    const FK_EXTRA_ID: &'static str = "fk_promotion_target_extra_id";
+
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn is_pk_error(err: &sqlx::Error) -> bool {
+        err.as_database_error()
+            .is_some_and(|db_err| db_err.constraint() == Some(Self::PK))
+    }
+
+    fn is_fk_target_error(err: &sqlx::Error) -> bool {
+        err.as_database_error().is_some_and(|db_err| {
+            matches!(
+                db_err.constraint(),
+                Some(Self::FK_CATALOG_ID | Self::FK_PRODUCT_ID | Self::FK_EXTRA_ID)
+            )
+        })
+    }
+
+    fn map_targets_error(err: sqlx::Error) -> promotion::Error {
+        if Self::is_fk_target_error(&err) {
+            promotion::Error::targets_not_found()
+        } else {
+            promotion::Error::any(err)
+        }
+    }
+
+    fn into_entities(
+        models: Vec<PromotionModel>,
+    ) -> Result<Vec<promotion::Promotion>, promotion::Error> {
+        models
+            .into_iter()
+            .map(PromotionModel::try_into_entity)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(promotion::Error::any)
+    }
+}
+
+impl promotion::Repository for PgPromotions {
+    async fn active(&self, at: OffsetDateTime) -> Result<promotion::Promotions, promotion::Error> {
+        let models = queries::ActiveQuery { at }
+            .exec(&self.pool)
+            .await
+            .map_err(promotion::Error::any)?;
+
+        Self::into_entities(models).map(promotion::Promotions::new)
+    }
+
+    async fn all(&self) -> Result<Vec<promotion::Promotion>, promotion::Error> {
+        let models = queries::AllQuery
+            .exec(&self.pool)
+            .await
+            .map_err(promotion::Error::any)?;
+
+        Self::into_entities(models)
+    }
+
+    async fn create(&mut self, promotion: &promotion::Promotion) -> Result<(), promotion::Error> {
+        let mut trx = self.pool.begin().await.map_err(promotion::Error::any)?;
+
+        let create_query = queries::CreateQuery { promotion };
+        create_query.exec(trx.as_mut()).await.map_err(|err| {
+            if Self::is_pk_error(&err) {
+                promotion::Error::id_conflict(promotion.id())
+            } else {
+                promotion::Error::any(err)
+            }
+        })?;
+
+        let create_targets_query = queries::CreateTargetsQuery {
+            id: promotion.id(),
+            targets: &promotion.targets,
+        };
+
+        create_targets_query
+            .exec(trx.as_mut())
+            .await
+            .map_err(Self::map_targets_error)?;
+
+        trx.commit().await.map_err(promotion::Error::any)
+    }
+
+    async fn delete(
+        &mut self,
+        id: promotion::Id,
+    ) -> Result<promotion::Promotion, promotion::Error> {
+        let query = queries::DeleteQuery { id };
+        let model = query.exec(&self.pool).await.map_err(|err| match &err {
+            sqlx::Error::RowNotFound => promotion::Error::id_not_found(id),
+            _ => promotion::Error::any(err),
+        })?;
+
+        model.try_into_entity().map_err(promotion::Error::any)
+    }
+
+    async fn find(&self, id: promotion::Id) -> Result<promotion::Promotion, promotion::Error> {
+        let query = queries::FindQuery { id };
+        let model = query.exec(&self.pool).await.map_err(|err| match &err {
+            sqlx::Error::RowNotFound => promotion::Error::id_not_found(id),
+            _ => promotion::Error::any(err),
+        })?;
+
+        model.try_into_entity().map_err(promotion::Error::any)
+    }
+
+    async fn update(&mut self, promotion: &promotion::Promotion) -> Result<(), promotion::Error> {
+        let mut trx = self.pool.begin().await.map_err(promotion::Error::any)?;
+
+        let update_query = queries::UpdateQuery { promotion };
+        update_query.exec(trx.as_mut()).await.map_err(|err| {
+            if matches!(err, sqlx::Error::RowNotFound) {
+                promotion::Error::id_not_found(promotion.id())
+            } else {
+                promotion::Error::any(err)
+            }
+        })?;
+
+        // targets have no identity of their own, so they are simply replaced
+        let delete_targets_query = queries::DeleteTargetsQuery { id: promotion.id() };
+        delete_targets_query
+            .exec(trx.as_mut())
+            .await
+            .map_err(promotion::Error::any)?;
+
+        let create_targets_query = queries::CreateTargetsQuery {
+            id: promotion.id(),
+            targets: &promotion.targets,
+        };
+
+        create_targets_query
+            .exec(trx.as_mut())
+            .await
+            .map_err(Self::map_targets_error)?;
+
+        trx.commit().await.map_err(promotion::Error::any)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+
+    use domain::product;
+    use domain::promotion::Repository;
+
+    use super::*;
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn active_method_works(pool: PgPool) {
+        let result = PgPromotions::new(pool)
+            .active(OffsetDateTime::now_utc())
+            .await;
+        let promotions = result.expect("Active promotions from fixtures");
+        assert_eq!(promotions.as_slice().len(), 1);
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn all_method_works(pool: PgPool) {
+        let result = PgPromotions::new(pool).all().await;
+        let promotions = result.expect("All promotions from fixtures");
+        assert_eq!(promotions.len(), 2);
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn create_with_target_not_found(pool: PgPool) {
+        use promotion::{Error, NotFoundKind};
+
+        let product_id = product::Id::parse_str("01913366-3b4c-7d5e-8f6a-7b8c9d0e1f23")
+            .expect("Valid product id not in fixtures");
+        let promotion = mocked_promotion(promotion::Target::Product(product_id));
+
+        let mut repository = PgPromotions::new(pool);
+        let result = repository.create(&promotion).await;
+        assert!(matches!(
+            result,
+            Err(Error::NotFound(NotFoundKind::Targets))
+        ));
+
+        let result = repository.find(promotion.id()).await;
+        assert!(matches!(result, Err(Error::NotFound(NotFoundKind::Id(_)))));
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn delete_with_not_found(pool: PgPool) {
+        use promotion::{Error, NotFoundKind};
+
+        let id = promotion::Id::parse_str("01913367-4c5d-7e6f-9a7b-8c9d0e1f2a34")
+            .expect("Valid promotion id not in fixtures");
+
+        let result = PgPromotions::new(pool).delete(id).await;
+        assert!(matches!(result, Err(Error::NotFound(NotFoundKind::Id(err_id))) if err_id == id));
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn update_replaces_targets(pool: PgPool) {
+        let mut repository = PgPromotions::new(pool);
+        let id = promotion::Id::parse_str("01913364-1f2a-7b3c-8d4e-5f6a7b8c9d01")
+            .expect("Valid promotion id from fixtures");
+        let mut promotion = repository.find(id).await.expect("Promotion from fixtures");
+        promotion.targets =
+            promotion::Targets::new(vec![promotion::Target::Kind(product::Kind::Vegan)])
+                .expect("Valid targets");
+
+        let result = repository.update(&promotion).await;
+        assert!(result.is_ok());
+
+        let promotion = repository.find(id).await.expect("Updated promotion");
+        assert_eq!(
+            promotion.targets.as_slice(),
+            &[promotion::Target::Kind(product::Kind::Vegan)]
+        );
+    }
+
+    fn mocked_promotion(target: promotion::Target) -> promotion::Promotion {
+        promotion::Promotion::new(
+            promotion::Name::new("Promotion").expect("Valid promotion name"),
+            promotion::Discount::Percentage(
+                promotion::Percentage::parse_str("10").expect("Valid percentage"),
+            ),
+            promotion::Targets::new(vec![target]).expect("Valid targets"),
+            promotion::Validity::default(),
+            0,
+        )
+    }
+}
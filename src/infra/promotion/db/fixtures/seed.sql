@@ -0,0 +1,24 @@
+insert into catalog (id, name, description, created_at, updated_at)
+values
+    ('0190ec30-286b-7211-aadb-003fc0449734', 'Burgers', null, now(), now());
+
+insert into product (id, catalog_id, name, price, currency, kind, created_at, updated_at)
+values
+    ('0190ec14-0af8-71d1-9554-f1e5249ae3a2', '0190ec30-286b-7211-aadb-003fc0449734', 'Cheese Burger', 20, 'BRL', 'burger', now(), now());
+
+insert into extra (id, name, price, currency, created_at, updated_at)
+values
+    ('0190ec10-4aa7-7552-ba8f-df997d9f8a8e', 'Hot Sauce', 1.5, 'BRL', now(), now());
+
+insert into promotion (id, name, discount_kind, discount_value, currency, priority, starts_at, ends_at, created_at, updated_at)
+values
+    -- always active
+    ('01913364-1f2a-7b3c-8d4e-5f6a7b8c9d01', 'Burger week', 'percentage', 10, null, 0, null, null, now(), now()),
+    -- already expired
+    ('01913365-2a3b-7c4d-9e5f-6a7b8c9d0e12', 'Sauce day', 'fixed', 0.5, 'BRL', 1, '2024-01-01T00:00:00Z', '2024-01-02T00:00:00Z', now(), now());
+
+insert into promotion_target (promotion_id, catalog_id, kind, product_id, extra_id)
+values
+    ('01913364-1f2a-7b3c-8d4e-5f6a7b8c9d01', '0190ec30-286b-7211-aadb-003fc0449734', null, null, null),
+    ('01913364-1f2a-7b3c-8d4e-5f6a7b8c9d01', null, 'burger', null, null),
+    ('01913365-2a3b-7c4d-9e5f-6a7b8c9d0e12', null, null, null, '0190ec10-4aa7-7552-ba8f-df997d9f8a8e');
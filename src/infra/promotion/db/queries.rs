@@ -0,0 +1,261 @@
+use sqlx::types::{Decimal, Uuid};
+use sqlx::PgExecutor;
+use time::OffsetDateTime;
+
+use domain::promotion;
+
+use crate::infra::promotion::PromotionModel;
+
+#[derive(Clone, Debug)]
+pub(super) struct ActiveQuery {
+    pub(super) at: OffsetDateTime,
+}
+
+impl ActiveQuery {
+    pub(super) async fn exec(
+        self,
+        exec: impl PgExecutor<'_>,
+    ) -> Result<Vec<PromotionModel>, sqlx::Error> {
+        let sql = include_str!("./sql/active.sql");
+        sqlx::query_as(sql).bind(self.at).fetch_all(exec).await
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct AllQuery;
+
+impl AllQuery {
+    pub(super) async fn exec(
+        self,
+        exec: impl PgExecutor<'_>,
+    ) -> Result<Vec<PromotionModel>, sqlx::Error> {
+        let sql = include_str!("./sql/all.sql");
+        sqlx::query_as(sql).fetch_all(exec).await
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct CreateQuery<'a> {
+    pub(super) promotion: &'a promotion::Promotion,
+}
+
+impl<'a> CreateQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
+        let (discount_kind, discount_value, currency) = discount_columns(&self.promotion.discount);
+        let sql = include_str!("./sql/create.sql");
+        sqlx::query(sql)
+            .bind(self.promotion.id().uuid())
+            .bind(self.promotion.name.as_str())
+            .bind(discount_kind)
+            .bind(discount_value)
+            .bind(currency)
+            .bind(i16::from(self.promotion.priority))
+            .bind(self.promotion.validity.starts_at())
+            .bind(self.promotion.validity.ends_at())
+            .bind(self.promotion.metadata.created_at())
+            .bind(self.promotion.metadata.updated_at())
+            .execute(exec)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct CreateTargetsQuery<'a> {
+    pub(super) id: promotion::Id,
+    pub(super) targets: &'a promotion::Targets,
+}
+
+impl<'a> CreateTargetsQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
+        let len = self.targets.as_slice().len();
+        let mut catalog_ids: Vec<Option<Uuid>> = Vec::with_capacity(len);
+        let mut kinds: Vec<Option<&str>> = Vec::with_capacity(len);
+        let mut product_ids: Vec<Option<Uuid>> = Vec::with_capacity(len);
+        let mut extra_ids: Vec<Option<Uuid>> = Vec::with_capacity(len);
+        for target in self.targets.iter() {
+            let (catalog_id, kind, product_id, extra_id) = match target {
+                promotion::Target::Catalog(id) => (Some(id.uuid()), None, None, None),
+                promotion::Target::Kind(kind) => (None, Some(kind.as_str()), None, None),
+                promotion::Target::Product(id) => (None, None, Some(id.uuid()), None),
+                promotion::Target::Extra(id) => (None, None, None, Some(id.uuid())),
+            };
+            catalog_ids.push(catalog_id);
+            kinds.push(kind);
+            product_ids.push(product_id);
+            extra_ids.push(extra_id);
+        }
+
+        let sql = include_str!("./sql/targets_create.sql");
+        sqlx::query(sql)
+            .bind(vec![self.id.uuid(); len])
+            .bind(catalog_ids)
+            .bind(kinds)
+            .bind(product_ids)
+            .bind(extra_ids)
+            .execute(exec)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct DeleteQuery {
+    pub(super) id: promotion::Id,
+}
+
+impl DeleteQuery {
+    pub(super) async fn exec(
+        self,
+        exec: impl PgExecutor<'_>,
+    ) -> Result<PromotionModel, sqlx::Error> {
+        let sql = include_str!("./sql/delete.sql");
+        sqlx::query_as(sql)
+            .bind(self.id.uuid())
+            .fetch_one(exec)
+            .await
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct DeleteTargetsQuery {
+    pub(super) id: promotion::Id,
+}
+
+impl DeleteTargetsQuery {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'_>) -> Result<(), sqlx::Error> {
+        let sql = include_str!("./sql/targets_delete.sql");
+        sqlx::query(sql).bind(self.id.uuid()).execute(exec).await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct FindQuery {
+    pub(super) id: promotion::Id,
+}
+
+impl FindQuery {
+    pub(super) async fn exec(
+        self,
+        exec: impl PgExecutor<'_>,
+    ) -> Result<PromotionModel, sqlx::Error> {
+        let sql = include_str!("./sql/find.sql");
+        sqlx::query_as(sql)
+            .bind(self.id.uuid())
+            .fetch_one(exec)
+            .await
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct UpdateQuery<'a> {
+    pub(super) promotion: &'a promotion::Promotion,
+}
+
+impl<'a> UpdateQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
+        let (discount_kind, discount_value, currency) = discount_columns(&self.promotion.discount);
+        let sql = include_str!("./sql/update.sql");
+        let result = sqlx::query(sql)
+            .bind(self.promotion.name.as_str())
+            .bind(discount_kind)
+            .bind(discount_value)
+            .bind(currency)
+            .bind(i16::from(self.promotion.priority))
+            .bind(self.promotion.validity.starts_at())
+            .bind(self.promotion.validity.ends_at())
+            .bind(self.promotion.metadata.updated_at())
+            .bind(self.promotion.id().uuid())
+            .execute(exec)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(sqlx::Error::RowNotFound);
+        }
+
+        Ok(())
+    }
+}
+
+/// Split `discount` into its `discount_kind`, `discount_value` and `currency` columns
+fn discount_columns(discount: &promotion::Discount) -> (&'static str, Decimal, Option<String>) {
+    match discount {
+        promotion::Discount::Percentage(percentage) => ("percentage", percentage.value(), None),
+        promotion::Discount::Fixed(amount) => (
+            "fixed",
+            amount.amount(),
+            Some(amount.currency().to_string()),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+
+    use domain::product;
+
+    use super::*;
+
+    #[sqlx::test(fixtures("seed"))]
+    async fn active_query_works(pool: PgPool) {
+        let at = OffsetDateTime::now_utc();
+        let result = ActiveQuery { at }.exec(&pool).await;
+        let models = result.expect("Active promotions from fixtures");
+        assert_eq!(models.len(), 1);
+        assert_eq!(models[0].targets.0.len(), 2);
+    }
+
+    #[sqlx::test(fixtures("seed"))]
+    async fn create_query_works(pool: PgPool) {
+        let promotion = promotion::Promotion::new(
+            promotion::Name::new("Vegan week").expect("Valid promotion name"),
+            promotion::Discount::Percentage(
+                promotion::Percentage::parse_str("5").expect("Valid percentage"),
+            ),
+            promotion::Targets::new(vec![promotion::Target::Kind(product::Kind::Vegan)])
+                .expect("Valid targets"),
+            promotion::Validity::default(),
+            2,
+        );
+
+        let result = CreateQuery {
+            promotion: &promotion,
+        }
+        .exec(&pool)
+        .await;
+        assert!(result.is_ok());
+
+        let result = CreateTargetsQuery {
+            id: promotion.id(),
+            targets: &promotion.targets,
+        }
+        .exec(&pool)
+        .await;
+        assert!(result.is_ok());
+
+        let model = FindQuery { id: promotion.id() }
+            .exec(&pool)
+            .await
+            .expect("Created promotion");
+        assert_eq!(model.discount_kind, "percentage");
+        assert_eq!(model.targets.0[0].kind.as_deref(), Some("vegan"));
+    }
+
+    #[sqlx::test(fixtures("seed"))]
+    async fn delete_query_works(pool: PgPool) {
+        let id = promotion::Id::parse_str("01913364-1f2a-7b3c-8d4e-5f6a7b8c9d01")
+            .expect("Valid promotion id from fixtures");
+
+        let result = DeleteQuery { id }.exec(&pool).await;
+        let model = result.expect("Deleted promotion");
+        assert_eq!(model.targets.0.len(), 2);
+
+        let result = FindQuery { id }.exec(&pool).await;
+        assert!(matches!(result, Err(sqlx::Error::RowNotFound)));
+    }
+}
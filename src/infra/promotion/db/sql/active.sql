@@ -0,0 +1,3 @@
+select promotion_with_targets.* from promotion_with_targets
+where (starts_at is null or starts_at <= $1) and (ends_at is null or ends_at > $1)
+order by id
\ No newline at end of file
@@ -0,0 +1 @@
+select promotion_with_targets.* from promotion_with_targets order by id
\ No newline at end of file
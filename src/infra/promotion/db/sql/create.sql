@@ -0,0 +1,5 @@
+insert into promotion (
+    id, name, discount_kind, discount_value, currency, priority,
+    starts_at, ends_at, created_at, updated_at
+)
+values ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
\ No newline at end of file
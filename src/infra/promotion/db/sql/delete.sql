@@ -0,0 +1,4 @@
+-- the outer select still sees the deleted promotion along with its targets
+with deleted as (delete from promotion where id = $1 returning id)
+select promotion_with_targets.* from promotion_with_targets
+inner join deleted on deleted.id = promotion_with_targets.id
\ No newline at end of file
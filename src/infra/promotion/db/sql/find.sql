@@ -0,0 +1 @@
+select promotion_with_targets.* from promotion_with_targets where id = $1
\ No newline at end of file
@@ -0,0 +1,2 @@
+insert into promotion_target (promotion_id, catalog_id, kind, product_id, extra_id)
+select * from unnest($1::uuid[], $2::uuid[], $3::varchar[], $4::uuid[], $5::uuid[])
\ No newline at end of file
@@ -0,0 +1 @@
+delete from promotion_target where promotion_id = $1
\ No newline at end of file
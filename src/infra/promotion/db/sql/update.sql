@@ -0,0 +1,4 @@
+update promotion
+set name = $1, discount_kind = $2, discount_value = $3, currency = $4, priority = $5,
+    starts_at = $6, ends_at = $7, updated_at = $8
+where id = $9
\ No newline at end of file
@@ -0,0 +1,91 @@
+use serde::Deserialize;
+use sqlx::types::{Decimal, Json, Uuid};
+use sqlx::FromRow;
+use time::OffsetDateTime;
+
+use domain::catalog;
+use domain::core::metadata;
+use domain::core::money::{Currency, Money};
+use domain::extra;
+use domain::product;
+use domain::promotion;
+
+#[derive(Clone, Debug, FromRow)]
+pub struct PromotionModel {
+    pub id: Uuid,
+    pub name: String,
+    pub discount_kind: String,
+    pub discount_value: Decimal,
+    pub currency: Option<String>,
+    pub priority: i16,
+    pub starts_at: Option<OffsetDateTime>,
+    pub ends_at: Option<OffsetDateTime>,
+    pub targets: Json<Vec<PromotionTargetModel>>,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+impl PromotionModel {
+    pub fn try_into_entity(self) -> Result<promotion::Promotion, Box<dyn std::error::Error>> {
+        let discount = match (self.discount_kind.as_str(), self.currency) {
+            ("percentage", None) => {
+                promotion::Discount::Percentage(promotion::Percentage::new(self.discount_value)?)
+            }
+            ("fixed", Some(currency)) => {
+                let currency = Currency::parse_str(&currency)?;
+                promotion::Discount::fixed(Money::new(self.discount_value, currency))?
+            }
+            _ => return Err("Promotion discount must be a percentage or a fixed amount".into()),
+        };
+
+        let targets = self
+            .targets
+            .0
+            .into_iter()
+            .map(PromotionTargetModel::try_into_target)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let metadata = metadata::Metadata::configured(self.created_at, self.updated_at)?;
+        let promotion = promotion::Promotion::config(promotion::PromotionConfig {
+            id: promotion::Id::from(self.id),
+            name: promotion::Name::new(self.name)?,
+            discount,
+            targets: promotion::Targets::new(targets)?,
+            validity: promotion::Validity::new(self.starts_at, self.ends_at)?,
+            priority: u8::try_from(self.priority)?,
+            metadata,
+        });
+
+        Ok(promotion)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct PromotionTargetModel {
+    pub catalog_id: Option<Uuid>,
+    pub kind: Option<String>,
+    pub product_id: Option<Uuid>,
+    pub extra_id: Option<Uuid>,
+}
+
+impl PromotionTargetModel {
+    pub fn try_into_target(self) -> Result<promotion::Target, Box<dyn std::error::Error>> {
+        let target = match (self.catalog_id, self.kind, self.product_id, self.extra_id) {
+            (Some(catalog_id), None, None, None) => {
+                promotion::Target::Catalog(catalog::Id::from(catalog_id))
+            }
+            (None, Some(kind), None, None) => {
+                promotion::Target::Kind(product::Kind::parse_str(&kind)?)
+            }
+            (None, None, Some(product_id), None) => {
+                promotion::Target::Product(product::Id::from(product_id))
+            }
+            (None, None, None, Some(extra_id)) => {
+                promotion::Target::Extra(extra::Id::from(extra_id))
+            }
+            _ => return Err("Promotion target must be exactly one of its columns".into()),
+        };
+
+        Ok(target)
+    }
+}
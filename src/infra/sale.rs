@@ -0,0 +1,5 @@
+mod db;
+mod model;
+
+pub use db::PgSales;
+pub(super) use model::SaleWithLinesModel;
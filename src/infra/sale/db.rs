@@ -0,0 +1,308 @@
+mod queries;
+
+use sqlx::PgPool;
+
+use domain::sale;
+
+use super::SaleWithLinesModel;
+
+#[derive(Clone, Debug)]
+pub struct PgSales {
+    pool: PgPool,
+}
+
+impl PgSales {
+    const PK: &'static str = "pk_sale";
+
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn is_pk_error(err: &sqlx::Error) -> bool {
+        err.as_database_error()
+            .is_some_and(|db_err| db_err.constraint() == Some(Self::PK))
+    }
+}
+
+impl sale::Repository for PgSales {
+    async fn create(&mut self, sale: &sale::Sale) -> Result<(), sale::Error> {
+        let mut trx = self.pool.begin().await.map_err(sale::Error::any)?;
+
+        let create_query = queries::CreateQuery { sale };
+        create_query.exec(trx.as_mut()).await.map_err(|err| {
+            if Self::is_pk_error(&err) {
+                sale::Error::id_conflict(sale.id())
+            } else {
+                sale::Error::any(err)
+            }
+        })?;
+
+        let create_lines_query = queries::CreateLinesQuery {
+            id: sale.id(),
+            lines: sale.lines().as_slice(),
+        };
+
+        create_lines_query
+            .exec(trx.as_mut())
+            .await
+            .map_err(sale::Error::any)?;
+
+        let create_extras_query = queries::CreateExtrasQuery {
+            lines: sale.lines().as_slice(),
+        };
+
+        create_extras_query
+            .exec(trx.as_mut())
+            .await
+            .map_err(sale::Error::any)?;
+
+        trx.commit().await.map_err(sale::Error::any)
+    }
+
+    async fn find(&self, id: sale::Id) -> Result<sale::Sale, sale::Error> {
+        let query = queries::FindQuery { id };
+        let model = query.exec(&self.pool).await.map_err(|err| match err {
+            sqlx::Error::RowNotFound => sale::Error::id_not_found(id),
+            _ => sale::Error::any(err),
+        })?;
+
+        model.try_into_entity().map_err(sale::Error::any)
+    }
+
+    async fn list(&self, query: sale::ListQuery) -> Result<sale::Pagination, sale::Error> {
+        let count = queries::CountQuery
+            .exec(&self.pool)
+            .await
+            .map_err(sale::Error::any)?;
+
+        let list_query = queries::ListQuery(query.clone());
+        let models = list_query
+            .exec(&self.pool)
+            .await
+            .map_err(sale::Error::any)?;
+
+        let sales = models
+            .into_iter()
+            .map(SaleWithLinesModel::try_into_entity)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(sale::Error::any)?;
+
+        Ok(sale::Pagination {
+            count,
+            page: query.page,
+            limit: query.limit,
+            items: sales,
+        })
+    }
+
+    async fn refund(&mut self, id: sale::Id, refund: &sale::Refund) -> Result<(), sale::Error> {
+        let mut trx = self.pool.begin().await.map_err(sale::Error::any)?;
+
+        let lock_query = queries::LockQuery {
+            id,
+            updated_at: refund.created_at(),
+        };
+        lock_query
+            .exec(trx.as_mut())
+            .await
+            .map_err(|err| match err {
+                sqlx::Error::RowNotFound => sale::Error::id_not_found(id),
+                _ => sale::Error::any(err),
+            })?;
+
+        // refunds recorded since the sale was loaded are only visible now,
+        // so quantities are checked again while the sale is locked
+        let find_query = queries::FindQuery { id };
+        let mut sale = find_query
+            .exec(trx.as_mut())
+            .await
+            .map_err(sale::Error::any)?
+            .try_into_entity()
+            .map_err(sale::Error::any)?;
+
+        sale.add_refund(refund.clone())?;
+
+        let create_refund_query = queries::CreateRefundQuery { id, refund };
+        create_refund_query
+            .exec(trx.as_mut())
+            .await
+            .map_err(sale::Error::any)?;
+
+        let create_lines_query = queries::CreateRefundLinesQuery { refund };
+        create_lines_query
+            .exec(trx.as_mut())
+            .await
+            .map_err(sale::Error::any)?;
+
+        trx.commit().await.map_err(sale::Error::any)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+
+    use domain::catalog;
+    use domain::core::metadata;
+    use domain::core::money::{Currency, Money};
+    use domain::core::quantity::Quantity;
+    use domain::extra;
+    use domain::product;
+    use domain::sale::Repository;
+
+    use super::*;
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn create_method_works(pool: PgPool) {
+        let sale = sale::Sale::new(sale::Lines::new(vec![mocked_line()]).expect("Valid lines"));
+
+        let mut repository = PgSales::new(pool);
+        let result = repository.create(&sale).await;
+        assert!(result.is_ok());
+
+        let created = repository.find(sale.id()).await.expect("Created sale");
+        assert_eq!(created.lines().len(), 1);
+        assert!(created.refunds().is_empty());
+        assert_eq!(created.total().ok(), sale.total().ok());
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn create_with_id_conflict(pool: PgPool) {
+        use sale::{ConflictKind, Error};
+
+        let sale = sale::Sale::config(sale::SaleConfig {
+            id: sale::Id::parse_str("01915a2e-3c4d-7e5f-8a6b-7c8d9e0f1a2b")
+                .expect("Valid sale id from fixtures"),
+            lines: sale::Lines::new(vec![mocked_line()]).expect("Valid lines"),
+            refunds: Vec::new(),
+            metadata: metadata::Metadata::new(),
+        });
+
+        let result = PgSales::new(pool).create(&sale).await;
+        assert!(matches!(result, Err(Error::Conflict(ConflictKind::Id(id))) if id == sale.id()));
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn find_method_works(pool: PgPool) {
+        let id = sale::Id::parse_str("01915a2e-3c4d-7e5f-8a6b-7c8d9e0f1a2b")
+            .expect("Valid sale id from fixtures");
+
+        let sale = PgSales::new(pool).find(id).await.expect("Found sale");
+        assert_eq!(sale.id(), id);
+        assert_eq!(
+            sale.total().ok(),
+            Some(Money::from_minor(6000, Currency::BRL))
+        );
+        assert_eq!(
+            sale.refunded().ok(),
+            Some(Money::from_minor(2000, Currency::BRL))
+        );
+
+        let line_id = sale.lines().as_slice()[0].id();
+        assert_eq!(sale.refundable_quantity(line_id), Some(2));
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn find_with_not_found(pool: PgPool) {
+        use sale::{Error, NotFoundKind};
+
+        let id = sale::Id::parse_str("01915a40-0d7e-7a61-b3c2-8f9e0a1b2c3d")
+            .expect("Valid sale id not in fixtures");
+
+        let result = PgSales::new(pool).find(id).await;
+        assert!(matches!(result, Err(Error::NotFound(NotFoundKind::Id(err_id))) if err_id == id));
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn list_method_works(pool: PgPool) {
+        use std::num::{NonZeroU32, NonZeroU8};
+
+        let query = sale::ListQuery {
+            page: NonZeroU32::new(1).unwrap(),
+            limit: NonZeroU8::new(10).unwrap(),
+        };
+
+        let pagination = PgSales::new(pool)
+            .list(query.clone())
+            .await
+            .expect("Paginated sale list");
+
+        assert_eq!(pagination.count, 1);
+        assert_eq!(pagination.items.len(), 1);
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn refund_method_works(pool: PgPool) {
+        let id = sale::Id::parse_str("01915a2e-3c4d-7e5f-8a6b-7c8d9e0f1a2b")
+            .expect("Valid sale id from fixtures");
+
+        let mut repository = PgSales::new(pool);
+        let mut sale = repository.find(id).await.expect("Found sale");
+        let reason = sale::Reason::new("Wrong order").expect("Valid reason");
+        let refund = sale.refund_all(reason).expect("Valid full refund");
+
+        let result = repository.refund(id, &refund).await;
+        assert!(result.is_ok());
+
+        let refunded = repository.find(id).await.expect("Refunded sale");
+        assert_eq!(refunded.refunds().len(), 2);
+        assert_eq!(refunded.refunded().ok(), refunded.total().ok());
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn refund_with_stale_sale(pool: PgPool) {
+        use sale::{ConflictKind, Error};
+
+        let id = sale::Id::parse_str("01915a2e-3c4d-7e5f-8a6b-7c8d9e0f1a2b")
+            .expect("Valid sale id from fixtures");
+
+        let mut repository = PgSales::new(pool);
+        let sale = repository.find(id).await.expect("Found sale");
+        let reason = sale::Reason::new("Wrong order").expect("Valid reason");
+
+        // both refunds are valid alone, but together exceed what was sold
+        let first = sale
+            .clone()
+            .refund_all(reason.clone())
+            .expect("Valid refund");
+        let second = sale.clone().refund_all(reason).expect("Valid refund");
+
+        assert!(repository.refund(id, &first).await.is_ok());
+        let result = repository.refund(id, &second).await;
+        assert!(matches!(
+            result,
+            Err(Error::Conflict(ConflictKind::Refund { refundable: 0, .. }))
+        ));
+    }
+
+    fn mocked_line() -> sale::Line {
+        let cheddar = extra::Extra::config(extra::ExtraConfig {
+            id: extra::Id::parse_str("0190ec13-15cc-7f53-bc0f-d60f0beea824")
+                .expect("Cheddar id from seed fixtures"),
+            name: extra::Name::new("Cheddar").expect("Valid extra name"),
+            price: Money::from_minor(200, Currency::BRL),
+            catalog_id: None,
+            category: None,
+            metadata: metadata::Metadata::new(),
+        });
+
+        let product = product::Product::config(product::ProductConfig {
+            id: product::Id::parse_str("0190ec14-0af8-71d1-9554-f1e5249ae3a2")
+                .expect("Valid product id from fixtures"),
+            catalog_id: catalog::Id::parse_str("0190ec30-286b-7211-aadb-003fc0449734")
+                .expect("Valid catalog id from fixtures"),
+            name: product::Name::new("Cheese Burger").expect("Valid product name"),
+            price: Money::from_minor(2000, Currency::BRL),
+            kind: product::Kind::Burger,
+            extras: Some(product::Extras::new(vec![cheddar.clone()]).expect("Valid extras")),
+            variants: None,
+            modifiers: None,
+            available: true,
+            schedule: None,
+            metadata: metadata::Metadata::new(),
+        });
+
+        let quantity = Quantity::new(2).expect("Valid quantity");
+        sale::Line::new(&product, &[cheddar.id()], quantity).expect("Valid sale line")
+    }
+}
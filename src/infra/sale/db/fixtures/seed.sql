@@ -0,0 +1,36 @@
+insert into catalog (id, name, description, created_at, updated_at)
+values
+    ('0190ec30-286b-7211-aadb-003fc0449734', 'Burgers', null, now(), now());
+
+insert into product (id, catalog_id, name, price, kind, created_at, updated_at)
+values
+    ('0190ec14-0af8-71d1-9554-f1e5249ae3a2', '0190ec30-286b-7211-aadb-003fc0449734', 'Cheese Burger', 20.00, 'burger', now(), now());
+
+insert into extra (id, name, price, created_at, updated_at)
+values
+    ('0190ec13-15cc-7f53-bc0f-d60f0beea824', 'Cheddar', 2.00, now(), now());
+
+insert into product_extras (product_id, extra_id)
+values
+    ('0190ec14-0af8-71d1-9554-f1e5249ae3a2', '0190ec13-15cc-7f53-bc0f-d60f0beea824');
+
+insert into sale (id, created_at, updated_at)
+values
+    ('01915a2e-3c4d-7e5f-8a6b-7c8d9e0f1a2b', now(), now());
+
+insert into sale_line (id, sale_id, position, product_id, catalog_id, name, price, currency, quantity)
+values
+    -- three Cheese Burgers with Cheddar, priced before any later change
+    ('01915a2e-4d5e-7f60-9b7c-8d9e0f1a2b3c', '01915a2e-3c4d-7e5f-8a6b-7c8d9e0f1a2b', 0, '0190ec14-0af8-71d1-9554-f1e5249ae3a2', '0190ec30-286b-7211-aadb-003fc0449734', 'Cheese Burger', 18.00, 'BRL', 3);
+
+insert into sale_line_extra (sale_line_id, extra_id, name, price, currency)
+values
+    ('01915a2e-4d5e-7f60-9b7c-8d9e0f1a2b3c', '0190ec13-15cc-7f53-bc0f-d60f0beea824', 'Cheddar', 2.00, 'BRL');
+
+insert into sale_refund (id, sale_id, reason, amount, currency, created_at)
+values
+    ('01915a30-5e6f-7071-8c8d-9e0f1a2b3c4d', '01915a2e-3c4d-7e5f-8a6b-7c8d9e0f1a2b', 'Burger arrived cold', 20.00, 'BRL', now());
+
+insert into sale_refund_line (refund_id, sale_line_id, quantity)
+values
+    ('01915a30-5e6f-7071-8c8d-9e0f1a2b3c4d', '01915a2e-4d5e-7f60-9b7c-8d9e0f1a2b3c', 1);
@@ -0,0 +1,289 @@
+use sqlx::PgExecutor;
+use time::OffsetDateTime;
+
+use domain::core::money::Currency;
+use domain::sale;
+
+use crate::infra::sale::SaleWithLinesModel;
+
+#[derive(Clone, Debug)]
+pub(super) struct CountQuery;
+
+impl CountQuery {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'_>) -> Result<u64, sqlx::Error> {
+        let sql = include_str!("./sql/count.sql");
+        let count: i64 = sqlx::query_scalar(sql).fetch_one(exec).await?;
+        Ok(u64::try_from(count).unwrap_or_default())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct CreateQuery<'a> {
+    pub(super) sale: &'a sale::Sale,
+}
+
+impl<'a> CreateQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
+        let sql = include_str!("./sql/create.sql");
+        sqlx::query(sql)
+            .bind(self.sale.id().uuid())
+            .bind(self.sale.metadata.created_at())
+            .bind(self.sale.metadata.updated_at())
+            .execute(exec)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct CreateLinesQuery<'a> {
+    pub(super) id: sale::Id,
+    pub(super) lines: &'a [sale::Line],
+}
+
+impl<'a> CreateLinesQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
+        let len = self.lines.len();
+        let mut ids = Vec::with_capacity(len);
+        let mut positions = Vec::with_capacity(len);
+        let mut products_ids = Vec::with_capacity(len);
+        let mut catalogs_ids = Vec::with_capacity(len);
+        let mut names = Vec::with_capacity(len);
+        let mut prices = Vec::with_capacity(len);
+        let mut currencies = Vec::with_capacity(len);
+        let mut quantities = Vec::with_capacity(len);
+
+        for (position, line) in self.lines.iter().enumerate() {
+            ids.push(line.id().uuid());
+            positions.push(i16::try_from(position).unwrap_or(i16::MAX));
+            products_ids.push(line.product_id().uuid());
+            catalogs_ids.push(line.catalog_id().uuid());
+            names.push(line.name().as_str());
+            prices.push(line.price().amount());
+            currencies.push(line.price().currency());
+            quantities.push(i16::try_from(line.quantity().value()).unwrap_or(i16::MAX));
+        }
+
+        let sql = include_str!("./sql/lines_create.sql");
+        sqlx::query(sql)
+            .bind(self.id.uuid())
+            .bind(ids)
+            .bind(positions)
+            .bind(products_ids)
+            .bind(catalogs_ids)
+            .bind(names)
+            .bind(prices)
+            .bind(currencies.iter().map(Currency::as_str).collect::<Vec<_>>())
+            .bind(quantities)
+            .execute(exec)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct CreateExtrasQuery<'a> {
+    pub(super) lines: &'a [sale::Line],
+}
+
+impl<'a> CreateExtrasQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
+        let mut lines_ids = Vec::new();
+        let mut extras_ids = Vec::new();
+        let mut names = Vec::new();
+        let mut prices = Vec::new();
+        let mut currencies = Vec::new();
+
+        for line in self.lines {
+            for extra in line.extras() {
+                lines_ids.push(line.id().uuid());
+                extras_ids.push(extra.id.uuid());
+                names.push(extra.name.as_str());
+                prices.push(extra.price.amount());
+                currencies.push(extra.price.currency());
+            }
+        }
+
+        let sql = include_str!("./sql/extras_create.sql");
+        sqlx::query(sql)
+            .bind(lines_ids)
+            .bind(extras_ids)
+            .bind(names)
+            .bind(prices)
+            .bind(currencies.iter().map(Currency::as_str).collect::<Vec<_>>())
+            .execute(exec)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct FindQuery {
+    pub(super) id: sale::Id,
+}
+
+impl FindQuery {
+    pub(super) async fn exec(
+        self,
+        exec: impl PgExecutor<'_>,
+    ) -> Result<SaleWithLinesModel, sqlx::Error> {
+        let sql = include_str!("./sql/find.sql");
+        sqlx::query_as(sql)
+            .bind(self.id.uuid())
+            .fetch_one(exec)
+            .await
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct ListQuery(pub(super) sale::ListQuery);
+
+impl ListQuery {
+    pub(super) async fn exec(
+        self,
+        exec: impl PgExecutor<'_>,
+    ) -> Result<Vec<SaleWithLinesModel>, sqlx::Error> {
+        let limit = u8::from(self.0.limit);
+        let page = u32::from(self.0.page);
+        let offset = page.saturating_sub(1) * u32::from(limit);
+
+        let sql = include_str!("./sql/list.sql");
+        sqlx::query_as(sql)
+            .bind(i64::from(limit))
+            .bind(i64::from(offset))
+            .fetch_all(exec)
+            .await
+    }
+}
+
+/// Touches sale `id`, holding its row lock until the transaction ends so
+/// concurrent refunds of the same sale are checked one after the other
+#[derive(Clone, Debug)]
+pub(super) struct LockQuery {
+    pub(super) id: sale::Id,
+    pub(super) updated_at: OffsetDateTime,
+}
+
+impl LockQuery {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'_>) -> Result<(), sqlx::Error> {
+        let sql = include_str!("./sql/lock.sql");
+        let result = sqlx::query(sql)
+            .bind(self.id.uuid())
+            .bind(self.updated_at)
+            .execute(exec)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(sqlx::Error::RowNotFound);
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct CreateRefundQuery<'a> {
+    pub(super) id: sale::Id,
+    pub(super) refund: &'a sale::Refund,
+}
+
+impl<'a> CreateRefundQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
+        let amount = self.refund.amount();
+        let sql = include_str!("./sql/refund_create.sql");
+        sqlx::query(sql)
+            .bind(self.refund.id().uuid())
+            .bind(self.id.uuid())
+            .bind(self.refund.reason().as_str())
+            .bind(amount.amount())
+            .bind(amount.currency().as_str())
+            .bind(self.refund.created_at())
+            .execute(exec)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct CreateRefundLinesQuery<'a> {
+    pub(super) refund: &'a sale::Refund,
+}
+
+impl<'a> CreateRefundLinesQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
+        let (lines_ids, quantities): (Vec<_>, Vec<_>) = self
+            .refund
+            .lines()
+            .iter()
+            .map(|line| {
+                let quantity = i16::try_from(line.quantity.value()).unwrap_or(i16::MAX);
+                (line.line_id.uuid(), quantity)
+            })
+            .unzip();
+
+        let sql = include_str!("./sql/refund_lines_create.sql");
+        sqlx::query(sql)
+            .bind(self.refund.id().uuid())
+            .bind(lines_ids)
+            .bind(quantities)
+            .execute(exec)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+
+    use super::*;
+
+    #[sqlx::test(fixtures("seed"))]
+    async fn count_query_works(pool: PgPool) {
+        let result = CountQuery.exec(&pool).await;
+        assert_eq!(result.ok(), Some(1u64));
+    }
+
+    #[sqlx::test(fixtures("seed"))]
+    async fn find_query_works(pool: PgPool) {
+        let id = sale::Id::parse_str("01915a2e-3c4d-7e5f-8a6b-7c8d9e0f1a2b")
+            .expect("Valid sale id from fixtures");
+
+        let result = FindQuery { id }.exec(&pool).await;
+        let found = result.expect("Found sale model");
+        assert_eq!(found.id, id.uuid());
+        assert_eq!(found.lines.len(), 1);
+        assert_eq!(found.lines[0].extras.len(), 1);
+        assert_eq!(found.refunds.len(), 1);
+        assert_eq!(found.refunds[0].lines.len(), 1);
+    }
+
+    #[sqlx::test(fixtures("seed"))]
+    async fn list_query_works(pool: PgPool) {
+        use std::num::{NonZeroU32, NonZeroU8};
+
+        let query = sale::ListQuery {
+            page: NonZeroU32::new(1).unwrap(),
+            limit: NonZeroU8::new(10).unwrap(),
+        };
+
+        let result = ListQuery(query).exec(&pool).await;
+        assert_eq!(result.map(|list| list.len()).ok(), Some(1));
+    }
+
+    #[sqlx::test(fixtures("seed"))]
+    async fn lock_query_with_not_found(pool: PgPool) {
+        let query = LockQuery {
+            id: sale::Id::new(),
+            updated_at: OffsetDateTime::now_utc(),
+        };
+
+        let result = query.exec(&pool).await;
+        assert!(matches!(result, Err(sqlx::Error::RowNotFound)));
+    }
+}
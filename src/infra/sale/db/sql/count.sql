@@ -0,0 +1 @@
+select count(id) from sale
\ No newline at end of file
@@ -0,0 +1,2 @@
+insert into sale_line_extra (sale_line_id, extra_id, name, price, currency)
+select * from unnest($1::uuid[], $2::uuid[], $3::varchar[], $4::decimal[], $5::char(3)[])
\ No newline at end of file
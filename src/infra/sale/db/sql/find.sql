@@ -0,0 +1,3 @@
+select sale.*
+from sale_with_lines as sale
+where sale.id = $1
\ No newline at end of file
@@ -0,0 +1,11 @@
+insert into sale_line (sale_id, id, position, product_id, catalog_id, name, price, currency, quantity)
+select $1, * from unnest(
+    $2::uuid[],
+    $3::smallint[],
+    $4::uuid[],
+    $5::uuid[],
+    $6::varchar[],
+    $7::decimal[],
+    $8::char(3)[],
+    $9::smallint[]
+)
\ No newline at end of file
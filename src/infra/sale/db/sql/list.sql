@@ -0,0 +1,4 @@
+select sale.*
+from sale_with_lines as sale
+order by sale.created_at desc
+limit $1 offset $2
\ No newline at end of file
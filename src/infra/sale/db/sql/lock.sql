@@ -0,0 +1,3 @@
+update sale
+set updated_at = $2
+where sale.id = $1
\ No newline at end of file
@@ -0,0 +1,2 @@
+insert into sale_refund (id, sale_id, reason, amount, currency, created_at)
+values ($1, $2, $3, $4, $5, $6)
\ No newline at end of file
@@ -0,0 +1,2 @@
+insert into sale_refund_line (refund_id, sale_line_id, quantity)
+select $1, * from unnest($2::uuid[], $3::smallint[])
\ No newline at end of file
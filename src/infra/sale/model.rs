@@ -0,0 +1,151 @@
+use serde::Deserialize;
+use sqlx::types::{Decimal, Json, Uuid};
+use sqlx::FromRow;
+use time::OffsetDateTime;
+
+use domain::catalog;
+use domain::core::metadata;
+use domain::core::money::{Currency, Money};
+use domain::core::quantity::Quantity;
+use domain::extra;
+use domain::product;
+use domain::sale;
+
+#[derive(Clone, Debug, Deserialize, FromRow)]
+pub struct SaleWithLinesModel {
+    pub id: Uuid,
+    pub lines: Json<Vec<SaleLineModel>>,
+    pub refunds: Json<Vec<SaleRefundModel>>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime,
+}
+
+impl SaleWithLinesModel {
+    pub fn try_into_entity(self) -> Result<sale::Sale, Box<dyn std::error::Error>> {
+        let lines = self
+            .lines
+            .0
+            .into_iter()
+            .map(SaleLineModel::try_into_entity)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let refunds = self
+            .refunds
+            .0
+            .into_iter()
+            .map(SaleRefundModel::try_into_entity)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let lines = sale::Lines::new(lines)?;
+        let metadata = metadata::Metadata::configured(self.created_at, self.updated_at)?;
+        let sale = sale::Sale::config(sale::SaleConfig {
+            id: sale::Id::from(self.id),
+            lines,
+            refunds,
+            metadata,
+        });
+
+        Ok(sale)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct SaleLineModel {
+    pub id: Uuid,
+    pub product_id: Uuid,
+    pub catalog_id: Uuid,
+    pub name: String,
+    pub price: Decimal,
+    pub currency: String,
+    pub quantity: i16,
+    pub extras: Vec<SaleLineExtraModel>,
+}
+
+impl SaleLineModel {
+    pub fn try_into_entity(self) -> Result<sale::Line, Box<dyn std::error::Error>> {
+        let name = product::Name::new(self.name)?;
+        let quantity = Quantity::new(u16::try_from(self.quantity)?)?;
+        let currency = Currency::parse_str(&self.currency)?;
+        let extras = self
+            .extras
+            .into_iter()
+            .map(SaleLineExtraModel::try_into_entity)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(sale::Line::config(sale::LineConfig {
+            id: sale::LineId::from(self.id),
+            product_id: product::Id::from(self.product_id),
+            catalog_id: catalog::Id::from(self.catalog_id),
+            name,
+            price: Money::new(self.price, currency),
+            quantity,
+            extras,
+        }))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct SaleLineExtraModel {
+    pub extra_id: Uuid,
+    pub name: String,
+    pub price: Decimal,
+    pub currency: String,
+}
+
+impl SaleLineExtraModel {
+    pub fn try_into_entity(self) -> Result<sale::LineExtra, Box<dyn std::error::Error>> {
+        let currency = Currency::parse_str(&self.currency)?;
+        Ok(sale::LineExtra {
+            id: extra::Id::from(self.extra_id),
+            name: extra::Name::new(self.name)?,
+            price: Money::new(self.price, currency),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct SaleRefundModel {
+    pub id: Uuid,
+    pub reason: String,
+    pub amount: Decimal,
+    pub currency: String,
+    pub lines: Vec<SaleRefundLineModel>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+}
+
+impl SaleRefundModel {
+    pub fn try_into_entity(self) -> Result<sale::Refund, Box<dyn std::error::Error>> {
+        let currency = Currency::parse_str(&self.currency)?;
+        let lines = self
+            .lines
+            .into_iter()
+            .map(SaleRefundLineModel::try_into_entity)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(sale::Refund::config(sale::RefundConfig {
+            id: sale::RefundId::from(self.id),
+            reason: sale::Reason::new(self.reason)?,
+            lines: sale::RefundLines::new(lines)?,
+            amount: Money::new(self.amount, currency),
+            created_at: self.created_at,
+        }))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct SaleRefundLineModel {
+    pub sale_line_id: Uuid,
+    pub quantity: i16,
+}
+
+impl SaleRefundLineModel {
+    pub fn try_into_entity(self) -> Result<sale::RefundLine, Box<dyn std::error::Error>> {
+        Ok(sale::RefundLine {
+            line_id: sale::LineId::from(self.sale_line_id),
+            quantity: Quantity::new(u16::try_from(self.quantity)?)?,
+        })
+    }
+}
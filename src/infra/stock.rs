@@ -0,0 +1,5 @@
+mod db;
+mod model;
+
+pub use db::PgStocks;
+pub(super) use model::StockModel;
@@ -0,0 +1,248 @@
+mod queries;
+
+use sqlx::PgPool;
+use time::OffsetDateTime;
+
+use domain::product;
+use domain::stock;
+
+use super::StockModel;
+
+#[derive(Clone, Debug)]
+pub struct PgStocks {
+    pool: PgPool,
+}
+
+impl PgStocks {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl stock::Repository for PgStocks {
+    async fn all(&self, products_ids: &[product::Id]) -> Result<stock::Stocks, stock::Error> {
+        let query = queries::AllQuery { products_ids };
+        let models = query.exec(&self.pool).await.map_err(stock::Error::any)?;
+
+        models
+            .into_iter()
+            .map(StockModel::try_into_entity)
+            .collect::<Result<Vec<_>, _>>()
+            .map(stock::Stocks::new)
+            .map_err(stock::Error::any)
+    }
+
+    async fn find(&self, product_id: product::Id) -> Result<stock::Stock, stock::Error> {
+        let query = queries::FindQuery { product_id };
+        let model = query.exec(&self.pool).await.map_err(|err| match err {
+            sqlx::Error::RowNotFound => stock::Error::untracked(product_id),
+            _ => stock::Error::any(err),
+        })?;
+
+        model.try_into_entity().map_err(stock::Error::any)
+    }
+
+    async fn adjust(
+        &mut self,
+        product_id: product::Id,
+        adjustment: stock::Adjustment,
+    ) -> Result<stock::Stock, stock::Error> {
+        let mut trx = self.pool.begin().await.map_err(stock::Error::any)?;
+
+        if !matches!(adjustment, stock::Adjustment::Decrement(_)) {
+            let track_query = queries::TrackQuery {
+                product_id,
+                created_at: OffsetDateTime::now_utc(),
+            };
+
+            track_query
+                .exec(trx.as_mut())
+                .await
+                .map_err(stock::Error::any)?;
+        }
+
+        let lock_query = queries::LockQuery { product_id };
+        lock_query
+            .exec(trx.as_mut())
+            .await
+            .map_err(|err| match err {
+                sqlx::Error::RowNotFound => stock::Error::untracked(product_id),
+                _ => stock::Error::any(err),
+            })?;
+
+        // expired holds of product are released under the lock before counting
+        // held units, as when holding them
+        let release_query = queries::ReleaseExpiredQuery {
+            product_id,
+            at: OffsetDateTime::now_utc(),
+        };
+        release_query
+            .exec(trx.as_mut())
+            .await
+            .map_err(stock::Error::any)?;
+
+        // counted only once the lock is held, so holds created while waiting
+        // for it are seen too
+        let find_query = queries::FindQuery { product_id };
+        let mut stock = find_query
+            .exec(trx.as_mut())
+            .await
+            .map_err(stock::Error::any)?
+            .try_into_entity()
+            .map_err(stock::Error::any)?;
+
+        stock.apply(adjustment)?;
+
+        let update_query = queries::UpdateQuery { stock: &stock };
+        update_query
+            .exec(trx.as_mut())
+            .await
+            .map_err(stock::Error::any)?;
+
+        trx.commit().await.map_err(stock::Error::any)?;
+
+        Ok(stock)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+
+    use domain::stock::Repository;
+
+    use super::*;
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn all_method_works(pool: PgPool) {
+        let tracked_id = product::Id::parse_str("0190ec14-0af8-71d1-9554-f1e5249ae3a2")
+            .expect("Valid product id from fixtures");
+        let untracked_id = product::Id::parse_str("0190ec15-7985-7e62-aaca-d65c07e6d2e5")
+            .expect("Valid product id from fixtures");
+
+        let stocks = PgStocks::new(pool)
+            .all(&[tracked_id, untracked_id])
+            .await
+            .expect("Found stocks");
+
+        assert_eq!(stocks.as_slice().len(), 1);
+        assert!(stocks.find(tracked_id).is_some());
+        assert!(stocks.find(untracked_id).is_none());
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn find_with_untracked(pool: PgPool) {
+        use stock::{Error, NotFoundKind};
+
+        let product_id = product::Id::parse_str("0190ec15-7985-7e62-aaca-d65c07e6d2e5")
+            .expect("Valid product id from fixtures");
+
+        let result = PgStocks::new(pool).find(product_id).await;
+        assert!(matches!(
+            result,
+            Err(Error::NotFound(NotFoundKind::Untracked(id))) if id == product_id
+        ));
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn adjust_method_works(pool: PgPool) {
+        let product_id = product::Id::parse_str("0190ec14-0af8-71d1-9554-f1e5249ae3a2")
+            .expect("Valid product id from fixtures");
+        let quantity = stock::Quantity::new(5).expect("Valid stock quantity");
+
+        let mut repository = PgStocks::new(pool);
+        let stock = repository
+            .adjust(product_id, stock::Adjustment::Decrement(quantity))
+            .await
+            .expect("Decremented stock");
+
+        assert!(stock.is_out_of_stock());
+
+        let found = repository.find(product_id).await.expect("Found stock");
+        assert!(found.is_out_of_stock());
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn adjust_releases_expired_holds(pool: PgPool) {
+        let product_id = product::Id::parse_str("0190ec14-0af8-71d1-9554-f1e5249ae3a2")
+            .expect("Valid product id from fixtures");
+        let quantity = stock::Quantity::new(5).expect("Valid stock quantity");
+
+        // units of the expired hold from fixtures can be consumed right away
+        let result = PgStocks::new(pool.clone())
+            .adjust(product_id, stock::Adjustment::Decrement(quantity))
+            .await;
+        assert!(result.is_ok());
+
+        let holds = sqlx::query_scalar::<_, i64>("select count(*) from hold")
+            .fetch_one(&pool)
+            .await;
+        assert_eq!(holds.ok(), Some(0));
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn adjust_untracked_starts_tracking(pool: PgPool) {
+        let product_id = product::Id::parse_str("0190ec15-7985-7e62-aaca-d65c07e6d2e5")
+            .expect("Valid product id from fixtures");
+        let quantity = stock::Quantity::new(3).expect("Valid stock quantity");
+
+        let mut repository = PgStocks::new(pool);
+        let stock = repository
+            .adjust(product_id, stock::Adjustment::Increment(quantity))
+            .await
+            .expect("Incremented stock");
+
+        assert_eq!(stock.level().value(), 3);
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn adjust_with_insufficient_stock(pool: PgPool) {
+        use stock::{ConflictKind, Error};
+
+        let product_id = product::Id::parse_str("0190fe96-917c-7ec2-a1cf-831f117df95a")
+            .expect("Valid product id from fixtures");
+        let quantity = stock::Quantity::new(1).expect("Valid stock quantity");
+
+        let result = PgStocks::new(pool)
+            .adjust(product_id, stock::Adjustment::Decrement(quantity))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(Error::Conflict(ConflictKind::Insufficient {
+                requested: 1,
+                available: 0,
+                ..
+            }))
+        ));
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn adjust_concurrently_never_oversells(pool: PgPool) {
+        let product_id = product::Id::parse_str("0190ec14-0af8-71d1-9554-f1e5249ae3a2")
+            .expect("Valid product id from fixtures");
+        let quantity = stock::Quantity::new(1).expect("Valid stock quantity");
+
+        let tasks = (0..8).map(|_| {
+            let mut repository = PgStocks::new(pool.clone());
+            tokio::spawn(async move {
+                repository
+                    .adjust(product_id, stock::Adjustment::Decrement(quantity))
+                    .await
+                    .is_ok()
+            })
+        });
+
+        let mut consumed = 0;
+        for task in tasks.collect::<Vec<_>>() {
+            if task.await.expect("Finished task") {
+                consumed += 1;
+            }
+        }
+
+        // only the 5 units from fixtures can be consumed
+        assert_eq!(consumed, 5);
+        let stock = PgStocks::new(pool).find(product_id).await.expect("Stock");
+        assert!(stock.is_out_of_stock());
+    }
+}
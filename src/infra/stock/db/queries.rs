@@ -0,0 +1,176 @@
+use sqlx::PgExecutor;
+use time::OffsetDateTime;
+
+use domain::product;
+use domain::stock;
+
+use crate::infra::stock::StockModel;
+
+#[derive(Clone, Debug)]
+pub(super) struct AllQuery<'a> {
+    pub(super) products_ids: &'a [product::Id],
+}
+
+impl<'a> AllQuery<'a> {
+    pub(super) async fn exec(
+        self,
+        exec: impl PgExecutor<'a>,
+    ) -> Result<Vec<StockModel>, sqlx::Error> {
+        let products_ids = self
+            .products_ids
+            .iter()
+            .map(product::Id::uuid)
+            .collect::<Vec<_>>();
+
+        let sql = include_str!("./sql/all.sql");
+        sqlx::query_as(sql).bind(products_ids).fetch_all(exec).await
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct FindQuery {
+    pub(super) product_id: product::Id,
+}
+
+impl FindQuery {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'_>) -> Result<StockModel, sqlx::Error> {
+        let sql = include_str!("./sql/find.sql");
+        sqlx::query_as(sql)
+            .bind(self.product_id.uuid())
+            .fetch_one(exec)
+            .await
+    }
+}
+
+/// Starts tracking stock of product at zero, unless already tracked
+#[derive(Clone, Debug)]
+pub(super) struct TrackQuery {
+    pub(super) product_id: product::Id,
+    pub(super) created_at: OffsetDateTime,
+}
+
+impl TrackQuery {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'_>) -> Result<(), sqlx::Error> {
+        let sql = include_str!("./sql/track.sql");
+        sqlx::query(sql)
+            .bind(self.product_id.uuid())
+            .bind(self.created_at)
+            .execute(exec)
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// Locks stock row of product until the transaction ends, so its held units
+/// must be counted by a later statement to see holds committed meanwhile
+#[derive(Clone, Debug)]
+pub(super) struct LockQuery {
+    pub(super) product_id: product::Id,
+}
+
+impl LockQuery {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'_>) -> Result<(), sqlx::Error> {
+        let sql = include_str!("./sql/lock.sql");
+        sqlx::query(sql)
+            .bind(self.product_id.uuid())
+            .fetch_one(exec)
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// Deletes holds of product expired `at`
+#[derive(Clone, Debug)]
+pub(super) struct ReleaseExpiredQuery {
+    pub(super) product_id: product::Id,
+    pub(super) at: OffsetDateTime,
+}
+
+impl ReleaseExpiredQuery {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'_>) -> Result<u64, sqlx::Error> {
+        let sql = include_str!("./sql/release_expired.sql");
+        let result = sqlx::query(sql)
+            .bind(self.product_id.uuid())
+            .bind(self.at)
+            .execute(exec)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct UpdateQuery<'a> {
+    pub(super) stock: &'a stock::Stock,
+}
+
+impl<'a> UpdateQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
+        let level = i32::try_from(self.stock.level().value()).unwrap_or(i32::MAX);
+        let sql = include_str!("./sql/update.sql");
+        sqlx::query(sql)
+            .bind(self.stock.product_id().uuid())
+            .bind(level)
+            .bind(self.stock.metadata.updated_at())
+            .execute(exec)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+
+    use super::*;
+
+    #[sqlx::test(fixtures("seed"))]
+    async fn all_query_works(pool: PgPool) {
+        let products_ids = [
+            product::Id::parse_str("0190ec14-0af8-71d1-9554-f1e5249ae3a2")
+                .expect("Valid product id from fixtures"),
+            product::Id::parse_str("0190fe96-917c-7ec2-a1cf-831f117df95a")
+                .expect("Valid product id from fixtures"),
+        ];
+
+        let result = AllQuery {
+            products_ids: &products_ids,
+        }
+        .exec(&pool)
+        .await;
+
+        assert_eq!(result.map(|models| models.len()).ok(), Some(2));
+    }
+
+    #[sqlx::test(fixtures("seed"))]
+    async fn track_query_keeps_tracked_level(pool: PgPool) {
+        let product_id = product::Id::parse_str("0190ec14-0af8-71d1-9554-f1e5249ae3a2")
+            .expect("Valid product id from fixtures");
+
+        let query = TrackQuery {
+            product_id,
+            created_at: OffsetDateTime::now_utc(),
+        };
+        assert!(query.exec(&pool).await.is_ok());
+
+        let found = FindQuery { product_id }
+            .exec(&pool)
+            .await
+            .expect("Found stock model");
+        assert_eq!(found.level, 5);
+    }
+
+    #[sqlx::test(fixtures("seed"))]
+    async fn release_expired_query_works(pool: PgPool) {
+        let query = ReleaseExpiredQuery {
+            product_id: product::Id::parse_str("0190ec14-0af8-71d1-9554-f1e5249ae3a2")
+                .expect("Valid product id from fixtures"),
+            at: OffsetDateTime::now_utc(),
+        };
+
+        assert_eq!(query.exec(&pool).await.ok(), Some(1));
+    }
+}
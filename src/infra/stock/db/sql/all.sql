@@ -0,0 +1,3 @@
+select stock.*, (select coalesce(sum(hold.quantity), 0) from hold where hold.product_id = stock.product_id) as held
+from stock
+where stock.product_id = any($1)
\ No newline at end of file
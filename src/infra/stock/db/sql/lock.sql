@@ -0,0 +1,4 @@
+select stock.product_id
+from stock
+where stock.product_id = $1
+for update of stock
\ No newline at end of file
@@ -0,0 +1 @@
+delete from hold where hold.product_id = $1 and hold.expires_at <= $2
\ No newline at end of file
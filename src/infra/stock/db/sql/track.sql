@@ -0,0 +1,3 @@
+insert into stock (product_id, level, created_at, updated_at)
+values ($1, 0, $2, $2)
+on conflict (product_id) do nothing
\ No newline at end of file
@@ -0,0 +1,3 @@
+update stock
+set level = $2, updated_at = $3
+where stock.product_id = $1
\ No newline at end of file
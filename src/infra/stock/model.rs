@@ -0,0 +1,31 @@
+use sqlx::types::Uuid;
+use sqlx::FromRow;
+use time::OffsetDateTime;
+
+use domain::core::metadata;
+use domain::product;
+use domain::stock;
+
+#[derive(Clone, Debug, FromRow)]
+pub struct StockModel {
+    pub product_id: Uuid,
+    pub level: i32,
+    pub held: i64,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+impl StockModel {
+    pub fn try_into_entity(self) -> Result<stock::Stock, Box<dyn std::error::Error>> {
+        let level = stock::Level::new(u32::try_from(self.level)?)?;
+        let metadata = metadata::Metadata::configured(self.created_at, self.updated_at)?;
+        let stock = stock::Stock::config(stock::StockConfig {
+            product_id: product::Id::from(self.product_id),
+            level,
+            held: u32::try_from(self.held)?,
+            metadata,
+        });
+
+        Ok(stock)
+    }
+}
@@ -0,0 +1,5 @@
+mod db;
+mod model;
+
+pub use db::PgTaxRules;
+pub(super) use model::TaxRuleModel;
@@ -0,0 +1,214 @@
+mod queries;
+
+use sqlx::PgPool;
+
+use domain::tax;
+
+use super::model::TaxRuleModel;
+
+#[derive(Clone, Debug)]
+pub struct PgTaxRules {
+    pool: PgPool,
+}
+
+impl PgTaxRules {
+    const PK: &'static str = "pk_tax_rule";
+    const AK_KIND: &'static str = "ak_tax_rule_kind";
+    const AK_CATALOG_ID: &'static str = "ak_tax_rule_catalog_id";
+    const FK_CATALOG_ID: &'static str = "fk_tax_rule_catalog_id";
+
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn is_pk_error(err: &sqlx::Error) -> bool {
+        err.as_database_error()
+            .is_some_and(|db_err| db_err.constraint() == Some(Self::PK))
+    }
+
+    fn is_ak_target_error(err: &sqlx::Error) -> bool {
+        err.as_database_error().is_some_and(|db_err| {
+            matches!(
+                db_err.constraint(),
+                Some(Self::AK_KIND | Self::AK_CATALOG_ID)
+            )
+        })
+    }
+
+    fn is_fk_catalog_id_error(err: &sqlx::Error) -> bool {
+        err.as_database_error()
+            .is_some_and(|db_err| db_err.constraint() == Some(Self::FK_CATALOG_ID))
+    }
+
+    fn map_write_error(err: sqlx::Error, rule: &tax::TaxRule) -> tax::Error {
+        if Self::is_ak_target_error(&err) {
+            tax::Error::target_conflict(rule.target)
+        } else if Self::is_fk_catalog_id_error(&err) {
+            match rule.target {
+                tax::Target::Catalog(catalog_id) => {
+                    tax::Error::NotFound(tax::NotFoundKind::Catalog(catalog_id))
+                }
+                tax::Target::Kind(_) => tax::Error::any(err),
+            }
+        } else {
+            tax::Error::any(err)
+        }
+    }
+}
+
+impl tax::Repository for PgTaxRules {
+    async fn all(&self) -> Result<tax::Rules, tax::Error> {
+        let models = queries::AllQuery
+            .exec(&self.pool)
+            .await
+            .map_err(tax::Error::any)?;
+
+        models
+            .into_iter()
+            .map(TaxRuleModel::try_into_entity)
+            .collect::<Result<Vec<_>, _>>()
+            .map(tax::Rules::new)
+            .map_err(tax::Error::any)
+    }
+
+    async fn create(&mut self, rule: &tax::TaxRule) -> Result<(), tax::Error> {
+        let query = queries::CreateQuery { rule };
+        query.exec(&self.pool).await.map_err(|err| {
+            if Self::is_pk_error(&err) {
+                tax::Error::id_conflict(rule.id())
+            } else {
+                Self::map_write_error(err, rule)
+            }
+        })
+    }
+
+    async fn delete(&mut self, id: tax::Id) -> Result<tax::TaxRule, tax::Error> {
+        let query = queries::DeleteQuery { id };
+        let model = query.exec(&self.pool).await.map_err(|err| match &err {
+            sqlx::Error::RowNotFound => tax::Error::id_not_found(id),
+            _ => tax::Error::any(err),
+        })?;
+
+        model.try_into_entity().map_err(tax::Error::any)
+    }
+
+    async fn find(&self, id: tax::Id) -> Result<tax::TaxRule, tax::Error> {
+        let query = queries::FindQuery { id };
+        let model = query.exec(&self.pool).await.map_err(|err| match &err {
+            sqlx::Error::RowNotFound => tax::Error::id_not_found(id),
+            _ => tax::Error::any(err),
+        })?;
+
+        model.try_into_entity().map_err(tax::Error::any)
+    }
+
+    async fn update(&mut self, rule: &tax::TaxRule) -> Result<(), tax::Error> {
+        let query = queries::UpdateQuery { rule };
+        query.exec(&self.pool).await.map_err(|err| {
+            if matches!(err, sqlx::Error::RowNotFound) {
+                tax::Error::id_not_found(rule.id())
+            } else {
+                Self::map_write_error(err, rule)
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+
+    use domain::catalog;
+    use domain::product;
+    use domain::tax::Repository;
+
+    use super::*;
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn all_method_works(pool: PgPool) {
+        let result = PgTaxRules::new(pool).all().await;
+        let rules = result.expect("All tax rules from fixtures");
+        assert_eq!(rules.as_slice().len(), 2);
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn create_method_works(pool: PgPool) {
+        let rule = mocked_rule(tax::Target::Kind(product::Kind::Vegan));
+
+        let result = PgTaxRules::new(pool).create(&rule).await;
+        assert!(result.is_ok());
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn create_with_target_conflict(pool: PgPool) {
+        use tax::{ConflictKind, Error};
+
+        let target = tax::Target::Kind(product::Kind::Burger);
+        let rule = mocked_rule(target);
+
+        let result = PgTaxRules::new(pool).create(&rule).await;
+        assert!(
+            matches!(result, Err(Error::Conflict(ConflictKind::Target(err_target))) if err_target == target)
+        );
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn create_with_catalog_not_found(pool: PgPool) {
+        use tax::{Error, NotFoundKind};
+
+        let catalog_id = catalog::Id::parse_str("01912a4f-3c2b-7d8e-9a1f-5b6c7d8e9f0a")
+            .expect("Valid catalog id not in fixtures");
+        let rule = mocked_rule(tax::Target::Catalog(catalog_id));
+
+        let result = PgTaxRules::new(pool).create(&rule).await;
+        assert!(
+            matches!(result, Err(Error::NotFound(NotFoundKind::Catalog(err_id))) if err_id == catalog_id)
+        );
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn delete_method_works(pool: PgPool) {
+        let id = tax::Id::parse_str("01912a40-5b1e-7c3d-9f2a-6e8b4d0c1a37")
+            .expect("Valid tax rule id from fixtures");
+
+        let result = PgTaxRules::new(pool).delete(id).await;
+        assert!(matches!(result, Ok(rule) if rule.id() == id));
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn find_with_not_found(pool: PgPool) {
+        use tax::{Error, NotFoundKind};
+
+        let id = tax::Id::parse_str("01912a4e-9d8c-7b6a-8f5e-4d3c2b1a0f9e")
+            .expect("Valid tax rule id not in fixtures");
+
+        let result = PgTaxRules::new(pool).find(id).await;
+        assert!(matches!(result, Err(Error::NotFound(NotFoundKind::Id(err_id))) if err_id == id));
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn update_with_target_conflict(pool: PgPool) {
+        use tax::{ConflictKind, Error};
+
+        let mut repository = PgTaxRules::new(pool);
+        let id = tax::Id::parse_str("01912a41-0c7d-7e95-a3b6-2f4d8e1c5b09")
+            .expect("Valid tax rule id from fixtures");
+        let mut rule = repository.find(id).await.expect("Tax rule from fixtures");
+        rule.target = tax::Target::Kind(product::Kind::Burger);
+
+        let result = repository.update(&rule).await;
+        assert!(matches!(
+            result,
+            Err(Error::Conflict(ConflictKind::Target(_)))
+        ));
+    }
+
+    fn mocked_rule(target: tax::Target) -> tax::TaxRule {
+        tax::TaxRule::new(
+            tax::Name::new("ICMS").expect("Valid tax name"),
+            target,
+            tax::Rate::parse_str("18").expect("Valid tax rate"),
+            tax::Mode::Inclusive,
+        )
+    }
+}
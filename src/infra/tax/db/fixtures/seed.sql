@@ -0,0 +1,9 @@
+insert into catalog (id, name, description, created_at, updated_at)
+values
+    ('0190ec30-286b-7211-aadb-003fc0449734', 'Burgers', null, now(), now()),
+    ('0190ec30-7e38-75c0-a207-13c52449957d', 'Vegan', null, now(), now());
+
+insert into tax_rule (id, name, kind, catalog_id, rate, mode, created_at, updated_at)
+values
+    ('01912a40-5b1e-7c3d-9f2a-6e8b4d0c1a37', 'ICMS', 'burger', null, 18, 'inclusive', now(), now()),
+    ('01912a41-0c7d-7e95-a3b6-2f4d8e1c5b09', 'ISS', null, '0190ec30-7e38-75c0-a207-13c52449957d', 5, 'exclusive', now(), now());
@@ -0,0 +1,167 @@
+use sqlx::PgExecutor;
+
+use domain::tax;
+
+use crate::infra::tax::TaxRuleModel;
+
+#[derive(Clone, Debug)]
+pub(super) struct AllQuery;
+
+impl AllQuery {
+    pub(super) async fn exec(
+        self,
+        exec: impl PgExecutor<'_>,
+    ) -> Result<Vec<TaxRuleModel>, sqlx::Error> {
+        let sql = include_str!("./sql/all.sql");
+        sqlx::query_as(sql).fetch_all(exec).await
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct CreateQuery<'a> {
+    pub(super) rule: &'a tax::TaxRule,
+}
+
+impl<'a> CreateQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
+        let (kind, catalog_id) = target_columns(&self.rule.target);
+        let sql = include_str!("./sql/create.sql");
+        sqlx::query(sql)
+            .bind(self.rule.id().uuid())
+            .bind(self.rule.name.as_str())
+            .bind(kind)
+            .bind(catalog_id)
+            .bind(self.rule.rate.value())
+            .bind(self.rule.mode.as_str())
+            .bind(self.rule.metadata.created_at())
+            .bind(self.rule.metadata.updated_at())
+            .execute(exec)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct DeleteQuery {
+    pub(super) id: tax::Id,
+}
+
+impl DeleteQuery {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'_>) -> Result<TaxRuleModel, sqlx::Error> {
+        let sql = include_str!("./sql/delete.sql");
+        sqlx::query_as(sql)
+            .bind(self.id.uuid())
+            .fetch_one(exec)
+            .await
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct FindQuery {
+    pub(super) id: tax::Id,
+}
+
+impl FindQuery {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'_>) -> Result<TaxRuleModel, sqlx::Error> {
+        let sql = include_str!("./sql/find.sql");
+        sqlx::query_as(sql)
+            .bind(self.id.uuid())
+            .fetch_one(exec)
+            .await
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct UpdateQuery<'a> {
+    pub(super) rule: &'a tax::TaxRule,
+}
+
+impl<'a> UpdateQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
+        let (kind, catalog_id) = target_columns(&self.rule.target);
+        let sql = include_str!("./sql/update.sql");
+        let result = sqlx::query(sql)
+            .bind(self.rule.name.as_str())
+            .bind(kind)
+            .bind(catalog_id)
+            .bind(self.rule.rate.value())
+            .bind(self.rule.mode.as_str())
+            .bind(self.rule.metadata.updated_at())
+            .bind(self.rule.id().uuid())
+            .execute(exec)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(sqlx::Error::RowNotFound);
+        }
+
+        Ok(())
+    }
+}
+
+/// Split `target` into its `kind` and `catalog_id` columns
+fn target_columns(target: &tax::Target) -> (Option<&str>, Option<sqlx::types::Uuid>) {
+    match target {
+        tax::Target::Catalog(catalog_id) => (None, Some(catalog_id.uuid())),
+        tax::Target::Kind(kind) => (Some(kind.as_str()), None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+
+    use domain::product;
+
+    use super::*;
+
+    #[sqlx::test(fixtures("seed"))]
+    async fn all_query_works(pool: PgPool) {
+        let result = AllQuery.exec(&pool).await;
+        let models = result.expect("All tax rules from fixtures");
+        assert_eq!(models.len(), 2);
+    }
+
+    #[sqlx::test(fixtures("seed"))]
+    async fn create_query_works(pool: PgPool) {
+        let rule = tax::TaxRule::new(
+            tax::Name::new("ICMS").expect("Valid tax name"),
+            tax::Target::Kind(product::Kind::Vegan),
+            tax::Rate::parse_str("12").expect("Valid tax rate"),
+            tax::Mode::Inclusive,
+        );
+
+        let result = CreateQuery { rule: &rule }.exec(&pool).await;
+        assert!(result.is_ok());
+
+        let model = FindQuery { id: rule.id() }
+            .exec(&pool)
+            .await
+            .expect("Created tax rule");
+        assert_eq!(model.kind.as_deref(), Some("vegan"));
+        assert_eq!(model.catalog_id, None);
+    }
+
+    #[sqlx::test(fixtures("seed"))]
+    async fn update_query_works(pool: PgPool) {
+        let id = tax::Id::parse_str("01912a40-5b1e-7c3d-9f2a-6e8b4d0c1a37")
+            .expect("Valid tax rule id from fixtures");
+        let mut rule = FindQuery { id }
+            .exec(&pool)
+            .await
+            .expect("Tax rule from fixtures")
+            .try_into_entity()
+            .expect("Valid tax rule from fixtures");
+        rule.mode = tax::Mode::Exclusive;
+
+        let result = UpdateQuery { rule: &rule }.exec(&pool).await;
+        assert!(result.is_ok());
+
+        let model = FindQuery { id }
+            .exec(&pool)
+            .await
+            .expect("Updated tax rule");
+        assert_eq!(model.mode, "exclusive");
+    }
+}
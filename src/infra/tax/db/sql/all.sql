@@ -0,0 +1 @@
+select tax_rule.* from tax_rule order by id
\ No newline at end of file
@@ -0,0 +1,2 @@
+insert into tax_rule (id, name, kind, catalog_id, rate, mode, created_at, updated_at)
+values ($1, $2, $3, $4, $5, $6, $7, $8)
\ No newline at end of file
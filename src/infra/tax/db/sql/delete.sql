@@ -0,0 +1,2 @@
+delete from tax_rule where id = $1
+returning *
\ No newline at end of file
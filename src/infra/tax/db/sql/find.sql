@@ -0,0 +1 @@
+select tax_rule.* from tax_rule where id = $1
\ No newline at end of file
@@ -0,0 +1,3 @@
+update tax_rule
+set name = $1, kind = $2, catalog_id = $3, rate = $4, mode = $5, updated_at = $6
+where id = $7
\ No newline at end of file
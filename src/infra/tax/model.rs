@@ -0,0 +1,41 @@
+use sqlx::types::{Decimal, Uuid};
+use sqlx::FromRow;
+use time::OffsetDateTime;
+
+use domain::catalog;
+use domain::core::metadata;
+use domain::product;
+use domain::tax;
+
+#[derive(Clone, Debug, FromRow)]
+pub struct TaxRuleModel {
+    pub id: Uuid,
+    pub name: String,
+    pub kind: Option<String>,
+    pub catalog_id: Option<Uuid>,
+    pub rate: Decimal,
+    pub mode: String,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+impl TaxRuleModel {
+    pub fn try_into_entity(self) -> Result<tax::TaxRule, Box<dyn std::error::Error>> {
+        let target = match (self.kind, self.catalog_id) {
+            (Some(kind), None) => tax::Target::Kind(product::Kind::parse_str(&kind)?),
+            (None, Some(catalog_id)) => tax::Target::Catalog(catalog::Id::from(catalog_id)),
+            _ => return Err("Tax rule must target either a kind or a catalog".into()),
+        };
+        let metadata = metadata::Metadata::configured(self.created_at, self.updated_at)?;
+        let rule = tax::TaxRule::config(tax::TaxRuleConfig {
+            id: tax::Id::from(self.id),
+            name: tax::Name::new(self.name)?,
+            target,
+            rate: tax::Rate::new(self.rate)?,
+            mode: tax::Mode::parse_str(&self.mode)?,
+            metadata,
+        });
+
+        Ok(rule)
+    }
+}
@@ -0,0 +1,5 @@
+mod db;
+mod model;
+
+pub use db::PgTickets;
+pub(super) use model::TicketWithProductModel;
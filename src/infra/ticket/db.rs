@@ -0,0 +1,278 @@
+mod queries;
+
+use sqlx::PgPool;
+
+use domain::ticket;
+
+use super::TicketWithProductModel;
+
+#[derive(Clone, Debug)]
+pub struct PgTickets {
+    pool: PgPool,
+}
+
+impl PgTickets {
+    const PK: &'static str = "pk_ticket";
+
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn is_pk_error(err: &sqlx::Error) -> bool {
+        err.as_database_error()
+            .is_some_and(|db_err| db_err.constraint() == Some(Self::PK))
+    }
+}
+
+impl ticket::Repository for PgTickets {
+    async fn create(&mut self, ticket: &ticket::Ticket) -> Result<(), ticket::Error> {
+        let mut trx = self.pool.begin().await.map_err(ticket::Error::any)?;
+
+        let create_query = queries::CreateQuery { ticket };
+        create_query.exec(trx.as_mut()).await.map_err(|err| {
+            if Self::is_pk_error(&err) {
+                ticket::Error::id_conflict(ticket.id())
+            } else {
+                ticket::Error::any(err)
+            }
+        })?;
+
+        let create_extras_query = queries::CreateExtrasQuery { ticket };
+        create_extras_query
+            .exec(trx.as_mut())
+            .await
+            .map_err(ticket::Error::any)?;
+
+        trx.commit().await.map_err(ticket::Error::any)
+    }
+
+    async fn delete(&mut self, id: ticket::Id) -> Result<ticket::Ticket, ticket::Error> {
+        let query = queries::DeleteQuery { id };
+        let model = query.exec(&self.pool).await.map_err(|err| match err {
+            sqlx::Error::RowNotFound => ticket::Error::id_not_found(id),
+            _ => ticket::Error::any(err),
+        })?;
+
+        model.try_into_entity().map_err(ticket::Error::any)
+    }
+
+    async fn find(&self, id: ticket::Id) -> Result<ticket::Ticket, ticket::Error> {
+        let query = queries::FindQuery { id };
+        let model = query.exec(&self.pool).await.map_err(|err| match err {
+            sqlx::Error::RowNotFound => ticket::Error::id_not_found(id),
+            _ => ticket::Error::any(err),
+        })?;
+
+        model.try_into_entity().map_err(ticket::Error::any)
+    }
+
+    async fn list(&self, query: ticket::ListQuery) -> Result<ticket::Pagination, ticket::Error> {
+        let count_query = queries::CountQuery {
+            status: query.status,
+        };
+
+        let count = count_query
+            .exec(&self.pool)
+            .await
+            .map_err(ticket::Error::any)?;
+
+        let list_query = queries::ListQuery(query.clone());
+        let models = list_query
+            .exec(&self.pool)
+            .await
+            .map_err(ticket::Error::any)?;
+
+        let tickets = models
+            .into_iter()
+            .map(TicketWithProductModel::try_into_entity)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(ticket::Error::any)?;
+
+        Ok(ticket::Pagination {
+            count,
+            page: query.page,
+            limit: query.limit,
+            items: tickets,
+        })
+    }
+
+    async fn update(
+        &mut self,
+        ticket: &ticket::Ticket,
+        status: ticket::Status,
+    ) -> Result<(), ticket::Error> {
+        let mut trx = self.pool.begin().await.map_err(ticket::Error::any)?;
+
+        // no row means the ticket left `status` since it was read
+        let update_query = queries::UpdateQuery { ticket, status };
+        update_query
+            .exec(trx.as_mut())
+            .await
+            .map_err(|err| match err {
+                sqlx::Error::RowNotFound => ticket::Error::status_conflict(ticket.id(), status),
+                _ => ticket::Error::any(err),
+            })?;
+
+        let delete_extras_query = queries::DeleteExtrasQuery { id: ticket.id() };
+        delete_extras_query
+            .exec(trx.as_mut())
+            .await
+            .map_err(ticket::Error::any)?;
+
+        let create_extras_query = queries::CreateExtrasQuery { ticket };
+        create_extras_query
+            .exec(trx.as_mut())
+            .await
+            .map_err(ticket::Error::any)?;
+
+        trx.commit().await.map_err(ticket::Error::any)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+
+    use domain::catalog;
+    use domain::core::metadata;
+    use domain::core::money::{Currency, Money};
+    use domain::extra;
+    use domain::product;
+    use domain::ticket::Repository;
+
+    use super::*;
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn create_method_works(pool: PgPool) {
+        let cheddar_id = extra::Id::parse_str("0190ec13-15cc-7f53-bc0f-d60f0beea824")
+            .expect("Cheddar id from seed fixtures");
+        let ticket = ticket::Ticket::new(mocked_product(), &[cheddar_id]).expect("Valid ticket");
+
+        let mut repository = PgTickets::new(pool);
+        let result = repository.create(&ticket).await;
+        assert!(result.is_ok());
+
+        let created = repository.find(ticket.id()).await.expect("Created ticket");
+        assert_eq!(created.status(), ticket::Status::Received);
+        assert_eq!(created.extras_ids(), &[cheddar_id]);
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn delete_method_works(pool: PgPool) {
+        let id = ticket::Id::parse_str("0190fb20-6d4e-7a83-b1c9-3e5f7a9c1d2b")
+            .expect("Valid ticket id from fixtures");
+
+        let result = PgTickets::new(pool).delete(id).await;
+        assert!(matches!(result, Ok(ticket) if ticket.id() == id));
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn find_with_not_found(pool: PgPool) {
+        use ticket::{Error, NotFoundKind};
+
+        let id = ticket::Id::parse_str("0190fb30-1c2d-7e3f-8a4b-5c6d7e8f9a0b")
+            .expect("Valid ticket id not in fixtures");
+
+        let result = PgTickets::new(pool).find(id).await;
+        assert!(matches!(result, Err(Error::NotFound(NotFoundKind::Id(err_id))) if err_id == id));
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn list_method_works(pool: PgPool) {
+        use std::num::{NonZeroU32, NonZeroU8};
+
+        let query = ticket::ListQuery {
+            page: NonZeroU32::new(1).unwrap(),
+            limit: NonZeroU8::new(10).unwrap(),
+            status: Some(ticket::Status::Preparing),
+        };
+
+        let pagination = PgTickets::new(pool)
+            .list(query.clone())
+            .await
+            .expect("Paginated ticket list");
+
+        assert_eq!(pagination.count, 1);
+        assert_eq!(pagination.items.len(), 1);
+        assert_eq!(pagination.items[0].status(), ticket::Status::Preparing);
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn update_method_works(pool: PgPool) {
+        let id = ticket::Id::parse_str("0190fb21-2a7c-7f15-8d3e-9b0c4e6a8f1d")
+            .expect("Valid ticket id from fixtures");
+
+        let mut repository = PgTickets::new(pool);
+        let mut ticket = repository.find(id).await.expect("Found ticket");
+        ticket
+            .transition(ticket::Status::Ready)
+            .expect("Valid ticket transition");
+
+        let result = repository.update(&ticket, ticket::Status::Preparing).await;
+        assert!(result.is_ok());
+
+        let updated = repository.find(id).await.expect("Updated ticket");
+        assert_eq!(updated.status(), ticket::Status::Ready);
+        assert!(updated.timeline().ready_at.is_some());
+        assert!(updated.timeline().preparing_at.is_some());
+    }
+
+    #[sqlx::test(fixtures("./db/fixtures/seed.sql"))]
+    async fn update_with_status_conflict(pool: PgPool) {
+        use ticket::{ConflictKind, Error};
+
+        let id = ticket::Id::parse_str("0190fb21-2a7c-7f15-8d3e-9b0c4e6a8f1d")
+            .expect("Valid ticket id from fixtures");
+
+        let mut repository = PgTickets::new(pool);
+        let mut ticket = repository.find(id).await.expect("Found ticket");
+        let mut stale = ticket.clone();
+        ticket
+            .transition(ticket::Status::Ready)
+            .expect("Valid ticket transition");
+        repository
+            .update(&ticket, ticket::Status::Preparing)
+            .await
+            .expect("Updated ticket");
+
+        stale
+            .transition(ticket::Status::Cancelled)
+            .expect("Valid ticket transition");
+        let result = repository.update(&stale, ticket::Status::Preparing).await;
+        assert!(matches!(
+            result,
+            Err(Error::Conflict(ConflictKind::Status {
+                id: err_id,
+                expected: ticket::Status::Preparing
+            })) if err_id == id
+        ));
+    }
+
+    fn mocked_product() -> product::Product {
+        let cheddar = extra::Extra::config(extra::ExtraConfig {
+            id: extra::Id::parse_str("0190ec13-15cc-7f53-bc0f-d60f0beea824")
+                .expect("Cheddar id from seed fixtures"),
+            name: extra::Name::new("Cheddar").expect("Valid extra name"),
+            price: Money::from_minor(200, Currency::BRL),
+            catalog_id: None,
+            category: None,
+            metadata: metadata::Metadata::new(),
+        });
+
+        product::Product::config(product::ProductConfig {
+            id: product::Id::parse_str("0190ec14-0af8-71d1-9554-f1e5249ae3a2")
+                .expect("Valid product id from fixtures"),
+            catalog_id: catalog::Id::parse_str("0190ec30-286b-7211-aadb-003fc0449734")
+                .expect("Valid catalog id from fixtures"),
+            name: product::Name::new("Cheese Burger").expect("Valid product name"),
+            price: Money::from_minor(2000, Currency::BRL),
+            kind: product::Kind::Burger,
+            extras: Some(product::Extras::new(vec![cheddar]).expect("Valid extras")),
+            variants: None,
+            modifiers: None,
+            available: true,
+            schedule: None,
+            metadata: metadata::Metadata::new(),
+        })
+    }
+}
@@ -0,0 +1,26 @@
+insert into catalog (id, name, description, created_at, updated_at)
+values
+    ('0190ec30-286b-7211-aadb-003fc0449734', 'Burgers', null, now(), now());
+
+insert into product (id, catalog_id, name, price, kind, created_at, updated_at)
+values
+    ('0190ec14-0af8-71d1-9554-f1e5249ae3a2', '0190ec30-286b-7211-aadb-003fc0449734', 'Cheese Burger', 20.00, 'burger', now(), now());
+
+insert into extra (id, name, price, created_at, updated_at)
+values
+    ('0190ec10-4aa7-7552-ba8f-df997d9f8a8e', 'Hot Sauce', 1.50, now(), now()),
+    ('0190ec13-15cc-7f53-bc0f-d60f0beea824', 'Cheddar', 2.00, now(), now());
+
+insert into product_extras (product_id, extra_id)
+values
+    ('0190ec14-0af8-71d1-9554-f1e5249ae3a2', '0190ec10-4aa7-7552-ba8f-df997d9f8a8e'),
+    ('0190ec14-0af8-71d1-9554-f1e5249ae3a2', '0190ec13-15cc-7f53-bc0f-d60f0beea824');
+
+insert into ticket (id, product_id, status, received_at, preparing_at, created_at, updated_at)
+values
+    ('0190fb20-6d4e-7a83-b1c9-3e5f7a9c1d2b', '0190ec14-0af8-71d1-9554-f1e5249ae3a2', 'received', now(), null, now(), now()),
+    ('0190fb21-2a7c-7f15-8d3e-9b0c4e6a8f1d', '0190ec14-0af8-71d1-9554-f1e5249ae3a2', 'preparing', now(), now(), now(), now());
+
+insert into ticket_extra (ticket_id, product_id, extra_id)
+values
+    ('0190fb20-6d4e-7a83-b1c9-3e5f7a9c1d2b', '0190ec14-0af8-71d1-9554-f1e5249ae3a2', '0190ec13-15cc-7f53-bc0f-d60f0beea824');
@@ -0,0 +1,238 @@
+use sqlx::PgExecutor;
+
+use domain::extra;
+use domain::ticket;
+
+use crate::infra::ticket::TicketWithProductModel;
+
+#[derive(Clone, Debug)]
+pub(super) struct CountQuery {
+    pub(super) status: Option<ticket::Status>,
+}
+
+impl CountQuery {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'_>) -> Result<u64, sqlx::Error> {
+        let sql = include_str!("./sql/count.sql");
+        let count: i64 = sqlx::query_scalar(sql)
+            .bind(self.status.map(ticket::Status::as_str))
+            .fetch_one(exec)
+            .await?;
+
+        Ok(u64::try_from(count).unwrap_or_default())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct CreateQuery<'a> {
+    pub(super) ticket: &'a ticket::Ticket,
+}
+
+impl<'a> CreateQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
+        let timeline = self.ticket.timeline();
+        let sql = include_str!("./sql/create.sql");
+        sqlx::query(sql)
+            .bind(self.ticket.id().uuid())
+            .bind(self.ticket.product().id().uuid())
+            .bind(self.ticket.status().as_str())
+            .bind(timeline.received_at)
+            .bind(timeline.preparing_at)
+            .bind(timeline.ready_at)
+            .bind(timeline.handed_over_at)
+            .bind(timeline.cancelled_at)
+            .bind(self.ticket.metadata.created_at())
+            .bind(self.ticket.metadata.updated_at())
+            .execute(exec)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct CreateExtrasQuery<'a> {
+    pub(super) ticket: &'a ticket::Ticket,
+}
+
+impl<'a> CreateExtrasQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
+        let extras_ids = self
+            .ticket
+            .extras_ids()
+            .iter()
+            .map(extra::Id::uuid)
+            .collect::<Vec<_>>();
+
+        let sql = include_str!("./sql/extras_create.sql");
+        sqlx::query(sql)
+            .bind(self.ticket.id().uuid())
+            .bind(self.ticket.product().id().uuid())
+            .bind(extras_ids)
+            .execute(exec)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct DeleteQuery {
+    pub(super) id: ticket::Id,
+}
+
+impl DeleteQuery {
+    pub(super) async fn exec(
+        self,
+        exec: impl PgExecutor<'_>,
+    ) -> Result<TicketWithProductModel, sqlx::Error> {
+        let sql = include_str!("./sql/delete.sql");
+        sqlx::query_as(sql)
+            .bind(self.id.uuid())
+            .fetch_one(exec)
+            .await
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct DeleteExtrasQuery {
+    pub(super) id: ticket::Id,
+}
+
+impl DeleteExtrasQuery {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'_>) -> Result<(), sqlx::Error> {
+        let sql = include_str!("./sql/extras_delete.sql");
+        sqlx::query(sql).bind(self.id.uuid()).execute(exec).await?;
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct FindQuery {
+    pub(super) id: ticket::Id,
+}
+
+impl FindQuery {
+    pub(super) async fn exec(
+        self,
+        exec: impl PgExecutor<'_>,
+    ) -> Result<TicketWithProductModel, sqlx::Error> {
+        let sql = include_str!("./sql/find.sql");
+        sqlx::query_as(sql)
+            .bind(self.id.uuid())
+            .fetch_one(exec)
+            .await
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct ListQuery(pub(super) ticket::ListQuery);
+
+impl ListQuery {
+    pub(super) async fn exec(
+        self,
+        exec: impl PgExecutor<'_>,
+    ) -> Result<Vec<TicketWithProductModel>, sqlx::Error> {
+        let limit = u8::from(self.0.limit);
+        let page = u32::from(self.0.page);
+        let offset = page.saturating_sub(1) * u32::from(limit);
+
+        let sql = include_str!("./sql/list.sql");
+        sqlx::query_as(sql)
+            .bind(self.0.status.map(ticket::Status::as_str))
+            .bind(i64::from(limit))
+            .bind(i64::from(offset))
+            .fetch_all(exec)
+            .await
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct UpdateQuery<'a> {
+    pub(super) ticket: &'a ticket::Ticket,
+    pub(super) status: ticket::Status,
+}
+
+impl<'a> UpdateQuery<'a> {
+    pub(super) async fn exec(self, exec: impl PgExecutor<'a>) -> Result<(), sqlx::Error> {
+        let timeline = self.ticket.timeline();
+        let sql = include_str!("./sql/update.sql");
+        let result = sqlx::query(sql)
+            .bind(self.ticket.status().as_str())
+            .bind(timeline.preparing_at)
+            .bind(timeline.ready_at)
+            .bind(timeline.handed_over_at)
+            .bind(timeline.cancelled_at)
+            .bind(self.ticket.metadata.updated_at())
+            .bind(self.ticket.id().uuid())
+            .bind(self.status.as_str())
+            .execute(exec)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(sqlx::Error::RowNotFound);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::{NonZeroU32, NonZeroU8};
+
+    use sqlx::PgPool;
+
+    use super::*;
+
+    #[sqlx::test(fixtures("seed"))]
+    async fn count_query_works(pool: PgPool) {
+        let result = CountQuery { status: None }.exec(&pool).await;
+        assert_eq!(result.ok(), Some(2u64));
+
+        let status = Some(ticket::Status::Preparing);
+        let result = CountQuery { status }.exec(&pool).await;
+        assert_eq!(result.ok(), Some(1u64));
+    }
+
+    #[sqlx::test(fixtures("seed"))]
+    async fn delete_extras_query_works(pool: PgPool) {
+        let id = ticket::Id::parse_str("0190fb20-6d4e-7a83-b1c9-3e5f7a9c1d2b")
+            .expect("Valid ticket id from fixtures");
+
+        let result = DeleteExtrasQuery { id }.exec(&pool).await;
+        assert!(result.is_ok());
+
+        let found = FindQuery { id }
+            .exec(&pool)
+            .await
+            .expect("Found ticket model");
+        assert!(found.extras_ids.is_empty());
+    }
+
+    #[sqlx::test(fixtures("seed"))]
+    async fn find_query_works(pool: PgPool) {
+        let id = ticket::Id::parse_str("0190fb20-6d4e-7a83-b1c9-3e5f7a9c1d2b")
+            .expect("Valid ticket id from fixtures");
+
+        let found = FindQuery { id }
+            .exec(&pool)
+            .await
+            .expect("Found ticket model");
+        assert_eq!(found.id, id.uuid());
+        assert_eq!(found.status, "received");
+        assert_eq!(found.extras_ids.len(), 1);
+    }
+
+    #[sqlx::test(fixtures("seed"))]
+    async fn list_query_works(pool: PgPool) {
+        let query = ticket::ListQuery {
+            page: NonZeroU32::new(1).unwrap(),
+            limit: NonZeroU8::new(10).unwrap(),
+            status: Some(ticket::Status::Received),
+        };
+
+        let models = ListQuery(query).exec(&pool).await.expect("Ticket models");
+        assert_eq!(models.len(), 1);
+        assert_eq!(models[0].status, "received");
+    }
+}
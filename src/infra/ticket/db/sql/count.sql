@@ -0,0 +1,3 @@
+select count(ticket.id)
+from ticket
+where $1::varchar is null or ticket.status = $1
\ No newline at end of file
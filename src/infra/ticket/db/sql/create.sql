@@ -0,0 +1,13 @@
+insert into ticket (
+    id,
+    product_id,
+    status,
+    received_at,
+    preparing_at,
+    ready_at,
+    handed_over_at,
+    cancelled_at,
+    created_at,
+    updated_at
+)
+values ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
\ No newline at end of file
@@ -0,0 +1,3 @@
+delete from ticket_with_product as ticket
+where ticket.id = $1
+returning ticket.*
\ No newline at end of file
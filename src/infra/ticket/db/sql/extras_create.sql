@@ -0,0 +1,2 @@
+insert into ticket_extra (ticket_id, product_id, extra_id)
+select $1, $2, * from unnest($3::uuid[])
\ No newline at end of file
@@ -0,0 +1,2 @@
+delete from ticket_extra as te
+where te.ticket_id = $1
\ No newline at end of file
@@ -0,0 +1,3 @@
+select ticket.*
+from ticket_with_product as ticket
+where ticket.id = $1
\ No newline at end of file
@@ -0,0 +1,5 @@
+select ticket.*
+from ticket_with_product as ticket
+where $1::varchar is null or ticket.status = $1
+order by ticket.received_at asc
+limit $2 offset $3
\ No newline at end of file
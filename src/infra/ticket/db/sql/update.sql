@@ -0,0 +1,10 @@
+update ticket
+set
+    status = $1,
+    preparing_at = $2,
+    ready_at = $3,
+    handed_over_at = $4,
+    cancelled_at = $5,
+    updated_at = $6
+where ticket.id = $7
+and ticket.status = $8
\ No newline at end of file
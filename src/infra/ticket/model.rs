@@ -0,0 +1,59 @@
+use serde::Deserialize;
+use sqlx::types::{Json, Uuid};
+use sqlx::FromRow;
+use time::OffsetDateTime;
+
+use domain::core::metadata;
+use domain::extra;
+use domain::ticket;
+
+use crate::infra::product::ProductModel;
+
+#[derive(Clone, Debug, Deserialize, FromRow)]
+pub struct TicketWithProductModel {
+    pub id: Uuid,
+    pub status: String,
+    pub product: Json<ProductModel>,
+    pub extras_ids: Json<Vec<Uuid>>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub received_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub preparing_at: Option<OffsetDateTime>,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub ready_at: Option<OffsetDateTime>,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub handed_over_at: Option<OffsetDateTime>,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub cancelled_at: Option<OffsetDateTime>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime,
+}
+
+impl TicketWithProductModel {
+    pub fn try_into_entity(self) -> Result<ticket::Ticket, Box<dyn std::error::Error>> {
+        let status = ticket::Status::parse_str(&self.status)?;
+        let product = self.product.0.try_into_entity()?;
+        let extras_ids = self.extras_ids.0.into_iter().map(extra::Id::from).collect();
+        let timeline = ticket::Timeline {
+            received_at: self.received_at,
+            preparing_at: self.preparing_at,
+            ready_at: self.ready_at,
+            handed_over_at: self.handed_over_at,
+            cancelled_at: self.cancelled_at,
+        };
+
+        let metadata = metadata::Metadata::configured(self.created_at, self.updated_at)?;
+        let ticket = ticket::Ticket::config(ticket::TicketConfig {
+            id: ticket::Id::from(self.id),
+            product,
+            extras_ids,
+            status,
+            timeline,
+            metadata,
+        });
+
+        Ok(ticket)
+    }
+}
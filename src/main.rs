@@ -6,19 +6,45 @@
 mod app;
 mod infra;
 
+use std::time::Duration;
+
 use axum::routing;
 use axum::Router;
 use sqlx::postgres::PgPoolOptions;
 use sqlx::PgPool;
+use time::OffsetDateTime;
 use tokio::net::TcpListener;
 
+use domain::core::money::Currency;
+use domain::hold::Repository as _;
+
+use crate::app::cart::api as cart_api;
 use crate::app::catalog::api as catalog_api;
+use crate::app::exchange::api as exchange_api;
 use crate::app::extra::api as extra_api;
+use crate::app::happy_hour::api as happy_hour_api;
+use crate::app::hold::api as hold_api;
+use crate::app::ingredient::api as ingredient_api;
+use crate::app::order::api as order_api;
+use crate::app::payment::api as payment_api;
 use crate::app::product::api as product_api;
+use crate::app::promotion::api as promotion_api;
+use crate::app::sale::api as sale_api;
+use crate::app::stock::api as stock_api;
+use crate::app::tax::api as tax_api;
+use crate::app::ticket::api as ticket_api;
+use crate::infra::{AnyPaymentProvider, PgHolds};
 
 #[derive(Clone, Debug)]
 pub struct Context {
     pool: PgPool,
+    /// Currency catalog prices are registered in, used as base to convert
+    /// them into other currencies
+    base_currency: Currency,
+    /// Gateway charging checkouts, shared so its state outlives requests.
+    /// Without one, every other route still works but payments are
+    /// unavailable
+    payment_provider: Option<AnyPaymentProvider>,
 }
 
 #[tokio::main]
@@ -32,43 +58,244 @@ async fn main() {
         .await
         .expect("Connection to postgres database");
 
-    let context = Context { pool };
-
-    let app = Router::new()
-        .nest(
-            "/api",
-            Router::new()
-                .route(
-                    "/catalogs",
-                    routing::get(catalog_api::list).post(catalog_api::create),
-                )
-                .route(
-                    "/catalogs/:id",
-                    routing::delete(catalog_api::delete)
-                        .get(catalog_api::find)
-                        .put(catalog_api::update),
-                )
-                .route(
-                    "/catalogs/:catalog_id/products",
-                    routing::post(product_api::create),
-                )
-                .route(
-                    "/catalogs/:catalog_id/products/:id",
-                    routing::delete(product_api::delete)
-                        .get(product_api::find)
-                        .put(product_api::update),
-                )
-                .route(
-                    "/extras",
-                    routing::get(extra_api::all).post(extra_api::create),
-                )
-                .route(
-                    "/extras/:id",
-                    routing::delete(extra_api::delete).put(extra_api::update),
-                ),
-        )
-        .with_state(context);
+    let base_currency = std::env::var("SHOP_BASE_CURRENCY").map_or(Currency::BRL, |currency| {
+        Currency::parse_str(&currency).expect("Valid base currency")
+    });
+
+    // providers must be asked for explicitly so a deployment never charges
+    // through the fake one by accident
+    let payment_provider = std::env::var("SHOP_PAYMENT_PROVIDER").ok().map(|name| {
+        AnyPaymentProvider::from_name(&name)
+            .expect("SHOP_PAYMENT_PROVIDER naming a supported payment provider, only `fake` so far")
+    });
+
+    tokio::spawn(release_expired_holds(pool.clone()));
+
+    let context = Context {
+        pool,
+        base_currency,
+        payment_provider,
+    };
+
+    let app = Router::new().nest("/api", api_router()).with_state(context);
 
     let listener = TcpListener::bind("0.0.0.0:3000").await.unwrap();
     axum::serve(listener, app).await.unwrap();
 }
+
+/// Periodically deletes expired holds, so their units become available again
+async fn release_expired_holds(pool: PgPool) {
+    let mut holds = PgHolds::new(pool);
+    let mut interval = tokio::time::interval(Duration::from_secs(30));
+
+    loop {
+        interval.tick().await;
+        if let Err(err) = holds.release_expired(OffsetDateTime::now_utc()).await {
+            eprintln!("Release expired holds error: {err:?}");
+        }
+    }
+}
+
+fn api_router() -> Router<Context> {
+    Router::new()
+        .merge(catalog_router())
+        .merge(product_router())
+        .merge(sale_router())
+}
+
+/// Routes managing what is sold and how it is priced
+fn catalog_router() -> Router<Context> {
+    Router::new()
+        .route(
+            "/catalogs",
+            routing::get(catalog_api::list).post(catalog_api::create),
+        )
+        .route("/catalogs/active", routing::get(catalog_api::active))
+        .route(
+            "/catalogs/:catalog_id/happy-hours",
+            routing::get(happy_hour_api::all).post(happy_hour_api::create),
+        )
+        .route(
+            "/catalogs/:catalog_id/happy-hours/:id",
+            routing::delete(happy_hour_api::delete)
+                .get(happy_hour_api::find)
+                .put(happy_hour_api::update),
+        )
+        .route(
+            "/catalogs/:id",
+            routing::delete(catalog_api::delete)
+                .get(catalog_api::find)
+                .put(catalog_api::update),
+        )
+        .route(
+            "/exchange-rates",
+            routing::get(exchange_api::list).post(exchange_api::upload),
+        )
+        .route(
+            "/extras",
+            routing::get(extra_api::list).post(extra_api::create),
+        )
+        .route(
+            "/extras/:id",
+            routing::delete(extra_api::delete)
+                .get(extra_api::find)
+                .put(extra_api::update),
+        )
+        .route("/extras/:id/products", routing::get(extra_api::products))
+        .route(
+            "/extras/:id/recipe",
+            routing::get(ingredient_api::extra_recipe).put(ingredient_api::set_extra_recipe),
+        )
+        .route(
+            "/promotions",
+            routing::get(promotion_api::all).post(promotion_api::create),
+        )
+        .route(
+            "/promotions/:id",
+            routing::delete(promotion_api::delete)
+                .get(promotion_api::find)
+                .put(promotion_api::update),
+        )
+        .route(
+            "/tax-rules",
+            routing::get(tax_api::all).post(tax_api::create),
+        )
+        .route(
+            "/tax-rules/:id",
+            routing::delete(tax_api::delete)
+                .get(tax_api::find)
+                .put(tax_api::update),
+        )
+}
+
+/// Routes managing catalog products along with their stock and ingredients
+fn product_router() -> Router<Context> {
+    Router::new()
+        .route("/products", routing::get(product_api::list))
+        .route(
+            "/catalogs/:catalog_id/products",
+            routing::get(product_api::list_in_catalog).post(product_api::create),
+        )
+        .route(
+            "/catalogs/:catalog_id/products/:id",
+            routing::delete(product_api::delete)
+                .get(product_api::find)
+                .put(product_api::update),
+        )
+        .route(
+            "/catalogs/:catalog_id/products/:id/consumptions",
+            routing::post(ingredient_api::consume),
+        )
+        .route(
+            "/catalogs/:catalog_id/products/:id/holds",
+            routing::post(hold_api::create),
+        )
+        .route(
+            "/catalogs/:catalog_id/products/:id/modifiers",
+            routing::get(product_api::modifiers).post(product_api::create_modifier),
+        )
+        .route(
+            "/catalogs/:catalog_id/products/:id/modifiers/:modifier_id",
+            routing::delete(product_api::delete_modifier)
+                .get(product_api::find_modifier)
+                .put(product_api::update_modifier),
+        )
+        .route(
+            "/catalogs/:catalog_id/products/:id/quote",
+            routing::post(product_api::quote),
+        )
+        .route(
+            "/catalogs/:catalog_id/products/:id/recipe",
+            routing::get(ingredient_api::product_recipe).put(ingredient_api::set_product_recipe),
+        )
+        .route(
+            "/catalogs/:catalog_id/products/:id/selection",
+            routing::post(product_api::validate_selection),
+        )
+        .route(
+            "/catalogs/:catalog_id/products/:id/stock",
+            routing::get(stock_api::find).put(stock_api::set),
+        )
+        .route(
+            "/catalogs/:catalog_id/products/:id/stock/consume",
+            routing::post(stock_api::consume),
+        )
+        .route(
+            "/catalogs/:catalog_id/products/:id/stock/increment",
+            routing::post(stock_api::increment),
+        )
+        .route(
+            "/catalogs/:catalog_id/products/:id/variants",
+            routing::get(product_api::variants).post(product_api::create_variant),
+        )
+        .route(
+            "/catalogs/:catalog_id/products/:id/variants/:variant_id",
+            routing::delete(product_api::delete_variant)
+                .get(product_api::find_variant)
+                .put(product_api::update_variant),
+        )
+        .route(
+            "/holds/:id",
+            routing::delete(hold_api::delete).get(hold_api::find),
+        )
+        .route(
+            "/ingredients",
+            routing::get(ingredient_api::all).post(ingredient_api::create),
+        )
+        .route(
+            "/ingredients/:id",
+            routing::delete(ingredient_api::delete)
+                .get(ingredient_api::find)
+                .put(ingredient_api::update),
+        )
+}
+
+/// Routes selling and charging for catalog products
+fn sale_router() -> Router<Context> {
+    Router::new()
+        .route("/carts", routing::post(cart_api::create))
+        .route(
+            "/carts/:id",
+            routing::delete(cart_api::delete).get(cart_api::find),
+        )
+        .route("/carts/:id/items", routing::post(cart_api::add_item))
+        .route(
+            "/carts/:id/items/:item_id",
+            routing::delete(cart_api::remove_item).put(cart_api::update_item),
+        )
+        .route("/checkout", routing::post(payment_api::checkout))
+        .route(
+            "/orders",
+            routing::get(order_api::list).post(order_api::create),
+        )
+        .route(
+            "/orders/:id",
+            routing::delete(order_api::delete).get(order_api::find),
+        )
+        .route("/payments/:id", routing::get(payment_api::find))
+        .route("/payments/:id/refund", routing::post(payment_api::refund))
+        .route("/payments/:id/void", routing::post(payment_api::void))
+        .route(
+            "/sales",
+            routing::get(sale_api::list).post(sale_api::create),
+        )
+        .route("/sales/:id", routing::get(sale_api::find))
+        .route(
+            "/sales/:id/refunds",
+            routing::get(sale_api::refunds).post(sale_api::refund),
+        )
+        .route(
+            "/tickets",
+            routing::get(ticket_api::list).post(ticket_api::create),
+        )
+        .route(
+            "/tickets/:id",
+            routing::delete(ticket_api::delete)
+                .get(ticket_api::find)
+                .put(ticket_api::update),
+        )
+        .route(
+            "/tickets/:id/transitions",
+            routing::post(ticket_api::transition),
+        )
+}